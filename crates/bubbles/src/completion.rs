@@ -0,0 +1,565 @@
+//! Completion menu that drops down under a text input.
+//!
+//! This module provides a [`Completion`] component pairing a [`TextInput`]
+//! with a menu of candidates fuzzy-filtered by what has been typed, for
+//! command palettes and path completion. The menu is rendered inline below
+//! the input, indented to line up with the text after the prompt.
+//!
+//! While the menu is open, up and down move the selection, scrolling once
+//! it reaches the edge, tab or enter accepts the selected candidate, and
+//! esc closes the menu. Every other key edits the input and filters the
+//! candidates again. Accepting replaces the input's value and emits a
+//! [`CompletionSelectedMsg`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use bubbles::completion::Completion;
+//! use bubbletea::{KeyMsg, Message};
+//!
+//! let mut completion = Completion::new().items(["open file", "open folder", "quit"]);
+//! completion.focus();
+//! completion.update(Message::new(KeyMsg::from_char('o')));
+//! completion.update(Message::new(KeyMsg::from_char('f')));
+//!
+//! let matches: Vec<&str> = completion.matches().collect();
+//! assert_eq!(matches, ["open file", "open folder"]);
+//! ```
+
+use crate::key::{Binding, matches};
+use crate::textinput::TextInput;
+use bubbletea::{Cmd, KeyMsg, Message, Model};
+use lipgloss::{Color, Style};
+
+/// Message sent when a candidate is accepted from the menu.
+///
+/// The input's value has already been replaced with the candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionSelectedMsg {
+    /// The accepted candidate.
+    pub value: String,
+    /// Index of the candidate in the full item list.
+    pub index: usize,
+}
+
+/// Key bindings for the completion menu.
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    /// Select the previous candidate.
+    pub prev: Binding,
+    /// Select the next candidate.
+    pub next: Binding,
+    /// Accept the selected candidate.
+    pub accept: Binding,
+    /// Close the menu.
+    pub close: Binding,
+    /// Open the menu without typing.
+    pub open: Binding,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self {
+            prev: Binding::new().keys(&["up", "ctrl+p"]).help("↑", "prev"),
+            next: Binding::new().keys(&["down", "ctrl+n"]).help("↓", "next"),
+            accept: Binding::new()
+                .keys(&["tab", "enter"])
+                .help("tab", "complete"),
+            close: Binding::new().keys(&["esc"]).help("esc", "close"),
+            open: Binding::new()
+                .keys(&["ctrl+@"])
+                .help("ctrl+space", "complete"),
+        }
+    }
+}
+
+/// Styles for the completion menu.
+#[derive(Debug, Clone)]
+pub struct Styles {
+    /// Style for unselected candidates.
+    pub item: Style,
+    /// Style for the selected candidate.
+    pub selected: Style,
+    /// Style for the characters that matched the typed text.
+    pub matched: Style,
+    /// Style for the matched characters of the selected candidate.
+    pub selected_matched: Style,
+    /// Style for the scroll indicator shown when candidates are hidden.
+    pub more: Style,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            item: Style::new().foreground_color(Color::from("250")),
+            selected: Style::new()
+                .foreground_color(Color::from("230"))
+                .background_color(Color::from("62")),
+            matched: Style::new().foreground_color(Color::from("212")).bold(),
+            selected_matched: Style::new()
+                .foreground_color(Color::from("212"))
+                .background_color(Color::from("62"))
+                .bold(),
+            more: Style::new().foreground_color(Color::from("240")),
+        }
+    }
+}
+
+/// A candidate that matched the typed text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Match {
+    /// Index into the item list.
+    index: usize,
+    /// Character positions of the matched characters.
+    positions: Vec<usize>,
+}
+
+/// Text input with a dropdown completion menu.
+#[derive(Debug, Clone)]
+pub struct Completion {
+    /// The input being completed.
+    pub input: TextInput,
+    /// Key bindings.
+    pub key_map: KeyMap,
+    /// Styles.
+    pub styles: Styles,
+    /// Maximum number of candidates shown at once.
+    pub height: usize,
+    /// Candidates offered for completion.
+    items: Vec<String>,
+    /// Candidates matching the input, best first.
+    matches: Vec<Match>,
+    /// Selected position in `matches`.
+    selected: usize,
+    /// First visible position in `matches`.
+    offset: usize,
+    /// Whether the menu is shown.
+    open: bool,
+}
+
+impl Default for Completion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Completion {
+    /// Creates a completion menu with no candidates.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            input: TextInput::new(),
+            key_map: KeyMap::default(),
+            styles: Styles::default(),
+            height: 6,
+            items: Vec::new(),
+            matches: Vec::new(),
+            selected: 0,
+            offset: 0,
+            open: false,
+        }
+    }
+
+    /// Sets the candidates.
+    #[must_use]
+    pub fn items<I, S>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.set_items(items);
+        self
+    }
+
+    /// Sets the maximum number of candidates shown at once.
+    #[must_use]
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = height.max(1);
+        self
+    }
+
+    /// Replaces the candidates, such as the entries of the directory being
+    /// typed during path completion, and filters them again.
+    pub fn set_items<I, S>(&mut self, items: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        self.refilter();
+    }
+
+    /// Returns the input's current value.
+    #[must_use]
+    pub fn value(&self) -> String {
+        self.input.value()
+    }
+
+    /// Returns the candidates matching the input, best first.
+    pub fn matches(&self) -> impl Iterator<Item = &str> {
+        self.matches.iter().map(|m| self.items[m.index].as_str())
+    }
+
+    /// Returns the selected candidate, if the menu has any.
+    #[must_use]
+    pub fn selected(&self) -> Option<&str> {
+        self.matches
+            .get(self.selected)
+            .map(|m| self.items[m.index].as_str())
+    }
+
+    /// Returns whether the menu is shown.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.open && !self.matches.is_empty()
+    }
+
+    /// Shows the menu, listing every candidate when nothing is typed.
+    pub fn open(&mut self) {
+        self.open = true;
+        self.refilter();
+    }
+
+    /// Hides the menu until the input changes.
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Focuses the input.
+    pub fn focus(&mut self) -> Option<Cmd> {
+        self.input.focus()
+    }
+
+    /// Blurs the input and hides the menu.
+    pub fn blur(&mut self) {
+        self.input.blur();
+        self.open = false;
+    }
+
+    /// Selects the previous candidate.
+    pub fn select_prev(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.offset = self.offset.min(self.selected);
+        }
+    }
+
+    /// Selects the next candidate.
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.matches.len() {
+            self.selected += 1;
+            if self.selected >= self.offset + self.height {
+                self.offset = self.selected + 1 - self.height;
+            }
+        }
+    }
+
+    /// Filters the candidates by the input's value, keeping the selection
+    /// on the same candidate when it still matches.
+    fn refilter(&mut self) {
+        let previous = self.matches.get(self.selected).map(|m| m.index);
+        let query: Vec<char> = self.input.value().to_lowercase().chars().collect();
+
+        let mut scored: Vec<(usize, Match)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| {
+                let positions = fuzzy_positions(item, &query)?;
+                let score = positions.last().map_or(0, |last| last - positions[0])
+                    + positions.first().copied().unwrap_or(0);
+                Some((score, Match { index, positions }))
+            })
+            .collect();
+        scored.sort_by_key(|(score, _)| *score);
+        self.matches = scored.into_iter().map(|(_, m)| m).collect();
+
+        self.selected = previous
+            .and_then(|index| self.matches.iter().position(|m| m.index == index))
+            .unwrap_or(0);
+        self.offset = self
+            .offset
+            .min(self.selected)
+            .max((self.selected + 1).saturating_sub(self.height))
+            .min(self.matches.len().saturating_sub(self.height));
+    }
+
+    /// Replaces the input's value with the selected candidate.
+    fn accept(&mut self) -> Option<Cmd> {
+        let index = self.matches.get(self.selected)?.index;
+        let value = self.items[index].clone();
+        self.input.set_value(&value);
+        self.input.cursor_end();
+        self.open = false;
+        Some(Cmd::new(move || {
+            Message::new(CompletionSelectedMsg { value, index })
+        }))
+    }
+
+    /// Updates the input and menu based on messages.
+    pub fn update(&mut self, msg: Message) -> Option<Cmd> {
+        if !self.input.focused() {
+            return None;
+        }
+
+        if let Some(key) = msg.downcast_ref::<KeyMsg>() {
+            let key_str = key.to_string();
+            if self.is_open() {
+                if matches(&key_str, &[&self.key_map.prev]) {
+                    self.select_prev();
+                    return None;
+                }
+                if matches(&key_str, &[&self.key_map.next]) {
+                    self.select_next();
+                    return None;
+                }
+                if matches(&key_str, &[&self.key_map.accept]) {
+                    return self.accept();
+                }
+                if matches(&key_str, &[&self.key_map.close]) {
+                    self.close();
+                    return None;
+                }
+            } else if matches(&key_str, &[&self.key_map.open]) {
+                self.open();
+                return None;
+            }
+        }
+
+        let before = self.input.value();
+        let cmd = self.input.update(msg);
+        if self.input.value() != before {
+            self.open = true;
+            self.refilter();
+        }
+        cmd
+    }
+
+    /// Renders the input with the menu below it when open.
+    #[must_use]
+    pub fn view(&self) -> String {
+        let mut out = self.input.view();
+        if !self.is_open() {
+            return out;
+        }
+
+        let indent = " ".repeat(lipgloss::width(&self.input.prompt));
+        let end = (self.offset + self.height).min(self.matches.len());
+        let visible = &self.matches[self.offset..end];
+        let width = visible
+            .iter()
+            .map(|m| lipgloss::width(&self.items[m.index]))
+            .max()
+            .unwrap_or(0);
+
+        for (i, m) in visible.iter().enumerate() {
+            let item = &self.items[m.index];
+            let (style, matched) = if self.offset + i == self.selected {
+                (&self.styles.selected, &self.styles.selected_matched)
+            } else {
+                (&self.styles.item, &self.styles.matched)
+            };
+            let padded = format!(" {item}{} ", " ".repeat(width - lipgloss::width(item)));
+            let positions: Vec<usize> = m.positions.iter().map(|p| p + 1).collect();
+            out.push('\n');
+            out.push_str(&indent);
+            out.push_str(&lipgloss::style_runes(
+                &padded,
+                &positions,
+                matched.clone(),
+                style.clone(),
+            ));
+        }
+
+        let hidden = self.matches.len() - visible.len();
+        if hidden > 0 {
+            out.push('\n');
+            out.push_str(&indent);
+            out.push_str(&self.styles.more.render(&format!(
+                " {}/{}",
+                self.selected + 1,
+                self.matches.len()
+            )));
+        }
+        out
+    }
+}
+
+/// Returns the character positions of `query`'s characters within `item`,
+/// matched in order and ignoring case, or `None` when they don't all occur.
+///
+/// Each query character matches its earliest occurrence after the previous
+/// one. `query` must already be lowercase.
+fn fuzzy_positions(item: &str, query: &[char]) -> Option<Vec<usize>> {
+    let mut positions = Vec::with_capacity(query.len());
+    let mut chars = item.chars().enumerate();
+    for &q in query {
+        let (i, _) = chars
+            .by_ref()
+            .find(|(_, c)| c.to_lowercase().eq(std::iter::once(q)))?;
+        positions.push(i);
+    }
+    Some(positions)
+}
+
+impl Model for Completion {
+    /// Initialize the completion menu.
+    fn init(&self) -> Option<Cmd> {
+        Model::init(&self.input)
+    }
+
+    /// Update the completion menu based on incoming messages.
+    fn update(&mut self, msg: Message) -> Option<Cmd> {
+        Completion::update(self, msg)
+    }
+
+    /// Render the completion menu.
+    fn view(&self) -> String {
+        Completion::view(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bubbletea::KeyType;
+
+    fn type_str(completion: &mut Completion, text: &str) {
+        for c in text.chars() {
+            completion.update(Message::new(KeyMsg::from_char(c)));
+        }
+    }
+
+    fn press(completion: &mut Completion, key_type: KeyType) -> Option<Cmd> {
+        completion.update(Message::new(KeyMsg::from_type(key_type)))
+    }
+
+    /// Returns a focused menu of git commands, rendered without styling.
+    fn menu() -> Completion {
+        let mut completion = Completion::new().items([
+            "checkout",
+            "cherry-pick",
+            "commit",
+            "clone",
+            "config",
+            "status",
+        ]);
+        completion.styles = Styles {
+            item: Style::new(),
+            selected: Style::new(),
+            matched: Style::new(),
+            selected_matched: Style::new(),
+            more: Style::new(),
+        };
+        completion.focus();
+        completion
+    }
+
+    #[test]
+    fn test_fuzzy_positions() {
+        assert_eq!(
+            fuzzy_positions("Cherry-Pick", &['c', 'p']),
+            Some(vec![0, 7])
+        );
+        assert_eq!(fuzzy_positions("clone", &['x']), None);
+        assert_eq!(fuzzy_positions("clone", &[]), Some(vec![]));
+    }
+
+    #[test]
+    fn test_completion_filters_fuzzily_best_first() {
+        let mut completion = menu();
+        assert!(!completion.is_open());
+
+        type_str(&mut completion, "co");
+        assert!(completion.is_open());
+        let matches: Vec<&str> = completion.matches().collect();
+        assert_eq!(matches, ["commit", "config", "clone", "checkout"]);
+
+        type_str(&mut completion, "n");
+        let matches: Vec<&str> = completion.matches().collect();
+        assert_eq!(matches, ["config", "clone"]);
+
+        type_str(&mut completion, "z");
+        assert!(!completion.is_open());
+    }
+
+    #[test]
+    fn test_completion_accept_replaces_value() {
+        let mut completion = menu();
+        type_str(&mut completion, "ch");
+        assert_eq!(completion.selected(), Some("checkout"));
+        press(&mut completion, KeyType::Down);
+        assert_eq!(completion.selected(), Some("cherry-pick"));
+
+        let msg = press(&mut completion, KeyType::Tab)
+            .unwrap()
+            .execute()
+            .unwrap();
+        assert_eq!(
+            msg.downcast_ref::<CompletionSelectedMsg>(),
+            Some(&CompletionSelectedMsg {
+                value: "cherry-pick".to_string(),
+                index: 1,
+            })
+        );
+        assert_eq!(completion.value(), "cherry-pick");
+        assert!(!completion.is_open());
+    }
+
+    #[test]
+    fn test_completion_scrolls_and_closes() {
+        let mut completion = menu().height(2);
+        press(&mut completion, KeyType::Null);
+        assert!(completion.is_open());
+        assert_eq!(completion.matches().count(), 6);
+
+        for _ in 0..3 {
+            press(&mut completion, KeyType::Down);
+        }
+        assert_eq!(completion.selected(), Some("clone"));
+        let view = completion.view();
+        assert!(view.contains("commit"));
+        assert!(view.contains("clone"));
+        assert!(!view.contains("cherry-pick"));
+        assert!(view.contains("4/6"));
+
+        press(&mut completion, KeyType::Up);
+        press(&mut completion, KeyType::Up);
+        assert!(completion.view().contains("cherry-pick"));
+
+        press(&mut completion, KeyType::Esc);
+        assert!(!completion.is_open());
+        assert_eq!(completion.view(), completion.input.view());
+    }
+
+    #[test]
+    fn test_completion_keeps_selection_while_typing() {
+        let mut completion = menu();
+        type_str(&mut completion, "c");
+        press(&mut completion, KeyType::Down);
+        press(&mut completion, KeyType::Down);
+        assert_eq!(completion.selected(), Some("commit"));
+
+        type_str(&mut completion, "m");
+        assert_eq!(completion.selected(), Some("commit"));
+    }
+
+    #[test]
+    fn test_completion_menu_anchors_under_text() {
+        let mut completion = menu();
+        type_str(&mut completion, "st");
+        let view = completion.view();
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1], "   status ");
+    }
+
+    #[test]
+    fn test_completion_ignores_keys_when_blurred() {
+        let mut completion = menu();
+        completion.blur();
+        type_str(&mut completion, "co");
+        assert_eq!(completion.value(), "");
+        assert!(!completion.is_open());
+    }
+}
@@ -0,0 +1,284 @@
+//! Debouncing and throttling of bursty messages.
+//!
+//! Some events arrive in bursts, such as keystrokes in a search box or
+//! resizes while a window is dragged, while the work they cause only needs
+//! doing once the burst settles, or at most so often. [`Debounce`] waits for
+//! a pause in the triggers before its message arrives; [`Throttle`] lets a
+//! message through at most once per interval. In both, the model triggers
+//! on each event and does the work when [`ready`](Debounce::ready) accepts
+//! the message that follows.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bubbles::debounce::Debounce;
+//! use bubbletea::{Cmd, KeyMsg, Message};
+//! use std::time::Duration;
+//!
+//! struct Search {
+//!     query: String,
+//!     debounce: Debounce,
+//! }
+//!
+//! impl Search {
+//!     fn update(&mut self, msg: Message) -> Option<Cmd> {
+//!         if let Some(key) = msg.downcast_ref::<KeyMsg>() {
+//!             self.query.extend(&key.runes);
+//!             // Search once typing pauses for 300ms
+//!             return Some(self.debounce.trigger());
+//!         }
+//!         if self.debounce.ready(&msg) {
+//!             // run the search for self.query
+//!         }
+//!         None
+//!     }
+//! }
+//!
+//! let search = Search {
+//!     query: String::new(),
+//!     debounce: Debounce::new(Duration::from_millis(300)),
+//! };
+//! ```
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use bubbletea::{Clock, Cmd, Message, system_clock, tick_with_clock};
+
+/// Global ID counter for debounce and throttle instances.
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+fn next_id() -> u64 {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Message sent when a [`Debounce`] wait ends.
+#[derive(Debug, Clone, Copy)]
+pub struct DebounceMsg {
+    /// The debounce ID.
+    pub id: u64,
+    /// Trigger the wait was started by.
+    tag: u64,
+}
+
+/// Delays a message until triggers pause for a set time.
+///
+/// Each [`trigger`](Self::trigger) restarts the wait, so only the message
+/// of the last trigger in a burst is [`ready`](Self::ready).
+#[derive(Debug, Clone)]
+pub struct Debounce {
+    /// How long triggers must pause.
+    duration: Duration,
+    /// Unique ID.
+    id: u64,
+    /// Tag of the latest trigger.
+    tag: u64,
+    /// Clock that waits run on.
+    clock: Arc<dyn Clock>,
+}
+
+impl Debounce {
+    /// Creates a debounce that waits for a pause of `duration`.
+    #[must_use]
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            id: next_id(),
+            tag: 0,
+            clock: system_clock(),
+        }
+    }
+
+    /// Sets the clock that waits run on.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the debounce's unique ID.
+    #[must_use]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns how long triggers must pause.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Starts the wait over, superseding earlier triggers.
+    ///
+    /// The returned command delivers a [`DebounceMsg`] after the wait.
+    #[must_use]
+    pub fn trigger(&mut self) -> Cmd {
+        self.tag = self.tag.wrapping_add(1);
+        let id = self.id;
+        let tag = self.tag;
+        tick_with_clock(Arc::clone(&self.clock), self.duration, move |_| {
+            Message::new(DebounceMsg { id, tag })
+        })
+    }
+
+    /// Returns whether `msg` ends the wait of the latest trigger.
+    #[must_use]
+    pub fn ready(&self, msg: &Message) -> bool {
+        msg.downcast_ref::<DebounceMsg>()
+            .is_some_and(|msg| msg.id == self.id && msg.tag == self.tag)
+    }
+}
+
+/// Message sent when a [`Throttle`] lets a trigger through.
+#[derive(Debug, Clone, Copy)]
+pub struct ThrottleMsg {
+    /// The throttle ID.
+    pub id: u64,
+}
+
+/// Lets triggers through at most once per interval.
+///
+/// A trigger in a quiet period comes through at once. Triggers within the
+/// interval of the last one are merged into a single message at the end of
+/// the interval, so the last event of a burst is never dropped.
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    /// Shortest time between messages.
+    interval: Duration,
+    /// Unique ID.
+    id: u64,
+    /// When the last message was accepted.
+    last: Option<Instant>,
+    /// Whether a message is on its way.
+    pending: bool,
+    /// Clock that waits run on.
+    clock: Arc<dyn Clock>,
+}
+
+impl Throttle {
+    /// Creates a throttle that lets one message through per `interval`.
+    #[must_use]
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            id: next_id(),
+            last: None,
+            pending: false,
+            clock: system_clock(),
+        }
+    }
+
+    /// Sets the clock that waits run on.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the throttle's unique ID.
+    #[must_use]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the shortest time between messages.
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Requests a [`ThrottleMsg`], returning the command that delivers it,
+    /// or `None` if one is already on its way.
+    pub fn trigger(&mut self) -> Option<Cmd> {
+        if self.pending {
+            return None;
+        }
+        self.pending = true;
+
+        let id = self.id;
+        let wait = self.last.map_or(Duration::ZERO, |last| {
+            let since = self.clock.now().saturating_duration_since(last);
+            self.interval.saturating_sub(since)
+        });
+        if wait.is_zero() {
+            return Some(Cmd::new(move || Message::new(ThrottleMsg { id })));
+        }
+        Some(tick_with_clock(Arc::clone(&self.clock), wait, move |_| {
+            Message::new(ThrottleMsg { id })
+        }))
+    }
+
+    /// Returns whether `msg` is this throttle's message, starting the next
+    /// interval if so.
+    pub fn ready(&mut self, msg: &Message) -> bool {
+        let ours = msg
+            .downcast_ref::<ThrottleMsg>()
+            .is_some_and(|msg| msg.id == self.id);
+        if !ours || !self.pending {
+            return false;
+        }
+        self.pending = false;
+        self.last = Some(self.clock.now());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bubbletea::TestClock;
+
+    #[test]
+    fn test_debounce_keeps_only_latest_trigger() {
+        let clock = TestClock::new();
+        let mut debounce =
+            Debounce::new(Duration::from_millis(300)).with_clock(Arc::new(clock.clone()));
+
+        let first = debounce.trigger();
+        let second = debounce.trigger();
+        let first = first.execute().unwrap();
+        let second = second.execute().unwrap();
+
+        assert!(!debounce.ready(&first));
+        assert!(debounce.ready(&second));
+        assert_eq!(clock.elapsed(), Duration::from_millis(600));
+    }
+
+    #[test]
+    fn test_debounce_ignores_other_instances() {
+        let mut a = Debounce::new(Duration::ZERO);
+        let b = Debounce::new(Duration::ZERO);
+        let msg = a.trigger().execute().unwrap();
+        assert!(a.ready(&msg));
+        assert!(!b.ready(&msg));
+        assert!(!a.ready(&Message::new(())));
+    }
+
+    #[test]
+    fn test_throttle_merges_triggers_within_interval() {
+        let clock = TestClock::new();
+        let mut throttle =
+            Throttle::new(Duration::from_secs(1)).with_clock(Arc::new(clock.clone()));
+
+        // The first trigger comes through at once
+        let msg = throttle.trigger().unwrap().execute().unwrap();
+        assert!(throttle.ready(&msg));
+        assert_eq!(clock.elapsed(), Duration::ZERO);
+
+        // Triggers 200ms later wait out the rest of the interval, as one
+        clock.advance(Duration::from_millis(200));
+        let cmd = throttle.trigger().unwrap();
+        assert!(throttle.trigger().is_none());
+        let msg = cmd.execute().unwrap();
+        assert!(throttle.ready(&msg));
+        assert!(!throttle.ready(&msg));
+        assert_eq!(clock.elapsed(), Duration::from_secs(1));
+
+        // After a quiet interval, triggers come through at once again
+        clock.advance(Duration::from_secs(5));
+        let msg = throttle.trigger().unwrap().execute().unwrap();
+        assert!(throttle.ready(&msg));
+        assert_eq!(clock.elapsed(), Duration::from_secs(6));
+    }
+}
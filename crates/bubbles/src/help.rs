@@ -84,6 +84,8 @@ pub struct Help {
     pub ellipsis: String,
     /// Styles for rendering.
     pub styles: Styles,
+    /// Width below which full help falls back to the short view (0 = never).
+    pub collapse_width: usize,
     /// Key bindings for standalone Model usage.
     bindings: Vec<Binding>,
 }
@@ -105,6 +107,7 @@ impl Help {
             full_separator: "    ".to_string(),
             ellipsis: "…".to_string(),
             styles: Styles::default(),
+            collapse_width: 0,
             bindings: Vec::new(),
         }
     }
@@ -141,18 +144,126 @@ impl Help {
         self
     }
 
+    /// Sets the width below which full help collapses to the short view.
+    #[must_use]
+    pub fn collapse_below(mut self, width: usize) -> Self {
+        self.collapse_width = width;
+        self
+    }
+
+    /// Returns whether full help is currently shown, taking the collapse
+    /// width into account.
+    #[must_use]
+    pub fn showing_full_help(&self) -> bool {
+        self.show_all && !(self.width > 0 && self.width < self.collapse_width)
+    }
+
     /// Renders the help view for a list of bindings.
     ///
-    /// Displays short help if `show_all` is false, full help otherwise.
+    /// Displays full help laid out in columns when `show_all` is set and the
+    /// view is wide enough, short help otherwise.
     #[must_use]
     pub fn view(&self, bindings: &[&Binding]) -> String {
-        if self.show_all {
-            self.full_help_view(&[bindings.to_vec()])
+        if self.showing_full_help() {
+            self.flowed_help_view(bindings)
         } else {
             self.short_help_view(bindings)
         }
     }
 
+    /// Renders the help view for only those bindings active in `mode`.
+    ///
+    /// Bindings restricted to other modes (see [`Binding::modes`]) are left
+    /// out, so a component can keep one keymap and switch help by mode.
+    #[must_use]
+    pub fn view_in_mode(&self, bindings: &[&Binding], mode: &str) -> String {
+        let active: Vec<&Binding> = bindings
+            .iter()
+            .copied()
+            .filter(|b| b.active_in(mode))
+            .collect();
+        self.view(&active)
+    }
+
+    /// Renders help for a [`KeyMap`], limited to the bindings active in the
+    /// keymap's current [`mode`](KeyMap::mode).
+    ///
+    /// Shows the keymap's full help when `show_all` is set and the view is
+    /// wide enough, its short help otherwise.
+    #[must_use]
+    pub fn key_map_view<K: KeyMap + ?Sized>(&self, keymap: &K) -> String {
+        let mode = keymap.mode();
+        let active = |b: &&Binding| mode.is_none_or(|mode| b.active_in(mode));
+        if self.showing_full_help() {
+            let groups = keymap.full_help();
+            let refs: Vec<Vec<&Binding>> = groups
+                .iter()
+                .map(|group| group.iter().filter(active).collect())
+                .collect();
+            self.full_help_view(&refs)
+        } else {
+            let bindings = keymap.short_help();
+            let refs: Vec<&Binding> = bindings.iter().filter(active).collect();
+            self.short_help_view(&refs)
+        }
+    }
+
+    /// Renders full help for a flat list of bindings, flowing them into as
+    /// many balanced columns as fit within the width.
+    ///
+    /// Bindings fill each column top to bottom. With no width set the
+    /// bindings form a single column; if even a single column is too wide,
+    /// descriptions are truncated with the ellipsis.
+    #[must_use]
+    pub fn flowed_help_view(&self, bindings: &[&Binding]) -> String {
+        let items: Vec<&Binding> = bindings
+            .iter()
+            .copied()
+            .filter(|b| {
+                let help = b.get_help();
+                b.enabled() && (!help.key.is_empty() || !help.desc.is_empty())
+            })
+            .collect();
+        if items.is_empty() {
+            return String::new();
+        }
+        if self.width == 0 {
+            return self.full_help_view(&[items]);
+        }
+
+        let sep_width = self.full_separator.width();
+        for rows in 1..=items.len() {
+            let groups: Vec<Vec<&Binding>> = items.chunks(rows).map(<[_]>::to_vec).collect();
+            let total: usize = groups
+                .iter()
+                .map(|group| sep_width + column_width(group))
+                .sum();
+            if total <= self.width {
+                return self.full_help_view(&groups);
+            }
+        }
+
+        // A single column is still too wide: shorten the descriptions.
+        let key_width = items
+            .iter()
+            .map(|b| b.get_help().key.width())
+            .max()
+            .unwrap_or(0);
+        let desc_width = self
+            .width
+            .saturating_sub(sep_width + key_width + 1)
+            .max(self.ellipsis.width());
+        let truncated: Vec<Binding> = items
+            .iter()
+            .map(|b| {
+                let help = b.get_help();
+                let desc = truncate_with_ellipsis(&help.desc, desc_width, &self.ellipsis);
+                (*b).clone().help(help.key.clone(), desc)
+            })
+            .collect();
+        self.full_help_view(&[truncated.iter().collect()])
+    }
+
     /// Renders short help from a list of bindings.
     #[must_use]
     pub fn short_help_view(&self, bindings: &[&Binding]) -> String {
@@ -259,8 +370,12 @@ impl Help {
             let max_desc_width = descs.iter().map(|d| d.width()).max().unwrap_or(0);
             let col_width = self.full_separator.width() + max_key_width + 1 + max_desc_width;
 
-            // Check width limit
+            // Check width limit, marking the overflow with an ellipsis
             if self.width > 0 && total_width + col_width > self.width {
+                let tail_width = 1 + self.ellipsis.width();
+                if total_width + tail_width <= self.width {
+                    columns.push(format!(" {}", self.styles.ellipsis.render(&self.ellipsis)));
+                }
                 break;
             }
 
@@ -283,6 +398,41 @@ fn should_render_column(bindings: &[&Binding]) -> bool {
     bindings.iter().any(|b| b.enabled())
 }
 
+/// Returns the width of a full help column, excluding the separator.
+fn column_width(bindings: &[&Binding]) -> usize {
+    let key_width = bindings
+        .iter()
+        .map(|b| b.get_help().key.width())
+        .max()
+        .unwrap_or(0);
+    let desc_width = bindings
+        .iter()
+        .map(|b| b.get_help().desc.width())
+        .max()
+        .unwrap_or(0);
+    key_width + 1 + desc_width
+}
+
+/// Shortens `s` to at most `width` columns, ending it with `ellipsis` if cut.
+fn truncate_with_ellipsis(s: &str, width: usize, ellipsis: &str) -> String {
+    if s.width() <= width {
+        return s.to_string();
+    }
+    let budget = width.saturating_sub(ellipsis.width());
+    let mut out = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+        if used + w > budget {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.push_str(ellipsis);
+    out
+}
+
 /// Trait for types that can provide key bindings for help display.
 pub trait KeyMap {
     /// Returns bindings for short help display.
@@ -290,6 +440,14 @@ pub trait KeyMap {
 
     /// Returns groups of bindings for full help display.
     fn full_help(&self) -> Vec<Vec<Binding>>;
+
+    /// Returns the component's current mode, if it has modes.
+    ///
+    /// When set, [`Help::key_map_view`] only shows bindings active in this
+    /// mode. The default has no modes, so every enabled binding is shown.
+    fn mode(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// Implement the Model trait for standalone bubbletea usage.
@@ -324,11 +482,7 @@ impl Model for Help {
     fn view(&self) -> String {
         // Use stored bindings for standalone Model view
         let binding_refs: Vec<&Binding> = self.bindings.iter().collect();
-        if self.show_all {
-            self.full_help_view(&[binding_refs])
-        } else {
-            self.short_help_view(&binding_refs)
-        }
+        Help::view(self, &binding_refs)
     }
 }
 
@@ -419,6 +573,78 @@ mod tests {
         assert!(should_render_column(&[&disabled, &enabled]));
     }
 
+    fn numbered_bindings(count: usize) -> Vec<Binding> {
+        (0..count)
+            .map(|i| {
+                Binding::new()
+                    .keys(&[&i.to_string()])
+                    .help(i.to_string(), format!("action {i}"))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_help_flowed_view_balances_columns() {
+        let bindings = numbered_bindings(6);
+        let refs: Vec<&Binding> = bindings.iter().collect();
+
+        // Each column is 4 (separator) + 1 + 1 + 8 = 14 wide, so three fit
+        let view = Help::new().width(45).flowed_help_view(&refs);
+        assert_eq!(view.lines().count(), 2);
+        for line in view.lines() {
+            assert!(lipgloss::width(line) <= 45, "line too wide: {line:?}");
+        }
+
+        let narrow = Help::new().width(30).flowed_help_view(&refs);
+        assert_eq!(narrow.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_help_flowed_view_without_width_is_single_column() {
+        let bindings = numbered_bindings(4);
+        let refs: Vec<&Binding> = bindings.iter().collect();
+        let view = Help::new().flowed_help_view(&refs);
+        assert_eq!(view.lines().count(), 4);
+    }
+
+    #[test]
+    fn test_help_flowed_view_truncates_overflow() {
+        let long = Binding::new()
+            .keys(&["x"])
+            .help("x", "a description far too long for the pane");
+        let help = Help::new().width(20);
+        let view = help.flowed_help_view(&[&long]);
+        assert!(view.ends_with('…'), "view: {view:?}");
+        assert!(lipgloss::width(&view) <= 20);
+    }
+
+    #[test]
+    fn test_help_full_view_marks_dropped_columns() {
+        let help = Help::new().width(30);
+        let b1 = Binding::new().keys(&["a"]).help("a", "first");
+        let b2 = Binding::new().keys(&["b"]).help("b", "second");
+        let b3 = Binding::new().keys(&["c"]).help("c", "third");
+
+        let view = help.full_help_view(&[vec![&b1], vec![&b2], vec![&b3]]);
+        assert!(view.contains('…'), "view: {view:?}");
+        assert!(!view.contains("third"));
+    }
+
+    #[test]
+    fn test_help_collapses_to_short_view_when_narrow() {
+        let bindings = numbered_bindings(4);
+        let refs: Vec<&Binding> = bindings.iter().collect();
+        let help = Help::new().show_all(true).collapse_below(40);
+
+        assert!(help.clone().width(60).showing_full_help());
+        assert!(!help.clone().width(30).showing_full_help());
+        assert!(help.clone().showing_full_help());
+
+        let view = help.width(30).view(&refs);
+        assert!(!view.contains('\n'));
+        assert!(view.contains(" • "));
+    }
+
     // Model trait tests
 
     #[test]
@@ -736,4 +962,82 @@ mod tests {
         assert!(view.contains("a very long description"));
         assert!(view.contains("another very long description"));
     }
+
+    struct ListKeys {
+        filtering: bool,
+        up: Binding,
+        filter: Binding,
+        clear: Binding,
+    }
+
+    impl ListKeys {
+        fn new() -> Self {
+            Self {
+                filtering: false,
+                up: Binding::new().keys(&["k"]).help("k", "up"),
+                filter: Binding::new()
+                    .keys(&["/"])
+                    .help("/", "filter")
+                    .modes(&["normal"]),
+                clear: Binding::new()
+                    .keys(&["esc"])
+                    .help("esc", "clear filter")
+                    .modes(&["filtering"]),
+            }
+        }
+    }
+
+    impl KeyMap for ListKeys {
+        fn short_help(&self) -> Vec<Binding> {
+            vec![self.up.clone(), self.filter.clone(), self.clear.clone()]
+        }
+
+        fn full_help(&self) -> Vec<Vec<Binding>> {
+            vec![
+                vec![self.up.clone()],
+                vec![self.filter.clone(), self.clear.clone()],
+            ]
+        }
+
+        fn mode(&self) -> Option<&str> {
+            Some(if self.filtering {
+                "filtering"
+            } else {
+                "normal"
+            })
+        }
+    }
+
+    #[test]
+    fn test_help_view_in_mode() {
+        let help = Help::new();
+        let keys = ListKeys::new();
+        let bindings = [&keys.up, &keys.filter, &keys.clear];
+
+        let normal = help.view_in_mode(&bindings, "normal");
+        assert!(normal.contains("up"));
+        assert!(normal.contains("filter"));
+        assert!(!normal.contains("clear filter"));
+
+        let filtering = help.view_in_mode(&bindings, "filtering");
+        assert!(filtering.contains("up"));
+        assert!(filtering.contains("clear filter"));
+        assert!(!filtering.contains("/ filter"));
+    }
+
+    #[test]
+    fn test_help_key_map_view_follows_mode() {
+        let mut keys = ListKeys::new();
+        let help = Help::new();
+        assert!(!help.key_map_view(&keys).contains("clear filter"));
+
+        keys.filtering = true;
+        let short = help.key_map_view(&keys);
+        assert!(short.contains("clear filter"));
+        assert!(!short.contains("/ filter"));
+
+        let full = Help::new().show_all(true).key_map_view(&keys);
+        assert!(full.contains("clear filter"));
+        assert!(!full.contains("/"));
+    }
 }
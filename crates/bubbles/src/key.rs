@@ -47,12 +47,15 @@ impl Help {
 /// A keybinding with associated help text.
 ///
 /// Bindings can be enabled/disabled and contain zero or more key sequences
-/// that trigger the binding.
+/// that trigger the binding. A binding may also be restricted to a set of
+/// component modes (such as `"normal"` or `"filtering"`) so that the help view
+/// and key matching only consider it while the component is in one of them.
 #[derive(Debug, Clone, Default)]
 pub struct Binding {
     keys: Vec<String>,
     help: Help,
     disabled: bool,
+    modes: Vec<String>,
 }
 
 impl Binding {
@@ -104,6 +107,28 @@ impl Binding {
         self
     }
 
+    /// Restricts the binding to the given modes.
+    ///
+    /// A binding with no modes is active in every mode.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bubbles::key::Binding;
+    ///
+    /// let clear = Binding::new()
+    ///     .keys(&["esc"])
+    ///     .help("esc", "clear filter")
+    ///     .modes(&["filtering"]);
+    /// assert!(clear.active_in("filtering"));
+    /// assert!(!clear.active_in("normal"));
+    /// ```
+    #[must_use]
+    pub fn modes(mut self, modes: &[&str]) -> Self {
+        self.modes = modes.iter().map(|&s| s.to_string()).collect();
+        self
+    }
+
     /// Sets the keys for this binding (mutable version).
     pub fn set_keys(&mut self, keys: &[&str]) {
         self.keys = keys.iter().map(|&s| s.to_string()).collect();
@@ -126,6 +151,23 @@ impl Binding {
         &self.help
     }
 
+    /// Sets the modes this binding is restricted to (mutable version).
+    pub fn set_modes(&mut self, modes: &[&str]) {
+        self.modes = modes.iter().map(|&s| s.to_string()).collect();
+    }
+
+    /// Returns the modes this binding is restricted to.
+    #[must_use]
+    pub fn get_modes(&self) -> &[String] {
+        &self.modes
+    }
+
+    /// Returns whether this binding is enabled and applies in `mode`.
+    #[must_use]
+    pub fn active_in(&self, mode: &str) -> bool {
+        self.enabled() && (self.modes.is_empty() || self.modes.iter().any(|m| m == mode))
+    }
+
     /// Returns whether this binding is enabled.
     ///
     /// A binding is enabled if it's not explicitly disabled and has at least one key.
@@ -187,6 +229,27 @@ pub fn matches<K: fmt::Display>(key: K, bindings: &[&Binding]) -> bool {
     false
 }
 
+/// Checks if the given key matches any of the bindings active in `mode`.
+///
+/// Like [`matches`], but bindings restricted to other modes are skipped.
+///
+/// # Example
+///
+/// ```rust
+/// use bubbles::key::{Binding, matches_in_mode};
+///
+/// let clear = Binding::new().keys(&["esc"]).modes(&["filtering"]);
+///
+/// assert!(matches_in_mode("esc", "filtering", &[&clear]));
+/// assert!(!matches_in_mode("esc", "normal", &[&clear]));
+/// ```
+pub fn matches_in_mode<K: fmt::Display>(key: K, mode: &str, bindings: &[&Binding]) -> bool {
+    let key_str = key.to_string();
+    bindings
+        .iter()
+        .any(|b| b.active_in(mode) && b.keys.contains(&key_str))
+}
+
 /// Checks if the given key matches a single binding.
 ///
 /// Convenience function for matching against a single binding.
@@ -194,6 +257,36 @@ pub fn matches_one<K: fmt::Display>(key: K, binding: &Binding) -> bool {
     matches(key, &[binding])
 }
 
+/// Extends a numeric prefix, such as the `42` of `42G`, with a typed key.
+///
+/// Returns the new count when `key` is a digit, or `None` for any other
+/// key. A `0` only continues a count, so it stays free for other bindings
+/// when nothing has been typed yet.
+///
+/// # Example
+///
+/// ```rust
+/// use bubbles::key::count_digit;
+///
+/// let count = count_digit("4", None);
+/// assert_eq!(count, Some(4));
+/// assert_eq!(count_digit("2", count), Some(42));
+/// assert_eq!(count_digit("0", None), None);
+/// assert_eq!(count_digit("G", count), None);
+/// ```
+pub fn count_digit<K: fmt::Display>(key: K, count: Option<usize>) -> Option<usize> {
+    let key = key.to_string();
+    let mut chars = key.chars();
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+    let digit = c.to_digit(10)? as usize;
+    if digit == 0 && count.is_none() {
+        return None;
+    }
+    Some(count.unwrap_or(0).saturating_mul(10).saturating_add(digit))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -282,4 +375,34 @@ mod tests {
         assert!(matches_one("ctrl+c", &quit));
         assert!(!matches_one("x", &quit));
     }
+
+    #[test]
+    fn test_binding_modes() {
+        let any = Binding::new().keys(&["q"]);
+        assert!(any.active_in("normal"));
+        assert!(any.active_in("filtering"));
+
+        let mut clear = Binding::new().keys(&["esc"]).modes(&["filtering"]);
+        assert!(clear.active_in("filtering"));
+        assert!(!clear.active_in("normal"));
+
+        clear.enable(false);
+        assert!(!clear.active_in("filtering"));
+
+        clear.set_modes(&[]);
+        clear.enable(true);
+        assert!(clear.get_modes().is_empty());
+        assert!(clear.active_in("normal"));
+    }
+
+    #[test]
+    fn test_matches_in_mode() {
+        let up = Binding::new().keys(&["k"]).modes(&["normal"]);
+        let clear = Binding::new().keys(&["esc"]).modes(&["filtering"]);
+
+        assert!(matches_in_mode("k", "normal", &[&up, &clear]));
+        assert!(!matches_in_mode("k", "filtering", &[&up, &clear]));
+        assert!(matches_in_mode("esc", "filtering", &[&up, &clear]));
+        assert!(!matches_in_mode("esc", "normal", &[&up, &clear]));
+    }
 }
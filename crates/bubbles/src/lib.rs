@@ -12,16 +12,20 @@
 //!
 //! Bubbles provides ready-to-use components including:
 //! - **cursor** - Text cursor with blinking support
-//! - **spinner** - Animated loading indicators with multiple styles
+//! - **spinner** - Animated loading indicators with multiple styles, shown
+//!   while tracked commands are pending
 //! - **timer** - Countdown timer with timeout notifications
 //! - **stopwatch** - Elapsed time tracking
+//! - **debounce** - Debouncing and throttling of bursty messages
 //! - **paginator** - Pagination for lists and tables
-//! - **progress** - Progress bar with gradient and animation support
+//! - **progress** - Progress bar with gradient and animation support, plus
+//!   multi-bar groups for concurrent tasks
 //! - **viewport** - Scrollable content viewport
 //! - **help** - Help view for displaying key bindings
 //! - **key** - Key binding definitions and matching
 //! - **runeutil** - Input sanitization utilities
 //! - **textinput** - Single-line text input with suggestions
+//! - **completion** - Dropdown completion menu under a text input
 //! - **textarea** - Multi-line text editor
 //! - **table** - Data table with keyboard navigation
 //! - **list** - Feature-rich filterable list
@@ -36,7 +40,9 @@
 //! let tick_msg = spinner.tick();
 //! ```
 
+pub mod completion;
 pub mod cursor;
+pub mod debounce;
 pub mod help;
 pub mod key;
 pub mod paginator;
@@ -56,13 +62,15 @@ pub mod table;
 
 /// Prelude module for convenient imports.
 pub mod prelude {
+    pub use crate::completion::{Completion, CompletionSelectedMsg};
     pub use crate::cursor::{Cursor, Mode as CursorMode, blink_cmd};
+    pub use crate::debounce::{Debounce, Throttle};
     pub use crate::help::Help;
     pub use crate::key::{Binding, Help as KeyHelp, matches};
     pub use crate::paginator::{Paginator, Type as PaginatorType};
-    pub use crate::progress::Progress;
+    pub use crate::progress::{PercentagePosition, Progress, ProgressGroup, TaskStatus};
     pub use crate::runeutil::Sanitizer;
-    pub use crate::spinner::{Spinner, SpinnerModel, spinners};
+    pub use crate::spinner::{Activity, Spinner, SpinnerModel, spinners};
     pub use crate::stopwatch::Stopwatch;
     pub use crate::textarea::TextArea;
     pub use crate::textinput::TextInput;
@@ -72,5 +80,5 @@ pub mod prelude {
     // Complex components
     pub use crate::filepicker::{DirEntry, FilePicker, ReadDirErrMsg, ReadDirMsg};
     pub use crate::list::{DefaultDelegate, FilterState, Item, ItemDelegate, List};
-    pub use crate::table::{Column, Row, Table};
+    pub use crate::table::{Column, Row, SortOrder, Table};
 }
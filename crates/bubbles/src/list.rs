@@ -3,6 +3,22 @@
 //! This module provides a list widget with optional filtering, pagination,
 //! help display, status messages, and spinner for TUI applications.
 //!
+//! With [`List::reordering`] enabled, the selected item can be grabbed,
+//! moved up and down with the cursor keys, and dropped; the list then
+//! emits a [`ReorderMsg`] with the item's old and new positions.
+//!
+//! [`List::goto_line`] and [`List::goto_percent`] jump to an item and show
+//! its page. Navigation keys take a numeric prefix, as in pagers: `42G`
+//! selects the 42nd item and `5j` moves five items down.
+//!
+//! `/` starts filtering. Items are matched fuzzily as the filter is typed,
+//! so `bna` finds `Banana`, and are ordered best match first, with the
+//! matched characters underlined by the [`DefaultDelegate`]. The status
+//! bar counts the items shown and those filtered out, and the pages are
+//! shown as dots, or as `2/9` when the dots don't fit. Use
+//! [`List::with_filter`] to match items another way, such as
+//! [`default_filter`] for plain substrings.
+//!
 //! # Example
 //!
 //! ```rust
@@ -29,12 +45,14 @@
 //! ```
 
 use crate::help::Help;
-use crate::key::{Binding, matches};
+use crate::key::{Binding, count_digit, matches};
 use crate::paginator::Paginator;
+use crate::paginator::Type;
 use crate::spinner::{SpinnerModel, TickMsg};
 use crate::textinput::TextInput;
 use bubbletea::{Cmd, KeyMsg, Message, Model, MouseAction, MouseButton, MouseMsg};
-use lipgloss::{Color, Style};
+use lipgloss::{Color, Style, style_runes};
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Trait for items that can be displayed in a list.
@@ -54,6 +72,21 @@ pub trait ItemDelegate<I: Item>: Clone + Send + 'static {
     /// Renders an item.
     fn render(&self, item: &I, index: usize, selected: bool, width: usize) -> String;
 
+    /// Renders an item while the list is filtered, `matches` holding the
+    /// indices of the characters of its filter value that match the filter.
+    /// Defaults to [`render`](Self::render), ignoring the matches.
+    fn render_with_matches(
+        &self,
+        item: &I,
+        index: usize,
+        selected: bool,
+        width: usize,
+        matches: &[usize],
+    ) -> String {
+        let _ = matches;
+        self.render(item, index, selected, width)
+    }
+
     /// Updates the delegate (optional).
     fn update(&mut self, _msg: &Message, _item: &mut I) -> Option<Cmd> {
         None
@@ -67,6 +100,10 @@ pub struct DefaultDelegate {
     pub normal_style: Style,
     /// Style for selected items.
     pub selected_style: Style,
+    /// Style for characters matching the filter in normal items.
+    pub matched_style: Style,
+    /// Style for characters matching the filter in selected items.
+    pub selected_matched_style: Style,
     /// Height of each item.
     pub item_height: usize,
     /// Spacing between items.
@@ -83,9 +120,12 @@ impl DefaultDelegate {
     /// Creates a new default delegate.
     #[must_use]
     pub fn new() -> Self {
+        let selected_style = Style::new().foreground_color(Color::from("212")).bold();
         Self {
             normal_style: Style::new(),
-            selected_style: Style::new().foreground_color(Color::from("212")).bold(),
+            matched_style: Style::new().underline(),
+            selected_matched_style: selected_style.clone().underline(),
+            selected_style,
             item_height: 1,
             item_spacing: 0,
         }
@@ -116,31 +156,7 @@ impl<I: Item> ItemDelegate<I> for DefaultDelegate {
     }
 
     fn render(&self, item: &I, _index: usize, selected: bool, width: usize) -> String {
-        let value = item.filter_value();
-
-        // Truncate based on display width
-        let truncated = {
-            use unicode_width::UnicodeWidthStr;
-            if UnicodeWidthStr::width(value) <= width {
-                value.to_string()
-            } else if width == 0 {
-                String::new()
-            } else {
-                let target_width = width.saturating_sub(1);
-                let mut current_width = 0;
-                let mut result = String::new();
-
-                for c in value.chars() {
-                    let w = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-                    if current_width + w > target_width {
-                        break;
-                    }
-                    result.push(c);
-                    current_width += w;
-                }
-                format!("{}…", result)
-            }
-        };
+        let truncated = truncate(item.filter_value(), width);
 
         if selected {
             self.selected_style.render(&truncated)
@@ -148,6 +164,50 @@ impl<I: Item> ItemDelegate<I> for DefaultDelegate {
             self.normal_style.render(&truncated)
         }
     }
+
+    fn render_with_matches(
+        &self,
+        item: &I,
+        index: usize,
+        selected: bool,
+        width: usize,
+        matches: &[usize],
+    ) -> String {
+        if matches.is_empty() {
+            return self.render(item, index, selected, width);
+        }
+        let truncated = truncate(item.filter_value(), width);
+        let (matched, unmatched) = if selected {
+            (&self.selected_matched_style, &self.selected_style)
+        } else {
+            (&self.matched_style, &self.normal_style)
+        };
+        style_runes(&truncated, matches, matched.clone(), unmatched.clone())
+    }
+}
+
+/// Truncates `value` to `width` display columns, ending it with `…` if cut.
+fn truncate(value: &str, width: usize) -> String {
+    use unicode_width::UnicodeWidthStr;
+    if UnicodeWidthStr::width(value) <= width {
+        value.to_string()
+    } else if width == 0 {
+        String::new()
+    } else {
+        let target_width = width.saturating_sub(1);
+        let mut current_width = 0;
+        let mut result = String::new();
+
+        for c in value.chars() {
+            let w = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+            if current_width + w > target_width {
+                break;
+            }
+            result.push(c);
+            current_width += w;
+        }
+        format!("{}…", result)
+    }
 }
 
 /// Represents a match rank from filtering.
@@ -181,9 +241,77 @@ impl std::fmt::Display for FilterState {
 }
 
 /// Type alias for filter functions.
-pub type FilterFn = Box<dyn Fn(&str, &[String]) -> Vec<Rank> + Send + Sync>;
+///
+/// A filter is given the filter term and the filter values of all items,
+/// and returns the items that match, in the order to show them.
+pub type FilterFn = Arc<dyn Fn(&str, &[String]) -> Vec<Rank> + Send + Sync>;
+
+/// Filter matching the term's characters in order, but not necessarily
+/// next to each other, ignoring case. This is the list's filter.
+///
+/// Matches are ordered best first: characters next to each other, at the
+/// start of the value or of a word score higher, and ties keep the order of
+/// the items.
+///
+/// ```rust
+/// use bubbles::list::fuzzy_filter;
+///
+/// let targets = ["Cabana".to_string(), "Banana".to_string()];
+/// let ranks = fuzzy_filter("bna", &targets);
+/// assert_eq!(ranks.len(), 2);
+/// assert_eq!(ranks[0].index, 1);
+/// assert_eq!(ranks[0].matched_indices, [0, 2, 3]);
+/// ```
+pub fn fuzzy_filter(term: &str, targets: &[String]) -> Vec<Rank> {
+    let term: Vec<char> = term.chars().collect();
+    let mut scored: Vec<(i64, Rank)> = targets
+        .iter()
+        .enumerate()
+        .filter_map(|(index, target)| {
+            let (score, matched_indices) = fuzzy_match(&term, target)?;
+            Some((
+                score,
+                Rank {
+                    index,
+                    matched_indices,
+                },
+            ))
+        })
+        .collect();
+    scored.sort_by(|(a, _), (b, _)| b.cmp(a));
+    scored.into_iter().map(|(_, rank)| rank).collect()
+}
+
+/// Matches `term` against `target`, returning a score and the indices of
+/// the matched characters of `target`.
+fn fuzzy_match(term: &[char], target: &str) -> Option<(i64, Vec<usize>)> {
+    let chars: Vec<char> = target.chars().collect();
+    let same = |a: char, b: char| a == b || a.to_lowercase().eq(b.to_lowercase());
+    let mut matched = Vec::with_capacity(term.len());
+    let mut score = 0;
+    let mut next = 0;
+    for &t in term {
+        let found = (next..chars.len()).find(|&i| same(chars[i], t))?;
+        score += 1;
+        if found > 0 && matched.last() == Some(&(found - 1)) {
+            score += 5;
+        }
+        let prev = found.checked_sub(1).map(|i| chars[i]);
+        match prev {
+            None => score += 8,
+            Some(prev) if !prev.is_alphanumeric() => score += 6,
+            Some(prev) if prev.is_lowercase() && chars[found].is_uppercase() => score += 6,
+            Some(_) => {}
+        }
+        matched.push(found);
+        next = found + 1;
+    }
+    // Prefer matches that start early
+    let lead = i64::try_from(matched.first().copied().unwrap_or(0)).unwrap_or(i64::MAX);
+    Some((score - lead.min(3), matched))
+}
 
-/// Default filter using simple substring matching.
+/// Filter matching the term as a substring, ignoring case.
 pub fn default_filter(term: &str, targets: &[String]) -> Vec<Rank> {
     let term_lower = term.to_lowercase();
     targets
@@ -234,6 +362,12 @@ pub struct KeyMap {
     pub quit: Binding,
     /// Force quit.
     pub force_quit: Binding,
+    /// Grab the selected item to reorder it.
+    pub grab_item: Binding,
+    /// Drop the grabbed item at its new position.
+    pub drop_item: Binding,
+    /// Return the grabbed item to where it was grabbed.
+    pub cancel_reorder: Binding,
 }
 
 impl Default for KeyMap {
@@ -263,6 +397,9 @@ impl Default for KeyMap {
             force_quit: Binding::new()
                 .keys(&["ctrl+c"])
                 .help("ctrl+c", "force quit"),
+            grab_item: Binding::new().keys(&["m"]).help("m", "move"),
+            drop_item: Binding::new().keys(&["m", "enter"]).help("m/enter", "drop"),
+            cancel_reorder: Binding::new().keys(&["esc"]).help("esc", "cancel"),
         }
     }
 }
@@ -292,8 +429,10 @@ pub struct Styles {
     pub active_pagination_dot: Style,
     /// Inactive pagination dot.
     pub inactive_pagination_dot: Style,
-    /// Divider dot.
+    /// Divider dot, between the item count and the filtered count.
     pub divider_dot: Style,
+    /// Marker beside the item being reordered.
+    pub grabbed_marker: Style,
 }
 
 impl Default for Styles {
@@ -311,6 +450,7 @@ impl Default for Styles {
             active_pagination_dot: Style::new().foreground_color(Color::from("212")),
             inactive_pagination_dot: Style::new().foreground_color(Color::from("240")),
             divider_dot: Style::new().foreground_color(Color::from("240")),
+            grabbed_marker: Style::new().foreground_color(Color::from("212")).bold(),
         }
     }
 }
@@ -323,6 +463,18 @@ pub struct FilterMatchesMsg(pub Vec<Rank>);
 #[derive(Debug, Clone, Copy)]
 pub struct StatusMessageTimeoutMsg;
 
+/// Message sent when a grabbed item is dropped at a new position.
+///
+/// The list has already moved the item; indices refer to the full item
+/// list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReorderMsg {
+    /// Where the item was before it was grabbed.
+    pub from: usize,
+    /// Where the item was dropped.
+    pub to: usize,
+}
+
 /// List model with filtering, pagination, and more.
 #[derive(Clone)]
 pub struct List<I: Item, D: ItemDelegate<I>> {
@@ -358,6 +510,8 @@ pub struct List<I: Item, D: ItemDelegate<I>> {
     pub mouse_wheel_delta: usize,
     /// Whether mouse click selection is enabled.
     pub mouse_click_enabled: bool,
+    /// Whether items can be grabbed and reordered.
+    pub reordering_enabled: bool,
 
     // Components
     /// Spinner for loading state.
@@ -372,6 +526,10 @@ pub struct List<I: Item, D: ItemDelegate<I>> {
     // State
     items: Vec<I>,
     filtered_indices: Vec<usize>,
+    /// Matched characters of each filtered item, empty when unfiltered.
+    matches: Vec<Vec<usize>>,
+    /// Filter used to match items.
+    filter: FilterFn,
     delegate: D,
     width: usize,
     height: usize,
@@ -379,6 +537,10 @@ pub struct List<I: Item, D: ItemDelegate<I>> {
     filter_state: FilterState,
     show_spinner: bool,
     status_message: Option<String>,
+    /// Original index of the item being reordered.
+    grabbed: Option<usize>,
+    /// Numeric prefix typed before a key, such as the `42` of `42G`.
+    count: Option<usize>,
 }
 
 impl<I: Item, D: ItemDelegate<I>> List<I, D> {
@@ -393,7 +555,12 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
         let available = height.saturating_sub(4); // Reserve space for chrome
         let per_page = available / item_height.max(1);
 
-        let mut paginator = Paginator::new().per_page(per_page.max(1));
+        let styles = Styles::default();
+        let mut paginator = Paginator::new()
+            .display_type(Type::Dots)
+            .per_page(per_page.max(1));
+        paginator.active_dot = styles.active_pagination_dot.render("•");
+        paginator.inactive_dot = styles.inactive_pagination_dot.render("•");
         paginator.set_total_pages_from_items(items_len);
 
         let mut filter_input = TextInput::new();
@@ -411,17 +578,20 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
             item_name_singular: "item".to_string(),
             item_name_plural: "items".to_string(),
             key_map: KeyMap::default(),
-            styles: Styles::default(),
+            styles,
             status_message_lifetime: Duration::from_secs(1),
             mouse_wheel_enabled: true,
             mouse_wheel_delta: 1,
             mouse_click_enabled: true,
+            reordering_enabled: false,
             spinner: SpinnerModel::new(),
             paginator,
             help: Help::new(),
             filter_input,
             items,
             filtered_indices,
+            matches: Vec::new(),
+            filter: Arc::new(fuzzy_filter),
             delegate,
             width,
             height,
@@ -429,6 +599,8 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
             filter_state: FilterState::Unfiltered,
             show_spinner: false,
             status_message: None,
+            grabbed: None,
+            count: None,
         }
     }
 
@@ -460,11 +632,31 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
         self
     }
 
+    /// Enables or disables grabbing and reordering items (builder pattern).
+    #[must_use]
+    pub fn reordering(mut self, enabled: bool) -> Self {
+        self.reordering_enabled = enabled;
+        self
+    }
+
+    /// Sets the filter used to match items (builder pattern). Defaults to
+    /// [`fuzzy_filter`].
+    #[must_use]
+    pub fn with_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&str, &[String]) -> Vec<Rank> + Send + Sync + 'static,
+    {
+        self.filter = Arc::new(filter);
+        self
+    }
+
     /// Sets the items.
     pub fn set_items(&mut self, items: Vec<I>) {
+        self.grabbed = None;
         let len = items.len();
         self.items = items;
         self.filtered_indices = (0..len).collect();
+        self.matches.clear();
         self.paginator.set_total_pages_from_items(len);
         self.paginator.set_page(0);
         self.cursor = 0;
@@ -485,6 +677,14 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
             .collect()
     }
 
+    /// Returns the indices of the characters of the filter value of item
+    /// `index` of the filtered list that match the filter, empty when the
+    /// list isn't filtered.
+    #[must_use]
+    pub fn matches_for_item(&self, index: usize) -> &[usize] {
+        self.matches.get(index).map_or(&[], Vec::as_slice)
+    }
+
     /// Returns the current cursor index in the filtered list.
     #[must_use]
     pub fn index(&self) -> usize {
@@ -504,6 +704,21 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
         self.cursor = index.min(self.filtered_indices.len().saturating_sub(1));
     }
 
+    /// Selects item `n` of the filtered list, counted from 0, and shows its
+    /// page. Indices past the end select the last item.
+    pub fn goto_line(&mut self, n: usize) {
+        self.select(n);
+        let per_page = self.paginator.get_per_page().max(1);
+        self.paginator.set_page(self.cursor / per_page);
+    }
+
+    /// Selects the item a fraction of the way through the filtered list,
+    /// from 0.0 for the first to 1.0 for the last, and shows its page.
+    pub fn goto_percent(&mut self, percent: f64) {
+        let last = self.filtered_indices.len().saturating_sub(1);
+        self.goto_line((percent.clamp(0.0, 1.0) * last as f64).round() as usize);
+    }
+
     /// Moves the cursor up.
     pub fn cursor_up(&mut self) {
         if self.filtered_indices.is_empty() {
@@ -532,6 +747,47 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
         }
     }
 
+    /// Returns whether an item is grabbed for reordering.
+    #[must_use]
+    pub fn is_reordering(&self) -> bool {
+        self.grabbed.is_some()
+    }
+
+    /// Moves the grabbed item one place up or down, swapping it with its
+    /// neighbor.
+    fn move_grabbed(&mut self, down: bool) {
+        let to = if down {
+            self.cursor + 1
+        } else {
+            self.cursor.wrapping_sub(1)
+        };
+        if to < self.items.len() {
+            self.items.swap(self.cursor, to);
+            self.cursor = to;
+        }
+    }
+
+    /// Handles keys while an item is grabbed.
+    fn update_reorder(&mut self, key_str: &str, from: usize) -> Option<Cmd> {
+        if matches(key_str, &[&self.key_map.cursor_up]) {
+            self.move_grabbed(false);
+        } else if matches(key_str, &[&self.key_map.cursor_down]) {
+            self.move_grabbed(true);
+        } else if matches(key_str, &[&self.key_map.drop_item]) {
+            self.grabbed = None;
+            let to = self.cursor;
+            if from != to {
+                return Some(Cmd::new(move || Message::new(ReorderMsg { from, to })));
+            }
+        } else if matches(key_str, &[&self.key_map.cancel_reorder]) {
+            self.grabbed = None;
+            let item = self.items.remove(self.cursor);
+            self.items.insert(from, item);
+            self.cursor = from;
+        }
+        None
+    }
+
     /// Returns the filter state.
     #[must_use]
     pub fn filter_state(&self) -> FilterState {
@@ -555,6 +811,7 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
         self.filter_input.reset();
         self.filter_state = FilterState::Unfiltered;
         self.filtered_indices = (0..self.items.len()).collect();
+        self.matches.clear();
         self.paginator.set_total_pages_from_items(self.items.len());
         self.paginator.set_page(0);
         self.cursor = 0;
@@ -562,25 +819,34 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
 
     /// Applies the current filter.
     fn apply_filter(&mut self) {
-        let term = self.filter_input.value();
-        if term.is_empty() {
+        if self.filter_input.value().is_empty() {
             self.reset_filter();
             return;
         }
+        self.filter_items();
+        self.filter_state = FilterState::FilterApplied;
+    }
 
+    /// Shows the items matching the current filter, best match first.
+    fn filter_items(&mut self) {
+        let term = self.filter_input.value();
         let targets: Vec<String> = self
             .items
             .iter()
             .map(|i| i.filter_value().to_string())
             .collect();
-        let ranks = default_filter(&term, &targets);
+        let ranks = (self.filter)(&term, &targets);
 
         self.filtered_indices = ranks.iter().map(|r| r.index).collect();
+        self.matches = if term.is_empty() {
+            Vec::new()
+        } else {
+            ranks.into_iter().map(|r| r.matched_indices).collect()
+        };
         self.paginator
             .set_total_pages_from_items(self.filtered_indices.len());
         self.paginator.set_page(0);
         self.cursor = 0;
-        self.filter_state = FilterState::FilterApplied;
     }
 
     /// Starts the spinner.
@@ -652,10 +918,11 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
         let item_height = self.delegate.height() + self.delegate.spacing();
         let available = self.height.saturating_sub(4); // Reserve space for chrome
         let per_page = available / item_height.max(1);
-        // Rebuild paginator with new per_page
-        self.paginator = Paginator::new().per_page(per_page);
+        let page = self.paginator.page();
+        self.paginator = self.paginator.clone().per_page(per_page);
         self.paginator
             .set_total_pages_from_items(self.filtered_indices.len());
+        self.paginator.set_page(page);
     }
 
     /// Updates the list based on messages.
@@ -688,15 +955,40 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
                     return None;
                 }
 
-                // Pass to filter input
-                return self.filter_input.update(msg);
+                // Pass to filter input, filtering as the term changes
+                let term = self.filter_input.value();
+                let cmd = self.filter_input.update(msg);
+                if self.filter_input.value() != term {
+                    self.filter_items();
+                }
+                return cmd;
             }
 
+            if let Some(from) = self.grabbed {
+                return self.update_reorder(&key_str, from);
+            }
+
+            if let Some(count) = count_digit(&key_str, self.count) {
+                self.count = Some(count);
+                return None;
+            }
+            let count = self.count.take();
+
             // Normal navigation
-            if matches(&key_str, &[&self.key_map.cursor_up]) {
-                self.cursor_up();
+            if matches(&key_str, &[&self.key_map.grab_item])
+                && self.reordering_enabled
+                && self.filter_state == FilterState::Unfiltered
+                && !self.items.is_empty()
+            {
+                self.grabbed = Some(self.cursor);
+            } else if matches(&key_str, &[&self.key_map.cursor_up]) {
+                for _ in 0..count.unwrap_or(1) {
+                    self.cursor_up();
+                }
             } else if matches(&key_str, &[&self.key_map.cursor_down]) {
-                self.cursor_down();
+                for _ in 0..count.unwrap_or(1) {
+                    self.cursor_down();
+                }
             } else if matches(&key_str, &[&self.key_map.next_page]) {
                 self.paginator.next_page();
                 // Move cursor to first item of new page
@@ -707,12 +999,10 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
                 let start = self.paginator.page() * self.paginator.get_per_page();
                 self.cursor = start.min(self.filtered_indices.len().saturating_sub(1));
             } else if matches(&key_str, &[&self.key_map.goto_start]) {
-                self.cursor = 0;
-                self.paginator.set_page(0);
+                // Counts number items from 1, like lines in a pager
+                self.goto_line(count.map_or(0, |n| n.saturating_sub(1)));
             } else if matches(&key_str, &[&self.key_map.goto_end]) {
-                self.cursor = self.filtered_indices.len().saturating_sub(1);
-                self.paginator
-                    .set_page(self.paginator.get_total_pages().saturating_sub(1));
+                self.goto_line(count.map_or(usize::MAX, |n| n.saturating_sub(1)));
             } else if matches(&key_str, &[&self.key_map.filter]) && self.filtering_enabled {
                 self.filter_state = FilterState::Filtering;
                 self.filter_input.focus();
@@ -809,8 +1099,24 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
                 let selected = global_idx == self.cursor;
 
                 if let Some(item) = self.items.get(item_idx) {
-                    let rendered = self.delegate.render(item, global_idx, selected, self.width);
-                    sections.push(rendered);
+                    if selected && self.grabbed.is_some() {
+                        let width = self.width.saturating_sub(2);
+                        let rendered = self.delegate.render(item, global_idx, selected, width);
+                        let marker = self.styles.grabbed_marker.render("↕ ");
+                        sections.push(format!("{marker}{rendered}"));
+                    } else if self.matches.is_empty() {
+                        let rendered = self.delegate.render(item, global_idx, selected, self.width);
+                        sections.push(rendered);
+                    } else {
+                        let rendered = self.delegate.render_with_matches(
+                            item,
+                            global_idx,
+                            selected,
+                            self.width,
+                            self.matches_for_item(global_idx),
+                        );
+                        sections.push(rendered);
+                    }
                 }
             }
         }
@@ -823,31 +1129,41 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
         // Status bar
         if self.show_status_bar {
             let status = if let Some(status) = self.status_message.as_deref() {
-                status.to_string()
+                self.styles.status_bar.render(status)
             } else {
-                let count = self.filtered_indices.len();
-                if count == 1 {
-                    format!("1 {}", self.item_name_singular)
-                } else {
-                    format!("{} {}", count, self.item_name_plural)
-                }
+                self.status_view()
             };
-            sections.push(self.styles.status_bar.render(&status));
+            sections.push(status);
         }
 
-        // Pagination
+        // Pagination, as numbers when there are too many pages for dots
         if self.show_pagination && self.paginator.get_total_pages() > 1 {
-            sections.push(self.paginator.view());
+            let too_wide = self.width > 0 && self.paginator.get_total_pages() > self.width;
+            if self.paginator.display_type == Type::Dots && too_wide {
+                let arabic = self.paginator.clone().display_type(Type::Arabic);
+                sections.push(self.styles.pagination.render(&arabic.view()));
+            } else {
+                sections.push(self.styles.pagination.render(&self.paginator.view()));
+            }
         }
 
         // Help
         if self.show_help {
-            let bindings: Vec<&Binding> = vec![
-                &self.key_map.cursor_up,
-                &self.key_map.cursor_down,
-                &self.key_map.filter,
-                &self.key_map.quit,
-            ];
+            let bindings: Vec<&Binding> = if self.grabbed.is_some() {
+                vec![
+                    &self.key_map.cursor_up,
+                    &self.key_map.cursor_down,
+                    &self.key_map.drop_item,
+                    &self.key_map.cancel_reorder,
+                ]
+            } else {
+                vec![
+                    &self.key_map.cursor_up,
+                    &self.key_map.cursor_down,
+                    &self.key_map.filter,
+                    &self.key_map.quit,
+                ]
+            };
             sections.push(
                 self.styles
                     .help
@@ -858,6 +1174,42 @@ impl<I: Item, D: ItemDelegate<I>> List<I, D> {
         sections.join("\n")
     }
 
+    /// Renders the item count, with the filter and how many items it hides
+    /// while filtered, as in `“ban” 2 items • 8 filtered`.
+    fn status_view(&self) -> String {
+        let visible = self.filtered_indices.len();
+        let name = if visible == 1 {
+            &self.item_name_singular
+        } else {
+            &self.item_name_plural
+        };
+        let items = format!("{visible} {name}");
+
+        let status = match self.filter_state {
+            FilterState::Filtering if visible == 0 => "Nothing matched".to_string(),
+            FilterState::Filtering => items,
+            _ if self.items.is_empty() => format!("No {}", self.item_name_plural),
+            FilterState::FilterApplied => {
+                let term = truncate(self.filter_input.value().trim(), 10);
+                format!("“{term}” {items}")
+            }
+            FilterState::Unfiltered => items,
+        };
+        let mut view = self.styles.status_bar.render(&status);
+
+        let filtered = self.items.len() - visible;
+        if filtered > 0 {
+            view.push_str(&self.styles.divider_dot.render(" • "));
+            view.push_str(
+                &self
+                    .styles
+                    .status_empty
+                    .render(&format!("{filtered} filtered")),
+            );
+        }
+        view
+    }
+
     /// Initializes the list (called when used as a standalone Model).
     ///
     /// Returns `None` by default since lists are typically initialized with items.
@@ -952,6 +1304,57 @@ mod tests {
         assert_eq!(list.index(), 1);
     }
 
+    #[test]
+    fn test_list_goto_line_and_percent() {
+        let items = (1..=30)
+            .map(|n| TestItem {
+                name: format!("Item {n}"),
+            })
+            .collect();
+        let mut list = List::new(items, DefaultDelegate::new(), 80, 14);
+        let per_page = list.paginator().get_per_page();
+        assert!(per_page < 30);
+
+        list.goto_line(20);
+        assert_eq!(list.index(), 20);
+        assert_eq!(list.paginator().page(), 20 / per_page);
+        assert!(list.view().contains("Item 21"));
+
+        list.goto_line(99);
+        assert_eq!(list.index(), 29);
+
+        list.goto_percent(0.5);
+        assert_eq!(list.index(), 15);
+        list.goto_percent(0.0);
+        assert_eq!(list.index(), 0);
+        assert_eq!(list.paginator().page(), 0);
+    }
+
+    #[test]
+    fn test_list_numeric_prefix() {
+        let mut list = List::new(test_items(), DefaultDelegate::new(), 80, 24);
+        let keys = |list: &mut List<TestItem, DefaultDelegate>, keys: &str| {
+            for c in keys.chars() {
+                list.update(Message::new(KeyMsg::from_char(c)));
+            }
+        };
+
+        keys(&mut list, "3G");
+        assert_eq!(list.index(), 2);
+        keys(&mut list, "2k");
+        assert_eq!(list.index(), 0);
+        keys(&mut list, "2j");
+        assert_eq!(list.index(), 2);
+        keys(&mut list, "1g");
+        assert_eq!(list.index(), 0);
+        keys(&mut list, "G");
+        assert_eq!(list.index(), 3);
+
+        // While filtering, digits are typed into the filter
+        keys(&mut list, "/2");
+        assert_eq!(list.filter_value(), "2");
+    }
+
     #[test]
     fn test_list_selected_item() {
         let mut list = List::new(test_items(), DefaultDelegate::new(), 80, 24);
@@ -1103,6 +1506,111 @@ mod tests {
         assert_eq!(ranks[0].index, 1); // Banana
     }
 
+    #[test]
+    fn test_fuzzy_filter_ranks_best_match_first() {
+        let targets = vec![
+            "Cranberry".to_string(),
+            "Banana".to_string(),
+            "Cherry".to_string(),
+        ];
+
+        let ranks = fuzzy_filter("bna", &targets);
+        assert_eq!(ranks.len(), 1);
+        assert_eq!(ranks[0].matched_indices, [0, 2, 3]);
+
+        let ranks = fuzzy_filter("RY", &targets);
+        let order: Vec<usize> = ranks.iter().map(|r| r.index).collect();
+        assert_eq!(order, [0, 2]);
+
+        let ranks = fuzzy_filter("cr", &targets);
+        assert_eq!(ranks[0].index, 0);
+        assert!(fuzzy_filter("xyz", &targets).is_empty());
+    }
+
+    #[test]
+    fn test_list_filters_while_typing() {
+        let mut list = List::new(test_items(), DefaultDelegate::new(), 80, 24);
+        list.update(key('/'));
+        assert_eq!(list.filter_state(), FilterState::Filtering);
+
+        list.update(key('b'));
+        list.update(key('n'));
+        assert_eq!(list.filter_state(), FilterState::Filtering);
+        assert_eq!(list.visible_items()[0].name, "Banana");
+        assert_eq!(list.matches_for_item(0), [0, 2]);
+        assert!(strip_ansi(&list.view()).contains("1 item • 3 filtered"));
+
+        list.update(key('z'));
+        assert!(strip_ansi(&list.view()).contains("Nothing matched"));
+
+        list.update(Message::new(KeyMsg::from_type(
+            bubbletea::KeyType::Backspace,
+        )));
+        list.update(Message::new(KeyMsg::from_type(bubbletea::KeyType::Enter)));
+        assert_eq!(list.filter_state(), FilterState::FilterApplied);
+        assert!(strip_ansi(&list.view()).contains("“bn” 1 item"));
+    }
+
+    #[test]
+    fn test_list_with_filter() {
+        let list =
+            List::new(test_items(), DefaultDelegate::new(), 80, 24).with_filter(default_filter);
+        let mut list = list;
+        list.set_filter_value("ae");
+        assert!(list.visible_items().is_empty());
+    }
+
+    #[test]
+    fn test_default_delegate_highlights_matches() {
+        let delegate = DefaultDelegate::new();
+        let item = TestItem {
+            name: "Banana".into(),
+        };
+        let plain = delegate.render(&item, 0, false, 80);
+        let highlighted = delegate.render_with_matches(&item, 0, false, 80, &[0, 2]);
+        assert_ne!(plain, highlighted);
+        assert_eq!(
+            delegate.render_with_matches(&item, 0, false, 80, &[]),
+            plain
+        );
+    }
+
+    #[test]
+    fn test_list_pagination_dots_fall_back_to_numbers() {
+        let items: Vec<TestItem> = (0..50)
+            .map(|i| TestItem {
+                name: format!("Item {}", i),
+            })
+            .collect();
+        let list = List::new(items.clone(), DefaultDelegate::new(), 80, 10);
+        assert!(list.view().contains('•'));
+        assert!(!list.view().contains("1/9"));
+
+        let list = List::new(items, DefaultDelegate::new(), 8, 10);
+        assert!(list.view().contains("1/9"));
+    }
+
+    fn strip_ansi(s: &str) -> String {
+        let mut result = String::with_capacity(s.len());
+        let mut in_escape = false;
+
+        for c in s.chars() {
+            if c == '\x1b' {
+                in_escape = true;
+                continue;
+            }
+            if in_escape {
+                if c == 'm' {
+                    in_escape = false;
+                }
+                continue;
+            }
+            result.push(c);
+        }
+
+        result
+    }
+
     #[test]
     fn test_default_delegate() {
         let delegate = DefaultDelegate::new().with_height(2).with_spacing(1);
@@ -1154,6 +1662,7 @@ mod tests {
             runes: vec!['j'], // 'j' is mapped to cursor_down
             alt: false,
             paste: false,
+            modifiers: bubbletea::KeyMod::NONE,
         });
 
         // Use the Model trait method explicitly
@@ -1201,4 +1710,63 @@ mod tests {
         // With 50 items, per_page=6, total_pages should be 9 (50/6 rounded up)
         assert_eq!(list.paginator().get_total_pages(), 9);
     }
+
+    fn key(c: char) -> Message {
+        Message::new(KeyMsg::from_char(c))
+    }
+
+    fn names(list: &List<TestItem, DefaultDelegate>) -> Vec<&str> {
+        list.items().iter().map(|i| i.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_list_reorder_grab_move_drop() {
+        let mut list = List::new(test_items(), DefaultDelegate::new(), 80, 24).reordering(true);
+        list.cursor_down();
+
+        assert!(list.update(key('m')).is_none());
+        assert!(list.is_reordering());
+        assert!(list.view().contains("↕ "));
+
+        list.update(key('j'));
+        list.update(key('j'));
+        assert_eq!(names(&list), ["Apple", "Cherry", "Date", "Banana"]);
+        assert_eq!(list.index(), 3);
+
+        let cmd = list
+            .update(Message::new(KeyMsg::from_type(bubbletea::KeyType::Enter)))
+            .expect("drop emits a command");
+        let msg = cmd.execute().unwrap();
+        assert_eq!(
+            msg.downcast_ref::<ReorderMsg>(),
+            Some(&ReorderMsg { from: 1, to: 3 })
+        );
+        assert!(!list.is_reordering());
+        assert!(!list.view().contains("↕ "));
+    }
+
+    #[test]
+    fn test_list_reorder_cancel_restores_position() {
+        let mut list = List::new(test_items(), DefaultDelegate::new(), 80, 24).reordering(true);
+        list.update(key('m'));
+        list.update(key('j'));
+        list.update(key('j'));
+        list.update(Message::new(KeyMsg::from_type(bubbletea::KeyType::Esc)));
+
+        assert!(!list.is_reordering());
+        assert_eq!(names(&list), ["Apple", "Banana", "Cherry", "Date"]);
+        assert_eq!(list.index(), 0);
+    }
+
+    #[test]
+    fn test_list_reorder_requires_opt_in_and_no_filter() {
+        let mut list = List::new(test_items(), DefaultDelegate::new(), 80, 24);
+        list.update(key('m'));
+        assert!(!list.is_reordering());
+
+        let mut list = list.reordering(true);
+        list.set_filter_value("an");
+        list.update(key('m'));
+        assert!(!list.is_reordering());
+    }
 }
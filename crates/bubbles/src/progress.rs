@@ -1,7 +1,10 @@
 //! Progress bar component.
 //!
 //! This module provides a progress bar with optional gradient fill and
-//! spring-based animations.
+//! spring-based animations. [`Progress::view_as`] renders any percentage
+//! directly, while [`Progress::set_percent`] animates the bar towards a new
+//! value. The percentage label can sit to the right or left of the bar or
+//! over its middle; see [`PercentagePosition`].
 //!
 //! # Example
 //!
@@ -15,10 +18,11 @@
 //! let view = progress.view_as(0.5);
 //! ```
 
+use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
-use bubbletea::{Cmd, Message, Model};
+use bubbletea::{Clock, Cmd, Message, Model, system_clock, tick_with_clock};
 use harmonica::Spring;
 use lipgloss::Style;
 
@@ -64,6 +68,18 @@ impl Default for Gradient {
     }
 }
 
+/// Where a progress bar's percentage label is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PercentagePosition {
+    /// After the bar.
+    #[default]
+    Right,
+    /// Before the bar.
+    Left,
+    /// Over the middle of the bar, which takes the full width.
+    Center,
+}
+
 /// Progress bar model.
 #[derive(Debug, Clone)]
 pub struct Progress {
@@ -87,6 +103,8 @@ pub struct Progress {
     pub percent_format: String,
     /// Style for percentage text.
     pub percentage_style: Style,
+    /// Where the percentage text is drawn.
+    pub percentage_position: PercentagePosition,
     /// Spring for animations.
     spring: Spring,
     /// Currently displayed percentage (for animation).
@@ -97,6 +115,8 @@ pub struct Progress {
     velocity: f64,
     /// Gradient configuration (if using gradient).
     gradient: Option<Gradient>,
+    /// Clock that animation frames wait on.
+    clock: Arc<dyn Clock>,
 }
 
 impl Default for Progress {
@@ -120,11 +140,13 @@ impl Progress {
             show_percentage: true,
             percent_format: " {:3.0}%".to_string(),
             percentage_style: Style::new(),
+            percentage_position: PercentagePosition::default(),
             spring: Spring::new(FPS as f64, DEFAULT_FREQUENCY, DEFAULT_DAMPING),
             percent_shown: 0.0,
             target_percent: 0.0,
             velocity: 0.0,
             gradient: None,
+            clock: system_clock(),
         }
     }
 
@@ -190,6 +212,42 @@ impl Progress {
         self
     }
 
+    /// Sets the style of the percentage text.
+    #[must_use]
+    pub fn percentage_style(mut self, style: Style) -> Self {
+        self.percentage_style = style;
+        self
+    }
+
+    /// Sets where the percentage text is drawn.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bubbles::progress::{PercentagePosition, Progress};
+    ///
+    /// let bar = Progress::new()
+    ///     .width(10)
+    ///     .fill_chars('#', '-')
+    ///     .percentage_position(PercentagePosition::Center);
+    /// assert!(bar.view_as(0.5).contains("50%"));
+    /// ```
+    #[must_use]
+    pub fn percentage_position(mut self, position: PercentagePosition) -> Self {
+        self.percentage_position = position;
+        self
+    }
+
+    /// Sets the clock that animation frames wait on.
+    ///
+    /// Pass a [`TestClock`](bubbletea::TestClock) in tests so that the bar
+    /// settles without waiting in real time.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Sets the spring animation parameters.
     pub fn set_spring_options(&mut self, frequency: f64, damping: f64) {
         self.spring = Spring::new(FPS as f64, frequency, damping);
@@ -209,7 +267,11 @@ impl Progress {
 
     /// Sets the percentage and returns a command to start animation.
     pub fn set_percent(&mut self, p: f64) -> Option<Cmd> {
-        self.target_percent = if p.is_finite() { p.clamp(0.0, 1.0) } else { 0.0 };
+        self.target_percent = if p.is_finite() {
+            p.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
         self.tag = self.tag.wrapping_add(1);
         self.next_frame()
     }
@@ -228,7 +290,7 @@ impl Progress {
     #[must_use]
     pub fn is_animating(&self) -> bool {
         let dist = (self.percent_shown - self.target_percent).abs();
-        !(dist < 0.001 && self.velocity < 0.01)
+        !(dist < 0.001 && self.velocity.abs() < 0.01)
     }
 
     /// Creates a command for the next animation frame.
@@ -237,8 +299,7 @@ impl Progress {
         let tag = self.tag;
         let delay = Duration::from_secs_f64(1.0 / f64::from(FPS));
 
-        Some(Cmd::new(move || {
-            std::thread::sleep(delay);
+        Some(tick_with_clock(Arc::clone(&self.clock), delay, move |_| {
             Message::new(FrameMsg { id, tag })
         }))
     }
@@ -275,22 +336,61 @@ impl Progress {
     /// Renders the progress bar at a specific percentage.
     #[must_use]
     pub fn view_as(&self, percent: f64) -> String {
-        let mut result = String::new();
-        let percent_view = self.percentage_view(percent);
-        let percent_width = percent_view.chars().count();
+        use unicode_width::UnicodeWidthStr;
 
-        self.bar_view(&mut result, percent, percent_width);
-        result.push_str(&percent_view);
-        result
+        let label = self.percentage_view(percent);
+        if label.is_empty() {
+            return self.bar_cells(percent, self.width).concat();
+        }
+        match self.percentage_position {
+            PercentagePosition::Right => {
+                let bar = self.bar_cells(percent, self.width.saturating_sub(label.width()));
+                bar.concat() + &self.percentage_style.render(&label)
+            }
+            PercentagePosition::Left => {
+                // The format's leading space separates it from the bar
+                let label = format!("{} ", label.strip_prefix(' ').unwrap_or(&label));
+                let bar = self.bar_cells(percent, self.width.saturating_sub(label.width()));
+                self.percentage_style.render(&label) + &bar.concat()
+            }
+            PercentagePosition::Center => {
+                let label = label.trim();
+                let start = self.width.saturating_sub(label.width()) / 2;
+                let end = start + label.width();
+                let mut result = String::new();
+                let mut placed = false;
+                let mut col = 0;
+                for cell in self.bar_cells(percent, self.width) {
+                    let cell_end = col + lipgloss::width(&cell);
+                    if cell_end <= start || col >= end {
+                        result.push_str(&cell);
+                    } else {
+                        // Wide cells cut by the label leave spaces behind
+                        if !placed {
+                            result.push_str(&" ".repeat(start.saturating_sub(col)));
+                            result.push_str(&self.percentage_style.render(label));
+                            placed = true;
+                        }
+                        result.push_str(&" ".repeat(cell_end.saturating_sub(end)));
+                    }
+                    col = cell_end;
+                }
+                if !placed {
+                    result.push_str(&self.percentage_style.render(label));
+                }
+                result
+            }
+        }
     }
 
-    fn bar_view(&self, buf: &mut String, percent: f64, text_width: usize) {
+    /// Renders the bar filled to `percent` in `available_width` columns, one
+    /// string per fill character.
+    fn bar_cells(&self, percent: f64, available_width: usize) -> Vec<String> {
         use unicode_width::UnicodeWidthChar;
 
         let full_width = self.full_char.width().unwrap_or(1).max(1);
         let empty_width = self.empty_char.width().unwrap_or(1).max(1);
 
-        let available_width = self.width.saturating_sub(text_width);
         let filled_target_width =
             ((available_width as f64 * percent).round() as usize).min(available_width);
 
@@ -300,6 +400,7 @@ impl Progress {
         let empty_target_width = available_width.saturating_sub(filled_visual_width);
         let empty_count = empty_target_width / empty_width;
 
+        let mut cells = Vec::with_capacity(available_width);
         if let Some(ref gradient) = self.gradient {
             // Gradient fill
             for i in 0..filled_count {
@@ -313,27 +414,23 @@ impl Progress {
 
                 // Simple linear interpolation between colors
                 let color = interpolate_color(&gradient.color_a, &gradient.color_b, p);
-                buf.push_str(&format!("\x1b[38;2;{}m{}\x1b[0m", color, self.full_char));
+                cells.push(format!("\x1b[38;2;{}m{}\x1b[0m", color, self.full_char));
             }
         } else {
             // Solid fill
-            let colored_char =
-                format_colored_char(self.full_char, &self.full_color).repeat(filled_count);
-            buf.push_str(&colored_char);
+            let colored_char = format_colored_char(self.full_char, &self.full_color);
+            cells.extend(std::iter::repeat_n(colored_char, filled_count));
         }
 
         // Empty fill
         let empty_colored = format_colored_char(self.empty_char, &self.empty_color);
-        for _ in 0..empty_count {
-            buf.push_str(&empty_colored);
-        }
+        cells.extend(std::iter::repeat_n(empty_colored, empty_count));
 
         // Pad remaining space if chars don't divide width evenly
         let used = (filled_count * full_width) + (empty_count * empty_width);
         let remaining = available_width.saturating_sub(used);
-        if remaining > 0 {
-            buf.push_str(&" ".repeat(remaining));
-        }
+        cells.extend(std::iter::repeat_n(" ".to_string(), remaining));
+        cells
     }
 
     fn percentage_view(&self, percent: f64) -> String {
@@ -404,6 +501,482 @@ impl Model for Progress {
     }
 }
 
+// ============================================================================
+// Progress group
+// ============================================================================
+
+const DEFAULT_LABEL_WIDTH: usize = 16;
+const OVERALL_LABEL: &str = "Total";
+
+/// Lifecycle status of a task in a [`ProgressGroup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskStatus {
+    /// The task has been added but has not reported progress yet.
+    #[default]
+    Pending,
+    /// The task is in progress.
+    Running,
+    /// The task finished successfully.
+    Done,
+    /// The task failed.
+    Failed,
+}
+
+impl TaskStatus {
+    /// Returns true if the task will not make further progress.
+    #[must_use]
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Done | Self::Failed)
+    }
+}
+
+/// Glyphs shown before each task label in a [`ProgressGroup`].
+#[derive(Debug, Clone)]
+pub struct StatusGlyphs {
+    /// Glyph for pending tasks.
+    pub pending: String,
+    /// Glyph for running tasks.
+    pub running: String,
+    /// Glyph for completed tasks.
+    pub done: String,
+    /// Glyph for failed tasks.
+    pub failed: String,
+}
+
+impl Default for StatusGlyphs {
+    fn default() -> Self {
+        Self {
+            pending: "·".to_string(),
+            running: "•".to_string(),
+            done: "✓".to_string(),
+            failed: "✗".to_string(),
+        }
+    }
+}
+
+impl StatusGlyphs {
+    /// Returns the glyph for the given status.
+    #[must_use]
+    pub fn glyph(&self, status: TaskStatus) -> &str {
+        match status {
+            TaskStatus::Pending => &self.pending,
+            TaskStatus::Running => &self.running,
+            TaskStatus::Done => &self.done,
+            TaskStatus::Failed => &self.failed,
+        }
+    }
+}
+
+/// Message reporting progress for a task in a [`ProgressGroup`].
+///
+/// Reaching 100% marks the task as [`TaskStatus::Done`].
+#[derive(Debug, Clone)]
+pub struct TaskProgressMsg {
+    /// The task ID.
+    pub task_id: String,
+    /// Completion fraction between 0.0 and 1.0.
+    pub percent: f64,
+}
+
+/// Message changing the status of a task in a [`ProgressGroup`].
+#[derive(Debug, Clone)]
+pub struct TaskStatusMsg {
+    /// The task ID.
+    pub task_id: String,
+    /// The new status.
+    pub status: TaskStatus,
+}
+
+/// A labeled task tracked by a [`ProgressGroup`].
+#[derive(Debug, Clone)]
+pub struct Task {
+    id: String,
+    label: String,
+    status: TaskStatus,
+    bar: Progress,
+}
+
+impl Task {
+    /// Returns the task ID.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the task label.
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the task status.
+    #[must_use]
+    pub fn status(&self) -> TaskStatus {
+        self.status
+    }
+
+    /// Returns the task's target completion fraction.
+    #[must_use]
+    pub fn percent(&self) -> f64 {
+        self.bar.percent()
+    }
+}
+
+/// Manages a set of labeled progress bars for concurrent tasks.
+///
+/// Tasks are added and removed by ID and updated through
+/// [`TaskProgressMsg`] and [`TaskStatusMsg`]. The group can render an
+/// aggregate bar across all tasks and collapse finished tasks into a single
+/// summary line.
+///
+/// # Example
+///
+/// ```rust
+/// use bubbles::progress::{ProgressGroup, TaskProgressMsg};
+/// use bubbletea::Message;
+///
+/// let mut group = ProgressGroup::new().width(60);
+/// group.add_task("fetch", "Fetching index");
+/// group.add_task("unpack", "Unpacking");
+///
+/// group.update(Message::new(TaskProgressMsg {
+///     task_id: "fetch".to_string(),
+///     percent: 0.5,
+/// }));
+/// assert!((group.overall_percent() - 0.25).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProgressGroup {
+    tasks: Vec<Task>,
+    template: Progress,
+    overall: Option<Progress>,
+    /// Total width of each rendered line.
+    pub width: usize,
+    /// Width reserved for task labels.
+    pub label_width: usize,
+    /// Whether finished tasks are collapsed into a summary line.
+    pub collapse_finished: bool,
+    /// Glyphs shown for each task status.
+    pub glyphs: StatusGlyphs,
+    /// Style for task labels.
+    pub label_style: Style,
+}
+
+impl Default for ProgressGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProgressGroup {
+    /// Creates an empty progress group with an aggregate bar.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tasks: Vec::new(),
+            template: Progress::new(),
+            overall: Some(Progress::new()),
+            width: DEFAULT_WIDTH + DEFAULT_LABEL_WIDTH + 2,
+            label_width: DEFAULT_LABEL_WIDTH,
+            collapse_finished: false,
+            glyphs: StatusGlyphs::default(),
+            label_style: Style::new(),
+        }
+    }
+
+    /// Sets the total line width.
+    #[must_use]
+    pub fn width(mut self, width: usize) -> Self {
+        self.set_width(width);
+        self
+    }
+
+    /// Sets the width reserved for task labels.
+    #[must_use]
+    pub fn label_width(mut self, width: usize) -> Self {
+        self.label_width = width;
+        self.set_width(self.width);
+        self
+    }
+
+    /// Sets the progress bar used as a template for new tasks.
+    ///
+    /// The template's width is managed by the group.
+    #[must_use]
+    pub fn bar_template(mut self, template: Progress) -> Self {
+        self.template = template;
+        self
+    }
+
+    /// Hides the aggregate bar.
+    #[must_use]
+    pub fn without_overall(mut self) -> Self {
+        self.overall = None;
+        self
+    }
+
+    /// Collapses finished tasks into a single summary line.
+    #[must_use]
+    pub fn collapse_finished(mut self, collapse: bool) -> Self {
+        self.collapse_finished = collapse;
+        self
+    }
+
+    /// Sets the total line width, resizing every bar to fit.
+    pub fn set_width(&mut self, width: usize) {
+        self.width = width;
+        let bar_width = self.bar_width();
+        for task in &mut self.tasks {
+            task.bar.width = bar_width;
+        }
+        if let Some(overall) = &mut self.overall {
+            overall.width = bar_width;
+        }
+    }
+
+    /// Width available to each bar after the glyph and label columns.
+    fn bar_width(&self) -> usize {
+        // glyph + space, label, space
+        self.width.saturating_sub(self.label_width + 3)
+    }
+
+    /// Adds a task, or relabels it if a task with the same ID exists.
+    pub fn add_task(&mut self, id: impl Into<String>, label: impl Into<String>) {
+        let id = id.into();
+        let label = label.into();
+        if let Some(task) = self.task_mut(&id) {
+            task.label = label;
+            return;
+        }
+        let mut bar = self.template.clone();
+        bar.id = next_id();
+        bar.width = self.bar_width();
+        self.tasks.push(Task {
+            id,
+            label,
+            status: TaskStatus::Pending,
+            bar,
+        });
+    }
+
+    /// Removes a task, returning it if it existed.
+    pub fn remove_task(&mut self, id: &str) -> Option<Task> {
+        let idx = self.tasks.iter().position(|t| t.id == id)?;
+        Some(self.tasks.remove(idx))
+    }
+
+    /// Returns the task with the given ID.
+    #[must_use]
+    pub fn task(&self, id: &str) -> Option<&Task> {
+        self.tasks.iter().find(|t| t.id == id)
+    }
+
+    fn task_mut(&mut self, id: &str) -> Option<&mut Task> {
+        self.tasks.iter_mut().find(|t| t.id == id)
+    }
+
+    /// Returns all tasks in insertion order.
+    #[must_use]
+    pub fn tasks(&self) -> &[Task] {
+        &self.tasks
+    }
+
+    /// Returns the number of tasks.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns true if the group has no tasks.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Sets a task's completion fraction and returns a command to animate it.
+    ///
+    /// Pending tasks become running, and reaching 100% marks the task done.
+    pub fn set_percent(&mut self, id: &str, percent: f64) -> Option<Cmd> {
+        let task = self.task_mut(id)?;
+        let cmd = task.bar.set_percent(percent);
+        if task.bar.percent() >= 1.0 {
+            task.status = TaskStatus::Done;
+        } else if task.status == TaskStatus::Pending {
+            task.status = TaskStatus::Running;
+        }
+        cmd
+    }
+
+    /// Sets a task's status. Marking a task done fills its bar.
+    pub fn set_status(&mut self, id: &str, status: TaskStatus) -> Option<Cmd> {
+        let task = self.task_mut(id)?;
+        task.status = status;
+        if status == TaskStatus::Done {
+            task.bar.set_percent(1.0)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the mean completion fraction across all tasks.
+    ///
+    /// Failed tasks count as finished so the aggregate can reach 100%.
+    #[must_use]
+    pub fn overall_percent(&self) -> f64 {
+        if self.tasks.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = self
+            .tasks
+            .iter()
+            .map(|t| {
+                if t.status.is_finished() {
+                    1.0
+                } else {
+                    t.bar.percent()
+                }
+            })
+            .sum();
+        total / self.tasks.len() as f64
+    }
+
+    /// Returns true if every task has finished.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        !self.tasks.is_empty() && self.tasks.iter().all(|t| t.status.is_finished())
+    }
+
+    /// Updates the group from task messages and bar animation frames.
+    pub fn update(&mut self, msg: Message) -> Option<Cmd> {
+        if let Some(progress) = msg.downcast_ref::<TaskProgressMsg>() {
+            return self.set_percent(&progress.task_id, progress.percent);
+        }
+        if let Some(status) = msg.downcast_ref::<TaskStatusMsg>() {
+            return self.set_status(&status.task_id, status.status);
+        }
+        if let Some(frame) = msg.downcast_ref::<FrameMsg>() {
+            let frame = *frame;
+            let task = self.tasks.iter_mut().find(|t| t.bar.id == frame.id)?;
+            return task.bar.update(Message::new(frame));
+        }
+        None
+    }
+
+    /// Renders one line per task, followed by the aggregate bar.
+    #[must_use]
+    pub fn view(&self) -> String {
+        let mut lines = Vec::new();
+        let mut finished = 0;
+        let mut failed = 0;
+
+        for task in &self.tasks {
+            if self.collapse_finished && task.status.is_finished() {
+                finished += 1;
+                if task.status == TaskStatus::Failed {
+                    failed += 1;
+                }
+                continue;
+            }
+            lines.push(self.row(
+                self.glyphs.glyph(task.status),
+                &task.label,
+                &task.bar.view(),
+            ));
+        }
+
+        if finished > 0 {
+            let mut summary = format!(
+                "{} {} finished",
+                self.glyphs.done,
+                pluralize_tasks(finished)
+            );
+            if failed > 0 {
+                summary.push_str(&format!(", {} {failed} failed", self.glyphs.failed));
+            }
+            lines.insert(0, summary);
+        }
+
+        if let Some(overall) = &self.overall
+            && !self.tasks.is_empty()
+        {
+            let glyph = if self.is_finished() {
+                self.glyphs.glyph(TaskStatus::Done)
+            } else {
+                self.glyphs.glyph(TaskStatus::Running)
+            };
+            lines.push(self.row(
+                glyph,
+                OVERALL_LABEL,
+                &overall.view_as(self.overall_percent()),
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    fn row(&self, glyph: &str, label: &str, bar: &str) -> String {
+        use unicode_width::UnicodeWidthStr;
+
+        let label = truncate_to_width(label, self.label_width);
+        let pad = self.label_width.saturating_sub(label.width());
+        format!(
+            "{glyph} {}{} {bar}",
+            self.label_style.render(&label),
+            " ".repeat(pad)
+        )
+    }
+}
+
+fn pluralize_tasks(n: usize) -> String {
+    if n == 1 {
+        "1 task".to_string()
+    } else {
+        format!("{n} tasks")
+    }
+}
+
+/// Truncates `s` to at most `width` display columns, ending with an ellipsis
+/// when shortened.
+fn truncate_to_width(s: &str, width: usize) -> String {
+    use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+    if s.width() <= width {
+        return s.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w + 1 > width {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.push('…');
+    out
+}
+
+impl Model for ProgressGroup {
+    /// Progress groups don't require initialization commands.
+    fn init(&self) -> Option<Cmd> {
+        None
+    }
+
+    /// Update task state and animations based on incoming messages.
+    fn update(&mut self, msg: Message) -> Option<Cmd> {
+        ProgressGroup::update(self, msg)
+    }
+
+    /// Render all tasks and the aggregate bar.
+    fn view(&self) -> String {
+        ProgressGroup::view(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -583,4 +1156,165 @@ mod tests {
         let p = Progress::new();
         accepts_model(p);
     }
+
+    #[test]
+    fn test_group_add_remove_tasks() {
+        let mut g = ProgressGroup::new();
+        g.add_task("a", "Alpha");
+        g.add_task("b", "Beta");
+        g.add_task("a", "Alpha 2");
+        assert_eq!(g.len(), 2);
+        assert_eq!(g.task("a").unwrap().label(), "Alpha 2");
+
+        let removed = g.remove_task("a").unwrap();
+        assert_eq!(removed.id(), "a");
+        assert!(g.remove_task("a").is_none());
+        assert_eq!(g.len(), 1);
+    }
+
+    #[test]
+    fn test_group_task_bars_have_distinct_ids() {
+        let mut g = ProgressGroup::new();
+        g.add_task("a", "Alpha");
+        g.add_task("b", "Beta");
+        assert_ne!(g.tasks()[0].bar.id(), g.tasks()[1].bar.id());
+    }
+
+    #[test]
+    fn test_group_messages_drive_status() {
+        let mut g = ProgressGroup::new();
+        g.add_task("a", "Alpha");
+        g.add_task("b", "Beta");
+        assert_eq!(g.task("a").unwrap().status(), TaskStatus::Pending);
+
+        g.update(Message::new(TaskProgressMsg {
+            task_id: "a".to_string(),
+            percent: 0.5,
+        }));
+        assert_eq!(g.task("a").unwrap().status(), TaskStatus::Running);
+        assert!((g.overall_percent() - 0.25).abs() < 1e-9);
+
+        g.update(Message::new(TaskProgressMsg {
+            task_id: "a".to_string(),
+            percent: 1.0,
+        }));
+        assert_eq!(g.task("a").unwrap().status(), TaskStatus::Done);
+
+        g.update(Message::new(TaskStatusMsg {
+            task_id: "b".to_string(),
+            status: TaskStatus::Failed,
+        }));
+        assert!(g.is_finished());
+        assert!((g.overall_percent() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_group_unknown_task_is_ignored() {
+        let mut g = ProgressGroup::new();
+        g.add_task("a", "Alpha");
+        let cmd = g.update(Message::new(TaskProgressMsg {
+            task_id: "missing".to_string(),
+            percent: 0.5,
+        }));
+        assert!(cmd.is_none());
+        assert_eq!(g.overall_percent(), 0.0);
+    }
+
+    #[test]
+    fn test_group_routes_frames_to_task_bar() {
+        let mut g = ProgressGroup::new();
+        g.add_task("a", "Alpha");
+        g.set_percent("a", 1.0);
+        let bar = &g.tasks()[0].bar;
+        let frame = FrameMsg {
+            id: bar.id(),
+            tag: bar.tag,
+        };
+        assert!(g.update(Message::new(frame)).is_some());
+        assert!(g.tasks()[0].bar.percent_shown > 0.0);
+    }
+
+    #[test]
+    fn test_group_view_layout() {
+        let mut g = ProgressGroup::new().width(40).label_width(8);
+        g.add_task("a", "Alpha");
+        g.add_task("b", "A very long label");
+        let view = g.view();
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("· Alpha"));
+        assert!(lines[1].contains("A very …"));
+        assert!(lines[2].contains(OVERALL_LABEL));
+        assert_eq!(g.tasks()[0].bar.width, 29);
+    }
+
+    #[test]
+    fn test_group_collapses_finished_tasks() {
+        let mut g = ProgressGroup::new()
+            .collapse_finished(true)
+            .without_overall();
+        g.add_task("a", "Alpha");
+        g.add_task("b", "Beta");
+        g.add_task("c", "Gamma");
+        g.set_status("a", TaskStatus::Done);
+        g.set_status("b", TaskStatus::Failed);
+        let view = g.view();
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "✓ 2 tasks finished, ✗ 1 failed");
+        assert!(lines[1].contains("Gamma"));
+        assert!(!view.contains("Alpha"));
+    }
+
+    #[test]
+    fn test_group_empty_view() {
+        let g = ProgressGroup::new();
+        assert!(g.is_empty());
+        assert!(!g.is_finished());
+        assert_eq!(g.view(), "");
+    }
+
+    /// Removes SGR escape sequences, leaving the visible text.
+    fn plain(s: &str) -> String {
+        let mut out = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|c| *c == 'm');
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_progress_percentage_positions() {
+        let bar = Progress::new().width(12).fill_chars('#', '-');
+        assert_eq!(plain(&bar.view_as(0.5)), "####---  50%");
+
+        let left = bar.clone().percentage_position(PercentagePosition::Left);
+        assert_eq!(plain(&left.view_as(0.5)), " 50% ####---");
+        assert_eq!(plain(&left.view_as(0.05)), "  5% -------");
+
+        let center = bar.percentage_position(PercentagePosition::Center);
+        assert_eq!(plain(&center.view_as(0.5)), "####50%-----");
+        assert_eq!(plain(&center.view_as(1.0)), "####100%####");
+    }
+
+    #[test]
+    fn test_progress_settles_on_test_clock() {
+        let clock = bubbletea::TestClock::new();
+        let mut p = Progress::new().with_clock(Arc::new(clock.clone()));
+        let mut cmd = p.set_percent(0.8);
+        let mut frames = 0;
+        while let Some(c) = cmd {
+            cmd = p.update(c.execute().unwrap());
+            frames += 1;
+            assert!(frames < 1000, "animation never settled");
+        }
+        assert!(!p.is_animating());
+        assert!((p.percent_shown - 0.8).abs() < 0.001);
+        assert!(clock.elapsed() > Duration::ZERO);
+    }
 }
@@ -1,6 +1,7 @@
 //! Spinner component for loading indicators.
 //!
-//! This module provides animated spinners with multiple preset styles.
+//! This module provides animated spinners with multiple preset styles, and
+//! [`Activity`], which shows a spinner only while commands are in flight.
 //!
 //! # Example
 //!
@@ -17,10 +18,12 @@
 //! let tick_msg = spinner.tick();
 //! ```
 
+use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
-use bubbletea::{Cmd, Message, Model};
+use bubbletea::message::BatchMsg;
+use bubbletea::{Clock, Cmd, Message, Model, system_clock, tick_with_clock};
 use lipgloss::Style;
 
 /// Global ID counter for spinner instances.
@@ -157,6 +160,7 @@ pub struct SpinnerModel {
     frame: usize,
     id: u64,
     tag: u64,
+    clock: Arc<dyn Clock>,
 }
 
 impl Default for SpinnerModel {
@@ -175,6 +179,7 @@ impl SpinnerModel {
             frame: 0,
             id: next_id(),
             tag: 0,
+            clock: system_clock(),
         }
     }
 
@@ -187,6 +192,7 @@ impl SpinnerModel {
             frame: 0,
             id: next_id(),
             tag: 0,
+            clock: system_clock(),
         }
     }
 
@@ -204,6 +210,16 @@ impl SpinnerModel {
         self
     }
 
+    /// Sets the clock that frame ticks wait on.
+    ///
+    /// Pass a [`TestClock`](bubbletea::TestClock) in tests so that ticks
+    /// arrive at once instead of after the frame duration.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Returns the spinner's unique ID.
     #[must_use]
     pub fn id(&self) -> u64 {
@@ -228,8 +244,7 @@ impl SpinnerModel {
         let tag = self.tag;
         let duration = self.spinner.frame_duration();
 
-        Cmd::new(move || {
-            std::thread::sleep(duration);
+        tick_with_clock(Arc::clone(&self.clock), duration, move |_| {
             Message::new(TickMsg { id, tag })
         })
     }
@@ -278,14 +293,7 @@ impl SpinnerModel {
 impl Model for SpinnerModel {
     fn init(&self) -> Option<Cmd> {
         // Return a command to start the spinner's tick cycle
-        let id = self.id;
-        let tag = self.tag;
-        let duration = self.spinner.frame_duration();
-
-        Some(Cmd::new(move || {
-            std::thread::sleep(duration);
-            Message::new(TickMsg { id, tag })
-        }))
+        Some(self.tick_cmd())
     }
 
     fn update(&mut self, msg: Message) -> Option<Cmd> {
@@ -297,6 +305,146 @@ impl Model for SpinnerModel {
     }
 }
 
+/// Wraps the message of a command tracked by an [`Activity`].
+struct CompletedMsg {
+    /// The activity that tracked the command.
+    id: u64,
+    /// The command's own message.
+    msg: Option<Message>,
+}
+
+/// A spinner shown while tracked commands are in flight.
+///
+/// Commands passed through [`track`](Self::track) are counted until their
+/// message comes back through [`update`](Self::update), which hands the
+/// message on to the model in a command of its own. The spinner ticks only
+/// while work is pending and [`view`](Self::view) is empty otherwise, so
+/// models need no `loading` flag of their own.
+///
+/// # Example
+///
+/// ```rust
+/// use bubbles::spinner::{Activity, SpinnerModel};
+/// use bubbletea::{Cmd, Message};
+///
+/// struct Loaded(String);
+///
+/// struct App {
+///     activity: Activity,
+///     data: Option<String>,
+/// }
+///
+/// impl App {
+///     fn load(&mut self) -> Cmd {
+///         self.activity
+///             .track(Cmd::new(|| Message::new(Loaded("data".into()))))
+///     }
+///
+///     fn update(&mut self, msg: Message) -> Option<Cmd> {
+///         if let Some(Loaded(data)) = msg.downcast_ref::<Loaded>() {
+///             self.data = Some(data.clone());
+///             return None;
+///         }
+///         self.activity.update(msg)
+///     }
+///
+///     fn view(&self) -> String {
+///         format!("{} {}", self.activity.view(), self.data.as_deref().unwrap_or(""))
+///     }
+/// }
+///
+/// let mut app = App {
+///     activity: Activity::new(SpinnerModel::new()),
+///     data: None,
+/// };
+/// let _cmd = app.load();
+/// assert!(app.activity.is_busy());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Activity {
+    /// The spinner shown while busy.
+    pub spinner: SpinnerModel,
+    /// Number of tracked commands in flight.
+    pending: usize,
+}
+
+impl Activity {
+    /// Creates an idle activity showing `spinner` while busy.
+    #[must_use]
+    pub fn new(spinner: SpinnerModel) -> Self {
+        Self {
+            spinner,
+            pending: 0,
+        }
+    }
+
+    /// Returns the number of tracked commands in flight.
+    #[must_use]
+    pub fn pending(&self) -> usize {
+        self.pending
+    }
+
+    /// Returns whether any tracked command is in flight.
+    #[must_use]
+    pub fn is_busy(&self) -> bool {
+        self.pending > 0
+    }
+
+    /// Counts `cmd` as in flight until its message reaches
+    /// [`update`](Self::update).
+    ///
+    /// The first command tracked while idle also starts the spinner. Track
+    /// each command on its own rather than a [`batch`](bubbletea::batch),
+    /// whose message arrives before the commands in it have run.
+    #[must_use]
+    pub fn track(&mut self, cmd: Cmd) -> Cmd {
+        let id = self.spinner.id();
+        let tracked = Cmd::new(move || {
+            Message::new(CompletedMsg {
+                id,
+                msg: cmd.execute(),
+            })
+        });
+        self.pending += 1;
+        if self.pending > 1 {
+            return tracked;
+        }
+        let tick = self.spinner.tick_cmd();
+        Cmd::new(move || Message::new(BatchMsg(vec![tracked, tick])))
+    }
+
+    /// Handles spinner ticks and the messages of tracked commands.
+    ///
+    /// A tracked command's message is returned in a command that delivers
+    /// it again, now unwrapped, for the model to handle. Ticks arriving
+    /// while idle are dropped, which stops the spinner.
+    pub fn update(&mut self, msg: Message) -> Option<Cmd> {
+        if msg
+            .downcast_ref::<CompletedMsg>()
+            .is_some_and(|done| done.id == self.spinner.id())
+        {
+            let done = msg.downcast::<CompletedMsg>()?;
+            self.pending = self.pending.saturating_sub(1);
+            let inner = done.msg?;
+            return Some(Cmd::new(move || inner));
+        }
+        if !self.is_busy() {
+            return None;
+        }
+        self.spinner.update(msg)
+    }
+
+    /// Renders the spinner while busy, or nothing when idle.
+    #[must_use]
+    pub fn view(&self) -> String {
+        if self.is_busy() {
+            self.spinner.view()
+        } else {
+            String::new()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -454,4 +602,88 @@ mod tests {
         let spinner = SpinnerModel::new();
         assert_eq!(Model::view(&spinner), spinner.view());
     }
+
+    #[test]
+    fn test_spinner_ticks_on_test_clock() {
+        let clock = bubbletea::TestClock::new();
+        let mut spinner =
+            SpinnerModel::with_spinner(spinners::dot()).with_clock(Arc::new(clock.clone()));
+        let frames = spinner.spinner.frames.len();
+
+        let mut cmd = Model::init(&spinner);
+        for _ in 0..frames {
+            let msg = cmd.unwrap().execute().unwrap();
+            cmd = spinner.update(msg);
+        }
+
+        assert_eq!(spinner.frame, 0);
+        assert_eq!(
+            clock.elapsed(),
+            spinner.spinner.frame_duration() * frames as u32
+        );
+    }
+
+    struct Loaded(u32);
+
+    /// Runs a command, opening batches, and returns the messages produced.
+    fn run(cmd: Cmd) -> Vec<Message> {
+        match cmd.execute() {
+            Some(msg) if msg.is::<BatchMsg>() => {
+                let BatchMsg(cmds) = msg.downcast::<BatchMsg>().unwrap();
+                cmds.into_iter().flat_map(run).collect()
+            }
+            msg => msg.into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn test_activity_counts_tracked_commands() {
+        let clock = bubbletea::TestClock::new();
+        let spinner = SpinnerModel::new().with_clock(Arc::new(clock));
+        let mut activity = Activity::new(spinner);
+        assert!(!activity.is_busy());
+        assert_eq!(activity.view(), "");
+
+        // The first command also starts the spinner
+        let first = run(activity.track(Cmd::new(|| Message::new(Loaded(1)))));
+        assert_eq!(first.len(), 2);
+        let second = run(activity.track(Cmd::new(|| Message::new(Loaded(2)))));
+        assert_eq!(second.len(), 1);
+        assert_eq!(activity.pending(), 2);
+        assert_eq!(activity.view(), activity.spinner.view());
+
+        let mut delivered = Vec::new();
+        for msg in first.into_iter().chain(second) {
+            if let Some(cmd) = activity.update(msg) {
+                delivered.extend(run(cmd));
+            }
+        }
+        let loaded: Vec<u32> = delivered
+            .iter()
+            .filter_map(|msg| msg.downcast_ref::<Loaded>().map(|l| l.0))
+            .collect();
+        assert_eq!(loaded, vec![1, 2]);
+        assert!(!activity.is_busy());
+        assert_eq!(activity.view(), "");
+
+        // Ticks arriving once idle stop the animation
+        let tick = delivered
+            .into_iter()
+            .find(|msg| msg.is::<TickMsg>())
+            .unwrap();
+        assert!(activity.update(tick).is_none());
+    }
+
+    #[test]
+    fn test_activity_ignores_other_activities() {
+        let mut a = Activity::new(SpinnerModel::new());
+        let mut b = Activity::new(SpinnerModel::new());
+        let _ = a.track(Cmd::new(|| Message::new(Loaded(1))));
+        let msgs = run(b.track(Cmd::new(|| Message::new(Loaded(2)))));
+        for msg in msgs {
+            assert!(a.update(msg).is_none());
+        }
+        assert_eq!(a.pending(), 1);
+        assert_eq!(b.pending(), 1);
+    }
 }
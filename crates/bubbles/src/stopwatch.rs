@@ -14,10 +14,11 @@
 //! assert!(!stopwatch.running());
 //! ```
 
+use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
-use bubbletea::{Cmd, Message, Model};
+use bubbletea::{Clock, Cmd, Message, Model, system_clock, tick_with_clock};
 
 /// Global ID counter for stopwatch instances.
 static NEXT_ID: AtomicU64 = AtomicU64::new(1);
@@ -66,12 +67,16 @@ pub struct Stopwatch {
     elapsed: Duration,
     /// Tick interval.
     interval: Duration,
+    /// Step the displayed time is rounded down to.
+    precision: Option<Duration>,
     /// Unique ID.
     id: u64,
     /// Message tag for ordering.
     tag: u64,
     /// Whether the stopwatch is running.
     running: bool,
+    /// Clock that ticks wait on.
+    clock: Arc<dyn Clock>,
 }
 
 impl Default for Stopwatch {
@@ -93,12 +98,32 @@ impl Stopwatch {
         Self {
             elapsed: Duration::ZERO,
             interval,
+            precision: None,
             id: next_id(),
             tag: 0,
             running: false,
+            clock: system_clock(),
         }
     }
 
+    /// Sets the clock that ticks wait on.
+    ///
+    /// Pass a [`TestClock`](bubbletea::TestClock) in tests so that the
+    /// stopwatch counts up without waiting in real time.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Rounds the displayed time down to a whole number of `precision`, so
+    /// a stopwatch ticking every 100ms can still show whole seconds.
+    #[must_use]
+    pub fn with_precision(mut self, precision: Duration) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
     /// Returns the stopwatch's unique ID.
     #[must_use]
     pub fn id(&self) -> u64 {
@@ -123,6 +148,12 @@ impl Stopwatch {
         self.interval
     }
 
+    /// Returns the step the displayed time is rounded to, if any.
+    #[must_use]
+    pub fn precision(&self) -> Option<Duration> {
+        self.precision
+    }
+
     /// Returns a command to initialize and start the stopwatch.
     #[must_use]
     pub fn init(&self) -> Option<Cmd> {
@@ -132,18 +163,9 @@ impl Stopwatch {
     /// Starts the stopwatch.
     fn start_cmd(&self) -> Option<Cmd> {
         let id = self.id;
-        let tag = self.tag;
-        let interval = self.interval;
-
-        bubbletea::sequence(vec![
-            Some(Cmd::new(move || {
-                Message::new(StartStopMsg { id, running: true })
-            })),
-            Some(Cmd::new(move || {
-                std::thread::sleep(interval);
-                Message::new(TickMsg { id, tag })
-            })),
-        ])
+        Some(Cmd::new(move || {
+            Message::new(StartStopMsg { id, running: true })
+        }))
     }
 
     /// Creates a command to start the stopwatch.
@@ -180,13 +202,16 @@ impl Stopwatch {
         let tag = self.tag;
         let interval = self.interval;
 
-        Cmd::new(move || {
-            std::thread::sleep(interval);
+        tick_with_clock(Arc::clone(&self.clock), interval, move |_| {
             Message::new(TickMsg { id, tag })
         })
     }
 
     /// Updates the stopwatch state.
+    ///
+    /// Starting and stopping supersede the tick in flight, so a paused
+    /// stopwatch resumes with a full interval and toggling quickly never
+    /// leaves two ticks running.
     pub fn update(&mut self, msg: Message) -> Option<Cmd> {
         // Handle start/stop
         if let Some(ss) = msg.downcast_ref::<StartStopMsg>() {
@@ -194,7 +219,8 @@ impl Stopwatch {
                 return None;
             }
             self.running = ss.running;
-            return None;
+            self.tag = self.tag.wrapping_add(1);
+            return self.running.then(|| self.tick_cmd());
         }
 
         // Handle reset
@@ -228,7 +254,15 @@ impl Stopwatch {
     /// Renders the stopwatch display.
     #[must_use]
     pub fn view(&self) -> String {
-        format_duration(self.elapsed)
+        let shown = match self.precision {
+            Some(precision) if !precision.is_zero() => {
+                let step = precision.as_nanos();
+                let nanos = self.elapsed.as_nanos() / step * step;
+                Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+            }
+            _ => self.elapsed,
+        };
+        format_duration(shown)
     }
 }
 
@@ -454,7 +488,7 @@ mod tests {
         });
         let result = sw.update(msg);
         assert!(sw.running());
-        assert!(result.is_none()); // start/stop doesn't return a command
+        assert!(result.is_some()); // starting schedules the first tick
 
         // Stop via update
         let msg = Message::new(StartStopMsg {
@@ -636,4 +670,46 @@ mod tests {
             "1h0m0.5s"
         );
     }
+
+    #[test]
+    fn test_stopwatch_counts_on_test_clock() {
+        let clock = bubbletea::TestClock::new();
+        let mut sw = Stopwatch::new().with_clock(Arc::new(clock.clone()));
+        sw.running = true;
+
+        let mut cmd = Some(sw.tick_cmd());
+        for _ in 0..90 {
+            let msg = cmd.unwrap().execute().unwrap();
+            cmd = sw.update(msg);
+        }
+
+        assert_eq!(sw.elapsed(), Duration::from_secs(90));
+        assert_eq!(clock.elapsed(), Duration::from_secs(90));
+        assert_eq!(sw.view(), "1m30s");
+    }
+
+    #[test]
+    fn test_stopwatch_resume_supersedes_old_tick() {
+        let clock = bubbletea::TestClock::new();
+        let mut sw = Stopwatch::new().with_clock(Arc::new(clock));
+        let tick = sw.update(sw.start().unwrap().execute().unwrap()).unwrap();
+        let in_flight = tick.execute().unwrap();
+
+        // Pause and resume before the tick arrives
+        sw.update(sw.stop().unwrap().execute().unwrap());
+        let resumed = sw.update(sw.start().unwrap().execute().unwrap()).unwrap();
+        assert!(sw.update(in_flight).is_none());
+        assert!(sw.update(resumed.execute().unwrap()).is_some());
+        assert_eq!(sw.elapsed(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_stopwatch_precision_rounds_down() {
+        let mut sw = Stopwatch::with_interval(Duration::from_millis(100))
+            .with_precision(Duration::from_secs(1));
+        sw.elapsed = Duration::from_millis(2900);
+        assert_eq!(sw.view(), "2s");
+        sw.elapsed = Duration::from_millis(900);
+        assert_eq!(sw.view(), "0s");
+    }
 }
@@ -23,6 +23,40 @@
 //!     .columns(columns)
 //!     .rows(rows);
 //! ```
+//!
+//! # Sorting, Selection and Wide Tables
+//!
+//! A focused table has an active column, moved with `←`/`→` (`h`/`l`).
+//! `s` sorts the rows by it, and pressing `s` again reverses the order.
+//! Cells that are all numbers sort by value. `<` and `>` narrow and widen
+//! the active column. Columns that don't fit the table's width scroll into
+//! view as the active column moves.
+//!
+//! `x` adds the row under the cursor to the selection, or removes it, and
+//! `ctrl+a` selects every row:
+//!
+//! ```rust
+//! use bubbles::table::{Column, SortOrder, Table};
+//!
+//! let mut table = Table::new()
+//!     .columns(vec![Column::new("Name", 10), Column::new("Age", 5)])
+//!     .rows(vec![
+//!         vec!["Bob".into(), "42".into()],
+//!         vec!["Alice".into(), "7".into()],
+//!     ]);
+//! table.sort_by(1, SortOrder::Ascending);
+//! assert_eq!(table.get_rows()[0][0], "Alice");
+//!
+//! // The cursor stays on Bob's row, now the second
+//! table.toggle_selected();
+//! assert_eq!(table.selected_indices(), [1]);
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
 
 use crate::key::{Binding, matches};
 use crate::viewport::Viewport;
@@ -52,6 +86,29 @@ impl Column {
 /// A row in the table (vector of cell values).
 pub type Row = Vec<String>;
 
+/// Order rows are sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// Smallest first.
+    Ascending,
+    /// Largest first.
+    Descending,
+}
+
+/// Returns the style of the cell at a row and column, used in place of
+/// [`Styles::cell`].
+pub type StyleFunc = Arc<dyn Fn(usize, usize) -> Style + Send + Sync>;
+
+/// A [`StyleFunc`] that can be printed.
+#[derive(Clone)]
+struct CellStyles(StyleFunc);
+
+impl fmt::Debug for CellStyles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<fn>")
+    }
+}
+
 /// Key bindings for table navigation.
 #[derive(Debug, Clone)]
 pub struct KeyMap {
@@ -71,6 +128,20 @@ pub struct KeyMap {
     pub goto_top: Binding,
     /// Go to bottom.
     pub goto_bottom: Binding,
+    /// Make the column to the left active.
+    pub column_left: Binding,
+    /// Make the column to the right active.
+    pub column_right: Binding,
+    /// Sort by the active column, reversing the order if already sorted.
+    pub sort: Binding,
+    /// Widen the active column.
+    pub widen_column: Binding,
+    /// Narrow the active column.
+    pub narrow_column: Binding,
+    /// Add the row under the cursor to the selection, or remove it.
+    pub toggle_select: Binding,
+    /// Select every row, or none if all are selected.
+    pub select_all: Binding,
 }
 
 impl Default for KeyMap {
@@ -94,6 +165,15 @@ impl Default for KeyMap {
             goto_bottom: Binding::new()
                 .keys(&["end", "G"])
                 .help("G/end", "go to end"),
+            column_left: Binding::new().keys(&["left", "h"]).help("←/h", "left"),
+            column_right: Binding::new().keys(&["right", "l"]).help("→/l", "right"),
+            sort: Binding::new().keys(&["s"]).help("s", "sort"),
+            widen_column: Binding::new().keys(&[">"]).help(">", "widen column"),
+            narrow_column: Binding::new().keys(&["<"]).help("<", "narrow column"),
+            toggle_select: Binding::new().keys(&["x"]).help("x", "select"),
+            select_all: Binding::new()
+                .keys(&["ctrl+a"])
+                .help("ctrl+a", "select all"),
         }
     }
 }
@@ -107,6 +187,10 @@ pub struct Styles {
     pub cell: Style,
     /// Style for the selected row.
     pub selected: Style,
+    /// Style for the header of the active column while focused.
+    pub active_header: Style,
+    /// Style for rows in the multi-row selection.
+    pub selection: Style,
 }
 
 impl Default for Styles {
@@ -115,6 +199,12 @@ impl Default for Styles {
             header: Style::new().bold().padding_left(1).padding_right(1),
             cell: Style::new().padding_left(1).padding_right(1),
             selected: Style::new().bold().foreground_color(Color::from("212")),
+            active_header: Style::new()
+                .bold()
+                .underline()
+                .padding_left(1)
+                .padding_right(1),
+            selection: Style::new().foreground_color(Color::from("36")),
         }
     }
 }
@@ -146,6 +236,16 @@ pub struct Table {
     start: usize,
     /// End index for rendered rows.
     end: usize,
+    /// Index of the active column.
+    column: usize,
+    /// Index of the first column shown.
+    x_offset: usize,
+    /// Column and order the rows are sorted by.
+    sort: Option<(usize, SortOrder)>,
+    /// Indices of the selected rows.
+    selection: BTreeSet<usize>,
+    /// Per-cell styles.
+    style_func: Option<CellStyles>,
 }
 
 impl Default for Table {
@@ -171,6 +271,11 @@ impl Table {
             viewport: Viewport::new(0, 20),
             start: 0,
             end: 0,
+            column: 0,
+            x_offset: 0,
+            sort: None,
+            selection: BTreeSet::new(),
+            style_func: None,
         }
     }
 
@@ -185,8 +290,7 @@ impl Table {
     /// Sets the rows (builder pattern).
     #[must_use]
     pub fn rows(mut self, rows: Vec<Row>) -> Self {
-        self.rows = rows;
-        self.update_viewport();
+        self.set_rows(rows);
         self
     }
 
@@ -251,6 +355,31 @@ impl Table {
         self
     }
 
+    /// Sets a function giving the style of each cell from its row and
+    /// column index, used in place of [`Styles::cell`] (builder pattern).
+    ///
+    /// ```rust
+    /// use bubbles::table::{Column, Table};
+    /// use lipgloss::Style;
+    ///
+    /// // Stripe the rows
+    /// let table = Table::new()
+    ///     .columns(vec![Column::new("Name", 10)])
+    ///     .with_style_func(|row, _col| {
+    ///         let style = Style::new().padding_left(1).padding_right(1);
+    ///         if row % 2 == 0 { style.faint() } else { style }
+    ///     });
+    /// ```
+    #[must_use]
+    pub fn with_style_func<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> Style + Send + Sync + 'static,
+    {
+        self.style_func = Some(CellStyles(Arc::new(f)));
+        self.update_viewport();
+        self
+    }
+
     /// Returns whether the table is focused.
     #[must_use]
     pub fn is_focused(&self) -> bool {
@@ -287,12 +416,15 @@ impl Table {
         self.update_viewport();
     }
 
-    /// Sets the rows.
+    /// Sets the rows, sorting them if the table is sorted. The selection
+    /// is cleared.
     pub fn set_rows(&mut self, rows: Vec<Row>) {
         self.rows = rows;
+        self.selection.clear();
         if self.cursor > self.rows.len().saturating_sub(1) {
             self.cursor = self.rows.len().saturating_sub(1);
         }
+        self.apply_sort();
         self.update_viewport();
     }
 
@@ -321,7 +453,7 @@ impl Table {
         self.viewport.width
     }
 
-    /// Returns the currently selected row, if any.
+    /// Returns the row under the cursor, if any.
     #[must_use]
     pub fn selected_row(&self) -> Option<&Row> {
         self.rows.get(self.cursor)
@@ -371,6 +503,122 @@ impl Table {
         self.update_viewport();
     }
 
+    /// Returns the index of the active column.
+    #[must_use]
+    pub fn active_column(&self) -> usize {
+        self.column
+    }
+
+    /// Makes column `n` active, scrolling it into view.
+    pub fn set_active_column(&mut self, n: usize) {
+        self.column = n.min(self.columns.len().saturating_sub(1));
+        self.update_viewport();
+    }
+
+    /// Sets the width of column `n`, keeping it at least 1.
+    pub fn set_column_width(&mut self, n: usize, width: usize) {
+        if let Some(column) = self.columns.get_mut(n) {
+            column.width = width.max(1);
+            self.update_viewport();
+        }
+    }
+
+    /// Returns the column and order the rows are sorted by, if any.
+    #[must_use]
+    pub fn sort_column(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Sorts the rows by column `n`. The cursor and the selection stay on
+    /// the same rows, and rows set later are sorted the same way.
+    pub fn sort_by(&mut self, n: usize, order: SortOrder) {
+        self.sort = Some((n, order));
+        self.apply_sort();
+        self.update_viewport();
+    }
+
+    /// Sorts by the active column, in reverse if the table is already
+    /// sorted by it in ascending order.
+    pub fn toggle_sort(&mut self) {
+        let order = match self.sort {
+            Some((n, SortOrder::Ascending)) if n == self.column => SortOrder::Descending,
+            _ => SortOrder::Ascending,
+        };
+        self.sort_by(self.column, order);
+    }
+
+    fn apply_sort(&mut self) {
+        let Some((n, order)) = self.sort else {
+            return;
+        };
+        let mut rows: Vec<(usize, Row)> = std::mem::take(&mut self.rows)
+            .into_iter()
+            .enumerate()
+            .collect();
+        rows.sort_by(|(_, a), (_, b)| {
+            let ordering = compare_cells(
+                a.get(n).map_or("", String::as_str),
+                b.get(n).map_or("", String::as_str),
+            );
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+
+        let mut cursor = self.cursor;
+        let mut selection = BTreeSet::new();
+        for (i, (old, row)) in rows.into_iter().enumerate() {
+            if old == self.cursor {
+                cursor = i;
+            }
+            if self.selection.contains(&old) {
+                selection.insert(i);
+            }
+            self.rows.push(row);
+        }
+        self.cursor = cursor;
+        self.selection = selection;
+    }
+
+    /// Adds the row under the cursor to the selection, or removes it.
+    pub fn toggle_selected(&mut self) {
+        if self.cursor < self.rows.len() && !self.selection.remove(&self.cursor) {
+            self.selection.insert(self.cursor);
+        }
+        self.update_viewport();
+    }
+
+    /// Selects every row.
+    pub fn select_all(&mut self) {
+        self.selection = (0..self.rows.len()).collect();
+        self.update_viewport();
+    }
+
+    /// Empties the selection.
+    pub fn clear_selection(&mut self) {
+        self.selection.clear();
+        self.update_viewport();
+    }
+
+    /// Returns whether row `n` is in the selection.
+    #[must_use]
+    pub fn is_selected(&self, n: usize) -> bool {
+        self.selection.contains(&n)
+    }
+
+    /// Returns the indices of the selected rows, in table order.
+    #[must_use]
+    pub fn selected_indices(&self) -> Vec<usize> {
+        self.selection.iter().copied().collect()
+    }
+
+    /// Returns the selected rows, in table order.
+    #[must_use]
+    pub fn selected_rows(&self) -> Vec<&Row> {
+        self.selection.iter().map(|&i| &self.rows[i]).collect()
+    }
+
     /// Parses rows from a string value with the given separator.
     pub fn from_values(&mut self, value: &str, separator: &str) {
         let rows: Vec<Row> = value
@@ -380,8 +628,50 @@ impl Table {
         self.set_rows(rows);
     }
 
+    /// Returns the columns that fit in the table's width, starting from the
+    /// first one shown. At least one column is shown.
+    fn visible_columns(&self) -> Range<usize> {
+        let start = self.x_offset.min(self.columns.len());
+        let width = self.viewport.width;
+        if width == 0 {
+            return start..self.columns.len();
+        }
+        let frame = self
+            .styles
+            .cell
+            .get_horizontal_frame_size()
+            .max(self.styles.header.get_horizontal_frame_size());
+        let mut used = 0;
+        let mut end = start;
+        for column in &self.columns[start..] {
+            let column_width = if column.width == 0 {
+                0
+            } else {
+                column.width + frame
+            };
+            if used + column_width > width && end > start {
+                break;
+            }
+            used += column_width;
+            end += 1;
+        }
+        start..end
+    }
+
+    /// Scrolls horizontally so that the active column is shown.
+    fn scroll_to_column(&mut self) {
+        self.column = self.column.min(self.columns.len().saturating_sub(1));
+        if self.column < self.x_offset {
+            self.x_offset = self.column;
+        }
+        while self.x_offset < self.column && !self.visible_columns().contains(&self.column) {
+            self.x_offset += 1;
+        }
+    }
+
     /// Updates the viewport to reflect current state.
     fn update_viewport(&mut self) {
+        self.scroll_to_column();
         if self.rows.is_empty() {
             self.start = 0;
             self.end = 0;
@@ -420,16 +710,31 @@ impl Table {
         self.viewport.set_content(&rendered.join("\n"));
     }
 
-    /// Renders the header row.
+    /// Renders the header row, marking the column the rows are sorted by.
     fn headers_view(&self) -> String {
-        let cells: Vec<String> = self
-            .columns
+        let range = self.visible_columns();
+        let cells: Vec<String> = self.columns[range.clone()]
             .iter()
-            .filter(|col| col.width > 0)
-            .map(|col| {
-                let truncated = truncate_string(&col.title, col.width);
-                let padded = pad_string(&truncated, col.width);
-                self.styles.header.render(&padded)
+            .zip(range)
+            .filter(|(col, _)| col.width > 0)
+            .map(|(col, i)| {
+                let title = match self.sort {
+                    Some((n, order)) if n == i => {
+                        let arrow = match order {
+                            SortOrder::Ascending => '▲',
+                            SortOrder::Descending => '▼',
+                        };
+                        let title = truncate_string(&col.title, col.width.saturating_sub(2));
+                        truncate_string(&format!("{title} {arrow}"), col.width)
+                    }
+                    _ => truncate_string(&col.title, col.width),
+                };
+                let padded = pad_string(&title, col.width);
+                if self.focus && i == self.column {
+                    self.styles.active_header.render(&padded)
+                } else {
+                    self.styles.header.render(&padded)
+                }
             })
             .collect();
 
@@ -440,16 +745,19 @@ impl Table {
     fn render_row(&self, row_idx: usize) -> String {
         let row = &self.rows[row_idx];
 
-        let cells: Vec<String> = self
-            .columns
+        let range = self.visible_columns();
+        let cells: Vec<String> = self.columns[range.clone()]
             .iter()
-            .enumerate()
-            .filter(|(_, col)| col.width > 0)
-            .map(|(i, col)| {
+            .zip(range)
+            .filter(|(col, _)| col.width > 0)
+            .map(|(col, i)| {
                 let value = row.get(i).map(String::as_str).unwrap_or("");
                 let truncated = truncate_string(value, col.width);
                 let padded = pad_string(&truncated, col.width);
-                self.styles.cell.render(&padded)
+                match &self.style_func {
+                    Some(CellStyles(style_func)) => style_func(row_idx, i).render(&padded),
+                    None => self.styles.cell.render(&padded),
+                }
             })
             .collect();
 
@@ -457,6 +765,8 @@ impl Table {
 
         if row_idx == self.cursor {
             self.styles.selected.render(&row_str)
+        } else if self.selection.contains(&row_idx) {
+            self.styles.selection.render(&row_str)
         } else {
             row_str
         }
@@ -487,6 +797,28 @@ impl Table {
                 self.goto_top();
             } else if matches(&key_str, &[&self.key_map.goto_bottom]) {
                 self.goto_bottom();
+            } else if matches(&key_str, &[&self.key_map.column_left]) {
+                self.set_active_column(self.column.saturating_sub(1));
+            } else if matches(&key_str, &[&self.key_map.column_right]) {
+                self.set_active_column(self.column + 1);
+            } else if matches(&key_str, &[&self.key_map.sort]) {
+                self.toggle_sort();
+            } else if matches(&key_str, &[&self.key_map.widen_column]) {
+                if let Some(width) = self.columns.get(self.column).map(|col| col.width) {
+                    self.set_column_width(self.column, width + 1);
+                }
+            } else if matches(&key_str, &[&self.key_map.narrow_column]) {
+                if let Some(width) = self.columns.get(self.column).map(|col| col.width) {
+                    self.set_column_width(self.column, width.saturating_sub(1));
+                }
+            } else if matches(&key_str, &[&self.key_map.toggle_select]) {
+                self.toggle_selected();
+            } else if matches(&key_str, &[&self.key_map.select_all]) {
+                if self.selection.len() == self.rows.len() {
+                    self.clear_selection();
+                } else {
+                    self.select_all();
+                }
             }
         }
 
@@ -537,6 +869,20 @@ impl Table {
     }
 }
 
+/// Orders cells by value when both are numbers, numbers before text, and
+/// text ignoring case.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a
+            .to_lowercase()
+            .cmp(&b.to_lowercase())
+            .then_with(|| a.cmp(b)),
+    }
+}
+
 /// Pads a string to the given width with spaces.
 fn pad_string(s: &str, width: usize) -> String {
     use unicode_width::UnicodeWidthStr;
@@ -1204,6 +1550,113 @@ mod tests {
         assert_eq!(table.selected_row().unwrap()[0], "Carol");
     }
 
+    fn key(c: char) -> Message {
+        Message::new(KeyMsg::from_char(c))
+    }
+
+    fn people() -> Table {
+        Table::new()
+            .columns(vec![Column::new("Name", 6), Column::new("Age", 4)])
+            .rows(vec![
+                vec!["bob".into(), "42".into()],
+                vec!["Alice".into(), "7".into()],
+                vec!["carol".into(), "100".into()],
+            ])
+            .width(40)
+            .focused(true)
+    }
+
+    #[test]
+    fn test_table_sorts_by_active_column() {
+        let mut table = people();
+        table.update(&key('s'));
+        assert_eq!(table.sort_column(), Some((0, SortOrder::Ascending)));
+        assert_eq!(table.get_rows()[0][0], "Alice");
+        assert!(table.view().contains("Name ▲"));
+
+        // Ages sort by value, and sorting again reverses
+        table.update(&key('l'));
+        assert_eq!(table.active_column(), 1);
+        table.update(&key('s'));
+        let ages: Vec<&str> = table.get_rows().iter().map(|r| r[1].as_str()).collect();
+        assert_eq!(ages, ["7", "42", "100"]);
+        table.update(&key('s'));
+        assert_eq!(table.sort_column(), Some((1, SortOrder::Descending)));
+        assert_eq!(table.get_rows()[0][1], "100");
+
+        // New rows are sorted the same way
+        table.set_rows(vec![
+            vec!["dan".into(), "1".into()],
+            vec!["eve".into(), "2".into()],
+        ]);
+        assert_eq!(table.get_rows()[0][0], "eve");
+    }
+
+    #[test]
+    fn test_table_selection_follows_sorted_rows() {
+        let mut table = people();
+        table.update(&key('x'));
+        table.move_down(2);
+        table.update(&key('x'));
+        assert_eq!(table.selected_indices(), [0, 2]);
+
+        table.sort_by(0, SortOrder::Descending);
+        let names: Vec<&str> = table
+            .selected_rows()
+            .iter()
+            .map(|r| r[0].as_str())
+            .collect();
+        assert_eq!(names, ["carol", "bob"]);
+        assert_eq!(table.selected_row().unwrap()[0], "carol");
+
+        let ctrl_a = Message::new(KeyMsg::from_type(bubbletea::KeyType::CtrlA));
+        table.update(&ctrl_a);
+        assert_eq!(table.selected_indices(), [0, 1, 2]);
+        table.update(&ctrl_a);
+        assert!(table.selected_indices().is_empty());
+    }
+
+    #[test]
+    fn test_table_resizes_and_scrolls_columns() {
+        let mut table = Table::new()
+            .columns(vec![
+                Column::new("A", 4),
+                Column::new("B", 4),
+                Column::new("C", 4),
+            ])
+            .rows(vec![vec!["a".into(), "b".into(), "c".into()]])
+            .width(13)
+            .focused(true);
+        assert_eq!(table.visible_columns(), 0..2);
+
+        table.update(&key('>'));
+        assert_eq!(table.get_columns()[0].width, 5);
+        table.update(&key('<'));
+        table.update(&key('<'));
+        assert_eq!(table.get_columns()[0].width, 3);
+
+        table.set_column_width(0, 4);
+        table.set_active_column(2);
+        assert_eq!(table.visible_columns(), 1..3);
+        assert!(table.view().contains(" c "));
+        assert!(!table.view().contains(" a "));
+        table.update(&key('h'));
+        table.update(&key('h'));
+        assert_eq!(table.visible_columns(), 0..2);
+    }
+
+    #[test]
+    fn test_table_style_func_styles_cells() {
+        let table = people().with_style_func(|row, col| {
+            if row == 1 && col == 1 {
+                Style::new().bold()
+            } else {
+                Style::new()
+            }
+        });
+        assert!(table.view().contains("\x1b[1m7   \x1b[0m"));
+    }
+
     // -------------------------------------------------------------------------
     // Mouse support tests (bd-3ps4)
     // -------------------------------------------------------------------------
@@ -1,7 +1,18 @@
 //! Multi-line text area component.
 //!
 //! This module provides a multi-line text editor for TUI applications with
-//! features like line numbers, word wrapping, and viewport scrolling.
+//! features like line numbers, soft wrapping, and viewport scrolling.
+//!
+//! With [`TextArea::soft_wrap`] enabled, lines longer than the width continue
+//! on further display lines. Up and down then move by display line, Home and
+//! End move to the ends of the display line, and Ctrl+A and Ctrl+E still move
+//! to the ends of the whole line.
+//!
+//! Edits can be undone with Ctrl+Z and redone with Alt+Z, a word of typing
+//! at a time. Text deleted with the emacs kill keys (Ctrl+K, Ctrl+U, Ctrl+W
+//! and Alt+D) goes to a kill ring, consecutive kills joining into one entry:
+//! Ctrl+Y yanks the last kill back in and Alt+Y right after it swaps the
+//! yanked text for earlier kills.
 //!
 //! # Example
 //!
@@ -15,6 +26,8 @@
 //! let view = textarea.view();
 //! ```
 
+use std::ops::Range;
+
 use crate::cursor::{Cursor, Mode as CursorMode, blink_cmd};
 use crate::key::{Binding, matches};
 use crate::runeutil::Sanitizer;
@@ -28,6 +41,8 @@ const DEFAULT_WIDTH: usize = 40;
 const DEFAULT_MAX_HEIGHT: usize = 99;
 const DEFAULT_MAX_WIDTH: usize = 500;
 const MAX_LINES: usize = 10000;
+const MAX_UNDO: usize = 100;
+const KILL_RING_SIZE: usize = 32;
 
 /// Key bindings for textarea navigation.
 #[derive(Debug, Clone)]
@@ -50,14 +65,20 @@ pub struct KeyMap {
     pub delete_word_forward: Binding,
     /// Insert newline.
     pub insert_newline: Binding,
-    /// Move to line end.
+    /// Move to line end, or to the end of the display line when soft
+    /// wrapping.
     pub line_end: Binding,
+    /// Move to the end of the line, even when it is soft wrapped.
+    pub hard_line_end: Binding,
     /// Move to next line.
     pub line_next: Binding,
     /// Move to previous line.
     pub line_previous: Binding,
-    /// Move to line start.
+    /// Move to line start, or to the start of the display line when soft
+    /// wrapping.
     pub line_start: Binding,
+    /// Move to the start of the line, even when it is soft wrapped.
+    pub hard_line_start: Binding,
     /// Paste from clipboard.
     pub paste: Binding,
     /// Move word backward.
@@ -76,6 +97,14 @@ pub struct KeyMap {
     pub capitalize_word_forward: Binding,
     /// Transpose character backward.
     pub transpose_character_backward: Binding,
+    /// Undo the last edit.
+    pub undo: Binding,
+    /// Redo the last undone edit.
+    pub redo: Binding,
+    /// Insert the last killed text.
+    pub yank: Binding,
+    /// Replace just-yanked text with the kill before it.
+    pub yank_pop: Binding,
 }
 
 impl Default for KeyMap {
@@ -120,12 +149,14 @@ impl Default for KeyMap {
             delete_character_forward: Binding::new()
                 .keys(&["delete", "ctrl+d"])
                 .help("delete", "delete character forward"),
-            line_start: Binding::new()
-                .keys(&["home", "ctrl+a"])
-                .help("home", "line start"),
-            line_end: Binding::new()
-                .keys(&["end", "ctrl+e"])
-                .help("end", "line end"),
+            line_start: Binding::new().keys(&["home"]).help("home", "line start"),
+            hard_line_start: Binding::new()
+                .keys(&["ctrl+a"])
+                .help("ctrl+a", "hard line start"),
+            line_end: Binding::new().keys(&["end"]).help("end", "line end"),
+            hard_line_end: Binding::new()
+                .keys(&["ctrl+e"])
+                .help("ctrl+e", "hard line end"),
             paste: Binding::new().keys(&["ctrl+v"]).help("ctrl+v", "paste"),
             input_begin: Binding::new()
                 .keys(&["alt+<", "ctrl+home"])
@@ -145,6 +176,16 @@ impl Default for KeyMap {
             transpose_character_backward: Binding::new()
                 .keys(&["ctrl+t"])
                 .help("ctrl+t", "transpose character backward"),
+            undo: Binding::new()
+                .keys(&["ctrl+z", "ctrl+_"])
+                .help("ctrl+z", "undo"),
+            redo: Binding::new()
+                .keys(&["alt+z", "ctrl+shift+z"])
+                .help("alt+z", "redo"),
+            yank: Binding::new().keys(&["ctrl+y"]).help("ctrl+y", "yank"),
+            yank_pop: Binding::new()
+                .keys(&["alt+y"])
+                .help("alt+y", "yank earlier kill"),
         }
     }
 }
@@ -193,6 +234,29 @@ pub struct PasteMsg(pub String);
 #[derive(Debug, Clone)]
 pub struct PasteErrMsg(pub String);
 
+/// Text and cursor position saved for undo.
+#[derive(Debug, Clone)]
+struct Snapshot {
+    value: Vec<Vec<char>>,
+    row: usize,
+    col: usize,
+}
+
+/// What the previous key press did, which decides whether typing joins the
+/// last undo step, a kill joins the last kill and Alt+Y can cycle a yank.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum LastEdit {
+    #[default]
+    None,
+    Typing,
+    Kill,
+    /// Text yanked from `start` to the cursor, `index` kills back.
+    Yank {
+        start: (usize, usize),
+        index: usize,
+    },
+}
+
 /// Multi-line text area model.
 #[derive(Debug, Clone)]
 pub struct TextArea {
@@ -204,6 +268,9 @@ pub struct TextArea {
     pub placeholder: String,
     /// Whether to show line numbers.
     pub show_line_numbers: bool,
+    /// Whether lines wider than the textarea wrap onto further display
+    /// lines instead of running past the edge.
+    pub soft_wrap: bool,
     /// End of buffer character.
     pub end_of_buffer_character: char,
     /// Key bindings.
@@ -236,12 +303,22 @@ pub struct TextArea {
     col: usize,
     /// Cursor row.
     row: usize,
-    /// Last character offset for vertical navigation.
+    /// Cell offset in the display line that vertical navigation keeps to.
     last_char_offset: usize,
+    /// First display line shown.
+    y_offset: usize,
     /// Viewport for scrolling.
     viewport: Viewport,
     /// Rune sanitizer.
     sanitizer: Sanitizer,
+    /// States to undo to, oldest first.
+    undo_stack: Vec<Snapshot>,
+    /// Undone states to redo, most recently undone last.
+    redo_stack: Vec<Snapshot>,
+    /// Killed text, most recent last.
+    kill_ring: Vec<String>,
+    /// What the previous key press did.
+    last_edit: LastEdit,
 }
 
 impl Default for TextArea {
@@ -261,6 +338,7 @@ impl TextArea {
             prompt: "┃ ".to_string(),
             placeholder: String::new(),
             show_line_numbers: true,
+            soft_wrap: false,
             end_of_buffer_character: ' ',
             key_map: KeyMap::default(),
             focused_style: Styles::default(),
@@ -278,8 +356,13 @@ impl TextArea {
             col: 0,
             row: 0,
             last_char_offset: 0,
+            y_offset: 0,
             viewport,
             sanitizer: Sanitizer::new(),
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            kill_ring: Vec::new(),
+            last_edit: LastEdit::None,
         };
 
         ta.set_height(DEFAULT_HEIGHT);
@@ -398,22 +481,253 @@ impl TextArea {
         self.row
     }
 
-    /// Moves cursor down one line.
+    /// Returns the cursor column in characters (0-indexed).
+    #[must_use]
+    pub fn column(&self) -> usize {
+        self.col
+    }
+
+    /// Undoes the last edit, returning whether there was one.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo_stack.pop() else {
+            return false;
+        };
+        let current = self.snapshot();
+        self.redo_stack.push(current);
+        self.restore(snapshot);
+        true
+    }
+
+    /// Redoes the last undone edit, returning whether there was one.
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.redo_stack.pop() else {
+            return false;
+        };
+        let current = self.snapshot();
+        self.undo_stack.push(current);
+        self.restore(snapshot);
+        true
+    }
+
+    /// Returns the killed text, most recent last.
+    #[must_use]
+    pub fn kill_ring(&self) -> &[String] {
+        &self.kill_ring
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            value: self.value.clone(),
+            row: self.row,
+            col: self.col,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.value = snapshot.value;
+        self.row = snapshot.row;
+        self.set_cursor_col(snapshot.col);
+        self.last_edit = LastEdit::None;
+    }
+
+    /// Saves the state before an edit to undo to.
+    fn push_undo(&mut self, snapshot: Snapshot) {
+        if self.undo_stack.len() == MAX_UNDO {
+            self.undo_stack.remove(0);
+        }
+        self.undo_stack.push(snapshot);
+        self.redo_stack.clear();
+    }
+
+    /// Adds killed text to the kill ring, joining it to the previous kill
+    /// when the last key press killed too.
+    fn kill(&mut self, text: String, forward: bool, last_edit: LastEdit) {
+        if text.is_empty() {
+            return;
+        }
+        match self.kill_ring.last_mut() {
+            Some(top) if last_edit == LastEdit::Kill => {
+                if forward {
+                    top.push_str(&text);
+                } else {
+                    top.insert_str(0, &text);
+                }
+            }
+            _ => {
+                if self.kill_ring.len() == KILL_RING_SIZE {
+                    self.kill_ring.remove(0);
+                }
+                self.kill_ring.push(text);
+            }
+        }
+        self.last_edit = LastEdit::Kill;
+    }
+
+    /// Inserts the kill `index` kills back at the cursor.
+    fn yank(&mut self, index: usize) {
+        let Some(text) = self
+            .kill_ring
+            .len()
+            .checked_sub(index + 1)
+            .and_then(|i| self.kill_ring.get(i))
+            .cloned()
+        else {
+            return;
+        };
+        let start = (self.row, self.col);
+        self.insert_string(&text);
+        self.last_edit = LastEdit::Yank { start, index };
+    }
+
+    /// Replaces the text just yanked with the kill before it, wrapping
+    /// around to the most recent.
+    fn yank_pop(&mut self, last_edit: LastEdit) {
+        let LastEdit::Yank { start, index } = last_edit else {
+            return;
+        };
+        self.delete_range(start, (self.row, self.col));
+        self.yank((index + 1) % self.kill_ring.len().max(1));
+    }
+
+    /// Deletes the text from `start` to `end`, leaving the cursor at `start`.
+    fn delete_range(&mut self, start: (usize, usize), end: (usize, usize)) {
+        let (row, col) = start;
+        let tail = self.value[end.0][end.1..].to_vec();
+        self.value[row].truncate(col);
+        self.value[row].extend(tail);
+        self.value.drain(row + 1..=end.0);
+        self.row = row;
+        self.set_cursor_col(col);
+    }
+
+    /// Returns the text removed from `old_line`, the current line before a
+    /// deletion that left the cursor where the text started.
+    fn removed_from(&self, old_line: &[char]) -> String {
+        let removed = old_line.len() - self.value[self.row].len();
+        old_line[self.col..self.col + removed].iter().collect()
+    }
+
+    /// Moves cursor down one line, or one display line when soft wrapping.
     pub fn cursor_down(&mut self) {
-        if self.row < self.value.len() - 1 {
+        if !self.soft_wrap {
+            if self.row < self.value.len() - 1 {
+                self.row += 1;
+                self.col = self.col.min(self.value[self.row].len());
+            }
+            return;
+        }
+
+        let segments = self.display_lines(self.row);
+        let index = segment_at(&segments, self.col);
+        let offset = self.sticky_offset(&segments[index]);
+        if index + 1 < segments.len() {
+            self.col = self.col_at_offset(self.row, &segments, index + 1, offset);
+        } else if self.row < self.value.len() - 1 {
             self.row += 1;
-            self.col = self.col.min(self.value[self.row].len());
+            let segments = self.display_lines(self.row);
+            self.col = self.col_at_offset(self.row, &segments, 0, offset);
         }
     }
 
-    /// Moves cursor up one line.
+    /// Moves cursor up one line, or one display line when soft wrapping.
     pub fn cursor_up(&mut self) {
-        if self.row > 0 {
+        if !self.soft_wrap {
+            if self.row > 0 {
+                self.row -= 1;
+                self.col = self.col.min(self.value[self.row].len());
+            }
+            return;
+        }
+
+        let segments = self.display_lines(self.row);
+        let index = segment_at(&segments, self.col);
+        let offset = self.sticky_offset(&segments[index]);
+        if index > 0 {
+            self.col = self.col_at_offset(self.row, &segments, index - 1, offset);
+        } else if self.row > 0 {
             self.row -= 1;
-            self.col = self.col.min(self.value[self.row].len());
+            let segments = self.display_lines(self.row);
+            let last = segments.len() - 1;
+            self.col = self.col_at_offset(self.row, &segments, last, offset);
+        }
+    }
+
+    /// Moves cursor to the start of its display line, which is the start of
+    /// the line unless soft wrapping broke it.
+    pub fn display_line_start(&mut self) {
+        let segments = self.display_lines(self.row);
+        let start = segments[segment_at(&segments, self.col)].start;
+        self.set_cursor_col(start);
+    }
+
+    /// Moves cursor to the end of its display line, which is the end of the
+    /// line unless soft wrapping broke it.
+    pub fn display_line_end(&mut self) {
+        let segments = self.display_lines(self.row);
+        let index = segment_at(&segments, self.col);
+        let segment = &segments[index];
+        // A wrapped display line ends before the next one's first character
+        let end = if index + 1 < segments.len() {
+            segment.end.saturating_sub(1).max(segment.start)
+        } else {
+            segment.end
+        };
+        self.set_cursor_col(end);
+    }
+
+    /// Splits line `row` into display lines.
+    fn display_lines(&self, row: usize) -> Vec<Range<usize>> {
+        let line = &self.value[row];
+        if self.soft_wrap {
+            wrap_line(line, self.width)
+        } else {
+            std::iter::once(0..line.len()).collect()
         }
     }
 
+    /// Returns the cursor's cell offset in `segment`, or the offset kept from
+    /// earlier vertical moves if that is further right.
+    fn sticky_offset(&mut self, segment: &Range<usize>) -> usize {
+        let line = &self.value[self.row];
+        let offset = cells(&line[segment.start..self.col.clamp(segment.start, segment.end)]);
+        self.last_char_offset = self.last_char_offset.max(offset);
+        self.last_char_offset
+    }
+
+    /// Returns the column closest to `offset` cells into display line
+    /// `index` of line `row`, without leaving that display line.
+    fn col_at_offset(
+        &self,
+        row: usize,
+        segments: &[Range<usize>],
+        index: usize,
+        offset: usize,
+    ) -> usize {
+        let line = &self.value[row];
+        let segment = &segments[index];
+        let mut col = segment.start;
+        let mut width = 0;
+        while col < segment.end {
+            let w = char_width(line[col]);
+            if width + w > offset {
+                break;
+            }
+            width += w;
+            col += 1;
+        }
+        if index + 1 < segments.len() && col == segment.end && segment.end > segment.start {
+            col -= 1;
+        }
+        col
+    }
+
+    /// Returns the display line the cursor is on, counting from the first
+    /// line of the value.
+    fn cursor_display_row(&self) -> usize {
+        let above: usize = (0..self.row).map(|row| self.display_lines(row).len()).sum();
+        above + segment_at(&self.display_lines(self.row), self.col)
+    }
+
     /// Sets cursor column position.
     pub fn set_cursor_col(&mut self, col: usize) {
         self.col = col.min(self.value[self.row].len());
@@ -460,11 +774,15 @@ impl TextArea {
         self.cursor.blur();
     }
 
-    /// Resets the textarea to empty.
+    /// Resets the textarea to empty, forgetting the undo history.
     pub fn reset(&mut self) {
         self.value = vec![Vec::new()];
         self.col = 0;
         self.row = 0;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.last_edit = LastEdit::None;
+        self.y_offset = 0;
         self.viewport.goto_top();
         self.set_cursor_col(0);
     }
@@ -783,14 +1101,15 @@ impl TextArea {
         self.row += 1;
     }
 
+    /// Scrolls so the cursor's display line is visible.
     fn reposition_view(&mut self) {
-        let minimum = self.viewport.y_offset();
-        let maximum = minimum + self.viewport.height.saturating_sub(1);
+        let row = self.cursor_display_row();
+        let maximum = self.y_offset + self.height.saturating_sub(1);
 
-        if self.row < minimum {
-            self.viewport.scroll_up(minimum - self.row);
-        } else if self.row > maximum {
-            self.viewport.scroll_down(self.row - maximum);
+        if row < self.y_offset {
+            self.y_offset = row;
+        } else if row > maximum {
+            self.y_offset += row - maximum;
         }
     }
 
@@ -803,9 +1122,11 @@ impl TextArea {
 
         let old_row = self.row;
         let old_col = self.col;
+        let mut before = (msg.is::<KeyMsg>() || msg.is::<PasteMsg>()).then(|| self.snapshot());
 
         // Handle paste message
         if let Some(paste) = msg.downcast_ref::<PasteMsg>() {
+            self.last_edit = LastEdit::None;
             self.insert_runes_from_user_input(&paste.0.chars().collect::<Vec<_>>());
         }
 
@@ -815,20 +1136,42 @@ impl TextArea {
 
         if let Some(key) = msg.downcast_ref::<KeyMsg>() {
             let key_str = key.to_string();
-
-            if matches(&key_str, &[&self.key_map.delete_after_cursor]) {
+            let last_edit = std::mem::take(&mut self.last_edit);
+            let lines = self.value.len();
+
+            if matches(&key_str, &[&self.key_map.undo]) {
+                before = None;
+                self.undo();
+            } else if matches(&key_str, &[&self.key_map.redo]) {
+                before = None;
+                self.redo();
+            } else if matches(&key_str, &[&self.key_map.yank]) {
+                self.yank(0);
+            } else if matches(&key_str, &[&self.key_map.yank_pop]) {
+                self.yank_pop(last_edit);
+            } else if matches(&key_str, &[&self.key_map.delete_after_cursor]) {
                 self.col = self.col.min(self.value[self.row].len());
                 if self.col >= self.value[self.row].len() {
                     self.merge_line_below(self.row);
+                    if self.value.len() < lines {
+                        self.kill("\n".to_string(), true, last_edit);
+                    }
                 } else {
+                    let killed = self.value[self.row][self.col..].iter().collect();
                     self.delete_after_cursor();
+                    self.kill(killed, true, last_edit);
                 }
             } else if matches(&key_str, &[&self.key_map.delete_before_cursor]) {
                 self.col = self.col.min(self.value[self.row].len());
                 if self.col == 0 {
                     self.merge_line_above(self.row);
+                    if self.value.len() < lines {
+                        self.kill("\n".to_string(), false, last_edit);
+                    }
                 } else {
+                    let killed = self.value[self.row][..self.col].iter().collect();
                     self.delete_before_cursor();
+                    self.kill(killed, false, last_edit);
                 }
             } else if matches(&key_str, &[&self.key_map.delete_character_backward]) {
                 self.col = self.col.min(self.value[self.row].len());
@@ -848,24 +1191,41 @@ impl TextArea {
             } else if matches(&key_str, &[&self.key_map.delete_word_backward]) {
                 if self.col == 0 {
                     self.merge_line_above(self.row);
+                    if self.value.len() < lines {
+                        self.kill("\n".to_string(), false, last_edit);
+                    }
                 } else {
+                    let old_line = self.value[self.row].clone();
                     self.delete_word_left();
+                    let killed = self.removed_from(&old_line);
+                    self.kill(killed, false, last_edit);
                 }
             } else if matches(&key_str, &[&self.key_map.delete_word_forward]) {
                 self.col = self.col.min(self.value[self.row].len());
                 if self.col >= self.value[self.row].len() {
                     self.merge_line_below(self.row);
+                    if self.value.len() < lines {
+                        self.kill("\n".to_string(), true, last_edit);
+                    }
                 } else {
+                    let old_line = self.value[self.row].clone();
                     self.delete_word_right();
+                    let killed = self.removed_from(&old_line);
+                    self.kill(killed, true, last_edit);
                 }
             } else if matches(&key_str, &[&self.key_map.insert_newline]) {
-                if self.max_height == 0 || self.value.len() < self.max_height {
+                let room = self.char_limit == 0 || self.length() < self.char_limit;
+                if room && (self.max_height == 0 || self.value.len() < self.max_height) {
                     self.col = self.col.min(self.value[self.row].len());
                     self.split_line(self.row, self.col);
                 }
             } else if matches(&key_str, &[&self.key_map.line_end]) {
+                self.display_line_end();
+            } else if matches(&key_str, &[&self.key_map.hard_line_end]) {
                 self.cursor_end();
             } else if matches(&key_str, &[&self.key_map.line_start]) {
+                self.display_line_start();
+            } else if matches(&key_str, &[&self.key_map.hard_line_start]) {
                 self.cursor_start();
             } else if matches(&key_str, &[&self.key_map.character_forward]) {
                 self.character_right();
@@ -892,14 +1252,27 @@ impl TextArea {
             } else if matches(&key_str, &[&self.key_map.transpose_character_backward]) {
                 self.transpose_left();
             } else if !matches(&key_str, &[&self.key_map.paste]) {
-                // Insert regular characters
+                // Insert regular characters, a word of typing making one
+                // undo step
                 let runes: Vec<char> = key.runes.clone();
-                if !runes.is_empty() {
+                if !runes.is_empty() && key.is_text() {
                     self.insert_runes_from_user_input(&runes);
+                    if runes.len() == 1 && !key.paste {
+                        if last_edit == LastEdit::Typing && !runes[0].is_whitespace() {
+                            before = None;
+                        }
+                        self.last_edit = LastEdit::Typing;
+                    }
                 }
             }
         }
 
+        if let Some(before) = before
+            && before.value != self.value
+        {
+            self.push_undo(before);
+        }
+
         self.viewport.update(&msg);
 
         let mut cmds: Vec<Option<Cmd>> = Vec::new();
@@ -936,59 +1309,61 @@ impl TextArea {
                 &style.text
             };
 
-            let mut s = String::new();
-
-            // Prompt
-            s.push_str(&style.prompt.render(&self.prompt));
-
-            // Line numbers
-            if self.show_line_numbers {
-                let ln_style = if is_cursor_line {
-                    &style.cursor_line_number
-                } else {
-                    &style.line_number
-                };
-                s.push_str(&ln_style.render(&format!("{:>3} ", l + 1)));
-            }
+            let segments = self.display_lines(l);
+            let cursor_segment = segment_at(&segments, self.col.min(line.len()));
+            for (index, segment) in segments.iter().enumerate() {
+                let mut s = String::new();
+
+                // Prompt
+                s.push_str(&style.prompt.render(&self.prompt));
+
+                // Line numbers, on the first display line only
+                if self.show_line_numbers {
+                    let ln_style = if is_cursor_line {
+                        &style.cursor_line_number
+                    } else {
+                        &style.line_number
+                    };
+                    if index == 0 {
+                        s.push_str(&ln_style.render(&format!("{:>3} ", l + 1)));
+                    } else {
+                        s.push_str(&ln_style.render("    "));
+                    }
+                }
 
-            // Line content
-            let line_str: String = line.iter().collect();
-            if is_cursor_line {
-                let before: String = line[..self.col.min(line.len())].iter().collect();
-                s.push_str(&line_style.render(&before));
+                // Line content
+                let text = &line[segment.clone()];
+                let mut current_line_width = cells(text);
+                if is_cursor_line && index == cursor_segment {
+                    let col = self.col.clamp(segment.start, segment.end) - segment.start;
+                    let before: String = text[..col].iter().collect();
+                    s.push_str(&line_style.render(&before));
 
-                if self.col < line.len() {
-                    let cursor_char: String = line[self.col..self.col + 1].iter().collect();
                     let mut cursor = self.cursor.clone();
-                    cursor.set_char(&cursor_char);
-                    s.push_str(&cursor.view());
-
-                    let after: String = line[self.col + 1..].iter().collect();
-                    s.push_str(&line_style.render(&after));
+                    if col < text.len() {
+                        cursor.set_char(&text[col].to_string());
+                        s.push_str(&cursor.view());
+
+                        let after: String = text[col + 1..].iter().collect();
+                        s.push_str(&line_style.render(&after));
+                    } else {
+                        cursor.set_char(" ");
+                        s.push_str(&cursor.view());
+                        current_line_width += 1; // Cursor at end adds a space
+                    }
                 } else {
-                    let mut cursor = self.cursor.clone();
-                    cursor.set_char(" ");
-                    s.push_str(&cursor.view());
+                    let line_str: String = text.iter().collect();
+                    s.push_str(&line_style.render(&line_str));
                 }
-            } else {
-                s.push_str(&line_style.render(&line_str));
-            }
 
-            // Padding
-            let mut current_line_width: usize = line
-                .iter()
-                .map(|c| unicode_width::UnicodeWidthChar::width(*c).unwrap_or(0))
-                .sum();
-            if is_cursor_line && self.col >= line.len() {
-                current_line_width += 1; // Cursor at end adds a space
-            }
+                // Padding
+                let padding = self.width.saturating_sub(current_line_width);
+                if padding > 0 {
+                    s.push_str(&line_style.render(&" ".repeat(padding)));
+                }
 
-            let padding = self.width.saturating_sub(current_line_width);
-            if padding > 0 {
-                s.push_str(&line_style.render(&" ".repeat(padding)));
+                lines.push(s);
             }
-
-            lines.push(s);
         }
 
         // Pad to height with empty lines
@@ -1009,7 +1384,7 @@ impl TextArea {
         }
 
         // Apply viewport
-        let start = self.viewport.y_offset();
+        let start = self.y_offset.min(lines.len().saturating_sub(self.height));
         let end = (start + self.height).min(lines.len());
         let visible: String = lines[start..end].join("\n");
 
@@ -1072,6 +1447,71 @@ impl TextArea {
     }
 }
 
+/// Returns the width of `c` in terminal cells.
+fn char_width(c: char) -> usize {
+    unicode_width::UnicodeWidthChar::width(c).unwrap_or(0)
+}
+
+/// Returns the width of `chars` in terminal cells.
+fn cells(chars: &[char]) -> usize {
+    chars.iter().map(|&c| char_width(c)).sum()
+}
+
+/// Splits `line` into display lines at most `width` cells wide, as ranges
+/// of character indices.
+///
+/// Lines break after the last space that fits, and words wider than a line
+/// break wherever the line fills up; wide characters never straddle a break.
+/// When the last display line is full, an empty one follows it so the cursor
+/// has somewhere to sit after the final character.
+fn wrap_line(line: &[char], width: usize) -> Vec<Range<usize>> {
+    let width = width.max(1);
+    let mut segments = Vec::new();
+    let mut start = 0;
+    let mut used = 0;
+    let mut after_space = None;
+    let mut i = 0;
+
+    while i < line.len() {
+        let w = char_width(line[i]);
+        if used + w > width && i > start {
+            // Spaces may hang past the edge rather than start a line
+            let end = if line[i] == ' ' {
+                i + 1
+            } else {
+                after_space.filter(|&end| end > start).unwrap_or(i)
+            };
+            segments.push(start..end);
+            start = end;
+            used = cells(&line[start..i.max(start)]);
+            after_space = None;
+            i = i.max(start);
+            continue;
+        }
+        used += w;
+        if line[i] == ' ' {
+            after_space = Some(i + 1);
+        }
+        i += 1;
+    }
+
+    segments.push(start..line.len());
+    if used >= width && !line.is_empty() {
+        segments.push(line.len()..line.len());
+    }
+    segments
+}
+
+/// Returns the index of the display line holding column `col`.
+///
+/// A column at the break between two display lines belongs to the second.
+fn segment_at(segments: &[Range<usize>], col: usize) -> usize {
+    segments
+        .iter()
+        .rposition(|segment| segment.start <= col)
+        .unwrap_or(0)
+}
+
 impl Model for TextArea {
     /// Initialize the textarea and return a blink command if focused.
     fn init(&self) -> Option<Cmd> {
@@ -1092,6 +1532,7 @@ impl Model for TextArea {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bubbletea::{KeyMod, KeyType};
 
     #[test]
     fn test_textarea_new() {
@@ -1566,6 +2007,185 @@ mod tests {
         assert!(!view.is_empty(), "View should work after width set");
     }
 
+    fn soft_wrapped(value: &str, width: usize) -> TextArea {
+        let mut ta = TextArea::new();
+        ta.prompt = String::new();
+        ta.show_line_numbers = false;
+        ta.soft_wrap = true;
+        ta.set_width(width);
+        ta.focus();
+        ta.set_value(value);
+        ta
+    }
+
+    fn press(ta: &mut TextArea, key: KeyMsg) {
+        let _ = Model::update(ta, Message::new(key));
+    }
+
+    #[test]
+    fn test_textarea_wrap_line_wide_characters() {
+        let line: Vec<char> = "日本語テキスト".chars().collect();
+        assert_eq!(wrap_line(&line, 5), vec![0..2, 2..4, 4..6, 6..7]);
+
+        let line: Vec<char> = "ab 日本語".chars().collect();
+        assert_eq!(wrap_line(&line, 5), vec![0..3, 3..5, 5..6]);
+
+        // A full last line leaves room for the cursor after it
+        let line: Vec<char> = "abcd".chars().collect();
+        assert_eq!(wrap_line(&line, 4), vec![0..4, 4..4]);
+        let empty = wrap_line(&[], 4);
+        assert_eq!(empty.len(), 1);
+        assert!(empty[0].is_empty());
+    }
+
+    #[test]
+    fn test_textarea_soft_wrap_view() {
+        let mut ta = soft_wrapped("日本語テキスト", 5);
+        ta.set_height(6);
+        ta.blur();
+
+        let view = ta.view();
+        let lines: Vec<&str> = view.lines().map(str::trim_end).collect();
+        assert_eq!(lines[..4], ["日本", "語テ", "キス", "ト"]);
+    }
+
+    #[test]
+    fn test_textarea_soft_wrap_up_down_by_display_line() {
+        let mut ta = soft_wrapped("日本語テキスト\nab", 5);
+        ta.row = 0;
+        ta.set_cursor_col(1);
+
+        press(&mut ta, KeyMsg::from_type(KeyType::Down));
+        assert_eq!((ta.row, ta.col), (0, 3));
+        press(&mut ta, KeyMsg::from_type(KeyType::Down));
+        assert_eq!((ta.row, ta.col), (0, 5));
+        press(&mut ta, KeyMsg::from_type(KeyType::Down));
+        assert_eq!((ta.row, ta.col), (0, 7));
+        press(&mut ta, KeyMsg::from_type(KeyType::Down));
+        assert_eq!((ta.row, ta.col), (1, 2));
+
+        press(&mut ta, KeyMsg::from_type(KeyType::Up));
+        assert_eq!((ta.row, ta.col), (0, 7));
+        press(&mut ta, KeyMsg::from_type(KeyType::Up));
+        assert_eq!((ta.row, ta.col), (0, 5));
+    }
+
+    #[test]
+    fn test_textarea_soft_wrap_keeps_column_across_short_lines() {
+        let mut ta = soft_wrapped("abcdefg\nx\nabcdefg", 4);
+        ta.row = 0;
+        ta.set_cursor_col(2);
+
+        press(&mut ta, KeyMsg::from_type(KeyType::Down));
+        assert_eq!((ta.row, ta.col), (0, 6));
+        press(&mut ta, KeyMsg::from_type(KeyType::Down));
+        assert_eq!((ta.row, ta.col), (1, 1));
+        press(&mut ta, KeyMsg::from_type(KeyType::Down));
+        assert_eq!((ta.row, ta.col), (2, 2));
+    }
+
+    #[test]
+    fn test_textarea_soft_and_hard_home_end() {
+        let mut ta = soft_wrapped("日本語テキスト", 5);
+        ta.set_cursor_col(3);
+
+        press(&mut ta, KeyMsg::from_type(KeyType::End));
+        assert_eq!(ta.col, 3, "end stays before the next display line");
+        press(&mut ta, KeyMsg::from_type(KeyType::Home));
+        assert_eq!(ta.col, 2);
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlE));
+        assert_eq!(ta.col, 7);
+        press(&mut ta, KeyMsg::from_type(KeyType::Home));
+        assert_eq!(ta.col, 6);
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlA));
+        assert_eq!(ta.col, 0);
+
+        // Without soft wrap, home and end reach the ends of the line
+        ta.soft_wrap = false;
+        press(&mut ta, KeyMsg::from_type(KeyType::End));
+        assert_eq!(ta.col, 7);
+        press(&mut ta, KeyMsg::from_type(KeyType::Home));
+        assert_eq!(ta.col, 0);
+    }
+
+    #[test]
+    fn test_textarea_soft_wrap_scrolls_to_cursor() {
+        let mut ta = soft_wrapped("日本語テキスト", 5);
+        ta.set_height(2);
+        ta.set_cursor_col(0);
+
+        press(&mut ta, KeyMsg::from_type(KeyType::Down));
+        press(&mut ta, KeyMsg::from_type(KeyType::Down));
+        ta.blur();
+        let view = ta.view();
+        let lines: Vec<&str> = view.lines().map(str::trim_end).collect();
+        assert_eq!(lines, ["語テ", "キス"]);
+    }
+
+    fn typed(text: &str) -> TextArea {
+        let mut ta = TextArea::new();
+        ta.focus();
+        for c in text.chars() {
+            press(&mut ta, KeyMsg::from_char(c));
+        }
+        ta
+    }
+
+    #[test]
+    fn test_textarea_undo_redo_by_word() {
+        let mut ta = typed("hello world");
+        press(&mut ta, KeyMsg::from_type(KeyType::Backspace));
+
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlZ));
+        assert_eq!(ta.value(), "hello world");
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlZ));
+        assert_eq!(ta.value(), "hello");
+        assert_eq!(ta.column(), 5);
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlZ));
+        assert_eq!(ta.value(), "");
+        assert!(!ta.undo());
+
+        press(&mut ta, KeyMsg::from_char('z').with_alt());
+        assert_eq!(ta.value(), "hello");
+        press(&mut ta, KeyMsg::from_char('!'));
+        assert!(!ta.redo());
+        assert_eq!(ta.value(), "hello!");
+    }
+
+    #[test]
+    fn test_textarea_kill_and_yank() {
+        let mut ta = typed("one two three");
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlW));
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlW));
+        assert_eq!(ta.value(), "one ");
+        assert_eq!(ta.kill_ring(), ["two three"]);
+
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlA));
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlK));
+        assert_eq!(ta.kill_ring(), ["two three", "one "]);
+
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlY));
+        assert_eq!(ta.value(), "one ");
+        press(&mut ta, KeyMsg::from_char('y').with_alt());
+        assert_eq!(ta.value(), "two three");
+        press(&mut ta, KeyMsg::from_char('y').with_alt());
+        assert_eq!(ta.value(), "one ");
+
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlZ));
+        assert_eq!(ta.value(), "two three");
+    }
+
+    #[test]
+    fn test_textarea_kill_line_break_joins_kill() {
+        let mut ta = typed("ab");
+        ta.set_value("ab\ncd");
+        ta.move_to_begin();
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlK));
+        press(&mut ta, KeyMsg::from_type(KeyType::CtrlK));
+        assert_eq!(ta.value(), "cd");
+        assert_eq!(ta.kill_ring(), ["ab\n"]);
+    }
+
     #[test]
     fn test_model_init_returns_blink_when_focused() {
         let mut ta = TextArea::new();
@@ -1602,12 +2222,27 @@ mod tests {
             runes: vec!['h', 'e', 'l', 'l', 'o'],
             alt: false,
             paste: true,
+            modifiers: KeyMod::NONE,
         });
         let _ = Model::update(&mut ta, key_msg);
 
         assert_eq!(ta.value(), "hello");
     }
 
+    #[test]
+    fn test_runes_with_ctrl_or_super_not_inserted() {
+        use bubbletea::{KeyMsg, Message};
+
+        let mut ta = TextArea::new();
+        ta.focus();
+
+        for modifiers in [KeyMod::CTRL | KeyMod::SHIFT, KeyMod::SUPER] {
+            let key = KeyMsg::from_char('a').with_modifiers(modifiers);
+            let _ = Model::update(&mut ta, Message::new(key));
+        }
+        assert_eq!(ta.value(), "");
+    }
+
     #[test]
     fn test_bracketed_paste_multiline_preserves_newlines() {
         use bubbletea::{KeyMsg, KeyType, Message};
@@ -1621,6 +2256,7 @@ mod tests {
             runes: "line1\nline2\nline3".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: KeyMod::NONE,
         });
         let _ = Model::update(&mut ta, key_msg);
 
@@ -1645,6 +2281,7 @@ mod tests {
             runes: "line1\r\nline2".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: KeyMod::NONE,
         });
         let _ = Model::update(&mut ta, key_msg);
 
@@ -1669,6 +2306,7 @@ mod tests {
             runes: "this is a very long paste".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: KeyMod::NONE,
         });
         let _ = Model::update(&mut ta, key_msg);
 
@@ -1687,6 +2325,7 @@ mod tests {
             runes: "ignored".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: KeyMod::NONE,
         });
         let _ = Model::update(&mut ta, key_msg);
 
@@ -1708,6 +2347,7 @@ mod tests {
             runes: " ".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: KeyMod::NONE,
         });
         let _ = Model::update(&mut ta, key_msg);
 
@@ -1726,6 +2366,7 @@ mod tests {
             runes: "hello 世界 🌍".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: KeyMod::NONE,
         });
         let _ = Model::update(&mut ta, key_msg);
 
@@ -1746,6 +2387,7 @@ mod tests {
             runes: large_text.chars().collect(),
             alt: false,
             paste: true,
+            modifiers: KeyMod::NONE,
         });
         let _ = Model::update(&mut ta, key_msg);
 
@@ -1768,6 +2410,7 @@ mod tests {
             runes: "line1\nline2\nline3".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: KeyMod::NONE,
         });
         let _ = Model::update(&mut ta, key_msg);
 
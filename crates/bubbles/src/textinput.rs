@@ -795,7 +795,7 @@ impl TextInput {
             ) {
                 // Input regular characters
                 let runes: Vec<char> = key.runes.clone();
-                if !runes.is_empty() {
+                if !runes.is_empty() && key.is_text() {
                     self.insert_runes_from_user_input(&runes);
                 }
             }
@@ -1198,6 +1198,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1289,12 +1290,30 @@ mod tests {
             runes: vec!['h', 'e', 'l', 'l', 'o'],
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
         assert_eq!(input.value(), "hello");
     }
 
+    #[test]
+    fn test_runes_with_ctrl_or_super_not_inserted() {
+        let mut input = TextInput::new();
+        input.focus();
+
+        // The kitty keyboard protocol reports ctrl+shift+a and super+c as
+        // runes with modifiers
+        for modifiers in [
+            bubbletea::KeyMod::CTRL | bubbletea::KeyMod::SHIFT,
+            bubbletea::KeyMod::SUPER,
+        ] {
+            let key = KeyMsg::from_char('a').with_modifiers(modifiers);
+            let _ = Model::update(&mut input, Message::new(key));
+        }
+        assert_eq!(input.value(), "");
+    }
+
     #[test]
     fn test_bracketed_paste_multiline_converts_newlines() {
         let mut input = TextInput::new();
@@ -1306,6 +1325,7 @@ mod tests {
             runes: "line1\nline2\nline3".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1327,6 +1347,7 @@ mod tests {
             runes: "line1\r\nline2".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1351,6 +1372,7 @@ mod tests {
                 .collect(),
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1375,6 +1397,7 @@ mod tests {
             runes: "world and more text".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1395,6 +1418,7 @@ mod tests {
             runes: "world".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1416,6 +1440,7 @@ mod tests {
             runes: "ignored".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1434,6 +1459,7 @@ mod tests {
             runes: " ".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1452,6 +1478,7 @@ mod tests {
             runes: "hello\x01\x02world".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1472,6 +1499,7 @@ mod tests {
             runes: "hello 世界 🌍".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1489,6 +1517,7 @@ mod tests {
             runes: "col1\tcol2".chars().collect(),
             alt: false,
             paste: true,
+            modifiers: bubbletea::KeyMod::NONE,
         });
         let _ = Model::update(&mut input, key_msg);
 
@@ -1517,6 +1546,9 @@ mod tests {
         input.set_value("1234");
 
         assert_eq!(input.value(), "123");
-        assert!(input.err.is_none(), "Validation should run on truncated value");
+        assert!(
+            input.err.is_none(),
+            "Validation should run on truncated value"
+        );
     }
 }
@@ -14,10 +14,11 @@
 //! assert!(!timer.timed_out());
 //! ```
 
+use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
 
-use bubbletea::{Cmd, Message, Model};
+use bubbletea::{Clock, Cmd, Message, Model, system_clock, tick_with_clock};
 
 /// Global ID counter for timer instances.
 static NEXT_ID: AtomicU64 = AtomicU64::new(1);
@@ -54,6 +55,13 @@ impl TickMsg {
     }
 }
 
+/// Message to reset the timer to its starting duration.
+#[derive(Debug, Clone, Copy)]
+pub struct ResetMsg {
+    /// The timer ID.
+    pub id: u64,
+}
+
 /// Message sent once when the timer times out.
 #[derive(Debug, Clone, Copy)]
 pub struct TimeoutMsg {
@@ -66,6 +74,10 @@ pub struct TimeoutMsg {
 pub struct Timer {
     /// Remaining time.
     timeout: Duration,
+    /// Duration the timer started from, restored on reset.
+    initial: Duration,
+    /// Step the displayed time is rounded up to.
+    precision: Option<Duration>,
     /// Tick interval.
     interval: Duration,
     /// Unique ID.
@@ -74,6 +86,8 @@ pub struct Timer {
     tag: u64,
     /// Whether the timer is running.
     running: bool,
+    /// Clock that ticks wait on.
+    clock: Arc<dyn Clock>,
 }
 
 impl Timer {
@@ -88,13 +102,35 @@ impl Timer {
     pub fn with_interval(timeout: Duration, interval: Duration) -> Self {
         Self {
             timeout,
+            initial: timeout,
+            precision: None,
             interval,
             id: next_id(),
             tag: 0,
             running: true,
+            clock: system_clock(),
         }
     }
 
+    /// Sets the clock that ticks wait on.
+    ///
+    /// Pass a [`TestClock`](bubbletea::TestClock) in tests so that the
+    /// timer counts down without waiting in real time.
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Rounds the displayed time up to a whole number of `precision`, so a
+    /// timer ticking every 100ms can still show whole seconds. The time
+    /// shown only reaches zero when the timer times out.
+    #[must_use]
+    pub fn with_precision(mut self, precision: Duration) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
     /// Returns the timer's unique ID.
     #[must_use]
     pub fn id(&self) -> u64 {
@@ -128,6 +164,12 @@ impl Timer {
         self.interval
     }
 
+    /// Returns the step the displayed time is rounded to, if any.
+    #[must_use]
+    pub fn precision(&self) -> Option<Duration> {
+        self.precision
+    }
+
     /// Returns a command to initialize the timer (start ticking).
     #[must_use]
     pub fn init(&self) -> Option<Cmd> {
@@ -150,6 +192,13 @@ impl Timer {
         }))
     }
 
+    /// Resets the timer to its starting duration, keeping it running or
+    /// paused as it was.
+    pub fn reset(&mut self) -> Option<Cmd> {
+        let id = self.id;
+        Some(Cmd::new(move || Message::new(ResetMsg { id })))
+    }
+
     /// Toggles the timer between running and stopped.
     pub fn toggle(&mut self) -> Option<Cmd> {
         if self.running() {
@@ -166,8 +215,7 @@ impl Timer {
         let interval = self.interval;
         let timed_out = self.timed_out();
 
-        Cmd::new(move || {
-            std::thread::sleep(interval);
+        tick_with_clock(Arc::clone(&self.clock), interval, move |_| {
             Message::new(TickMsg {
                 id,
                 tag,
@@ -177,6 +225,10 @@ impl Timer {
     }
 
     /// Updates the timer state.
+    ///
+    /// Starting, stopping and resetting supersede the tick in flight, so a
+    /// paused timer resumes with a full interval and toggling quickly never
+    /// leaves two ticks running.
     pub fn update(&mut self, msg: Message) -> Option<Cmd> {
         // Handle start/stop
         if let Some(ss) = msg.downcast_ref::<StartStopMsg>() {
@@ -184,7 +236,18 @@ impl Timer {
                 return None;
             }
             self.running = ss.running;
-            return Some(self.tick_cmd());
+            self.tag = self.tag.wrapping_add(1);
+            return self.running().then(|| self.tick_cmd());
+        }
+
+        // Handle reset
+        if let Some(reset) = msg.downcast_ref::<ResetMsg>() {
+            if reset.id != 0 && reset.id != self.id {
+                return None;
+            }
+            self.timeout = self.initial;
+            self.tag = self.tag.wrapping_add(1);
+            return self.running().then(|| self.tick_cmd());
         }
 
         // Handle tick
@@ -221,7 +284,15 @@ impl Timer {
     /// Renders the timer display.
     #[must_use]
     pub fn view(&self) -> String {
-        format_duration(self.timeout)
+        let shown = match self.precision {
+            Some(precision) if !precision.is_zero() => {
+                let step = precision.as_nanos();
+                let nanos = self.timeout.as_nanos().div_ceil(step) * step;
+                Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+            }
+            _ => self.timeout,
+        };
+        format_duration(shown)
     }
 }
 
@@ -232,48 +303,11 @@ impl Model for Timer {
     }
 
     fn update(&mut self, msg: Message) -> Option<Cmd> {
-        // Handle start/stop
-        if let Some(ss) = msg.downcast_ref::<StartStopMsg>() {
-            if ss.id != 0 && ss.id != self.id {
-                return None;
-            }
-            self.running = ss.running;
-            return Some(self.tick_cmd());
-        }
-
-        // Handle tick
-        if let Some(tick) = msg.downcast_ref::<TickMsg>() {
-            if !self.running() || (tick.id != 0 && tick.id != self.id) {
-                return None;
-            }
-
-            // Reject old tags
-            if tick.tag > 0 && tick.tag != self.tag {
-                return None;
-            }
-
-            // Decrease timeout
-            self.timeout = self.timeout.saturating_sub(self.interval);
-            self.tag = self.tag.wrapping_add(1);
-
-            // Return tick command and optionally timeout message
-            if self.timed_out() {
-                let id = self.id;
-                let tick_cmd = self.tick_cmd();
-                return bubbletea::batch(vec![
-                    Some(tick_cmd),
-                    Some(Cmd::new(move || Message::new(TimeoutMsg { id }))),
-                ]);
-            }
-
-            return Some(self.tick_cmd());
-        }
-
-        None
+        Timer::update(self, msg)
     }
 
     fn view(&self) -> String {
-        format_duration(self.timeout)
+        Timer::view(self)
     }
 }
 
@@ -592,4 +626,58 @@ mod tests {
         assert!(!timer.running());
         assert_eq!(timer.view(), "0s");
     }
+
+    #[test]
+    fn test_timer_runs_down_on_test_clock() {
+        let clock = bubbletea::TestClock::new();
+        let mut timer = Timer::new(Duration::from_secs(30)).with_clock(Arc::new(clock.clone()));
+
+        let mut cmd = timer.init();
+        while !timer.timed_out() {
+            let msg = cmd.unwrap().execute().unwrap();
+            cmd = timer.update(msg);
+        }
+
+        assert_eq!(clock.elapsed(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_timer_pause_resume_and_reset() {
+        let clock = bubbletea::TestClock::new();
+        let mut timer = Timer::new(Duration::from_secs(10)).with_clock(Arc::new(clock));
+        let first = timer.init().unwrap().execute().unwrap();
+        assert!(timer.update(first).is_some());
+        assert_eq!(timer.remaining(), Duration::from_secs(9));
+
+        // Pausing drops the tick in flight
+        let in_flight = timer.tick_cmd().execute().unwrap();
+        let stop_msg = timer.stop().unwrap().execute().unwrap();
+        assert!(timer.update(stop_msg).is_none());
+        assert!(timer.update(in_flight).is_none());
+        assert_eq!(timer.remaining(), Duration::from_secs(9));
+
+        // Resuming supersedes ticks from before the pause
+        let before = timer.tick_cmd().execute().unwrap();
+        let start_msg = timer.start().unwrap().execute().unwrap();
+        let resumed = timer.update(start_msg);
+        assert!(timer.update(before).is_none());
+        timer.update(resumed.unwrap().execute().unwrap());
+        assert_eq!(timer.remaining(), Duration::from_secs(8));
+
+        let reset_msg = timer.reset().unwrap().execute().unwrap();
+        assert!(timer.update(reset_msg).is_some());
+        assert_eq!(timer.remaining(), Duration::from_secs(10));
+        assert!(timer.running());
+    }
+
+    #[test]
+    fn test_timer_precision_rounds_up() {
+        let mut timer =
+            Timer::new(Duration::from_millis(2500)).with_precision(Duration::from_secs(1));
+        assert_eq!(timer.view(), "3s");
+        timer.timeout = Duration::from_millis(1);
+        assert_eq!(timer.view(), "1s");
+        timer.timeout = Duration::ZERO;
+        assert_eq!(timer.view(), "0s");
+    }
 }
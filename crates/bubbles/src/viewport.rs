@@ -14,8 +14,56 @@
 //! // Scroll down
 //! viewport.scroll_down(1);
 //! ```
+//!
+//! # Following Logs
+//!
+//! With [`Viewport::follow`] enabled the viewport sticks to the bottom as
+//! lines are added, until the user scrolls up to read earlier output:
+//!
+//! ```rust
+//! use bubbles::viewport::Viewport;
+//!
+//! let mut viewport = Viewport::new(80, 2);
+//! viewport.follow = true;
+//! viewport.append_lines(["starting", "listening on :8080"]);
+//! viewport.append_lines(["GET /"]);
+//! assert!(viewport.at_bottom());
+//! assert_eq!(viewport.y_offset(), 1);
+//! ```
+//!
+//! # Jumping Around
+//!
+//! Besides scrolling, the viewport can jump to a line with
+//! [`Viewport::goto_line`], to a fraction of the way through with
+//! [`Viewport::goto_percent`], or center a line with
+//! [`Viewport::center_on`]. Like in `less`, keys take a numeric prefix:
+//! `42G` goes to line 42, `50%` halfway down, and `5j` five lines down.
+//!
+//! ```rust
+//! use bubbles::viewport::Viewport;
+//! use bubbletea::{KeyMsg, Message};
+//!
+//! let mut viewport = Viewport::new(80, 10);
+//! viewport.set_content(&(1..=100).map(|n| n.to_string()).collect::<Vec<_>>().join("\n"));
+//! for c in "42G".chars() {
+//!     viewport.update(&Message::new(KeyMsg::from_char(c)));
+//! }
+//! assert_eq!(viewport.y_offset(), 41);
+//! ```
+//!
+//! # Fast Scrolling
+//!
+//! Each scroll moves every line of the body, so by default the whole body is
+//! written again. A program run with
+//! [`Program::with_scroll_regions`](bubbletea::Program::with_scroll_regions)
+//! on the alternate screen instead has the terminal scroll the body's rows,
+//! and only the lines scrolled into view are written:
+//!
+//! ```rust,ignore
+//! Program::new(pager).with_alt_screen().with_scroll_regions().run()?;
+//! ```
 
-use crate::key::{Binding, matches};
+use crate::key::{Binding, count_digit, matches};
 use bubbletea::{Cmd, KeyMsg, Message, Model, MouseMsg};
 use lipgloss::Style;
 use unicode_width::UnicodeWidthChar;
@@ -39,6 +87,12 @@ pub struct KeyMap {
     pub left: Binding,
     /// Scroll right binding.
     pub right: Binding,
+    /// Go to the top, or to the line given as a count.
+    pub goto_top: Binding,
+    /// Go to the bottom, or to the line given as a count.
+    pub goto_bottom: Binding,
+    /// Go to the percentage given as a count.
+    pub goto_percent: Binding,
 }
 
 impl Default for KeyMap {
@@ -60,6 +114,9 @@ impl Default for KeyMap {
             right: Binding::new()
                 .keys(&["right", "l"])
                 .help("→/l", "move right"),
+            goto_top: Binding::new().keys(&["home", "g"]).help("g/home", "top"),
+            goto_bottom: Binding::new().keys(&["end", "G"]).help("G/end", "bottom"),
+            goto_percent: Binding::new().keys(&["%"]).help("N%", "go to percent"),
         }
     }
 }
@@ -77,6 +134,11 @@ pub struct Viewport {
     pub mouse_wheel_enabled: bool,
     /// Number of lines to scroll per mouse wheel tick.
     pub mouse_wheel_delta: usize,
+    /// Whether to stay scrolled to the bottom as content changes.
+    ///
+    /// Following only applies while the viewport is at the bottom, so
+    /// scrolling up pauses it and scrolling back down resumes it.
+    pub follow: bool,
     /// Vertical scroll offset.
     y_offset: usize,
     /// Horizontal scroll offset.
@@ -89,6 +151,8 @@ pub struct Viewport {
     lines: Vec<String>,
     /// Width of the longest line.
     longest_line_width: usize,
+    /// Numeric prefix typed before a key, such as the `42` of `42G`.
+    count: Option<usize>,
 }
 
 impl Viewport {
@@ -101,31 +165,88 @@ impl Viewport {
             key_map: KeyMap::default(),
             mouse_wheel_enabled: true,
             mouse_wheel_delta: 3,
+            follow: false,
             y_offset: 0,
             x_offset: 0,
             horizontal_step: 0,
             style: Style::new(),
             lines: Vec::new(),
             longest_line_width: 0,
+            count: None,
         }
     }
 
     /// Sets the content of the viewport.
     pub fn set_content(&mut self, content: &str) {
-        let normalized = content.replace("\r\n", "\n");
-        self.lines = normalized.split('\n').map(String::from).collect();
-        self.longest_line_width = self
-            .lines
-            .iter()
-            .map(|l| visible_width(l))
-            .max()
-            .unwrap_or(0);
+        let following = self.is_following();
+        self.replace_lines(split_lines(content));
 
-        if self.y_offset > self.lines.len().saturating_sub(1) {
+        if following || self.y_offset > self.lines.len().saturating_sub(1) {
             self.goto_bottom();
         }
     }
 
+    /// Sets the content of the viewport, keeping the same lines in view.
+    ///
+    /// When the new content only adds lines after the old content, the
+    /// offset is unchanged; when it only adds lines before it, the offset
+    /// moves down by the same amount so the visible lines don't shift.
+    /// Otherwise the offset is kept and clamped to the new content. In
+    /// follow mode a viewport at the bottom stays at the bottom.
+    pub fn set_content_preserve_offset(&mut self, content: &str) {
+        let following = self.is_following();
+        let lines = split_lines(content);
+        let added = lines.len().saturating_sub(self.lines.len());
+        let prepended =
+            added > 0 && lines.ends_with(&self.lines) && !lines.starts_with(&self.lines);
+        let offset = if prepended {
+            self.y_offset + added
+        } else {
+            self.y_offset
+        };
+        self.replace_lines(lines);
+
+        if following {
+            self.goto_bottom();
+        } else {
+            self.set_y_offset(offset);
+        }
+    }
+
+    /// Appends lines to the end of the content.
+    ///
+    /// The scroll position is kept, except in follow mode where a viewport
+    /// at the bottom scrolls to show the new lines. Lines containing
+    /// newlines are split.
+    pub fn append_lines<I, S>(&mut self, lines: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let following = self.is_following();
+        for line in lines {
+            for line in split_lines(line.as_ref()) {
+                self.longest_line_width = self.longest_line_width.max(visible_width(&line));
+                self.lines.push(line);
+            }
+        }
+
+        if following {
+            self.goto_bottom();
+        }
+    }
+
+    /// Replaces the content lines and recomputes the longest line width.
+    fn replace_lines(&mut self, lines: Vec<String>) {
+        self.longest_line_width = lines.iter().map(|l| visible_width(l)).max().unwrap_or(0);
+        self.lines = lines;
+    }
+
+    /// Returns whether content changes should keep the viewport at the bottom.
+    fn is_following(&self) -> bool {
+        self.follow && self.at_bottom()
+    }
+
     /// Returns the vertical scroll offset.
     #[must_use]
     pub fn y_offset(&self) -> usize {
@@ -304,10 +425,35 @@ impl Viewport {
         self.set_y_offset(self.max_y_offset());
     }
 
+    /// Scrolls so line `n`, counted from 0, is at the top, or as close to
+    /// it as the content allows.
+    pub fn goto_line(&mut self, n: usize) {
+        self.set_y_offset(n);
+    }
+
+    /// Scrolls to a fraction of the way through the content, from 0.0 at
+    /// the top to 1.0 at the bottom, the inverse of
+    /// [`scroll_percent`](Self::scroll_percent).
+    pub fn goto_percent(&mut self, percent: f64) {
+        let offset = (percent.clamp(0.0, 1.0) * self.max_y_offset() as f64).round();
+        self.set_y_offset(offset as usize);
+    }
+
+    /// Scrolls so line `n`, counted from 0, is in the middle of the
+    /// viewport, or as close to it as the content allows.
+    pub fn center_on(&mut self, n: usize) {
+        self.set_y_offset(n.saturating_sub(self.content_height().saturating_sub(1) / 2));
+    }
+
     /// Updates the viewport based on key/mouse input.
     pub fn update(&mut self, msg: &Message) {
         if let Some(key) = msg.downcast_ref::<KeyMsg>() {
             let key_str = key.to_string();
+            if let Some(count) = count_digit(&key_str, self.count) {
+                self.count = Some(count);
+                return;
+            }
+            let count = self.count.take();
 
             if matches(&key_str, &[&self.key_map.page_down]) {
                 self.page_down();
@@ -318,9 +464,21 @@ impl Viewport {
             } else if matches(&key_str, &[&self.key_map.half_page_up]) {
                 self.half_page_up();
             } else if matches(&key_str, &[&self.key_map.down]) {
-                self.scroll_down(1);
+                self.scroll_down(count.unwrap_or(1));
             } else if matches(&key_str, &[&self.key_map.up]) {
-                self.scroll_up(1);
+                self.scroll_up(count.unwrap_or(1));
+            } else if matches(&key_str, &[&self.key_map.goto_top]) {
+                // Counts number lines from 1, like `less`
+                self.goto_line(count.map_or(0, |n| n.saturating_sub(1)));
+            } else if matches(&key_str, &[&self.key_map.goto_bottom]) {
+                match count {
+                    Some(n) => self.goto_line(n.saturating_sub(1)),
+                    None => self.goto_bottom(),
+                }
+            } else if matches(&key_str, &[&self.key_map.goto_percent])
+                && let Some(n) = count
+            {
+                self.goto_percent(n as f64 / 100.0);
             } else if matches(&key_str, &[&self.key_map.left]) {
                 self.scroll_left(self.horizontal_step);
             } else if matches(&key_str, &[&self.key_map.right]) {
@@ -420,6 +578,14 @@ impl Model for Viewport {
     }
 }
 
+fn split_lines(content: &str) -> Vec<String> {
+    content
+        .replace("\r\n", "\n")
+        .split('\n')
+        .map(String::from)
+        .collect()
+}
+
 fn as_u16(value: usize) -> u16 {
     value.min(u16::MAX as usize) as u16
 }
@@ -503,6 +669,68 @@ mod tests {
         assert_eq!(v.total_line_count(), 7);
     }
 
+    #[test]
+    fn test_viewport_append_lines_keeps_offset() {
+        let mut v = Viewport::new(80, 3);
+        v.set_content("1\n2\n3\n4");
+        v.set_y_offset(1);
+
+        v.append_lines(["5", "6\n7"]);
+        assert_eq!(v.total_line_count(), 7);
+        assert_eq!(v.y_offset(), 1);
+        assert!(v.view().starts_with('2'));
+
+        let mut wide = Viewport::new(2, 3);
+        wide.append_lines(["wide line"]);
+        wide.set_x_offset(5);
+        assert_eq!(wide.x_offset(), 5);
+    }
+
+    #[test]
+    fn test_viewport_follow_sticks_to_bottom() {
+        let mut v = Viewport::new(80, 3);
+        v.follow = true;
+        v.append_lines(["1", "2", "3", "4"]);
+        assert!(v.at_bottom());
+        assert_eq!(v.y_offset(), 1);
+
+        v.set_content("1\n2\n3\n4\n5\n6");
+        assert_eq!(v.y_offset(), 3);
+
+        // Scrolling up pauses following...
+        v.scroll_up(2);
+        v.append_lines(["7"]);
+        assert_eq!(v.y_offset(), 1);
+
+        // ...and returning to the bottom resumes it.
+        v.goto_bottom();
+        v.append_lines(["8"]);
+        assert_eq!(v.y_offset(), 5);
+        assert_eq!(v.view().lines().last().map(str::trim_end), Some("8"));
+    }
+
+    #[test]
+    fn test_viewport_set_content_preserve_offset() {
+        let mut v = Viewport::new(80, 2);
+        v.set_content("a\nb\nc\nd");
+        v.set_y_offset(1);
+
+        // Appended lines keep the offset.
+        v.set_content_preserve_offset("a\nb\nc\nd\ne");
+        assert_eq!(v.y_offset(), 1);
+
+        // Prepended lines shift it so the same lines stay in view.
+        v.set_content_preserve_offset("z\ny\na\nb\nc\nd\ne");
+        assert_eq!(v.y_offset(), 3);
+        let view = v.view();
+        let visible: Vec<_> = view.lines().map(str::trim_end).collect();
+        assert_eq!(visible, ["b", "c"]);
+
+        // Unrelated content keeps the offset, clamped to the new length.
+        v.set_content_preserve_offset("x\ny");
+        assert_eq!(v.y_offset(), 0);
+    }
+
     #[test]
     fn test_viewport_at_top_bottom() {
         let mut v = Viewport::new(80, 3);
@@ -542,6 +770,70 @@ mod tests {
         assert_eq!(v.y_offset(), 0);
     }
 
+    #[test]
+    fn test_viewport_goto_line_percent_and_center() {
+        let mut v = Viewport::new(80, 5);
+        v.set_content(
+            &(0..20)
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+
+        v.goto_line(7);
+        assert_eq!(v.y_offset(), 7);
+        v.goto_line(99);
+        assert_eq!(v.y_offset(), 15);
+
+        v.goto_percent(0.5);
+        assert_eq!(v.y_offset(), 8);
+        v.goto_percent(v.scroll_percent());
+        assert_eq!(v.y_offset(), 8);
+        v.goto_percent(2.0);
+        assert!(v.at_bottom());
+
+        v.center_on(10);
+        assert_eq!(v.y_offset(), 8);
+        assert_eq!(v.view().lines().nth(2).map(str::trim_end), Some("10"));
+        v.center_on(1);
+        assert_eq!(v.y_offset(), 0);
+    }
+
+    #[test]
+    fn test_viewport_numeric_prefix() {
+        let mut v = Viewport::new(80, 5);
+        v.set_content(
+            &(1..=100)
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+        let keys = |v: &mut Viewport, keys: &str| {
+            for c in keys.chars() {
+                v.update(&Message::new(KeyMsg::from_char(c)));
+            }
+        };
+
+        keys(&mut v, "42G");
+        assert_eq!(v.y_offset(), 41);
+        keys(&mut v, "5k");
+        assert_eq!(v.y_offset(), 36);
+        keys(&mut v, "10j");
+        assert_eq!(v.y_offset(), 46);
+        keys(&mut v, "g");
+        assert_eq!(v.y_offset(), 0);
+        keys(&mut v, "100%");
+        assert!(v.at_bottom());
+        keys(&mut v, "3g");
+        assert_eq!(v.y_offset(), 2);
+
+        // A count is used up by the next key, even one it doesn't apply to
+        keys(&mut v, "9%G");
+        assert!(v.at_bottom());
+        keys(&mut v, "%");
+        assert!(v.at_bottom());
+    }
+
     #[test]
     fn test_viewport_scroll_percent() {
         let mut v = Viewport::new(80, 5);
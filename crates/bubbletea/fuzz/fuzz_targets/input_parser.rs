@@ -0,0 +1,29 @@
+//! Fuzzes the terminal input parser with arbitrary byte streams.
+//!
+//! Run with `cargo +nightly fuzz run input_parser` from `crates/bubbletea`.
+//! The first byte picks a chunk size so split escape sequences are covered.
+
+#![no_main]
+
+use bubbletea::{InputParser, InterruptMsg, QuitMsg};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&chunk, bytes)) = data.split_first() else {
+        return;
+    };
+    let chunk = usize::from(chunk).max(1);
+
+    let mut parser = InputParser::new();
+    let mut msgs = Vec::new();
+    for part in bytes.chunks(chunk) {
+        msgs.extend(parser.push_bytes(part, true));
+    }
+    msgs.extend(parser.flush());
+
+    assert!(!parser.has_pending());
+    assert!(!msgs.iter().any(|m| m.is::<QuitMsg>()));
+    if !bytes.contains(&0x03) {
+        assert!(!msgs.iter().any(|m| m.is::<InterruptMsg>()));
+    }
+});
@@ -0,0 +1,228 @@
+//! The crossterm commands the renderer uses, for `wasm32` targets.
+//!
+//! crossterm doesn't build for `wasm32`, where the only terminal is an
+//! emulator that reads escape sequences anyway, so these write the same
+//! sequences crossterm does without the console API fallbacks. The modules
+//! and [`queue!`]/[`execute!`] mirror crossterm's, so the renderer imports
+//! them in its place.
+
+use std::fmt;
+
+/// Writes commands to a writer, as crossterm's `queue!` does.
+macro_rules! queue {
+    ($writer:expr $(, $command:expr)* $(,)?) => {{
+        // Callers usually have `Write` in scope already
+        #[allow(unused_imports)]
+        use ::std::io::Write as _;
+
+        // This allows the macro to take both mut impl Write and &mut impl Write.
+        Ok::<_, ::std::io::Error>($writer.by_ref())
+            $(.and_then(|writer| write!(writer, "{}", $command).map(|()| writer)))*
+            .map(|_| ())
+    }};
+}
+
+/// Writes commands to a writer and flushes it, as crossterm's `execute!`
+/// does.
+macro_rules! execute {
+    ($writer:expr $(, $command:expr)* $(,)?) => {{
+        #[allow(unused_imports)]
+        use ::std::io::Write as _;
+
+        $crate::ansi::queue!($writer $(, $command)*).and_then(|()| $writer.flush())
+    }};
+}
+
+pub(crate) use {execute, queue};
+
+/// Writes a control sequence introducer followed by `args`.
+fn csi(f: &mut fmt::Formatter<'_>, args: fmt::Arguments<'_>) -> fmt::Result {
+    write!(f, "\x1b[{args}")
+}
+
+pub(crate) mod cursor {
+    use std::fmt;
+
+    use super::csi;
+
+    /// Moves the cursor to a column and row, counted from 0.
+    pub(crate) struct MoveTo(pub u16, pub u16);
+
+    impl fmt::Display for MoveTo {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            csi(f, format_args!("{};{}H", self.1 + 1, self.0 + 1))
+        }
+    }
+
+    /// Moves the cursor to a column of its row, counted from 0.
+    pub(crate) struct MoveToColumn(pub u16);
+
+    impl fmt::Display for MoveToColumn {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            csi(f, format_args!("{}G", self.0 + 1))
+        }
+    }
+
+    /// Moves the cursor up some rows.
+    pub(crate) struct MoveUp(pub u16);
+
+    impl fmt::Display for MoveUp {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            csi(f, format_args!("{}A", self.0))
+        }
+    }
+
+    /// Moves the cursor down some rows.
+    pub(crate) struct MoveDown(pub u16);
+
+    impl fmt::Display for MoveDown {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            csi(f, format_args!("{}B", self.0))
+        }
+    }
+
+    pub(crate) struct Hide;
+
+    impl fmt::Display for Hide {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("\x1b[?25l")
+        }
+    }
+
+    pub(crate) struct Show;
+
+    impl fmt::Display for Show {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("\x1b[?25h")
+        }
+    }
+}
+
+pub(crate) mod event {
+    use std::fmt;
+
+    /// Turns on mouse reports for presses, drags and motion, in SGR
+    /// encoding where supported.
+    pub(crate) struct EnableMouseCapture;
+
+    impl fmt::Display for EnableMouseCapture {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("\x1b[?1000h\x1b[?1002h\x1b[?1003h\x1b[?1015h\x1b[?1006h")
+        }
+    }
+
+    /// Undoes [`EnableMouseCapture`], in reverse order.
+    pub(crate) struct DisableMouseCapture;
+
+    impl fmt::Display for DisableMouseCapture {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("\x1b[?1006l\x1b[?1015l\x1b[?1003l\x1b[?1002l\x1b[?1000l")
+        }
+    }
+
+    pub(crate) struct EnableFocusChange;
+
+    impl fmt::Display for EnableFocusChange {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("\x1b[?1004h")
+        }
+    }
+
+    pub(crate) struct DisableFocusChange;
+
+    impl fmt::Display for DisableFocusChange {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("\x1b[?1004l")
+        }
+    }
+
+    pub(crate) struct EnableBracketedPaste;
+
+    impl fmt::Display for EnableBracketedPaste {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("\x1b[?2004h")
+        }
+    }
+
+    pub(crate) struct DisableBracketedPaste;
+
+    impl fmt::Display for DisableBracketedPaste {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("\x1b[?2004l")
+        }
+    }
+}
+
+pub(crate) mod terminal {
+    use std::fmt;
+
+    use super::csi;
+
+    /// The part of the screen [`Clear`] erases.
+    pub(crate) enum ClearType {
+        All,
+        FromCursorDown,
+        UntilNewLine,
+    }
+
+    pub(crate) struct Clear(pub ClearType);
+
+    impl fmt::Display for Clear {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(match self.0 {
+                ClearType::All => "\x1b[2J",
+                ClearType::FromCursorDown => "\x1b[J",
+                ClearType::UntilNewLine => "\x1b[K",
+            })
+        }
+    }
+
+    /// Scrolls the scroll region up some rows; nothing for 0.
+    pub(crate) struct ScrollUp(pub u16);
+
+    impl fmt::Display for ScrollUp {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if self.0 == 0 {
+                return Ok(());
+            }
+            csi(f, format_args!("{}S", self.0))
+        }
+    }
+
+    /// Scrolls the scroll region down some rows; nothing for 0.
+    pub(crate) struct ScrollDown(pub u16);
+
+    impl fmt::Display for ScrollDown {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            if self.0 == 0 {
+                return Ok(());
+            }
+            csi(f, format_args!("{}T", self.0))
+        }
+    }
+
+    pub(crate) struct EnterAlternateScreen;
+
+    impl fmt::Display for EnterAlternateScreen {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("\x1b[?1049h")
+        }
+    }
+
+    pub(crate) struct LeaveAlternateScreen;
+
+    impl fmt::Display for LeaveAlternateScreen {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("\x1b[?1049l")
+        }
+    }
+
+    /// Sets the window title.
+    pub(crate) struct SetTitle<T>(pub T);
+
+    impl<T: fmt::Display> fmt::Display for SetTitle<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "\x1b]0;{}\x07", self.0)
+        }
+    }
+}
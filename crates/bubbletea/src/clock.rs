@@ -0,0 +1,159 @@
+//! Time sources for tick commands.
+//!
+//! [`tick`](crate::tick) and [`every`](crate::every) wait on the real clock.
+//! Their [`tick_with_clock`](crate::tick_with_clock) and
+//! [`every_with_clock`](crate::every_with_clock) variants take a [`Clock`]
+//! instead, so that tests can use a [`TestClock`], on which waiting takes no
+//! real time and the time read is always the same for the same steps.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//! use bubbletea::{Message, TestClock, tick_with_clock};
+//!
+//! let clock = TestClock::new();
+//! let cmd = tick_with_clock(Arc::new(clock.clone()), Duration::from_secs(60), |_| {
+//!     Message::new("done")
+//! });
+//!
+//! // Returns at once, with the clock a minute later
+//! let msg = cmd.execute().unwrap();
+//! assert_eq!(msg.downcast::<&str>(), Some("done"));
+//! assert_eq!(clock.elapsed(), Duration::from_secs(60));
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+/// A source of time that tick commands read and wait on.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current monotonic time.
+    fn now(&self) -> Instant;
+
+    /// Returns the current wall clock time, which [`every`](crate::every)
+    /// aligns ticks to.
+    fn wall_now(&self) -> SystemTime;
+
+    /// Blocks until `duration` has passed on this clock.
+    fn sleep(&self, duration: Duration);
+}
+
+/// Returns the real clock as a shared [`Clock`].
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// The real clock, which waits by sleeping the thread.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn wall_now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// A clock that only moves when told to, for deterministic tests.
+///
+/// Sleeping on it returns at once and moves the clock forward by the time
+/// slept, as if the sleep had taken exactly that long, and
+/// [`advance`](Self::advance) moves it forward by hand. Clones share the
+/// same time, so a test can keep one and give another to the code under
+/// test.
+///
+/// The wall clock starts at the Unix epoch, so ticks from
+/// [`every_with_clock`](crate::every_with_clock) fall on exact multiples of
+/// their interval.
+#[derive(Debug, Clone)]
+pub struct TestClock {
+    start: Instant,
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl TestClock {
+    /// Creates a clock at time zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Moves the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    /// Returns the time passed on the clock since it was created.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        *self.lock()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Duration> {
+        // A panic while holding the lock can't leave the duration invalid
+        self.elapsed
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Default for TestClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+
+    fn wall_now(&self) -> SystemTime {
+        SystemTime::UNIX_EPOCH + self.elapsed()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_moves_only_when_told() {
+        let clock = TestClock::new();
+        let start = clock.now();
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::from_secs(2));
+        clock.sleep(Duration::from_millis(500));
+        assert_eq!(clock.now() - start, Duration::from_millis(2500));
+        assert_eq!(
+            clock.wall_now(),
+            SystemTime::UNIX_EPOCH + Duration::from_millis(2500)
+        );
+    }
+
+    #[test]
+    fn test_clock_clones_share_time() {
+        let clock = TestClock::new();
+        let shared: Arc<dyn Clock> = Arc::new(clock.clone());
+        shared.sleep(Duration::from_secs(1));
+        assert_eq!(clock.elapsed(), Duration::from_secs(1));
+    }
+}
@@ -12,10 +12,15 @@
 //!
 //! Both types are automatically handled by the program's command executor.
 
+use std::io;
+use std::process::{Command, ExitStatus};
+use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime};
 
+use crate::clock::{Clock, SystemClock};
 use crate::message::{
-    BatchMsg, Message, PrintLineMsg, QuitMsg, RequestWindowSizeMsg, SequenceMsg, SetWindowTitleMsg,
+    BatchMsg, Message, NotifyMsg, PrintLineMsg, QuitMsg, RequestInputLatencyMsg,
+    RequestWindowSizeMsg, SequenceMsg, SetWindowTitleMsg, SuspendMsg,
 };
 
 #[cfg(feature = "async")]
@@ -43,7 +48,11 @@ use std::pin::Pin;
 ///     })
 /// }
 /// ```
-pub struct Cmd(Box<dyn FnOnce() -> Option<Message> + Send + 'static>);
+pub struct Cmd {
+    run: Box<dyn FnOnce() -> Option<Message> + Send + 'static>,
+    /// Time to wait before running, for commands made by [`after`].
+    delay: Option<Duration>,
+}
 
 impl Cmd {
     /// Create a new command from a closure.
@@ -51,7 +60,7 @@ impl Cmd {
     where
         F: FnOnce() -> Message + Send + 'static,
     {
-        Self(Box::new(move || Some(f())))
+        Self::new_optional(move || Some(f()))
     }
 
     /// Create a command that may not produce a message.
@@ -59,7 +68,10 @@ impl Cmd {
     where
         F: FnOnce() -> Option<Message> + Send + 'static,
     {
-        Self(Box::new(f))
+        Self {
+            run: Box::new(f),
+            delay: None,
+        }
     }
 
     /// Create an empty command that does nothing.
@@ -68,8 +80,20 @@ impl Cmd {
     }
 
     /// Execute the command and return the resulting message.
+    ///
+    /// A command made by [`after`] sleeps for its delay first.
     pub fn execute(self) -> Option<Message> {
-        (self.0)()
+        if let Some(delay) = self.delay {
+            std::thread::sleep(delay);
+        }
+        (self.run)()
+    }
+
+    /// Takes the delay of a command made by [`after`], leaving a command
+    /// that runs at once. Runtimes without threads to sleep on wait out the
+    /// delay themselves.
+    pub(crate) fn take_delay(&mut self) -> Option<Duration> {
+        self.delay.take()
     }
 
     /// Create a command that performs blocking I/O.
@@ -139,6 +163,38 @@ impl Cmd {
             Err(err) => on_error(err),
         })
     }
+
+    /// Create a command that runs an external program, such as `$EDITOR`
+    /// or `less`, with the terminal to itself.
+    ///
+    /// The program releases the terminal as with
+    /// [`screen::exec`](crate::screen::exec), waits for `command` to exit,
+    /// restores the terminal and delivers the message `callback` makes from
+    /// the exit status. The command inherits the program's stdin, stdout
+    /// and stderr unless configured otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use bubbletea::{Cmd, Message};
+    /// use std::io;
+    /// use std::process::{Command, ExitStatus};
+    ///
+    /// struct EditorClosedMsg(io::Result<ExitStatus>);
+    ///
+    /// fn open_editor(path: &str) -> Cmd {
+    ///     let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+    ///     let mut command = Command::new(editor);
+    ///     command.arg(path);
+    ///     Cmd::exec_process(command, |status| Message::new(EditorClosedMsg(status)))
+    /// }
+    /// ```
+    pub fn exec_process<F>(mut command: Command, callback: F) -> Self
+    where
+        F: FnOnce(io::Result<ExitStatus>) -> Message + Send + 'static,
+    {
+        crate::screen::exec(move || callback(command.status()))
+    }
 }
 
 // =============================================================================
@@ -303,6 +359,33 @@ pub fn quit() -> Cmd {
     Cmd::new(|| Message::new(QuitMsg))
 }
 
+/// Command that suspends the program, as ctrl+z does in a shell.
+///
+/// The program releases the terminal and stops until the shell continues
+/// it, for example with `fg`, then restores the terminal and sends the model
+/// a [`ResumeMsg`](crate::ResumeMsg). In raw mode ctrl+z arrives as a key
+/// rather than a signal, so models bind it to this command themselves. Where
+/// there is no job control, such as on Windows or with custom I/O, the
+/// program resumes at once.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use bubbletea::{Cmd, KeyMsg, KeyType, Message, suspend};
+///
+/// fn update(&mut self, msg: Message) -> Option<Cmd> {
+///     if let Some(key) = msg.downcast_ref::<KeyMsg>()
+///         && key.key_type == KeyType::CtrlZ
+///     {
+///         return Some(suspend());
+///     }
+///     None
+/// }
+/// ```
+pub fn suspend() -> Cmd {
+    Cmd::new(|| Message::new(SuspendMsg))
+}
+
 /// Command that ticks after a duration.
 ///
 /// The tick runs for the full duration from when it's invoked.
@@ -322,12 +405,52 @@ pub fn quit() -> Cmd {
 /// }
 /// ```
 pub fn tick<F>(duration: Duration, f: F) -> Cmd
+where
+    F: FnOnce(Instant) -> Message + Send + 'static,
+{
+    tick_with_clock(Arc::new(SystemClock), duration, f)
+}
+
+/// Command that produces a message after a delay.
+///
+/// Unlike [`tick`], the message isn't given the time it was made, which
+/// lets runtimes without a clock to read or a thread to sleep on, such as
+/// a [`WebProgram`](crate::web::WebProgram) in the browser, schedule the
+/// delay themselves. Elsewhere it waits like [`tick`].
+///
+/// # Example
+///
+/// ```rust
+/// use bubbletea::{Cmd, Message, after};
+/// use std::time::Duration;
+///
+/// struct BlinkMsg;
+///
+/// fn blink() -> Cmd {
+///     after(Duration::from_millis(500), || Message::new(BlinkMsg))
+/// }
+/// ```
+pub fn after<F>(duration: Duration, f: F) -> Cmd
+where
+    F: FnOnce() -> Message + Send + 'static,
+{
+    Cmd {
+        delay: Some(duration),
+        ..Cmd::new(f)
+    }
+}
+
+/// Like [`tick`], but waits on the given [`Clock`].
+///
+/// With a [`TestClock`](crate::TestClock) the command returns at once, so
+/// tests of animated components don't wait in real time.
+pub fn tick_with_clock<F>(clock: Arc<dyn Clock>, duration: Duration, f: F) -> Cmd
 where
     F: FnOnce(Instant) -> Message + Send + 'static,
 {
     Cmd::new(move || {
-        std::thread::sleep(duration);
-        f(Instant::now())
+        clock.sleep(duration);
+        f(clock.now())
     })
 }
 
@@ -350,6 +473,14 @@ where
 /// }
 /// ```
 pub fn every<F>(duration: Duration, f: F) -> Cmd
+where
+    F: FnOnce(Instant) -> Message + Send + 'static,
+{
+    every_with_clock(Arc::new(SystemClock), duration, f)
+}
+
+/// Like [`every`], but aligns to and waits on the given [`Clock`].
+pub fn every_with_clock<F>(clock: Arc<dyn Clock>, duration: Duration, f: F) -> Cmd
 where
     F: FnOnce(Instant) -> Message + Send + 'static,
 {
@@ -357,19 +488,20 @@ where
         let duration_nanos = duration.as_nanos() as u64;
         if duration_nanos == 0 {
             // Zero duration means tick immediately
-            return f(Instant::now());
+            return f(clock.now());
         }
 
         // Get current wall clock time as nanos since Unix epoch
-        let now_nanos = SystemTime::now()
+        let now_nanos = clock
+            .wall_now()
             .duration_since(SystemTime::UNIX_EPOCH)
             .map(|d| d.as_nanos() as u64)
             .unwrap_or(0);
         // Calculate time until next tick aligned with system clock
         let next_tick_nanos = ((now_nanos / duration_nanos) + 1) * duration_nanos;
         let sleep_nanos = next_tick_nanos - now_nanos;
-        std::thread::sleep(Duration::from_nanos(sleep_nanos));
-        f(Instant::now())
+        clock.sleep(Duration::from_nanos(sleep_nanos));
+        f(clock.now())
     })
 }
 
@@ -448,11 +580,43 @@ where
 }
 
 /// Command to set the terminal window title.
+///
+/// The title is cleared again when the program exits.
 pub fn set_window_title(title: impl Into<String>) -> Cmd {
     let title = title.into();
     Cmd::new(move || Message::new(SetWindowTitleMsg(title)))
 }
 
+/// Command to show a desktop notification, e.g. when a long task finishes.
+///
+/// The notification is sent with the terminal's
+/// [`NotificationProtocol`](crate::NotificationProtocol) and ignored on
+/// terminals without one. Combined with
+/// [`Program::with_report_focus`](crate::Program::with_report_focus), a
+/// model can notify only while the terminal is in the background.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use bubbletea::{BlurMsg, Cmd, FocusMsg, Message, Model, notify};
+///
+/// impl Model for MyModel {
+///     fn update(&mut self, msg: Message) -> Option<Cmd> {
+///         if msg.is::<FocusMsg>() || msg.is::<BlurMsg>() {
+///             self.focused = msg.is::<FocusMsg>();
+///         }
+///         if msg.is::<BuildFinished>() && !self.focused {
+///             return Some(notify("Build finished", "All 42 tests passed"));
+///         }
+///         None
+///     }
+/// }
+/// ```
+pub fn notify(title: impl Into<String>, body: impl Into<String>) -> Cmd {
+    let (title, body) = (title.into(), body.into());
+    Cmd::new(move || Message::new(NotifyMsg { title, body }))
+}
+
 /// Command to query the current window size.
 ///
 /// The result is delivered as a `WindowSizeMsg`.
@@ -460,6 +624,14 @@ pub fn window_size() -> Cmd {
     Cmd::new(|| Message::new(RequestWindowSizeMsg))
 }
 
+/// Command to query the input latencies recorded so far.
+///
+/// The result is delivered as an
+/// [`InputLatencyMsg`](crate::message::InputLatencyMsg).
+pub fn input_latency() -> Cmd {
+    Cmd::new(|| Message::new(RequestInputLatencyMsg))
+}
+
 /// Print a line above the program's TUI output.
 ///
 /// This output is unmanaged by the program and will persist across renders.
@@ -718,6 +890,23 @@ mod tests {
         assert!(msg.is::<QuitMsg>());
     }
 
+    #[test]
+    fn test_suspend() {
+        let msg = suspend().execute().unwrap();
+        assert!(msg.is::<SuspendMsg>());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_exec_process_reports_exit_status() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "exit 3"]);
+        let msg = Cmd::exec_process(command, Message::new).execute().unwrap();
+        let exec = msg.downcast::<crate::screen::ExecMsg>().unwrap();
+        let status = exec.run().downcast::<io::Result<ExitStatus>>().unwrap();
+        assert_eq!(status.unwrap().code(), Some(3));
+    }
+
     #[test]
     fn test_set_window_title() {
         let cmd = set_window_title("My App");
@@ -800,6 +989,49 @@ mod tests {
         assert!(msg.is::<FileError>());
     }
 
+    #[test]
+    fn test_tick_with_test_clock() {
+        use crate::clock::TestClock;
+
+        let clock = TestClock::new();
+        let start = clock.now();
+        let cmd = tick_with_clock(Arc::new(clock.clone()), Duration::from_secs(3600), |t| {
+            Message::new(t)
+        });
+        let msg = cmd.execute().unwrap();
+        assert_eq!(
+            msg.downcast::<Instant>().unwrap() - start,
+            Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn test_after_waits_before_running() {
+        let start = Instant::now();
+        let msg = after(Duration::from_millis(20), || Message::new(7u8))
+            .execute()
+            .unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(msg.downcast::<u8>(), Some(7));
+
+        let mut cmd = after(Duration::from_secs(3600), || Message::new(()));
+        assert_eq!(cmd.take_delay(), Some(Duration::from_secs(3600)));
+        assert!(cmd.execute().is_some());
+    }
+
+    #[test]
+    fn test_every_with_test_clock_aligns() {
+        use crate::clock::TestClock;
+
+        let clock = TestClock::new();
+        clock.advance(Duration::from_millis(2300));
+        let cmd = every_with_clock(Arc::new(clock.clone()), Duration::from_secs(1), |_| {
+            Message::new(())
+        });
+        cmd.execute().unwrap();
+        assert_eq!(clock.elapsed(), Duration::from_secs(3));
+    }
+
     // =============================================================================
     // Async Command Tests (requires "async" feature)
     // =============================================================================
@@ -4,6 +4,7 @@
 //! special keys, control combinations, and regular character input.
 
 use std::fmt;
+use std::ops::{BitOr, BitOrAssign};
 
 /// Keyboard key event message.
 ///
@@ -32,6 +33,8 @@ pub struct KeyMsg {
     pub alt: bool,
     /// Whether this came from a paste operation.
     pub paste: bool,
+    /// Modifiers held with the key that `key_type` doesn't already express.
+    pub modifiers: KeyMod,
 }
 
 impl KeyMsg {
@@ -42,6 +45,7 @@ impl KeyMsg {
             runes: Vec::new(),
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         }
     }
 
@@ -52,6 +56,7 @@ impl KeyMsg {
             runes: vec![c],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         }
     }
 
@@ -62,6 +67,7 @@ impl KeyMsg {
             runes,
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         }
     }
 
@@ -76,6 +82,29 @@ impl KeyMsg {
         self.paste = true;
         self
     }
+
+    /// Add modifiers the key type doesn't express.
+    pub fn with_modifiers(mut self, modifiers: KeyMod) -> Self {
+        self.modifiers |= modifiers;
+        self
+    }
+
+    /// Check if this key types its runes as text.
+    ///
+    /// Shortcuts such as ctrl+shift+a, which the kitty keyboard protocol
+    /// reports as runes with [`KeyMod::CTRL`] or [`KeyMod::SUPER`], don't.
+    ///
+    /// ```rust
+    /// use bubbletea::{KeyMod, KeyMsg};
+    ///
+    /// assert!(KeyMsg::from_char('a').is_text());
+    /// assert!(!KeyMsg::from_char('a').with_modifiers(KeyMod::CTRL).is_text());
+    /// ```
+    pub fn is_text(&self) -> bool {
+        self.key_type == KeyType::Runes
+            && !self.modifiers.contains(KeyMod::CTRL)
+            && !self.modifiers.contains(KeyMod::SUPER)
+    }
 }
 
 impl fmt::Display for KeyMsg {
@@ -83,6 +112,7 @@ impl fmt::Display for KeyMsg {
         if self.alt {
             write!(f, "alt+")?;
         }
+        write!(f, "{}", self.modifiers)?;
         if self.key_type == KeyType::Runes {
             if self.paste {
                 write!(f, "[")?;
@@ -100,6 +130,78 @@ impl fmt::Display for KeyMsg {
     }
 }
 
+/// Modifier keys held with a key, other than Alt, which
+/// [`KeyMsg::alt`] reports.
+///
+/// Legacy terminal input folds Ctrl and Shift into the [`KeyType`], as in
+/// [`KeyType::CtrlA`] and [`KeyType::ShiftTab`], and can't report them with
+/// most other keys. With the kitty keyboard protocol (see
+/// [`Program::with_keyboard_enhancements`](crate::Program::with_keyboard_enhancements)),
+/// combinations no key type expresses, such as shift+enter or ctrl+i, are
+/// reported with these modifiers instead.
+///
+/// # Example
+///
+/// ```rust
+/// use bubbletea::{KeyMod, KeyMsg, KeyType};
+///
+/// let key = KeyMsg::from_type(KeyType::Enter).with_modifiers(KeyMod::SHIFT);
+/// assert_eq!(key.to_string(), "shift+enter");
+/// assert!(key.modifiers.contains(KeyMod::SHIFT));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct KeyMod(u8);
+
+impl KeyMod {
+    /// No modifiers.
+    pub const NONE: Self = Self(0);
+    /// Shift.
+    pub const SHIFT: Self = Self(1);
+    /// Ctrl.
+    pub const CTRL: Self = Self(1 << 2);
+    /// Super, the Windows or Command key.
+    pub const SUPER: Self = Self(1 << 3);
+
+    /// Check whether all of `other` is held.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Check whether no modifiers are held.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for KeyMod {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for KeyMod {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl fmt::Display for KeyMod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.contains(Self::CTRL) {
+            write!(f, "ctrl+")?;
+        }
+        if self.contains(Self::SHIFT) {
+            write!(f, "shift+")?;
+        }
+        if self.contains(Self::SUPER) {
+            write!(f, "super+")?;
+        }
+        Ok(())
+    }
+}
+
 /// Key type enumeration.
 ///
 /// Represents different types of keys that can be pressed.
@@ -448,6 +550,7 @@ impl KeyType {
 }
 
 /// Convert a crossterm KeyCode to our KeyType.
+#[cfg(not(target_arch = "wasm32"))]
 pub fn from_crossterm_key(
     code: crossterm::event::KeyCode,
     modifiers: crossterm::event::KeyModifiers,
@@ -458,58 +561,52 @@ pub fn from_crossterm_key(
     let shift = modifiers.contains(KeyModifiers::SHIFT);
     let alt = modifiers.contains(KeyModifiers::ALT);
 
+    // Ctrl and Shift with keys whose key type can't express them are only
+    // reported by terminals using the kitty keyboard protocol.
+    let mut mods = KeyMod::NONE;
+    if modifiers.contains(KeyModifiers::SUPER) {
+        mods |= KeyMod::SUPER;
+    }
+    let mut held = |ctrl_held: bool, shift_held: bool| {
+        if ctrl_held {
+            mods |= KeyMod::CTRL;
+        }
+        if shift_held {
+            mods |= KeyMod::SHIFT;
+        }
+    };
+
     let (key_type, runes) = match code {
-        KeyCode::Char(c) if ctrl => {
-            let kt = match c.to_ascii_lowercase() {
-                '@' => KeyType::Null,
-                'a' => KeyType::CtrlA,
-                'b' => KeyType::CtrlB,
-                'c' => KeyType::CtrlC,
-                'd' => KeyType::CtrlD,
-                'e' => KeyType::CtrlE,
-                'f' => KeyType::CtrlF,
-                'g' => KeyType::CtrlG,
-                'h' => KeyType::CtrlH,
-                'i' => KeyType::Tab,
-                'j' => KeyType::CtrlJ,
-                'k' => KeyType::CtrlK,
-                'l' => KeyType::CtrlL,
-                'm' => KeyType::Enter,
-                'n' => KeyType::CtrlN,
-                'o' => KeyType::CtrlO,
-                'p' => KeyType::CtrlP,
-                'q' => KeyType::CtrlQ,
-                'r' => KeyType::CtrlR,
-                's' => KeyType::CtrlS,
-                't' => KeyType::CtrlT,
-                'u' => KeyType::CtrlU,
-                'v' => KeyType::CtrlV,
-                'w' => KeyType::CtrlW,
-                'x' => KeyType::CtrlX,
-                'y' => KeyType::CtrlY,
-                'z' => KeyType::CtrlZ,
-                '\\' => KeyType::CtrlBackslash,
-                ']' => KeyType::CtrlCloseBracket,
-                '^' => KeyType::CtrlCaret,
-                '_' => KeyType::CtrlUnderscore,
-                _ => {
-                    return KeyMsg {
-                        key_type: KeyType::Runes,
-                        runes: vec![c],
-                        alt,
-                        paste: false,
-                    };
-                }
-            };
-            (kt, Vec::new())
+        KeyCode::Char(c) if ctrl => match legacy_ctrl_key(c) {
+            Some(kt) if !(shift && c.is_ascii_alphabetic()) => (kt, Vec::new()),
+            _ => {
+                held(true, shift && c.is_ascii_alphabetic());
+                (KeyType::Runes, vec![c.to_ascii_lowercase()])
+            }
+        },
+        KeyCode::Char(' ') => {
+            held(false, shift);
+            (KeyType::Space, Vec::new())
         }
-        KeyCode::Char(' ') => (KeyType::Space, Vec::new()),
         KeyCode::Char(c) => (KeyType::Runes, vec![c]),
-        KeyCode::Enter => (KeyType::Enter, Vec::new()),
-        KeyCode::Backspace => (KeyType::Backspace, Vec::new()),
-        KeyCode::Tab if shift => (KeyType::ShiftTab, Vec::new()),
-        KeyCode::Tab => (KeyType::Tab, Vec::new()),
-        KeyCode::Esc => (KeyType::Esc, Vec::new()),
+        KeyCode::Enter => {
+            held(ctrl, shift);
+            (KeyType::Enter, Vec::new())
+        }
+        KeyCode::Backspace => {
+            held(ctrl, shift);
+            (KeyType::Backspace, Vec::new())
+        }
+        KeyCode::BackTab => (KeyType::ShiftTab, Vec::new()),
+        KeyCode::Tab if shift && !ctrl => (KeyType::ShiftTab, Vec::new()),
+        KeyCode::Tab => {
+            held(ctrl, shift);
+            (KeyType::Tab, Vec::new())
+        }
+        KeyCode::Esc => {
+            held(ctrl, shift);
+            (KeyType::Esc, Vec::new())
+        }
         KeyCode::Delete => (KeyType::Delete, Vec::new()),
         KeyCode::Insert => (KeyType::Insert, Vec::new()),
         KeyCode::Up if ctrl && shift => (KeyType::CtrlShiftUp, Vec::new()),
@@ -568,7 +665,88 @@ pub fn from_crossterm_key(
         runes,
         alt,
         paste: false,
+        modifiers: mods,
+    }
+}
+
+/// Returns the key type legacy terminal input reports for Ctrl with `c`.
+///
+/// Ctrl+I and Ctrl+M have none of their own: legacy input can't tell them
+/// from Tab and Enter, while the kitty keyboard protocol can.
+fn legacy_ctrl_key(c: char) -> Option<KeyType> {
+    let kt = match c.to_ascii_lowercase() {
+        '@' | ' ' => KeyType::Null,
+        'a' => KeyType::CtrlA,
+        'b' => KeyType::CtrlB,
+        'c' => KeyType::CtrlC,
+        'd' => KeyType::CtrlD,
+        'e' => KeyType::CtrlE,
+        'f' => KeyType::CtrlF,
+        'g' => KeyType::CtrlG,
+        'h' => KeyType::CtrlH,
+        'j' => KeyType::CtrlJ,
+        'k' => KeyType::CtrlK,
+        'l' => KeyType::CtrlL,
+        'n' => KeyType::CtrlN,
+        'o' => KeyType::CtrlO,
+        'p' => KeyType::CtrlP,
+        'q' => KeyType::CtrlQ,
+        'r' => KeyType::CtrlR,
+        's' => KeyType::CtrlS,
+        't' => KeyType::CtrlT,
+        'u' => KeyType::CtrlU,
+        'v' => KeyType::CtrlV,
+        'w' => KeyType::CtrlW,
+        'x' => KeyType::CtrlX,
+        'y' => KeyType::CtrlY,
+        'z' => KeyType::CtrlZ,
+        '\\' => KeyType::CtrlBackslash,
+        ']' => KeyType::CtrlCloseBracket,
+        '^' => KeyType::CtrlCaret,
+        '_' => KeyType::CtrlUnderscore,
+        _ => return None,
+    };
+    Some(kt)
+}
+
+/// Builds the key a kitty keyboard protocol report `CSI code ; mods u`
+/// stands for, where `modifiers` is the protocol's modifier bit set (the
+/// reported value minus one).
+///
+/// Combinations legacy input also reports, such as ctrl+a or shift+tab,
+/// get their usual key type so existing key bindings keep working. Returns
+/// `None` for keys in the protocol's private use range, such as keypad and
+/// media keys, which are only reported with further enhancements enabled.
+pub(crate) fn from_kitty_key(code: u32, modifiers: u8) -> Option<KeyMsg> {
+    let alt = modifiers & 0b10 != 0;
+    let mut mods = KeyMod(modifiers & (KeyMod::SHIFT.0 | KeyMod::CTRL.0 | KeyMod::SUPER.0));
+    let mut key = match code {
+        9 if mods == KeyMod::SHIFT => {
+            mods = KeyMod::NONE;
+            KeyMsg::from_type(KeyType::ShiftTab)
+        }
+        9 => KeyMsg::from_type(KeyType::Tab),
+        13 => KeyMsg::from_type(KeyType::Enter),
+        27 => KeyMsg::from_type(KeyType::Esc),
+        32 => KeyMsg::from_type(KeyType::Space),
+        127 => KeyMsg::from_type(KeyType::Backspace),
+        0xE000..=0xF8FF => return None,
+        _ => {
+            let c = char::from_u32(code).filter(|c| !c.is_control())?;
+            match legacy_ctrl_key(c) {
+                Some(kt) if mods == KeyMod::CTRL => {
+                    mods = KeyMod::NONE;
+                    KeyMsg::from_type(kt)
+                }
+                _ => KeyMsg::from_char(c),
+            }
+        }
+    };
+    key.modifiers = mods;
+    if alt {
+        key = key.with_alt();
     }
+    Some(key)
 }
 
 /// Parse a raw ANSI escape sequence into a KeyMsg.
@@ -628,14 +806,14 @@ pub fn parse_sequence(input: &[u8]) -> Option<KeyMsg> {
 /// assert_eq!(len, 3);
 /// ```
 pub fn parse_sequence_prefix(input: &[u8]) -> Option<(KeyMsg, usize)> {
-    let s = std::str::from_utf8(input).ok()?;
-
-    // We need to find the longest sequence that 's' starts with.
+    // Compare bytes rather than decoding, so invalid UTF-8 later in the
+    // input doesn't hide a valid sequence at its start.
+    // We need to find the longest sequence that the input starts with.
     // Iterating the whole map is O(N), where N is ~100. This is fast enough.
     let mut best_match: Option<(KeyMsg, usize)> = None;
 
     for (seq, key) in SEQUENCES.iter() {
-        if s.starts_with(seq) {
+        if input.starts_with(seq.as_bytes()) {
             let len = seq.len();
             match best_match {
                 None => best_match = Some((key.clone(), len)),
@@ -661,12 +839,9 @@ pub fn parse_sequence_prefix(input: &[u8]) -> Option<(KeyMsg, usize)> {
 ///
 /// Returns `true` if the input is a prefix of a known sequence, `false` otherwise.
 pub fn is_sequence_prefix(input: &[u8]) -> bool {
-    let s = match std::str::from_utf8(input) {
-        Ok(s) => s,
-        Err(_) => return false,
-    };
-
-    SEQUENCES.keys().any(|seq| seq.starts_with(s))
+    SEQUENCES
+        .keys()
+        .any(|seq| seq.as_bytes().starts_with(input))
 }
 
 use std::collections::HashMap;
@@ -1097,6 +1272,60 @@ mod tests {
         assert_eq!(key.to_string(), "[hello]");
     }
 
+    #[test]
+    fn test_key_msg_display_modifiers() {
+        let key = KeyMsg::from_type(KeyType::Enter).with_modifiers(KeyMod::SHIFT);
+        assert_eq!(key.to_string(), "shift+enter");
+
+        let key = KeyMsg::from_char('i')
+            .with_modifiers(KeyMod::CTRL)
+            .with_alt();
+        assert_eq!(key.to_string(), "alt+ctrl+i");
+    }
+
+    #[test]
+    fn test_from_kitty_key() {
+        const SHIFT: u8 = 1;
+        const ALT: u8 = 2;
+        const CTRL: u8 = 4;
+
+        // Keys legacy input can't tell apart
+        let key = from_kitty_key(13, SHIFT).unwrap();
+        assert_eq!(key.key_type, KeyType::Enter);
+        assert_eq!(key.modifiers, KeyMod::SHIFT);
+        let key = from_kitty_key('i' as u32, CTRL).unwrap();
+        assert_eq!(key.to_string(), "ctrl+i");
+        let key = from_kitty_key('a' as u32, CTRL | SHIFT).unwrap();
+        assert_eq!(key.to_string(), "ctrl+shift+a");
+
+        // Keys legacy input reports keep their key type
+        let key = from_kitty_key('a' as u32, CTRL | ALT).unwrap();
+        assert_eq!(key, KeyMsg::from_type(KeyType::CtrlA).with_alt());
+        assert_eq!(
+            from_kitty_key(9, SHIFT),
+            Some(KeyMsg::from_type(KeyType::ShiftTab))
+        );
+        assert_eq!(from_kitty_key(27, 0), Some(KeyMsg::from_type(KeyType::Esc)));
+
+        // Keypad and media keys
+        assert_eq!(from_kitty_key(57_399, 0), None);
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn test_from_crossterm_key_modifiers() {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let key = from_crossterm_key(KeyCode::Enter, KeyModifiers::SHIFT);
+        assert_eq!(key.to_string(), "shift+enter");
+        let key = from_crossterm_key(KeyCode::Char('i'), KeyModifiers::CONTROL);
+        assert_eq!(key.to_string(), "ctrl+i");
+        let key = from_crossterm_key(KeyCode::Char('a'), KeyModifiers::CONTROL);
+        assert_eq!(key, KeyMsg::from_type(KeyType::CtrlA));
+        let key = from_crossterm_key(KeyCode::Char('A'), KeyModifiers::SHIFT);
+        assert_eq!(key, KeyMsg::from_char('A'));
+    }
+
     #[test]
     fn test_key_type_display() {
         assert_eq!(KeyType::Enter.to_string(), "enter");
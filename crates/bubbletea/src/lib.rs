@@ -162,6 +162,11 @@
 //!
 //! ## Screen Control
 //!
+//! Programs render inline by default, below whatever the shell printed
+//! before them, and leave their last frame behind on exit, which suits
+//! prompts and forms. [`Program::with_alt_screen`] runs them full screen
+//! instead, restoring the terminal's contents on exit.
+//!
 //! Control terminal features with screen commands:
 //!
 //! ```rust
@@ -172,7 +177,31 @@
 //! let cmd = screen::hide_cursor();
 //! let cmd = screen::enable_mouse_cell_motion();
 //! ```
+//!
+//! ## Terminals
+//!
+//! Programs read input from the process's terminal by default. The
+//! [`terminal`] module's [`Terminal`] trait abstracts that away, so
+//! [`Program::with_terminal`] can run a program on something else, such as
+//! the experimental [`WebTerminal`] bridge to browser terminal emulators.
+//!
+//! The [`web`] module runs programs inside the browser instead, compiled to
+//! `wasm32`: a [`web::WebProgram`] steps the model as a JavaScript host
+//! feeds it input from xterm.js, and the `web` feature exports it to
+//! JavaScript. The shim connecting the two ships in the crate's `web/`
+//! directory.
+//!
+//! ## Debugging
+//!
+//! [`Program::with_tracer`] records each message the model handles with the
+//! time its update took, and each frame drawn with its size, into a ring
+//! buffer or a log file; setting `BUBBLETEA_LOG=path` does the same without
+//! code changes. [`log_to_file`] returns a tracer whose
+//! [`log`](Tracer::log) writes your own debug lines to the file as well.
 
+#[cfg(target_arch = "wasm32")]
+mod ansi;
+pub mod clock;
 pub mod command;
 pub mod key;
 pub mod message;
@@ -180,20 +209,31 @@ pub mod mouse;
 pub mod program;
 pub mod screen;
 pub mod simulator;
+pub mod terminal;
+pub mod trace;
+pub mod web;
 
 // Re-exports
+pub use clock::{Clock, SystemClock, TestClock, system_clock};
 pub use command::{
-    Cmd, batch, every, printf, println, quit, sequence, set_window_title, tick, window_size,
+    Cmd, after, batch, every, every_with_clock, input_latency, notify, printf, println, quit,
+    sequence, set_window_title, suspend, tick, tick_with_clock, window_size,
 };
 
 #[cfg(feature = "async")]
 pub use command::{AsyncCmd, every_async, tick_async};
-pub use key::{KeyMsg, KeyType, parse_sequence, parse_sequence_prefix};
+pub use key::{KeyMod, KeyMsg, KeyType, parse_sequence, parse_sequence_prefix};
 pub use message::{
-    BlurMsg, FocusMsg, InterruptMsg, Message, QuitMsg, ResumeMsg, SuspendMsg, WindowSizeMsg,
+    BlurMsg, ColorProfile, ColorProfileMsg, FocusMsg, InputLatencyMsg, InterruptMsg,
+    LatencyHistogram, Message, NotificationProtocol, QuitMsg, ResizeSettledMsg, ResumeMsg,
+    SuspendMsg, WindowSizeMsg,
 };
 pub use mouse::{MouseAction, MouseButton, MouseMsg, parse_mouse_event_sequence};
-pub use program::{Error, Model, Program, ProgramHandle, ProgramOptions, Result};
+pub use program::{Error, InputParser, Model, Program, ProgramHandle, ProgramOptions, Result};
+#[cfg(not(target_arch = "wasm32"))]
+pub use terminal::CrosstermTerminal;
+pub use terminal::{Terminal, WebTerminal};
+pub use trace::{Tracer, log_to_file};
 
 // Re-export derive macro when macros feature is enabled.
 // Derive macros and traits live in different namespaces, so both can be named `Model`.
@@ -205,7 +245,7 @@ pub use bubbletea_macros::*;
 /// Prelude module for convenient imports.
 pub mod prelude {
     pub use crate::command::{Cmd, batch, every, printf, println, quit, sequence, tick};
-    pub use crate::key::{KeyMsg, KeyType};
+    pub use crate::key::{KeyMod, KeyMsg, KeyType};
     pub use crate::message::{Message, QuitMsg, WindowSizeMsg};
     pub use crate::mouse::{MouseAction, MouseButton, MouseMsg};
     pub use crate::program::{Model, Program};
@@ -5,6 +5,7 @@
 
 use std::any::Any;
 use std::fmt;
+use std::time::{Duration, Instant};
 
 /// A type-erased message container.
 ///
@@ -23,35 +24,70 @@ use std::fmt;
 ///     assert_eq!(my_msg.0, 42);
 /// }
 /// ```
-pub struct Message(Box<dyn Any + Send>);
+pub struct Message {
+    inner: Box<dyn Any + Send>,
+    type_name: &'static str,
+    timestamp: Option<Instant>,
+}
 
 impl Message {
     /// Create a new message from any sendable type.
     pub fn new<M: Any + Send + 'static>(msg: M) -> Self {
-        Self(Box::new(msg))
+        Self {
+            inner: Box::new(msg),
+            type_name: std::any::type_name::<M>(),
+            timestamp: None,
+        }
     }
 
     /// Try to downcast to a specific message type.
     ///
     /// Returns `Some(T)` if the message is of type `T`, otherwise `None`.
     pub fn downcast<M: Any + Send + 'static>(self) -> Option<M> {
-        self.0.downcast::<M>().ok().map(|b| *b)
+        self.inner.downcast::<M>().ok().map(|b| *b)
     }
 
     /// Try to get a reference to the message as a specific type.
     pub fn downcast_ref<M: Any + Send + 'static>(&self) -> Option<&M> {
-        self.0.downcast_ref::<M>()
+        self.inner.downcast_ref::<M>()
     }
 
     /// Check if the message is of a specific type.
     pub fn is<M: Any + Send + 'static>(&self) -> bool {
-        self.0.is::<M>()
+        self.inner.is::<M>()
+    }
+
+    /// Returns the name of the message's type, for debugging.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns when the input event behind this message was read, if known.
+    ///
+    /// The program stamps messages decoded from terminal input and from
+    /// [`Program::with_input`](crate::Program::with_input) with the monotonic
+    /// time they were read. Other messages are unstamped unless stamped with
+    /// [`with_timestamp`](Self::with_timestamp).
+    pub fn timestamp(&self) -> Option<Instant> {
+        self.timestamp
+    }
+
+    /// Stamps the message as an input event read at `at`.
+    ///
+    /// Stamped messages count towards the input latency metrics, see
+    /// [`Program::with_latency_metrics`](crate::Program::with_latency_metrics).
+    pub fn with_timestamp(mut self, at: Instant) -> Self {
+        self.timestamp = Some(at);
+        self
     }
 }
 
 impl fmt::Debug for Message {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Message").finish_non_exhaustive()
+        f.debug_struct("Message")
+            .field("type", &self.type_name)
+            .field("timestamp", &self.timestamp)
+            .finish_non_exhaustive()
     }
 }
 
@@ -65,11 +101,11 @@ pub struct QuitMsg;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct InterruptMsg;
 
-/// Message to suspend the program (Ctrl+Z).
+/// Message to suspend the program, produced by [`suspend`](crate::suspend).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SuspendMsg;
 
-/// Message when program resumes from suspension.
+/// Message when the program resumes after [`suspend`](crate::suspend).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ResumeMsg;
 
@@ -82,6 +118,211 @@ pub struct WindowSizeMsg {
     pub height: u16,
 }
 
+/// Message sent once a burst of window resizes has settled.
+///
+/// Only sent when resize debouncing is enabled with
+/// [`Program::with_resize_debounce`](crate::Program::with_resize_debounce).
+/// It follows the final [`WindowSizeMsg`] of the burst and carries the same
+/// size, making it a good trigger for expensive reflows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResizeSettledMsg {
+    /// Terminal width in columns.
+    pub width: u16,
+    /// Terminal height in rows.
+    pub height: u16,
+}
+
+/// Color support of a terminal, from least to most capable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum ColorProfile {
+    /// No colors.
+    Ascii,
+    /// The 16 ANSI colors.
+    Ansi,
+    /// The 256-color palette.
+    Ansi256,
+    /// 24-bit colors.
+    TrueColor,
+}
+
+/// Escape sequence used to show desktop notifications.
+///
+/// Used by [`notify`](crate::notify). The protocol is detected from the
+/// environment with [`detect`](Self::detect) unless
+/// [`ProgramOptions::notifications`](crate::ProgramOptions::notifications)
+/// sets one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum NotificationProtocol {
+    /// `OSC 9`, as in iTerm2 and kitty. It carries a single message, so the
+    /// title and body are joined.
+    Osc9,
+    /// `OSC 777 ; notify`, as in foot, Ghostty, WezTerm, rxvt-unicode and
+    /// VTE-based terminals built with notification support.
+    Osc777,
+}
+
+impl NotificationProtocol {
+    /// Returns the protocol of the terminal named by the environment, or
+    /// `None` if it is not known to show notifications.
+    pub fn detect() -> Option<Self> {
+        Self::detect_with(|name| std::env::var(name).ok())
+    }
+
+    fn detect_with(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let program = var("TERM_PROGRAM").unwrap_or_default();
+        let term = var("TERM").unwrap_or_default();
+        if matches!(program.as_str(), "WezTerm" | "ghostty")
+            || term == "xterm-ghostty"
+            || term.starts_with("foot")
+            || term.starts_with("rxvt-unicode")
+            || var("VTE_VERSION").is_some()
+        {
+            Some(Self::Osc777)
+        } else if program == "iTerm.app" || term == "xterm-kitty" {
+            Some(Self::Osc9)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the escape sequence showing a notification, with control
+    /// characters removed from `title` and `body`.
+    pub(crate) fn sequence(self, title: &str, body: &str) -> String {
+        let clean = |text: &str| text.chars().filter(|c| !c.is_control()).collect::<String>();
+        let (title, body) = (clean(title), clean(body));
+        match self {
+            Self::Osc9 => {
+                let message = match (title.is_empty(), body.is_empty()) {
+                    (false, false) => format!("{title}: {body}"),
+                    (false, true) => title,
+                    (true, _) => body,
+                };
+                format!("\x1b]9;{message}\x07")
+            }
+            // Semicolons separate the fields, so the title can't contain one
+            Self::Osc777 => format!("\x1b]777;notify;{};{body}\x07", title.replace(';', ",")),
+        }
+    }
+}
+
+/// Message sent at startup when the color profile is overridden.
+///
+/// Only sent when [`ProgramOptions::color_profile`](crate::ProgramOptions::color_profile)
+/// is set. Views should render with this profile instead of the one
+/// detected from the environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorProfileMsg(pub ColorProfile);
+
+/// Number of buckets in a [`LatencyHistogram`].
+const LATENCY_BUCKETS: usize = 32;
+
+/// Histogram of input latencies, from reading an input event to finishing
+/// the render that followed it.
+///
+/// Bucket `i` counts latencies below `2^i` microseconds and, except for the
+/// first, at least `2^(i-1)`. The last bucket also takes everything longer
+/// and reports [`Duration::MAX`] as its bound.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    buckets: [u64; LATENCY_BUCKETS],
+    count: u64,
+    total: Duration,
+    max: Duration,
+}
+
+impl LatencyHistogram {
+    /// Creates an empty histogram.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one latency.
+    pub fn record(&mut self, latency: Duration) {
+        let micros = latency.as_micros();
+        let bucket = (u128::BITS - micros.leading_zeros()) as usize;
+        self.buckets[bucket.min(LATENCY_BUCKETS - 1)] += 1;
+        self.count += 1;
+        self.total += latency;
+        self.max = self.max.max(latency);
+    }
+
+    /// Returns the number of recorded latencies.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns whether nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the mean latency, if any was recorded.
+    pub fn mean(&self) -> Option<Duration> {
+        let count = u32::try_from(self.count).unwrap_or(u32::MAX);
+        (count > 0).then(|| self.total / count)
+    }
+
+    /// Returns the longest latency, if any was recorded.
+    pub fn max(&self) -> Option<Duration> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// Returns an upper bound for the given percentile (0 to 100), if any
+    /// latency was recorded.
+    ///
+    /// The bound is the upper edge of the bucket the percentile falls in,
+    /// capped at the longest recorded latency.
+    pub fn percentile(&self, p: f64) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        let rank = ((p.clamp(0.0, 100.0) / 100.0) * self.count as f64).ceil() as u64;
+        let mut seen = 0;
+        for (upper, count) in self.buckets() {
+            seen += count;
+            if seen >= rank.max(1) {
+                return Some(upper.min(self.max));
+            }
+        }
+        Some(self.max)
+    }
+
+    /// Returns the non-empty buckets as `(upper bound, count)` pairs, from
+    /// the shortest latencies to the longest.
+    pub fn buckets(&self) -> impl Iterator<Item = (Duration, u64)> + '_ {
+        self.buckets
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(i, count)| {
+                let upper = if i + 1 < LATENCY_BUCKETS {
+                    Duration::from_micros(1 << i)
+                } else {
+                    Duration::MAX
+                };
+                (upper, *count)
+            })
+    }
+}
+
+/// Message carrying the input latencies recorded so far.
+///
+/// Sent in response to [`input_latency`](crate::input_latency). The
+/// histogram is empty unless the program was started with
+/// [`Program::with_latency_metrics`](crate::Program::with_latency_metrics).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputLatencyMsg(pub LatencyHistogram);
+
 /// Message when terminal gains focus.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FocusMsg;
@@ -94,10 +335,21 @@ pub struct BlurMsg;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct SetWindowTitleMsg(pub String);
 
+/// Internal message to show a desktop notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NotifyMsg {
+    pub title: String,
+    pub body: String,
+}
+
 /// Internal message to request window size.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct RequestWindowSizeMsg;
 
+/// Internal message to request the input latency histogram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RequestInputLatencyMsg;
+
 /// Message for batch command execution.
 ///
 /// This is produced by [`batch`](crate::batch) and handled by the program runtime.
@@ -140,6 +392,57 @@ mod tests {
         assert!(msg.downcast::<TestMsg2>().is_none());
     }
 
+    #[test]
+    fn test_message_timestamp() {
+        let msg = Message::new(QuitMsg);
+        assert_eq!(msg.timestamp(), None);
+
+        let now = Instant::now();
+        let msg = msg.with_timestamp(now);
+        assert_eq!(msg.timestamp(), Some(now));
+        assert!(msg.is::<QuitMsg>());
+    }
+
+    #[test]
+    fn test_latency_histogram() {
+        let mut histogram = LatencyHistogram::new();
+        assert!(histogram.is_empty());
+        assert_eq!(histogram.mean(), None);
+        assert_eq!(histogram.percentile(50.0), None);
+
+        for micros in [0, 3, 3, 100, 1_494] {
+            histogram.record(Duration::from_micros(micros));
+        }
+        assert_eq!(histogram.count(), 5);
+        assert_eq!(histogram.max(), Some(Duration::from_micros(1_494)));
+        assert_eq!(histogram.mean(), Some(Duration::from_micros(320)));
+        assert_eq!(
+            histogram.buckets().collect::<Vec<_>>(),
+            [
+                (Duration::from_micros(1), 1),
+                (Duration::from_micros(4), 2),
+                (Duration::from_micros(128), 1),
+                (Duration::from_micros(2048), 1),
+            ]
+        );
+        assert_eq!(histogram.percentile(50.0), Some(Duration::from_micros(4)));
+        assert_eq!(
+            histogram.percentile(100.0),
+            Some(Duration::from_micros(1_494))
+        );
+    }
+
+    #[test]
+    fn test_latency_histogram_clamps_long_latencies() {
+        let mut histogram = LatencyHistogram::new();
+        histogram.record(Duration::from_secs(10_000));
+        assert_eq!(
+            histogram.buckets().collect::<Vec<_>>(),
+            [(Duration::MAX, 1)]
+        );
+        assert_eq!(histogram.percentile(0.0), histogram.max());
+    }
+
     #[test]
     fn test_quit_msg() {
         let msg = Message::new(QuitMsg);
@@ -341,4 +644,55 @@ mod tests {
         let size_copy = size; // Copy, not move
         assert_eq!(size, size_copy);
     }
+
+    #[test]
+    fn test_notification_protocol_detection() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        let detect = NotificationProtocol::detect_with;
+        assert_eq!(
+            detect(env(&[("TERM_PROGRAM", "iTerm.app")])),
+            Some(NotificationProtocol::Osc9)
+        );
+        assert_eq!(
+            detect(env(&[("TERM", "xterm-kitty")])),
+            Some(NotificationProtocol::Osc9)
+        );
+        assert_eq!(
+            detect(env(&[("TERM", "foot")])),
+            Some(NotificationProtocol::Osc777)
+        );
+        assert_eq!(
+            detect(env(&[("TERM", "xterm-256color"), ("VTE_VERSION", "7600")])),
+            Some(NotificationProtocol::Osc777)
+        );
+        assert_eq!(detect(env(&[("TERM", "xterm-256color")])), None);
+        assert_eq!(detect(env(&[("TERM_PROGRAM", "tmux")])), None);
+    }
+
+    #[test]
+    fn test_notification_sequence() {
+        assert_eq!(
+            NotificationProtocol::Osc9.sequence("Build", "done"),
+            "\x1b]9;Build: done\x07"
+        );
+        assert_eq!(
+            NotificationProtocol::Osc9.sequence("", "done"),
+            "\x1b]9;done\x07"
+        );
+        assert_eq!(
+            NotificationProtocol::Osc777.sequence("a;b", "c;d"),
+            "\x1b]777;notify;a,b;c;d\x07"
+        );
+        // Control characters can't end the sequence early
+        assert_eq!(
+            NotificationProtocol::Osc777.sequence("x\x07\x1b]0;y", "z\n"),
+            "\x1b]777;notify;x]0,y;z\x07"
+        );
+    }
 }
@@ -171,6 +171,7 @@ impl fmt::Display for MouseButton {
 }
 
 /// Convert a crossterm mouse event to our MouseMsg.
+#[cfg(not(target_arch = "wasm32"))]
 pub fn from_crossterm_mouse(event: crossterm::event::MouseEvent) -> MouseMsg {
     use crossterm::event::{MouseButton as CtButton, MouseEventKind};
 
@@ -3,10 +3,15 @@
 //! The Program struct manages the entire TUI application lifecycle,
 //! including terminal setup, event handling, and rendering.
 
+use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::{Cell, RefCell};
 use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Once};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "async")]
 use crate::command::CommandKind;
@@ -17,6 +22,15 @@ use tokio_util::sync::CancellationToken;
 #[cfg(feature = "async")]
 use tokio_util::task::TaskTracker;
 
+#[cfg(feature = "async")]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[cfg(feature = "async")]
+use std::future::Future;
+
+#[cfg(feature = "async")]
+use std::task::Poll;
+
 use tracing::debug;
 
 /// Spawn a closure for batch command execution.
@@ -37,24 +51,44 @@ fn spawn_batch(f: impl FnOnce() + Send + 'static) {
     let _ = thread::spawn(f);
 }
 
+#[cfg(target_arch = "wasm32")]
+use crate::ansi::{
+    cursor::{Hide, MoveDown, MoveTo, MoveToColumn, MoveUp, Show},
+    event::{self, DisableMouseCapture, EnableMouseCapture},
+    execute, queue,
+    terminal::{
+        self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, ScrollDown, ScrollUp,
+    },
+};
+#[cfg(not(target_arch = "wasm32"))]
 use crossterm::{
-    cursor::{Hide, MoveTo, Show},
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind},
-    execute,
+    cursor::{Hide, MoveDown, MoveTo, MoveToColumn, MoveUp, Show},
+    event::{self, DisableMouseCapture, EnableMouseCapture},
+    execute, queue,
     terminal::{
-        self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
-        enable_raw_mode,
+        self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, ScrollDown, ScrollUp,
     },
 };
+use unicode_width::UnicodeWidthChar;
 
 use crate::command::Cmd;
-use crate::key::{from_crossterm_key, is_sequence_prefix};
+use crate::key::is_sequence_prefix;
 use crate::message::{
-    BatchMsg, BlurMsg, FocusMsg, InterruptMsg, Message, PrintLineMsg, QuitMsg,
-    RequestWindowSizeMsg, SequenceMsg, SetWindowTitleMsg, WindowSizeMsg,
+    BatchMsg, BlurMsg, ColorProfile, ColorProfileMsg, FocusMsg, InputLatencyMsg, InterruptMsg,
+    LatencyHistogram, Message, NotificationProtocol, NotifyMsg, PrintLineMsg, QuitMsg,
+    RequestInputLatencyMsg, RequestWindowSizeMsg, ResizeSettledMsg, ResumeMsg, SequenceMsg,
+    SetWindowTitleMsg, SuspendMsg, WindowSizeMsg,
+};
+use crate::screen::{
+    DisableBracketedPasteMsg, DisableReportFocusMsg, EnableBracketedPasteMsg, EnableReportFocusMsg,
+    EnterAltScreenMsg, ExecMsg, ExitAltScreenMsg, ReleaseTerminalMsg, RestoreTerminalMsg,
 };
-use crate::mouse::from_crossterm_mouse;
-use crate::screen::{ReleaseTerminalMsg, RestoreTerminalMsg};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::terminal::CrosstermTerminal;
+use crate::terminal::Terminal;
+#[cfg(target_arch = "wasm32")]
+use crate::terminal::WebTerminal;
+use crate::trace::{TraceEvent, Tracer};
 use crate::{KeyMsg, KeyType};
 
 /// Errors that can occur when running a bubbletea program.
@@ -270,9 +304,34 @@ pub trait Model: Send + 'static {
 }
 
 /// Program options.
+///
+/// Options are usually set with the `Program::with_*` builders. With the
+/// `serde` feature they can also be loaded from a configuration file, so
+/// end users can tune terminal behavior without every application adding
+/// its own flags. Missing keys keep their defaults; custom I/O and resize
+/// debouncing are wired up in code and are not read from configuration.
+///
+/// ```rust,ignore
+/// use bubbletea::{Program, ProgramOptions};
+///
+/// let options: ProgramOptions = toml::from_str(
+///     r#"
+///     alt_screen = true
+///     fps = 30
+///     color_profile = "ansi256"
+///     "#,
+/// )?;
+/// let model = Program::new(MyModel::new()).with_options(options).run()?;
+/// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct ProgramOptions {
-    /// Use alternate screen buffer.
+    /// Use alternate screen buffer. Otherwise the program renders inline,
+    /// below the output before it, and leaves its last frame behind.
     pub alt_screen: bool,
     /// Enable mouse cell motion tracking.
     pub mouse_cell_motion: bool,
@@ -282,14 +341,32 @@ pub struct ProgramOptions {
     pub bracketed_paste: bool,
     /// Enable focus reporting.
     pub report_focus: bool,
+    /// Request the kitty keyboard protocol's disambiguated key reports.
+    pub keyboard_enhancements: bool,
     /// Use custom I/O (skip terminal setup and event polling).
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub custom_io: bool,
-    /// Target frames per second for rendering.
+    /// Target frames per second for rendering; updates are drawn at most
+    /// this often.
     pub fps: u32,
     /// Disable signal handling.
     pub without_signals: bool,
     /// Don't catch panics.
     pub without_catch_panics: bool,
+    /// Quiet period used to coalesce bursts of window resizes, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub resize_debounce: Option<Duration>,
+    /// Color profile to use instead of the detected one, if any.
+    pub color_profile: Option<ColorProfile>,
+    /// Notification protocol to use instead of the detected one, if any.
+    pub notifications: Option<NotificationProtocol>,
+    /// Record input latencies.
+    pub latency_metrics: bool,
+    /// Move scrolled lines with the terminal's scroll region.
+    pub scroll_regions: bool,
+    /// Longest time the commands of quit hooks may take altogether.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub shutdown_timeout: Duration,
 }
 
 impl Default for ProgramOptions {
@@ -300,12 +377,719 @@ impl Default for ProgramOptions {
             mouse_all_motion: false,
             bracketed_paste: true,
             report_focus: false,
+            keyboard_enhancements: false,
             custom_io: false,
             fps: 60,
             without_signals: false,
             without_catch_panics: false,
+            resize_debounce: None,
+            color_profile: None,
+            notifications: None,
+            latency_metrics: false,
+            scroll_regions: false,
+            shutdown_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Coalesces bursts of window resizes.
+///
+/// The first resize of a burst is delivered immediately. Later ones are held
+/// back until no resize has arrived for the debounce interval, at which point
+/// the final size (if it differs from the last delivered one) is delivered
+/// followed by a [`ResizeSettledMsg`].
+struct ResizeDebouncer {
+    interval: Duration,
+    last_resize: Option<Instant>,
+    pending: Option<WindowSizeMsg>,
+    delivered: Option<WindowSizeMsg>,
+}
+
+impl ResizeDebouncer {
+    fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_resize: None,
+            pending: None,
+            delivered: None,
+        }
+    }
+
+    /// Records a resize, returning whether it should be delivered right away.
+    fn push(&mut self, size: WindowSizeMsg, now: Instant) -> bool {
+        let in_burst = self.last_resize.replace(now).is_some();
+        if in_burst {
+            self.pending = Some(size);
+        } else {
+            self.delivered = Some(size);
+        }
+        !in_burst
+    }
+
+    /// Returns the messages to deliver once the current burst has settled.
+    fn poll(&mut self, now: Instant) -> Vec<Message> {
+        let Some(last) = self.last_resize else {
+            return Vec::new();
+        };
+        if now.duration_since(last) < self.interval {
+            return Vec::new();
+        }
+        self.last_resize = None;
+
+        let mut msgs = Vec::new();
+        if let Some(size) = self.pending.take()
+            && self.delivered != Some(size)
+        {
+            self.delivered = Some(size);
+            msgs.push(Message::new(size));
+        }
+        if let Some(WindowSizeMsg { width, height }) = self.delivered {
+            msgs.push(Message::new(ResizeSettledMsg { width, height }));
+        }
+        msgs
+    }
+}
+
+/// Collects input latencies for [`Program::with_latency_metrics`].
+struct LatencyRecorder {
+    enabled: bool,
+    histogram: LatencyHistogram,
+    unrendered: Vec<Instant>,
+}
+
+impl LatencyRecorder {
+    fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            histogram: LatencyHistogram::new(),
+            unrendered: Vec::new(),
+        }
+    }
+
+    /// Notes a message about to be passed to the model.
+    fn updating(&mut self, msg: &Message) {
+        if self.enabled
+            && let Some(read_at) = msg.timestamp()
+        {
+            self.unrendered.push(read_at);
+        }
+    }
+
+    /// Records the latency of every input handled since the last render.
+    fn rendered(&mut self, now: Instant) {
+        for read_at in self.unrendered.drain(..) {
+            self.histogram.record(now.duration_since(read_at));
+        }
+    }
+
+    fn report(&self) -> Message {
+        Message::new(InputLatencyMsg(self.histogram.clone()))
+    }
+}
+
+/// The last frame drawn, so that the next one can replace it.
+///
+/// On the alternate screen frames are drawn from the top left corner.
+/// Inline, frames are drawn from the cursor down, and each frame moves back
+/// up over the rows of the last one, so output above the program is kept.
+/// Only the lines that changed since the last frame are written, unless
+/// lines wrap or the frame was invalidated. With scroll regions on, lines
+/// that moved up or down the alternate screen are scrolled there by the
+/// terminal rather than written again.
+#[derive(Debug, Default)]
+pub(crate) struct Frame {
+    /// The last view drawn, empty when the next must be drawn regardless.
+    view: String,
+    /// Whether the alternate screen is active.
+    pub(crate) alt_screen: bool,
+    /// Whether moved lines are scrolled with a scroll region.
+    pub(crate) scroll_regions: bool,
+    /// Terminal rows the last inline frame takes, the cursor being on the
+    /// last of them.
+    rows: usize,
+    /// Terminal size, zero while unknown.
+    width: u16,
+    height: u16,
+}
+
+impl Frame {
+    pub(crate) fn new(alt_screen: bool) -> Self {
+        Self {
+            alt_screen,
+            ..Self::default()
+        }
+    }
+
+    /// Makes the next draw happen even if the view is unchanged.
+    fn invalidate(&mut self) {
+        self.view.clear();
+    }
+
+    /// Notes a new terminal size. The terminal rewraps the last inline
+    /// frame, so its rows are counted again, and the next view is drawn in
+    /// full.
+    pub(crate) fn resize(&mut self, size: WindowSizeMsg) {
+        self.width = size.width;
+        self.height = size.height;
+        if self.rows > 0 {
+            self.rows = self.rows_of(&self.clip(&self.view));
+        }
+        self.invalidate();
+    }
+
+    /// Draws `view` over the last frame, unless it is unchanged.
+    pub(crate) fn draw<W: Write>(&mut self, writer: &mut W, view: String) -> io::Result<()> {
+        if view == self.view {
+            return Ok(());
+        }
+        // Raw mode doesn't return the cursor to the line start on `\n`
+        if self.alt_screen {
+            if !self.view.is_empty() && self.fits(&self.view) && self.fits(&view) {
+                self.repaint_alt(writer, &view)?;
+            } else {
+                queue!(writer, MoveTo(0, 0), Clear(ClearType::All))?;
+                write!(writer, "{}", view.replace('\n', "\r\n"))?;
+            }
+        } else {
+            let shown = self.clip(&view);
+            let last = self.clip(&self.view);
+            if self.rows > 0 && !self.view.is_empty() && self.fits(&last) && self.fits(&shown) {
+                self.repaint_inline(writer, &last, &shown)?;
+            } else {
+                self.erase(writer)?;
+                write!(writer, "{}", shown.replace('\n', "\r\n"))?;
+            }
+            self.rows = self.rows_of(&shown);
+        }
+        writer.flush()?;
+        self.view = view;
+        Ok(())
+    }
+
+    /// Returns whether every line of `view` takes one row and all of them
+    /// fit on the screen, so lines can be addressed by row.
+    fn fits(&self, view: &str) -> bool {
+        let height = usize::from(self.height);
+        (height == 0 || view.split('\n').count() <= height)
+            && view.split('\n').all(|line| self.line_rows(line) == 1)
+    }
+
+    /// Rewrites the lines of the alternate screen that differ from the
+    /// last frame.
+    fn repaint_alt<W: Write>(&self, writer: &mut W, view: &str) -> io::Result<()> {
+        let mut last: Vec<&str> = self.view.split('\n').collect();
+        let lines: Vec<&str> = view.split('\n').collect();
+        let row = |i: usize| u16::try_from(i).unwrap_or(u16::MAX);
+        if self.scroll_regions
+            && let Some(scroll) = Scroll::find(&last, &lines)
+        {
+            scroll.apply(writer, &mut last)?;
+        }
+        for (i, line) in lines.iter().enumerate() {
+            if last.get(i) != Some(line) {
+                queue!(writer, MoveTo(0, row(i)))?;
+                self.write_line(writer, line)?;
+            }
+        }
+        if lines.len() < last.len() {
+            queue!(
+                writer,
+                MoveTo(0, row(lines.len())),
+                Clear(ClearType::FromCursorDown)
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Rewrites the lines of an inline frame that differ from `last`, the
+    /// part of the last frame on screen, leaving the cursor on the new last
+    /// row.
+    fn repaint_inline<W: Write>(&self, writer: &mut W, last: &str, view: &str) -> io::Result<()> {
+        let last: Vec<&str> = last.split('\n').collect();
+        let lines: Vec<&str> = view.split('\n').collect();
+        let mut row = last.len() - 1;
+        for (i, line) in lines.iter().enumerate() {
+            if last.get(i) != Some(line) {
+                move_to_row(writer, &mut row, i, last.len())?;
+                queue!(writer, MoveToColumn(0))?;
+                self.write_line(writer, line)?;
+            }
+        }
+        if lines.len() < last.len() {
+            move_to_row(writer, &mut row, lines.len(), last.len())?;
+            queue!(writer, MoveToColumn(0), Clear(ClearType::FromCursorDown))?;
+        }
+        move_to_row(writer, &mut row, lines.len() - 1, last.len())
+    }
+
+    /// Writes `line` at the cursor and clears the rest of the row. A line
+    /// as wide as the terminal leaves the cursor on its last cell, which the
+    /// clear would erase, so it is not cleared.
+    fn write_line<W: Write>(&self, writer: &mut W, line: &str) -> io::Result<()> {
+        write!(writer, "{line}")?;
+        if self.width == 0 || visible_width(line) < usize::from(self.width) {
+            queue!(writer, Clear(ClearType::UntilNewLine))?;
+        }
+        Ok(())
+    }
+
+    /// Prints `text` above the inline frame, which is drawn again below it
+    /// on the next render.
+    pub(crate) fn print_above<W: Write>(&mut self, writer: &mut W, text: &str) -> io::Result<()> {
+        self.erase(writer)?;
+        for line in text.lines() {
+            write!(writer, "{line}\r\n")?;
+        }
+        writer.flush()?;
+        self.invalidate();
+        Ok(())
+    }
+
+    /// Enters or leaves the alternate screen. Leaving restores the main
+    /// screen with the last inline frame and the cursor where they were.
+    pub(crate) fn set_alt_screen<W: Write>(
+        &mut self,
+        writer: &mut W,
+        alt_screen: bool,
+    ) -> io::Result<()> {
+        if alt_screen == self.alt_screen {
+            return Ok(());
+        }
+        if alt_screen {
+            execute!(writer, EnterAlternateScreen)?;
+        } else {
+            execute!(writer, LeaveAlternateScreen)?;
+        }
+        self.alt_screen = alt_screen;
+        self.invalidate();
+        Ok(())
+    }
+
+    /// Leaves the alternate screen, or moves below the inline frame, so
+    /// that output after the program doesn't overwrite the last frame.
+    pub(crate) fn finish<W: Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        if self.alt_screen {
+            execute!(writer, LeaveAlternateScreen)
+        } else if self.rows > 0 {
+            self.rows = 0;
+            write!(writer, "\r\n")?;
+            writer.flush()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Moves to the first row of the last inline frame and clears it and
+    /// everything below.
+    fn erase<W: Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        if self.rows > 1 {
+            let up = u16::try_from(self.rows - 1).unwrap_or(u16::MAX);
+            queue!(writer, MoveUp(up))?;
+        }
+        if self.rows > 0 {
+            queue!(writer, MoveToColumn(0), Clear(ClearType::FromCursorDown))?;
+        }
+        self.rows = 0;
+        Ok(())
+    }
+
+    /// Returns the terminal rows `line` takes once long lines wrap.
+    fn line_rows(&self, line: &str) -> usize {
+        let width = visible_width(line);
+        if self.width == 0 || width == 0 {
+            1
+        } else {
+            width.div_ceil(usize::from(self.width))
+        }
+    }
+
+    fn rows_of(&self, view: &str) -> usize {
+        view.split('\n').map(|line| self.line_rows(line)).sum()
+    }
+
+    /// Returns the end of `view` that fits in the terminal's height, since
+    /// rows scrolled off the top can't be moved back up to.
+    fn clip(&self, view: &str) -> String {
+        let height = usize::from(self.height);
+        if height == 0 || self.rows_of(view) <= height {
+            return view.to_string();
+        }
+        let mut rows = 0;
+        let mut lines: Vec<&str> = view
+            .rsplit('\n')
+            .take_while(|line| {
+                rows += self.line_rows(line);
+                rows <= height
+            })
+            .collect();
+        lines.reverse();
+        lines.join("\n")
+    }
+}
+
+/// Input reporting modes, which commands can switch while the program runs.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct InputModes {
+    keyboard_enhancements: bool,
+    bracketed_paste: bool,
+    report_focus: bool,
+}
+
+impl InputModes {
+    pub(crate) fn new(options: &ProgramOptions) -> Self {
+        Self {
+            keyboard_enhancements: options.keyboard_enhancements,
+            bracketed_paste: options.bracketed_paste,
+            report_focus: options.report_focus,
+        }
+    }
+
+    /// Turns on the modes that are active.
+    pub(crate) fn enable<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.keyboard_enhancements {
+            // Push the "disambiguate escape codes" flag; the terminal keeps
+            // a stack of flags, so popping restores the shell's.
+            write!(writer, "\x1b[>1u")?;
+            writer.flush()?;
+        }
+        if self.report_focus {
+            execute!(writer, event::EnableFocusChange)?;
+        }
+        if self.bracketed_paste {
+            enable_bracketed_paste(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Turns off the modes that are active, in reverse order of enabling.
+    pub(crate) fn disable<W: Write>(&self, writer: &mut W) {
+        if self.bracketed_paste {
+            let _ = execute!(writer, event::DisableBracketedPaste);
+        }
+        if self.report_focus {
+            let _ = execute!(writer, event::DisableFocusChange);
+        }
+        if self.keyboard_enhancements {
+            let _ = write!(writer, "\x1b[<u");
+            let _ = writer.flush();
+        }
+    }
+
+    /// Switches a mode if `msg` is one of the mode commands, returning
+    /// whether it was.
+    pub(crate) fn apply<W: Write>(&mut self, writer: &mut W, msg: &Message) -> io::Result<bool> {
+        if msg.is::<EnableBracketedPasteMsg>() || msg.is::<DisableBracketedPasteMsg>() {
+            let on = msg.is::<EnableBracketedPasteMsg>();
+            if on != self.bracketed_paste {
+                self.bracketed_paste = on;
+                if on {
+                    enable_bracketed_paste(writer)?;
+                } else {
+                    execute!(writer, event::DisableBracketedPaste)?;
+                }
+            }
+            return Ok(true);
+        }
+        if msg.is::<EnableReportFocusMsg>() || msg.is::<DisableReportFocusMsg>() {
+            let on = msg.is::<EnableReportFocusMsg>();
+            if on != self.report_focus {
+                self.report_focus = on;
+                if on {
+                    execute!(writer, event::EnableFocusChange)?;
+                } else {
+                    execute!(writer, event::DisableFocusChange)?;
+                }
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+thread_local! {
+    /// Set while this thread runs an event loop that catches panics.
+    static CATCHING_PANICS: Cell<bool> = const { Cell::new(false) };
+    /// The report of the last panic caught while `CATCHING_PANICS` was set.
+    static PANIC_REPORT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, catching a panic so that the terminal can be restored before
+/// [`resume_panic`] carries on unwinding.
+///
+/// The default panic hook would print the panic while the terminal is in
+/// raw mode, or on the alternate screen where it's lost on exit, so a
+/// chained hook holds the report back until the terminal is restored.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> thread::Result<T> {
+    install_panic_hook();
+    let catching = CATCHING_PANICS.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_PANICS.set(catching);
+    if result.is_ok() {
+        // Drop the report of any panic the model caught itself
+        PANIC_REPORT.take();
+    }
+    result
+}
+
+/// Like [`catch_panic`], but for the async event loop, which may move
+/// between threads while it waits.
+#[cfg(feature = "async")]
+async fn catch_panic_async<F: Future>(future: F) -> thread::Result<F::Output> {
+    let mut future = std::pin::pin!(future);
+    std::future::poll_fn(|cx| match catch_panic(|| future.as_mut().poll(cx)) {
+        Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+        Ok(Poll::Pending) => Poll::Pending,
+        Err(payload) => Poll::Ready(Err(payload)),
+    })
+    .await
+}
+
+/// Prints the report held back by [`catch_panic`] and resumes unwinding
+/// with `payload`.
+fn resume_panic(payload: Box<dyn Any + Send>) -> ! {
+    if let Some(report) = PANIC_REPORT.take() {
+        eprintln!("{report}");
+    }
+    panic::resume_unwind(payload)
+}
+
+/// Chains a panic hook that holds back reports of panics on threads
+/// running [`catch_panic`], and defers to the previous hook otherwise.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING_PANICS.get() {
+                previous(info);
+                return;
+            }
+            let thread = thread::current();
+            let name = thread.name().unwrap_or("<unnamed>");
+            let mut report = format!("thread '{name}' {info}");
+            let backtrace = Backtrace::capture();
+            if backtrace.status() == BacktraceStatus::Captured {
+                report.push_str(&format!("\nstack backtrace:\n{backtrace}"));
+            }
+            PANIC_REPORT.set(Some(report));
+        }));
+    });
+}
+
+/// Turns on bracketed paste where the console supports it. The legacy
+/// Windows console doesn't, and pastes arrive there as typed keys.
+fn enable_bracketed_paste<W: Write>(writer: &mut W) -> io::Result<()> {
+    match execute!(writer, event::EnableBracketedPaste) {
+        Err(err) if err.kind() == io::ErrorKind::Unsupported => Ok(()),
+        result => result,
+    }
+}
+
+/// Runs the quit hooks in order, giving their commands `timeout` altogether,
+/// and returns the lines the commands print.
+fn run_quit_hooks<M>(hooks: Vec<QuitHook<M>>, model: &M, timeout: Duration) -> Vec<String> {
+    let deadline = Instant::now() + timeout;
+    let mut printed = Vec::new();
+    for hook in hooks {
+        let Some(cmd) = hook(model) else {
+            continue;
+        };
+        // A command that overruns is left behind on its thread
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = tx.send(cmd.execute());
+        });
+        match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(msg) => printed.extend(printed_line(msg)),
+            Err(_) => {
+                debug!(target: "bubbletea::program", "quit hook abandoned at shutdown timeout");
+            }
+        }
+    }
+    printed
+}
+
+/// Runs the quit hooks like [`run_quit_hooks`], on the blocking pool.
+#[cfg(feature = "async")]
+async fn run_quit_hooks_async<M>(
+    hooks: Vec<QuitHook<M>>,
+    model: &M,
+    timeout: Duration,
+) -> Vec<String> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut printed = Vec::new();
+    for hook in hooks {
+        let Some(cmd) = hook(model) else {
+            continue;
+        };
+        let task = tokio::task::spawn_blocking(move || cmd.execute());
+        match tokio::time::timeout_at(deadline, task).await {
+            Ok(Ok(msg)) => printed.extend(printed_line(msg)),
+            _ => {
+                debug!(target: "bubbletea::program", "quit hook abandoned at shutdown timeout");
+            }
+        }
+    }
+    printed
+}
+
+/// Returns the line `msg` asks to print, if it is a [`PrintLineMsg`].
+fn printed_line(msg: Option<Message>) -> Option<String> {
+    msg?.downcast::<PrintLineMsg>().map(|msg| msg.0)
+}
+
+/// Writes `lines` at the cursor, which cleanup leaves below the program's
+/// output.
+fn print_lines<W: Write>(writer: &mut W, lines: &[String]) {
+    for line in lines.iter().flat_map(|text| text.lines()) {
+        let _ = write!(writer, "{line}\r\n");
+    }
+    let _ = writer.flush();
+}
+
+/// Moves the cursor from `row` to row `to` of an inline frame that has
+/// `rows` rows, adding rows below the frame as needed.
+fn move_to_row<W: Write>(
+    writer: &mut W,
+    row: &mut usize,
+    to: usize,
+    rows: usize,
+) -> io::Result<()> {
+    let count = |n: usize| u16::try_from(n).unwrap_or(u16::MAX);
+    if to < *row {
+        queue!(writer, MoveUp(count(*row - to)))?;
+    } else if to > *row {
+        // Moving down doesn't scroll, so rows past the bottom of the
+        // screen are added with newlines
+        let existing = to.min(rows - 1);
+        if existing > *row {
+            queue!(writer, MoveDown(count(existing - *row)))?;
+        }
+        for _ in existing.max(*row)..to {
+            write!(writer, "\r\n")?;
+        }
+    }
+    *row = to;
+    Ok(())
+}
+
+/// Lines of the alternate screen that moved between two frames, which the
+/// terminal can scroll within a scroll region instead of them being written
+/// again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Scroll {
+    /// First row of the region.
+    top: usize,
+    /// Last row of the region.
+    bottom: usize,
+    /// Rows the region scrolls by, up if positive and down if negative.
+    by: isize,
+}
+
+impl Scroll {
+    /// Finds the scroll that leaves the fewest rows of `lines` to write over
+    /// `last`, if any saves more rows than it clears.
+    fn find(last: &[&str], lines: &[&str]) -> Option<Self> {
+        let rows = last.len().min(lines.len());
+        let mut best: Option<(usize, Self)> = None;
+        for shift in 1..rows {
+            for up in [true, false] {
+                // Rows of `lines` showing the line `shift` rows below (up) or
+                // above (down) in `last`
+                let moved = |r: usize| {
+                    if up {
+                        r + shift < rows && lines[r] == last[r + shift]
+                    } else {
+                        r >= shift && lines[r] == last[r - shift]
+                    }
+                };
+                let mut start = 0;
+                while start < rows {
+                    if !moved(start) {
+                        start += 1;
+                        continue;
+                    }
+                    let mut end = start;
+                    while end + 1 < rows && moved(end + 1) {
+                        end += 1;
+                    }
+                    let saved = (start..=end).filter(|&r| lines[r] != last[r]).count();
+                    if saved > shift && best.is_none_or(|(most, _)| saved > most) {
+                        let by = isize::try_from(shift).unwrap_or(isize::MAX);
+                        let scroll = if up {
+                            Self {
+                                top: start,
+                                bottom: end + shift,
+                                by,
+                            }
+                        } else {
+                            Self {
+                                top: start - shift,
+                                bottom: end,
+                                by: -by,
+                            }
+                        };
+                        best = Some((saved, scroll));
+                    }
+                    start = end + 1;
+                }
+            }
+        }
+        best.map(|(_, scroll)| scroll)
+    }
+
+    /// Scrolls the region and moves its lines in `last` to match, leaving
+    /// the rows scrolled in empty.
+    fn apply<W: Write>(self, writer: &mut W, last: &mut [&str]) -> io::Result<()> {
+        let shift = self.by.unsigned_abs();
+        let count = u16::try_from(shift).unwrap_or(u16::MAX);
+        let region = &mut last[self.top..=self.bottom];
+        write!(writer, "\x1b[{};{}r", self.top + 1, self.bottom + 1)?;
+        if self.by > 0 {
+            queue!(writer, ScrollUp(count))?;
+            let kept = region.len() - shift;
+            region.rotate_left(shift);
+            region[kept..].fill("");
+        } else {
+            queue!(writer, ScrollDown(count))?;
+            region.rotate_right(shift);
+            region[..shift].fill("");
+        }
+        // Reset the region to the whole screen
+        write!(writer, "\x1b[r")
+    }
+}
+
+/// Returns the display width of `line`, skipping escape sequences.
+fn visible_width(line: &str) -> usize {
+    let mut width = 0;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            width += c.width().unwrap_or(0);
+            continue;
+        }
+        match chars.next() {
+            // CSI, ending with a byte in `@`..=`~`
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC, ending with BEL or ST
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' || (c == '\x1b' && chars.next() == Some('\\')) {
+                        break;
+                    }
+                }
+            }
+            _ => {}
         }
     }
+    width
 }
 
 /// Handle to a running program.
@@ -393,6 +1177,25 @@ pub struct Program<M: Model> {
     external_rx: Option<Receiver<Message>>,
     input: Option<Box<dyn Read + Send>>,
     output: Option<Box<dyn Write + Send>>,
+    terminal: Arc<dyn Terminal>,
+    tracer: Option<Tracer>,
+    quit_hooks: Vec<QuitHook<M>>,
+}
+
+/// A hook run with the final model when the program quits.
+type QuitHook<M> = Box<dyn FnOnce(&M) -> Option<Cmd> + Send>;
+
+/// Returns the terminal programs run on unless given another.
+#[cfg(not(target_arch = "wasm32"))]
+fn default_terminal() -> Arc<dyn Terminal> {
+    Arc::new(CrosstermTerminal)
+}
+
+/// Returns the terminal programs run on unless given another. `wasm32`
+/// has no terminal of its own, and [`Program::run`] refuses to start there.
+#[cfg(target_arch = "wasm32")]
+fn default_terminal() -> Arc<dyn Terminal> {
+    Arc::new(WebTerminal::new(80, 24))
 }
 
 impl<M: Model> Program<M> {
@@ -404,6 +1207,9 @@ impl<M: Model> Program<M> {
             external_rx: None,
             input: None,
             output: None,
+            terminal: default_terminal(),
+            tracer: None,
+            quit_hooks: Vec::new(),
         }
     }
 
@@ -435,7 +1241,35 @@ impl<M: Model> Program<M> {
         self
     }
 
+    /// Read input from `terminal` instead of the process's own terminal.
+    ///
+    /// The terminal also provides raw mode and the window size. Output
+    /// still goes to the program's writer, so pair this with
+    /// [`run_with_writer`](Self::run_with_writer) for terminals that are not
+    /// backed by stdout, such as a [`WebTerminal`](crate::WebTerminal).
+    pub fn with_terminal<T: Terminal>(mut self, terminal: T) -> Self {
+        self.terminal = Arc::new(terminal);
+        self
+    }
+
+    /// Record each message handled and frame drawn with `tracer`.
+    ///
+    /// Without a tracer, the program traces into the file named by the
+    /// `BUBBLETEA_LOG` environment variable, if set. See [`crate::trace`].
+    pub fn with_tracer(mut self, tracer: Tracer) -> Self {
+        self.tracer = Some(tracer);
+        self
+    }
+
     /// Use alternate screen buffer (full-screen mode).
+    ///
+    /// Without it, the program renders inline: each frame replaces the
+    /// rows of the last one below the cursor, output printed before the
+    /// program is kept, and the last frame stays on screen when the program
+    /// exits. Frames taller than the terminal show only their last rows.
+    /// The [`enter_alt_screen`](crate::screen::enter_alt_screen) and
+    /// [`exit_alt_screen`](crate::screen::exit_alt_screen) commands switch
+    /// between the two while the program runs.
     pub fn with_alt_screen(mut self) -> Self {
         self.options.alt_screen = true;
         self
@@ -459,22 +1293,153 @@ impl<M: Model> Program<M> {
 
     /// Set the target frames per second.
     ///
-    /// Default is 60 FPS. Valid range is 1-120 FPS.
+    /// The view is drawn at most this often: updates within a frame are
+    /// drawn together once it ends, and only the lines that changed are
+    /// written. Lower rates send less output, which helps over slow links
+    /// such as SSH. Default is 60 FPS. Valid range is 1-120 FPS.
     pub fn with_fps(mut self, fps: u32) -> Self {
         self.options.fps = fps.clamp(1, 120);
         self
     }
 
+    /// Enable disambiguated key reports from terminals supporting the
+    /// kitty keyboard protocol.
+    ///
+    /// Keys that legacy input can't tell apart are then reported with the
+    /// modifiers held, such as shift+enter, or ctrl+i apart from tab, in
+    /// [`KeyMsg::modifiers`]. Keys legacy input does report keep their
+    /// usual [`KeyType`], and terminals without the protocol ignore the
+    /// request and keep sending legacy input.
+    pub fn with_keyboard_enhancements(mut self) -> Self {
+        self.options.keyboard_enhancements = true;
+        self
+    }
+
     /// Enable focus reporting.
     ///
-    /// Sends FocusMsg and BlurMsg when terminal gains/loses focus.
+    /// Sends FocusMsg and BlurMsg when terminal gains/loses focus, so models
+    /// can pause spinners or timers while unfocused. The
+    /// [`enable_report_focus`](crate::screen::enable_report_focus) and
+    /// [`disable_report_focus`](crate::screen::disable_report_focus) commands
+    /// switch it while the program runs.
     pub fn with_report_focus(mut self) -> Self {
         self.options.report_focus = true;
         self
     }
 
-    /// Disable bracketed paste mode.
-    pub fn without_bracketed_paste(mut self) -> Self {
+    /// Debounce window resizes.
+    ///
+    /// While the terminal is being resized continuously, only the first and
+    /// the final [`WindowSizeMsg`] of the burst are delivered. Once no resize
+    /// has arrived for `interval`, a [`ResizeSettledMsg`] follows, so
+    /// expensive reflows can wait for it instead of running on every event.
+    pub fn with_resize_debounce(mut self, interval: Duration) -> Self {
+        self.options.resize_debounce = Some(interval);
+        self
+    }
+
+    /// Override the terminal's color profile.
+    ///
+    /// The model receives a [`ColorProfileMsg`] at startup so views can
+    /// render with `profile` instead of the detected one.
+    pub fn with_color_profile(mut self, profile: ColorProfile) -> Self {
+        self.options.color_profile = Some(profile);
+        self
+    }
+
+    /// Override the terminal's notification protocol.
+    ///
+    /// [`notify`](crate::notify) commands are sent with `protocol` instead
+    /// of the one detected from the environment. With custom I/O nothing is
+    /// detected, so notifications are only shown once a protocol is set.
+    pub fn with_notifications(mut self, protocol: NotificationProtocol) -> Self {
+        self.options.notifications = Some(protocol);
+        self
+    }
+
+    /// Record input latencies.
+    ///
+    /// For every input event, the time from reading it to finishing the
+    /// render that follows its update is recorded in a [`LatencyHistogram`].
+    /// The [`input_latency`](crate::input_latency) command delivers the
+    /// histogram to the model as an [`InputLatencyMsg`].
+    pub fn with_latency_metrics(mut self) -> Self {
+        self.options.latency_metrics = true;
+        self
+    }
+
+    /// Scroll moved lines with the terminal's scroll region.
+    ///
+    /// On the alternate screen, when lines of a frame show up a few rows
+    /// above or below where the last frame had them, as when a viewport
+    /// scrolls, the terminal is told to scroll those rows and only the lines
+    /// scrolled in are written instead of the whole body.
+    pub fn with_scroll_regions(mut self) -> Self {
+        self.options.scroll_regions = true;
+        self
+    }
+
+    /// Register a hook to run when the program quits.
+    ///
+    /// Hooks run after the model quits and its last view is drawn, but
+    /// before the terminal is restored, in the order they were registered.
+    /// Each is given the final model and may return a command for slow work
+    /// such as saving state. The commands run one at a time, and any still
+    /// running when the [shutdown timeout](Self::with_shutdown_timeout) runs
+    /// out is abandoned. Lines a command prints with
+    /// [`println`](crate::println) appear below the program's output, after
+    /// it leaves the alternate screen.
+    ///
+    /// Hooks don't run when the program fails or panics.
+    ///
+    /// ```rust,ignore
+    /// Program::new(app)
+    ///     .on_quit(|app| {
+    ///         let state = app.state.clone();
+    ///         Some(Cmd::new_optional(move || {
+    ///             state.save().ok();
+    ///             None
+    ///         }))
+    ///     })
+    ///     .on_quit(|app| Some(println(format!("{} tasks left", app.tasks.len()))))
+    ///     .run()?;
+    /// ```
+    pub fn on_quit<F>(mut self, hook: F) -> Self
+    where
+        F: FnOnce(&M) -> Option<Cmd> + Send + 'static,
+    {
+        self.quit_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Set the longest time the commands of [quit hooks](Self::on_quit) may
+    /// take altogether. Defaults to 5 seconds.
+    pub fn with_shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.options.shutdown_timeout = timeout;
+        self
+    }
+
+    /// Replace all options at once, e.g. with options loaded from a
+    /// configuration file.
+    ///
+    /// Custom I/O set up with [`with_input`](Self::with_input) or
+    /// [`with_output`](Self::with_output) stays enabled, and the frame rate
+    /// is clamped as in [`with_fps`](Self::with_fps).
+    pub fn with_options(mut self, options: ProgramOptions) -> Self {
+        let custom_io = self.options.custom_io || options.custom_io;
+        self.options = ProgramOptions {
+            custom_io,
+            fps: options.fps.clamp(1, 120),
+            ..options
+        };
+        self
+    }
+
+    /// Disable bracketed paste mode.
+    ///
+    /// Pasted text then arrives as ordinary key presses rather than a
+    /// single [`KeyMsg`] with `paste` set.
+    pub fn without_bracketed_paste(mut self) -> Self {
         self.options.bracketed_paste = false;
         self
     }
@@ -486,6 +1451,11 @@ impl<M: Model> Program<M> {
     }
 
     /// Don't catch panics.
+    ///
+    /// By default a panic in the model is caught so the terminal can be
+    /// restored (raw mode off, cursor shown, alternate screen left) before
+    /// the panic is reported and unwinding resumes. With this set, a panic
+    /// leaves the terminal as it was.
     pub fn without_catch_panics(mut self) -> Self {
         self.options.without_catch_panics = true;
         self
@@ -501,13 +1471,26 @@ impl<M: Model> Program<M> {
     }
 
     /// Run the program with a custom writer.
-    pub fn run_with_writer<W: Write + Send + 'static>(self, mut writer: W) -> Result<M> {
+    ///
+    /// On `wasm32` targets this fails with [`io::ErrorKind::Unsupported`]:
+    /// the event loop needs threads, which the browser doesn't give it. Run
+    /// the model with a [`WebProgram`](crate::web::WebProgram) there.
+    pub fn run_with_writer<W: Write + Send + 'static>(mut self, mut writer: W) -> Result<M> {
+        if cfg!(target_arch = "wasm32") {
+            return Err(Error::Io(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "programs can't run threads on wasm32; use bubbletea::web::WebProgram",
+            )));
+        }
+
         // Save options for cleanup (since self will be moved)
         let options = self.options.clone();
+        let terminal = Arc::clone(&self.terminal);
+        let quit_hooks = std::mem::take(&mut self.quit_hooks);
 
         // Setup terminal (skip for custom IO)
         if !options.custom_io {
-            enable_raw_mode()?;
+            self.terminal.enable_raw_mode()?;
         }
 
         if options.alt_screen {
@@ -522,41 +1505,45 @@ impl<M: Model> Program<M> {
             execute!(writer, EnableMouseCapture)?;
         }
 
-        if options.report_focus {
-            execute!(writer, event::EnableFocusChange)?;
-        }
-
-        if options.bracketed_paste {
-            execute!(writer, event::EnableBracketedPaste)?;
-        }
+        let mut modes = InputModes::new(&options);
+        modes.enable(&mut writer)?;
 
         // Run the event loop
-        let result = self.event_loop(&mut writer);
+        let mut window_title_set = false;
+        let mut frame = Frame::new(options.alt_screen);
+        frame.scroll_regions = options.scroll_regions;
+        let result = if options.without_catch_panics {
+            Ok(self.event_loop(&mut writer, &mut window_title_set, &mut frame, &mut modes))
+        } else {
+            catch_panic(|| {
+                self.event_loop(&mut writer, &mut window_title_set, &mut frame, &mut modes)
+            })
+        };
+        let printed = match &result {
+            Ok(Ok(model)) => run_quit_hooks(quit_hooks, model, options.shutdown_timeout),
+            _ => Vec::new(),
+        };
 
         // Cleanup terminal
-        if options.bracketed_paste {
-            let _ = execute!(writer, event::DisableBracketedPaste);
+        if window_title_set {
+            let _ = execute!(writer, terminal::SetTitle(""));
         }
 
-        if options.report_focus {
-            let _ = execute!(writer, event::DisableFocusChange);
-        }
+        modes.disable(&mut writer);
 
         if options.mouse_all_motion || options.mouse_cell_motion {
             let _ = execute!(writer, DisableMouseCapture);
         }
 
         let _ = execute!(writer, Show);
-
-        if options.alt_screen {
-            let _ = execute!(writer, LeaveAlternateScreen);
-        }
+        let _ = frame.finish(&mut writer);
+        print_lines(&mut writer, &printed);
 
         if !options.custom_io {
-            let _ = disable_raw_mode();
+            let _ = terminal.disable_raw_mode();
         }
 
-        result
+        result.unwrap_or_else(|payload| resume_panic(payload))
     }
 
     /// Run the program and return the final model state.
@@ -618,7 +1605,19 @@ impl<M: Model> Program<M> {
         }
     }
 
-    fn event_loop<W: Write>(mut self, writer: &mut W) -> Result<M> {
+    /// Runs the event loop until the program quits.
+    ///
+    /// `window_title_set` is raised once the model changes the window title,
+    /// so the caller knows to clear it during cleanup, `frame` holds the
+    /// last frame drawn, which cleanup leaves behind, and `modes` holds the
+    /// input modes cleanup turns off.
+    fn event_loop<W: Write>(
+        mut self,
+        writer: &mut W,
+        window_title_set: &mut bool,
+        frame: &mut Frame,
+        modes: &mut InputModes,
+    ) -> Result<M> {
         // Create message channel
         let (tx, rx): (Sender<Message>, Receiver<Message>) = mpsc::channel();
 
@@ -645,10 +1644,11 @@ impl<M: Model> Program<M> {
                     match input.read(&mut buf) {
                         Ok(0) => break,
                         Ok(n) => {
+                            let read_at = Instant::now();
                             // We always assume there could be more data unless we hit EOF (Ok(0))
                             let can_have_more_data = true;
                             for msg in parser.push_bytes(&buf[..n], can_have_more_data) {
-                                if tx_clone.send(msg).is_err() {
+                                if tx_clone.send(msg.with_timestamp(read_at)).is_err() {
                                     debug!(target: "bubbletea::input", "input message dropped — receiver disconnected");
                                     return;
                                 }
@@ -661,8 +1661,9 @@ impl<M: Model> Program<M> {
                     }
                 }
 
+                let read_at = Instant::now();
                 for msg in parser.flush() {
-                    if tx_clone.send(msg).is_err() {
+                    if tx_clone.send(msg.with_timestamp(read_at)).is_err() {
                         debug!(target: "bubbletea::input", "flush message dropped — receiver disconnected");
                         break;
                     }
@@ -672,7 +1673,7 @@ impl<M: Model> Program<M> {
 
         // Get initial window size (only if not custom IO, otherwise trust init msg)
         if !self.options.custom_io
-            && let Ok((width, height)) = terminal::size()
+            && let Ok((width, height)) = self.terminal.size()
             && tx
                 .send(Message::new(WindowSizeMsg { width, height }))
                 .is_err()
@@ -680,91 +1681,63 @@ impl<M: Model> Program<M> {
             debug!(target: "bubbletea::event", "initial window size dropped — receiver disconnected");
         }
 
+        if let Some(profile) = self.options.color_profile
+            && tx.send(Message::new(ColorProfileMsg(profile))).is_err()
+        {
+            debug!(target: "bubbletea::event", "color profile dropped — receiver disconnected");
+        }
+
+        if self.tracer.is_none() {
+            self.tracer = Tracer::from_env();
+        }
+
         // Call init and handle initial command
         if let Some(cmd) = self.model.init() {
             self.handle_command(cmd, tx.clone());
         }
 
         // Render initial view
-        let mut last_view = String::new();
-        self.render(writer, &mut last_view)?;
+        self.render(writer, frame)?;
 
-        // Frame timing
+        // Frame timing. Updates are drawn at most once a frame.
         let frame_duration = Duration::from_secs_f64(1.0 / self.options.fps as f64);
+        let mut last_render = Instant::now();
+        let mut needs_render = false;
+        let mut resize = self.options.resize_debounce.map(ResizeDebouncer::new);
+        let mut latency = LatencyRecorder::new(self.options.latency_metrics);
 
         // Event loop
         loop {
             // Poll for events with frame-rate limiting (skip poll if custom IO)
             // In custom IO mode, events are injected via `with_input_receiver()` or `with_input()`.
             // Crossterm polling is skipped since input comes from external sources.
-            if !self.options.custom_io && event::poll(frame_duration)? {
-                match event::read()? {
-                    Event::Key(key_event) => {
-                        // Only handle key press events, not release
-                        if key_event.kind != KeyEventKind::Press {
-                            continue;
-                        }
-
-                        let key_msg = from_crossterm_key(key_event.code, key_event.modifiers);
-
-                        // Handle Ctrl+C specially
-                        if key_msg.key_type == crate::KeyType::CtrlC {
-                            if tx.send(Message::new(InterruptMsg)).is_err() {
-                                debug!(target: "bubbletea::event", "interrupt message dropped — receiver disconnected");
-                            }
-                        } else if tx.send(Message::new(key_msg)).is_err() {
-                            debug!(target: "bubbletea::event", "key message dropped — receiver disconnected");
-                        }
-                    }
-                    Event::Mouse(mouse_event) => {
-                        let mouse_msg = from_crossterm_mouse(mouse_event);
-                        if tx.send(Message::new(mouse_msg)).is_err() {
-                            debug!(target: "bubbletea::event", "mouse message dropped — receiver disconnected");
-                        }
-                    }
-                    Event::Resize(width, height) => {
-                        if tx
-                            .send(Message::new(WindowSizeMsg { width, height }))
-                            .is_err()
-                        {
-                            debug!(target: "bubbletea::event", "resize message dropped — receiver disconnected");
-                        }
-                    }
-                    Event::FocusGained => {
-                        if tx.send(Message::new(FocusMsg)).is_err() {
-                            debug!(target: "bubbletea::event", "focus message dropped — receiver disconnected");
-                        }
-                    }
-                    Event::FocusLost => {
-                        if tx.send(Message::new(BlurMsg)).is_err() {
-                            debug!(target: "bubbletea::event", "blur message dropped — receiver disconnected");
-                        }
-                    }
-                    Event::Paste(text) => {
-                        // Send as a key message with paste flag
-                        let key_msg = KeyMsg {
-                            key_type: crate::KeyType::Runes,
-                            runes: text.chars().collect(),
-                            alt: false,
-                            paste: true,
-                        };
-                        if tx.send(Message::new(key_msg)).is_err() {
-                            debug!(target: "bubbletea::event", "paste message dropped — receiver disconnected");
-                        }
+            if !self.options.custom_io {
+                // Wake up in time to draw a pending update
+                let timeout = if needs_render {
+                    frame_duration.saturating_sub(last_render.elapsed())
+                } else {
+                    frame_duration
+                };
+                let msgs = self.terminal.poll(timeout)?;
+                let read_at = Instant::now();
+                for msg in msgs {
+                    if tx.send(msg.with_timestamp(read_at)).is_err() {
+                        debug!(target: "bubbletea::event", "input message dropped — receiver disconnected");
                     }
                 }
             }
 
             // Process all pending messages
-            let mut needs_render = false;
             while let Ok(msg) = rx.try_recv() {
-                // Check for quit message
+                // Check for quit message, leaving the final view on screen
                 if msg.is::<QuitMsg>() {
+                    self.render(writer, frame)?;
                     return Ok(self.model);
                 }
 
                 // Check for interrupt message (Ctrl+C)
                 if msg.is::<InterruptMsg>() {
+                    self.render(writer, frame)?;
                     return Ok(self.model);
                 }
 
@@ -776,13 +1749,46 @@ impl<M: Model> Program<M> {
                 // Handle window title
                 if let Some(title_msg) = msg.downcast_ref::<SetWindowTitleMsg>() {
                     execute!(writer, terminal::SetTitle(&title_msg.0))?;
+                    *window_title_set = true;
+                    continue;
+                }
+
+                // Switch bracketed paste and focus reporting
+                if modes.apply(writer, &msg)? {
+                    continue;
+                }
+
+                // Switch between the alternate screen and inline rendering
+                if msg.is::<EnterAltScreenMsg>() || msg.is::<ExitAltScreenMsg>() {
+                    frame.set_alt_screen(writer, msg.is::<EnterAltScreenMsg>())?;
+                    needs_render = true;
+                    continue;
+                }
+
+                // Handle desktop notification
+                if let Some(notify) = msg.downcast_ref::<NotifyMsg>() {
+                    if let Some(protocol) = self.notification_protocol() {
+                        write!(writer, "{}", protocol.sequence(&notify.title, &notify.body))?;
+                        writer.flush()?;
+                    }
                     continue;
                 }
 
+                // Debounce window resizes
+                if let Some(debouncer) = resize.as_mut()
+                    && let Some(size) = msg.downcast_ref::<WindowSizeMsg>()
+                    && !debouncer.push(*size, Instant::now())
+                {
+                    continue;
+                }
+                if let Some(size) = msg.downcast_ref::<WindowSizeMsg>() {
+                    frame.resize(*size);
+                }
+
                 // Handle window size request
                 if msg.is::<RequestWindowSizeMsg>() {
                     if !self.options.custom_io
-                        && let Ok((width, height)) = terminal::size()
+                        && let Ok((width, height)) = self.terminal.size()
                         && tx
                             .send(Message::new(WindowSizeMsg { width, height }))
                             .is_err()
@@ -792,16 +1798,18 @@ impl<M: Model> Program<M> {
                     continue;
                 }
 
+                // Handle input latency request
+                if msg.is::<RequestInputLatencyMsg>() {
+                    if tx.send(latency.report()).is_err() {
+                        debug!(target: "bubbletea::event", "input latency response dropped — receiver disconnected");
+                    }
+                    continue;
+                }
+
                 // Handle print line message (only when not in alt screen)
                 if let Some(print_msg) = msg.downcast_ref::<PrintLineMsg>() {
-                    if !self.options.alt_screen {
-                        // Print each line above the TUI
-                        for line in print_msg.0.lines() {
-                            let _ = writeln!(writer, "{}", line);
-                        }
-                        let _ = writer.flush();
-                        // Force a full re-render since we printed above
-                        last_view.clear();
+                    if !frame.alt_screen {
+                        let _ = frame.print_above(writer, &print_msg.0);
                         needs_render = true;
                     }
                     continue;
@@ -809,63 +1817,71 @@ impl<M: Model> Program<M> {
 
                 // Handle release terminal
                 if msg.is::<ReleaseTerminalMsg>() {
-                    if !self.options.custom_io {
-                        // Disable features in reverse order
-                        if self.options.bracketed_paste {
-                            let _ = execute!(writer, event::DisableBracketedPaste);
-                        }
-                        if self.options.report_focus {
-                            let _ = execute!(writer, event::DisableFocusChange);
-                        }
-                        if self.options.mouse_all_motion || self.options.mouse_cell_motion {
-                            let _ = execute!(writer, DisableMouseCapture);
-                        }
-                        let _ = execute!(writer, Show);
-                        if self.options.alt_screen {
-                            let _ = execute!(writer, LeaveAlternateScreen);
-                        }
-                        let _ = disable_raw_mode();
-                    }
+                    self.release_terminal(writer, frame, modes);
                     continue;
                 }
 
                 // Handle restore terminal
                 if msg.is::<RestoreTerminalMsg>() {
-                    if !self.options.custom_io {
-                        // Re-enable features in original order
-                        let _ = enable_raw_mode();
-                        if self.options.alt_screen {
-                            let _ = execute!(writer, EnterAlternateScreen);
-                        }
-                        let _ = execute!(writer, Hide);
-                        if self.options.mouse_all_motion {
-                            let _ = execute!(writer, EnableMouseCapture);
-                        } else if self.options.mouse_cell_motion {
-                            let _ = execute!(writer, EnableMouseCapture);
-                        }
-                        if self.options.report_focus {
-                            let _ = execute!(writer, event::EnableFocusChange);
-                        }
-                        if self.options.bracketed_paste {
-                            let _ = execute!(writer, event::EnableBracketedPaste);
+                    self.restore_terminal(writer, frame, modes);
+                    needs_render = true;
+                    continue;
+                }
+
+                // Run an external process with the terminal released. Input
+                // is read on this thread, so none is consumed meanwhile.
+                if msg.is::<ExecMsg>() {
+                    if let Some(exec) = msg.downcast::<ExecMsg>() {
+                        self.release_terminal(writer, frame, modes);
+                        let result = exec.run();
+                        self.restore_terminal(writer, frame, modes);
+                        if tx.send(result).is_err() {
+                            debug!(target: "bubbletea::command", "exec result dropped — receiver disconnected");
                         }
-                        // Force a full re-render
-                        last_view.clear();
+                    }
+                    needs_render = true;
+                    continue;
+                }
+
+                // Stop until the shell continues the process
+                if msg.is::<SuspendMsg>() {
+                    self.release_terminal(writer, frame, modes);
+                    self.suspend();
+                    self.restore_terminal(writer, frame, modes);
+                    if tx.send(Message::new(ResumeMsg)).is_err() {
+                        debug!(target: "bubbletea::event", "resume message dropped — receiver disconnected");
                     }
                     needs_render = true;
                     continue;
                 }
 
                 // Update model
-                if let Some(cmd) = self.model.update(msg) {
+                latency.updating(&msg);
+                if let Some(cmd) = self.update_model(msg) {
                     self.handle_command(cmd, tx.clone());
                 }
                 needs_render = true;
             }
 
-            // Render if needed
-            if needs_render {
-                self.render(writer, &mut last_view)?;
+            // Deliver the final size once a resize burst has settled
+            if let Some(debouncer) = resize.as_mut() {
+                for msg in debouncer.poll(Instant::now()) {
+                    if let Some(size) = msg.downcast_ref::<WindowSizeMsg>() {
+                        frame.resize(*size);
+                    }
+                    if let Some(cmd) = self.update_model(msg) {
+                        self.handle_command(cmd, tx.clone());
+                    }
+                    needs_render = true;
+                }
+            }
+
+            // Render if needed, once the frame since the last render is over
+            if needs_render && last_render.elapsed() >= frame_duration {
+                self.render(writer, frame)?;
+                last_render = Instant::now();
+                latency.rendered(last_render);
+                needs_render = false;
             }
 
             // Sleep a bit if loop is tight (only needed if poll didn't sleep)
@@ -875,6 +1891,51 @@ impl<M: Model> Program<M> {
         }
     }
 
+    /// Returns the terminal to its normal state so an external process can
+    /// use it. Features are disabled in reverse order of enabling.
+    fn release_terminal<W: Write>(&self, writer: &mut W, frame: &mut Frame, modes: &InputModes) {
+        if self.options.custom_io {
+            return;
+        }
+        modes.disable(writer);
+        if self.options.mouse_all_motion || self.options.mouse_cell_motion {
+            let _ = execute!(writer, DisableMouseCapture);
+        }
+        let _ = execute!(writer, Show);
+        let _ = frame.finish(writer);
+        let _ = self.terminal.disable_raw_mode();
+    }
+
+    /// Stops the process until it is continued, unless the program runs
+    /// on custom I/O.
+    fn suspend(&self) {
+        if self.options.custom_io {
+            return;
+        }
+        if let Err(err) = self.terminal.suspend() {
+            debug!(target: "bubbletea::event", "suspend failed: {err}");
+        }
+    }
+
+    /// Re-enables the program's terminal features after
+    /// [`release_terminal`](Self::release_terminal) and forces a full
+    /// re-render.
+    fn restore_terminal<W: Write>(&self, writer: &mut W, frame: &mut Frame, modes: &InputModes) {
+        if self.options.custom_io {
+            return;
+        }
+        let _ = self.terminal.enable_raw_mode();
+        if frame.alt_screen {
+            let _ = execute!(writer, EnterAlternateScreen);
+        }
+        let _ = execute!(writer, Hide);
+        if self.options.mouse_all_motion || self.options.mouse_cell_motion {
+            let _ = execute!(writer, EnableMouseCapture);
+        }
+        let _ = modes.enable(writer);
+        frame.invalidate();
+    }
+
     fn handle_command(&self, cmd: Cmd, tx: Sender<Message>) {
         thread::spawn(move || {
             if let Some(msg) = cmd.execute() {
@@ -910,22 +1971,49 @@ impl<M: Model> Program<M> {
         });
     }
 
-    fn render<W: Write>(&self, writer: &mut W, last_view: &mut String) -> Result<()> {
-        let view = self.model.view();
+    /// Returns the protocol notifications are sent with, if any.
+    fn notification_protocol(&self) -> Option<NotificationProtocol> {
+        // With custom I/O, the environment doesn't describe the terminal
+        self.options.notifications.or_else(|| {
+            if self.options.custom_io {
+                None
+            } else {
+                NotificationProtocol::detect()
+            }
+        })
+    }
 
-        // Skip if view hasn't changed
-        if view == *last_view {
+    fn render<W: Write>(&self, writer: &mut W, frame: &mut Frame) -> Result<()> {
+        let Some(tracer) = &self.tracer else {
+            frame.draw(writer, self.model.view())?;
             return Ok(());
-        }
-
-        // Clear and render
-        execute!(writer, MoveTo(0, 0), Clear(ClearType::All))?;
-        write!(writer, "{}", view)?;
-        writer.flush()?;
-
-        *last_view = view;
+        };
+        let start = Instant::now();
+        let view = self.model.view();
+        let (lines, bytes) = (view.lines().count(), view.len());
+        frame.draw(writer, view)?;
+        tracer.record(TraceEvent::Render {
+            lines,
+            bytes,
+            elapsed: start.elapsed(),
+        });
         Ok(())
     }
+
+    /// Passes `msg` to the model's `update`, tracing how long it took.
+    fn update_model(&mut self, msg: Message) -> Option<Cmd> {
+        let Some(tracer) = &self.tracer else {
+            return self.model.update(msg);
+        };
+        let message = msg.type_name();
+        let start = Instant::now();
+        let cmd = self.model.update(msg);
+        tracer.record(TraceEvent::Update {
+            message,
+            elapsed: start.elapsed(),
+        });
+        cmd
+    }
 }
 
 // =============================================================================
@@ -965,15 +2053,17 @@ impl<M: Model> Program<M> {
 
     /// Run the program using the tokio async runtime with a custom writer.
     pub async fn run_async_with_writer<W: Write + Send + 'static>(
-        self,
+        mut self,
         mut writer: W,
     ) -> Result<M> {
         // Save options for cleanup (since self will be moved)
         let options = self.options.clone();
+        let terminal = Arc::clone(&self.terminal);
+        let quit_hooks = std::mem::take(&mut self.quit_hooks);
 
         // Setup terminal (skip for custom I/O)
         if !options.custom_io {
-            enable_raw_mode()?;
+            self.terminal.enable_raw_mode()?;
         }
 
         if options.alt_screen {
@@ -988,44 +2078,56 @@ impl<M: Model> Program<M> {
             execute!(writer, EnableMouseCapture)?;
         }
 
-        if options.report_focus {
-            execute!(writer, event::EnableFocusChange)?;
-        }
-
-        if options.bracketed_paste {
-            execute!(writer, event::EnableBracketedPaste)?;
-        }
+        let mut modes = InputModes::new(&options);
+        modes.enable(&mut writer)?;
 
         // Run the async event loop
-        let result = self.event_loop_async(&mut writer).await;
+        let mut window_title_set = false;
+        let mut frame = Frame::new(options.alt_screen);
+        frame.scroll_regions = options.scroll_regions;
+        let event_loop =
+            self.event_loop_async(&mut writer, &mut window_title_set, &mut frame, &mut modes);
+        let result = if options.without_catch_panics {
+            Ok(event_loop.await)
+        } else {
+            catch_panic_async(event_loop).await
+        };
+        let printed = match &result {
+            Ok(Ok(model)) => {
+                run_quit_hooks_async(quit_hooks, model, options.shutdown_timeout).await
+            }
+            _ => Vec::new(),
+        };
 
         // Cleanup terminal
-        if options.bracketed_paste {
-            let _ = execute!(writer, event::DisableBracketedPaste);
+        if window_title_set {
+            let _ = execute!(writer, terminal::SetTitle(""));
         }
 
-        if options.report_focus {
-            let _ = execute!(writer, event::DisableFocusChange);
-        }
+        modes.disable(&mut writer);
 
         if options.mouse_all_motion || options.mouse_cell_motion {
             let _ = execute!(writer, DisableMouseCapture);
         }
 
         let _ = execute!(writer, Show);
-
-        if options.alt_screen {
-            let _ = execute!(writer, LeaveAlternateScreen);
-        }
+        let _ = frame.finish(&mut writer);
+        print_lines(&mut writer, &printed);
 
         if !options.custom_io {
-            let _ = disable_raw_mode();
+            let _ = terminal.disable_raw_mode();
         }
 
-        result
+        result.unwrap_or_else(|payload| resume_panic(payload))
     }
 
-    async fn event_loop_async<W: Write>(mut self, stdout: &mut W) -> Result<M> {
+    async fn event_loop_async<W: Write>(
+        mut self,
+        stdout: &mut W,
+        window_title_set: &mut bool,
+        frame: &mut Frame,
+        modes: &mut InputModes,
+    ) -> Result<M> {
         // Create async message channel
         let (tx, mut rx) = tokio::sync::mpsc::channel::<Message>(256);
 
@@ -1078,10 +2180,11 @@ impl<M: Model> Program<M> {
                     match input.read(&mut buf) {
                         Ok(0) => break,
                         Ok(n) => {
+                            let read_at = Instant::now();
                             // We always assume there could be more data unless we hit EOF (Ok(0))
                             let can_have_more_data = true;
                             for msg in parser.push_bytes(&buf[..n], can_have_more_data) {
-                                if tx_clone.blocking_send(msg).is_err() {
+                                if tx_clone.blocking_send(msg.with_timestamp(read_at)).is_err() {
                                     return;
                                 }
                             }
@@ -1093,8 +2196,9 @@ impl<M: Model> Program<M> {
                     }
                 }
 
+                let read_at = Instant::now();
                 for msg in parser.flush() {
-                    if tx_clone.blocking_send(msg).is_err() {
+                    if tx_clone.blocking_send(msg.with_timestamp(read_at)).is_err() {
                         break;
                     }
                 }
@@ -1102,25 +2206,33 @@ impl<M: Model> Program<M> {
         }
 
         // Spawn event listener thread
-        let (event_tx, mut event_rx) = tokio::sync::mpsc::channel::<Event>(100);
+        let (event_tx, mut event_rx) = tokio::sync::mpsc::channel::<Message>(100);
         let event_cancel = cancel_token.clone();
+        let input_paused = Arc::new(AtomicBool::new(false));
+        let event_paused = Arc::clone(&input_paused);
 
         if !self.options.custom_io {
+            let terminal = Arc::clone(&self.terminal);
             std::thread::spawn(move || {
                 loop {
                     if event_cancel.is_cancelled() {
                         break;
                     }
+                    // Leave input to an external process while one runs
+                    if event_paused.load(Ordering::SeqCst) {
+                        std::thread::sleep(Duration::from_millis(20));
+                        continue;
+                    }
                     // Poll with timeout to check cancellation
-                    match event::poll(Duration::from_millis(100)) {
-                        Ok(true) => {
-                            if let Ok(evt) = event::read()
-                                && event_tx.blocking_send(evt).is_err()
-                            {
-                                break;
+                    match terminal.poll(Duration::from_millis(100)) {
+                        Ok(msgs) => {
+                            let read_at = Instant::now();
+                            for msg in msgs {
+                                if event_tx.blocking_send(msg.with_timestamp(read_at)).is_err() {
+                                    return;
+                                }
                             }
                         }
-                        Ok(false) => {} // timeout
                         Err(_) => {
                             break;
                         } // error
@@ -1131,7 +2243,7 @@ impl<M: Model> Program<M> {
 
         // Get initial window size
         if !self.options.custom_io {
-            let (width, height) = terminal::size()?;
+            let (width, height) = self.terminal.size()?;
             if tx
                 .send(Message::new(WindowSizeMsg { width, height }))
                 .await
@@ -1141,6 +2253,19 @@ impl<M: Model> Program<M> {
             }
         }
 
+        if let Some(profile) = self.options.color_profile
+            && tx
+                .send(Message::new(ColorProfileMsg(profile)))
+                .await
+                .is_err()
+        {
+            debug!(target: "bubbletea::event", "async color profile dropped — receiver disconnected");
+        }
+
+        if self.tracer.is_none() {
+            self.tracer = Tracer::from_env();
+        }
+
         // Call init and handle initial command
         if let Some(cmd) = self.model.init() {
             Self::handle_command_tracked(
@@ -1152,82 +2277,37 @@ impl<M: Model> Program<M> {
         }
 
         // Render initial view
-        let mut last_view = String::new();
-        self.render(stdout, &mut last_view)?;
+        self.render(stdout, frame)?;
 
         // Frame timing
         let frame_duration = Duration::from_secs_f64(1.0 / self.options.fps as f64);
         let mut frame_interval = tokio::time::interval(frame_duration);
+        let mut needs_render = false;
+        let mut resize = self.options.resize_debounce.map(ResizeDebouncer::new);
+        let mut latency = LatencyRecorder::new(self.options.latency_metrics);
 
         // Event loop
         loop {
             tokio::select! {
                 // Check for terminal events via channel
-                Some(event) = event_rx.recv(), if !self.options.custom_io => {
-                    match event {
-                        Event::Key(key_event) => {
-                            // Only handle key press events, not release
-                            if key_event.kind != KeyEventKind::Press {
-                                continue;
-                            }
-
-                            let key_msg = from_crossterm_key(key_event.code, key_event.modifiers);
-
-                            // Handle Ctrl+C specially
-                            if key_msg.key_type == crate::KeyType::CtrlC {
-                                if tx.send(Message::new(InterruptMsg)).await.is_err() {
-                                    debug!(target: "bubbletea::event", "async interrupt message dropped — receiver disconnected");
-                                }
-                            } else if tx.send(Message::new(key_msg)).await.is_err() {
-                                debug!(target: "bubbletea::event", "async key message dropped — receiver disconnected");
-                            }
-                        }
-                        Event::Mouse(mouse_event) => {
-                            let mouse_msg = from_crossterm_mouse(mouse_event);
-                            if tx.send(Message::new(mouse_msg)).await.is_err() {
-                                debug!(target: "bubbletea::event", "async mouse message dropped — receiver disconnected");
-                            }
-                        }
-                        Event::Resize(width, height) => {
-                            if tx.send(Message::new(WindowSizeMsg { width, height })).await.is_err() {
-                                debug!(target: "bubbletea::event", "async resize message dropped — receiver disconnected");
-                            }
-                        }
-                        Event::FocusGained => {
-                            if tx.send(Message::new(FocusMsg)).await.is_err() {
-                                debug!(target: "bubbletea::event", "async focus message dropped — receiver disconnected");
-                            }
-                        }
-                        Event::FocusLost => {
-                            if tx.send(Message::new(BlurMsg)).await.is_err() {
-                                debug!(target: "bubbletea::event", "async blur message dropped — receiver disconnected");
-                            }
-                        }
-                        Event::Paste(text) => {
-                            // Send as a key message with paste flag
-                            let key_msg = KeyMsg {
-                                key_type: crate::KeyType::Runes,
-                                runes: text.chars().collect(),
-                                alt: false,
-                                paste: true,
-                            };
-                            if tx.send(Message::new(key_msg)).await.is_err() {
-                                debug!(target: "bubbletea::event", "async paste message dropped — receiver disconnected");
-                            }
-                        }
+                Some(msg) = event_rx.recv(), if !self.options.custom_io => {
+                    if tx.send(msg).await.is_err() {
+                        debug!(target: "bubbletea::event", "async input message dropped — receiver disconnected");
                     }
                 }
 
                 // Process incoming messages
                 Some(msg) = rx.recv() => {
-                    // Check for quit message - initiate graceful shutdown
+                    // Check for quit message - draw the final view and shut down gracefully
                     if msg.is::<QuitMsg>() {
+                        self.render(stdout, frame)?;
                         Self::graceful_shutdown(&cancel_token, &task_tracker).await;
                         return Ok(self.model);
                     }
 
                     // Check for interrupt message (Ctrl+C) - initiate graceful shutdown
                     if msg.is::<InterruptMsg>() {
+                        self.render(stdout, frame)?;
                         Self::graceful_shutdown(&cancel_token, &task_tracker).await;
                         return Ok(self.model);
                     }
@@ -1240,13 +2320,46 @@ impl<M: Model> Program<M> {
                     // Handle window title
                     if let Some(title_msg) = msg.downcast_ref::<SetWindowTitleMsg>() {
                         execute!(stdout, terminal::SetTitle(&title_msg.0))?;
+                        *window_title_set = true;
+                        continue;
+                    }
+
+                    // Switch bracketed paste and focus reporting
+                    if modes.apply(stdout, &msg)? {
                         continue;
                     }
 
+                    // Switch between the alternate screen and inline rendering
+                    if msg.is::<EnterAltScreenMsg>() || msg.is::<ExitAltScreenMsg>() {
+                        frame.set_alt_screen(stdout, msg.is::<EnterAltScreenMsg>())?;
+                        needs_render = true;
+                        continue;
+                    }
+
+                    // Handle desktop notification
+                    if let Some(notify) = msg.downcast_ref::<NotifyMsg>() {
+                        if let Some(protocol) = self.notification_protocol() {
+                            write!(stdout, "{}", protocol.sequence(&notify.title, &notify.body))?;
+                            stdout.flush()?;
+                        }
+                        continue;
+                    }
+
+                    // Debounce window resizes
+                    if let Some(debouncer) = resize.as_mut()
+                        && let Some(size) = msg.downcast_ref::<WindowSizeMsg>()
+                        && !debouncer.push(*size, Instant::now())
+                    {
+                        continue;
+                    }
+                    if let Some(size) = msg.downcast_ref::<WindowSizeMsg>() {
+                        frame.resize(*size);
+                    }
+
                     // Handle window size request
                     if msg.is::<RequestWindowSizeMsg>() {
                         if !self.options.custom_io {
-                            let (width, height) = terminal::size()?;
+                            let (width, height) = self.terminal.size()?;
                             if tx.send(Message::new(WindowSizeMsg { width, height })).await.is_err() {
                                 debug!(target: "bubbletea::event", "async window size response dropped — receiver disconnected");
                             }
@@ -1254,72 +2367,71 @@ impl<M: Model> Program<M> {
                         continue;
                     }
 
+                    // Handle input latency request
+                    if msg.is::<RequestInputLatencyMsg>() {
+                        if tx.send(latency.report()).await.is_err() {
+                            debug!(target: "bubbletea::event", "async input latency response dropped — receiver disconnected");
+                        }
+                        continue;
+                    }
+
                     // Handle print line message (only when not in alt screen)
                     if let Some(print_msg) = msg.downcast_ref::<PrintLineMsg>() {
-                        if !self.options.alt_screen {
-                            // Print each line above the TUI
-                            for line in print_msg.0.lines() {
-                                let _ = writeln!(stdout, "{}", line);
-                            }
-                            let _ = stdout.flush();
-                            // Force a full re-render since we printed above
-                            last_view.clear();
+                        if !frame.alt_screen {
+                            let _ = frame.print_above(stdout, &print_msg.0);
                         }
-                        self.render(stdout, &mut last_view)?;
+                        needs_render = true;
                         continue;
                     }
 
                     // Handle release terminal
                     if msg.is::<ReleaseTerminalMsg>() {
-                        if !self.options.custom_io {
-                            // Disable features in reverse order
-                            if self.options.bracketed_paste {
-                                let _ = execute!(stdout, event::DisableBracketedPaste);
-                            }
-                            if self.options.report_focus {
-                                let _ = execute!(stdout, event::DisableFocusChange);
-                            }
-                            if self.options.mouse_all_motion || self.options.mouse_cell_motion {
-                                let _ = execute!(stdout, DisableMouseCapture);
-                            }
-                            let _ = execute!(stdout, Show);
-                            if self.options.alt_screen {
-                                let _ = execute!(stdout, LeaveAlternateScreen);
-                            }
-                            let _ = disable_raw_mode();
-                        }
+                        self.release_terminal(stdout, frame, modes);
                         continue;
                     }
 
                     // Handle restore terminal
                     if msg.is::<RestoreTerminalMsg>() {
-                        if !self.options.custom_io {
-                            // Re-enable features in original order
-                            let _ = enable_raw_mode();
-                            if self.options.alt_screen {
-                                let _ = execute!(stdout, EnterAlternateScreen);
-                            }
-                            let _ = execute!(stdout, Hide);
-                            if self.options.mouse_all_motion {
-                                let _ = execute!(stdout, EnableMouseCapture);
-                            } else if self.options.mouse_cell_motion {
-                                let _ = execute!(stdout, EnableMouseCapture);
-                            }
-                            if self.options.report_focus {
-                                let _ = execute!(stdout, event::EnableFocusChange);
-                            }
-                            if self.options.bracketed_paste {
-                                let _ = execute!(stdout, event::EnableBracketedPaste);
+                        self.restore_terminal(stdout, frame, modes);
+                        needs_render = true;
+                        continue;
+                    }
+
+                    // Run an external process with the terminal released,
+                    // pausing the event reader so it keeps all input.
+                    if msg.is::<ExecMsg>() {
+                        if let Some(exec) = msg.downcast::<ExecMsg>() {
+                            input_paused.store(true, Ordering::SeqCst);
+                            self.release_terminal(stdout, frame, modes);
+                            let result = exec.run();
+                            self.restore_terminal(stdout, frame, modes);
+                            input_paused.store(false, Ordering::SeqCst);
+                            if tx.send(result).await.is_err() {
+                                debug!(target: "bubbletea::command", "async exec result dropped — receiver disconnected");
                             }
-                            // Force a full re-render
-                            last_view.clear();
                         }
-                        self.render(stdout, &mut last_view)?;
+                        needs_render = true;
+                        continue;
+                    }
+
+                    // Stop until the shell continues the process, pausing
+                    // the event reader meanwhile
+                    if msg.is::<SuspendMsg>() {
+                        input_paused.store(true, Ordering::SeqCst);
+                        self.release_terminal(stdout, frame, modes);
+                        self.suspend();
+                        self.restore_terminal(stdout, frame, modes);
+                        input_paused.store(false, Ordering::SeqCst);
+                        if tx.send(Message::new(ResumeMsg)).await.is_err() {
+                            debug!(target: "bubbletea::event", "async resume message dropped — receiver disconnected");
+                        }
+                        needs_render = true;
                         continue;
                     }
 
                     // Update model
-                    if let Some(cmd) = self.model.update(msg) {
+                    latency.updating(&msg);
+                    if let Some(cmd) = self.update_model(msg) {
                         Self::handle_command_tracked(
                             cmd.into(),
                             tx.clone(),
@@ -1328,13 +2440,38 @@ impl<M: Model> Program<M> {
                         );
                     }
 
-                    // Render after processing message
-                    self.render(stdout, &mut last_view)?;
+                    needs_render = true;
                 }
 
                 // Frame tick for rendering
                 _ = frame_interval.tick() => {
-                    // Periodic render check (in case we missed something)
+                    // Deliver the final size once a resize burst has settled
+                    if let Some(debouncer) = resize.as_mut() {
+                        let msgs = debouncer.poll(Instant::now());
+                        if !msgs.is_empty() {
+                            for msg in msgs {
+                                if let Some(size) = msg.downcast_ref::<WindowSizeMsg>() {
+                                    frame.resize(*size);
+                                }
+                                if let Some(cmd) = self.update_model(msg) {
+                                    Self::handle_command_tracked(
+                                        cmd.into(),
+                                        tx.clone(),
+                                        &task_tracker,
+                                        cancel_token.clone(),
+                                    );
+                                }
+                            }
+                            needs_render = true;
+                        }
+                    }
+
+                    // Draw the updates of the last frame at once
+                    if needs_render {
+                        self.render(stdout, frame)?;
+                        latency.rendered(Instant::now());
+                        needs_render = false;
+                    }
                 }
             }
         }
@@ -1465,16 +2602,66 @@ impl<M: Model> Program<M> {
 // Custom Input Parsing (for custom I/O mode)
 // =============================================================================
 
-struct InputParser {
-    buffer: Vec<u8>,
+/// Longest escape sequence held back while waiting for more bytes.
+///
+/// A sequence that grows past this without terminating is treated as
+/// garbage and discarded, so a broken stream cannot stall input forever.
+const MAX_SEQUENCE_LEN: usize = 64;
+
+/// Incremental parser turning raw terminal input bytes into messages.
+///
+/// This is the parser used for custom input streams (see
+/// [`Program::with_input`]), exposed for hosts such as SSH servers that read
+/// terminal input themselves.
+///
+/// Input may arrive in arbitrary chunks: incomplete escape sequences and
+/// UTF-8 characters are held back until the next call. Malformed input never
+/// panics. Unknown or corrupted CSI sequences are discarded as a whole rather
+/// than being replayed as typed text, and the parser resynchronizes at the
+/// next byte that cannot belong to the broken sequence.
+///
+/// # Example
+///
+/// ```rust
+/// use bubbletea::{InputParser, KeyMsg, KeyType};
+///
+/// let mut parser = InputParser::new();
+/// // The arrow key sequence is split across two reads.
+/// assert!(parser.push_bytes(b"\x1b[", true).is_empty());
+/// let msgs = parser.push_bytes(b"A", true);
+/// let key = msgs[0].downcast_ref::<KeyMsg>().unwrap();
+/// assert_eq!(key.key_type, KeyType::Up);
+///
+/// // A corrupted sequence is dropped instead of being typed as "99;99q".
+/// assert!(parser.push_bytes(b"\x1b[99;99q", true).is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct InputParser {
+    buffer: Vec<u8>,
 }
 
 impl InputParser {
-    fn new() -> Self {
-        Self { buffer: Vec::new() }
+    /// Creates an empty parser.
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    fn push_bytes(&mut self, bytes: &[u8], can_have_more_data: bool) -> Vec<Message> {
+    /// Returns whether bytes are buffered waiting for the rest of a
+    /// sequence.
+    ///
+    /// Callers reading from a live stream can use this to [`flush`] after a
+    /// short timeout, so a lone Escape key press is delivered.
+    ///
+    /// [`flush`]: Self::flush
+    pub fn has_pending(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+
+    /// Parses `bytes` appended to any previously buffered input.
+    ///
+    /// Set `can_have_more_data` when more input may follow, so incomplete
+    /// sequences are held back instead of being parsed as individual keys.
+    pub fn push_bytes(&mut self, bytes: &[u8], can_have_more_data: bool) -> Vec<Message> {
         if !bytes.is_empty() {
             self.buffer.extend_from_slice(bytes);
         }
@@ -1499,7 +2686,9 @@ impl InputParser {
         messages
     }
 
-    fn flush(&mut self) -> Vec<Message> {
+    /// Parses everything still buffered, treating incomplete sequences as
+    /// complete input.
+    pub fn flush(&mut self) -> Vec<Message> {
         let mut messages = Vec::new();
         loop {
             if self.buffer.is_empty() {
@@ -1542,10 +2731,18 @@ fn parse_one_message(buf: &[u8], can_have_more_data: bool) -> ParseOutcome {
         return outcome;
     }
 
+    if let Some(outcome) = parse_kitty_key(buf, can_have_more_data) {
+        return outcome;
+    }
+
     if let Some(outcome) = parse_key_sequence(buf, can_have_more_data) {
         return outcome;
     }
 
+    if let Some(outcome) = parse_unknown_csi(buf, can_have_more_data) {
+        return outcome;
+    }
+
     parse_runes_or_control(buf, can_have_more_data)
 }
 
@@ -1558,26 +2755,37 @@ fn parse_mouse_event(buf: &[u8], can_have_more_data: bool) -> Option<ParseOutcom
                 ParseOutcome::Parsed(1, Some(replacement_message()))
             });
         }
+        // X10 events have a fixed length, so a bad event is skipped whole.
         let seq = &buf[..6];
         return Some(match crate::mouse::parse_mouse_event_sequence(seq) {
             Ok(msg) => ParseOutcome::Parsed(6, Some(Message::new(msg))),
-            Err(_) => ParseOutcome::Parsed(1, Some(replacement_message())),
+            Err(_) => discard(seq.len(), buf),
         });
     }
 
     if buf.starts_with(b"\x1b[<") {
-        if let Some(end_idx) = buf.iter().position(|b| *b == b'M' || *b == b'm') {
-            let seq = &buf[..=end_idx];
-            return Some(match crate::mouse::parse_mouse_event_sequence(seq) {
-                Ok(msg) => ParseOutcome::Parsed(seq.len(), Some(Message::new(msg))),
-                Err(_) => ParseOutcome::Parsed(1, Some(replacement_message())),
-            });
-        }
-        return Some(if can_have_more_data {
-            ParseOutcome::NeedMore
-        } else {
-            ParseOutcome::Parsed(1, Some(replacement_message()))
-        });
+        const PREFIX: usize = 3;
+        let body = &buf[PREFIX..];
+        return Some(
+            match body
+                .iter()
+                .position(|b| !(b.is_ascii_digit() || *b == b';'))
+            {
+                Some(idx) if matches!(body[idx], b'M' | b'm') => {
+                    let seq = &buf[..PREFIX + idx + 1];
+                    match crate::mouse::parse_mouse_event_sequence(seq) {
+                        Ok(msg) => ParseOutcome::Parsed(seq.len(), Some(Message::new(msg))),
+                        Err(_) => discard(seq.len(), buf),
+                    }
+                }
+                // Resynchronize at the byte that broke the sequence.
+                Some(idx) => discard(PREFIX + idx, buf),
+                None if can_have_more_data && buf.len() < MAX_SEQUENCE_LEN => {
+                    ParseOutcome::NeedMore
+                }
+                None => discard(buf.len(), buf),
+            },
+        );
     }
 
     None
@@ -1627,6 +2835,58 @@ fn parse_bracketed_paste(buf: &[u8], can_have_more_data: bool) -> Option<ParseOu
     })
 }
 
+/// Parses a kitty keyboard protocol key report,
+/// `ESC [ code[:alternates] ; modifiers[:event] [; text] u`.
+///
+/// Releases are dropped, as crossterm does, and reports of keys that have
+/// no [`KeyMsg`] are discarded.
+fn parse_kitty_key(buf: &[u8], can_have_more_data: bool) -> Option<ParseOutcome> {
+    const PREFIX: usize = 2;
+    if !buf.starts_with(b"\x1b[") {
+        return None;
+    }
+    let body = &buf[PREFIX..];
+    let end = body
+        .iter()
+        .position(|b| !(b.is_ascii_digit() || *b == b';' || *b == b':'));
+    let Some(end) = end else {
+        return (can_have_more_data && !body.is_empty() && buf.len() < MAX_SEQUENCE_LEN)
+            .then_some(ParseOutcome::NeedMore);
+    };
+    if body[end] != b'u' || end == 0 {
+        return None;
+    }
+
+    let len = PREFIX + end + 1;
+    // The body is ASCII digits and separators, so this can't fail.
+    let params = std::str::from_utf8(&body[..end]).ok()?;
+    let mut params = params.split(';');
+    let number = |field: Option<&str>, default: u32| {
+        field
+            .filter(|f| !f.is_empty())
+            .map_or(Some(default), |f| f.parse().ok())
+    };
+    let mut key = params.next().unwrap_or_default().split(':');
+    let mut modifiers = params.next().unwrap_or_default().split(':');
+    let (Some(code), Some(mods), Some(event)) = (
+        number(key.next(), 0),
+        number(modifiers.next(), 1),
+        number(modifiers.next(), 1),
+    ) else {
+        return Some(discard(len, buf));
+    };
+
+    const RELEASE: u32 = 3;
+    if event == RELEASE {
+        return Some(ParseOutcome::Parsed(len, None));
+    }
+    let bits = u8::try_from(mods.saturating_sub(1)).unwrap_or(u8::MAX);
+    Some(match crate::key::from_kitty_key(code, bits) {
+        Some(key) => ParseOutcome::Parsed(len, Some(message_from_key(key))),
+        None => discard(len, buf),
+    })
+}
+
 fn parse_key_sequence(buf: &[u8], can_have_more_data: bool) -> Option<ParseOutcome> {
     if let Some((key, len)) = crate::key::parse_sequence_prefix(buf) {
         return Some(ParseOutcome::Parsed(len, Some(message_from_key(key))));
@@ -1649,6 +2909,46 @@ fn parse_key_sequence(buf: &[u8], can_have_more_data: bool) -> Option<ParseOutco
     None
 }
 
+/// Consumes a CSI sequence (`ESC [ params intermediates final`) that no
+/// other parser recognized.
+///
+/// Without this, the bytes after `ESC [` would be replayed as typed text, so
+/// a corrupted sequence such as `ESC [ 9 9 ; q` could trigger key bindings.
+/// A lone `ESC [` at the end of input is left for [`parse_runes_or_control`],
+/// which reports it as Alt+[.
+fn parse_unknown_csi(buf: &[u8], can_have_more_data: bool) -> Option<ParseOutcome> {
+    const PREFIX: usize = 2;
+    if !buf.starts_with(b"\x1b[") || (buf.len() == PREFIX && !can_have_more_data) {
+        return None;
+    }
+
+    let mut idx = PREFIX;
+    while idx < buf.len() && (0x30..=0x3F).contains(&buf[idx]) {
+        idx += 1;
+    }
+    while idx < buf.len() && (0x20..=0x2F).contains(&buf[idx]) {
+        idx += 1;
+    }
+
+    Some(match buf.get(idx) {
+        Some(0x40..=0x7E) => discard(idx + 1, buf),
+        // Not a valid CSI byte: drop what we have and resynchronize here.
+        Some(_) => discard(idx, buf),
+        None if can_have_more_data && buf.len() < MAX_SEQUENCE_LEN => ParseOutcome::NeedMore,
+        None => discard(buf.len(), buf),
+    })
+}
+
+/// Drops the first `len` bytes of `buf` as an unusable escape sequence.
+fn discard(len: usize, buf: &[u8]) -> ParseOutcome {
+    debug!(
+        target: "bubbletea::input",
+        sequence = ?String::from_utf8_lossy(&buf[..len]),
+        "discarding unrecognized escape sequence"
+    );
+    ParseOutcome::Parsed(len, None)
+}
+
 fn parse_runes_or_control(buf: &[u8], can_have_more_data: bool) -> ParseOutcome {
     let mut alt = false;
     let mut idx = 0;
@@ -1867,6 +3167,637 @@ mod tests {
         assert_eq!(program.options.fps, 1); // Clamped to minimum of 1 to avoid division by zero
     }
 
+    // === Resize Debouncing Tests ===
+
+    fn size(width: u16, height: u16) -> WindowSizeMsg {
+        WindowSizeMsg { width, height }
+    }
+
+    #[test]
+    fn test_resize_debouncer_delivers_first_and_final_size() {
+        let start = Instant::now();
+        let ms = |n| start + Duration::from_millis(n);
+        let mut debouncer = ResizeDebouncer::new(Duration::from_millis(50));
+
+        assert!(debouncer.push(size(80, 24), ms(0)));
+        assert!(!debouncer.push(size(90, 24), ms(10)));
+        assert!(!debouncer.push(size(100, 30), ms(20)));
+        assert!(debouncer.poll(ms(60)).is_empty());
+
+        let msgs = debouncer.poll(ms(70));
+        assert_eq!(msgs.len(), 2);
+        assert_eq!(
+            msgs[0].downcast_ref::<WindowSizeMsg>(),
+            Some(&size(100, 30))
+        );
+        assert_eq!(
+            msgs[1].downcast_ref::<ResizeSettledMsg>(),
+            Some(&ResizeSettledMsg {
+                width: 100,
+                height: 30
+            })
+        );
+        assert!(debouncer.poll(ms(200)).is_empty());
+
+        // A new burst delivers its first size immediately again.
+        assert!(debouncer.push(size(120, 40), ms(300)));
+    }
+
+    #[test]
+    fn test_resize_debouncer_skips_unchanged_final_size() {
+        let start = Instant::now();
+        let mut debouncer = ResizeDebouncer::new(Duration::from_millis(50));
+
+        assert!(debouncer.push(size(80, 24), start));
+        assert!(!debouncer.push(size(90, 24), start + Duration::from_millis(5)));
+        assert!(!debouncer.push(size(80, 24), start + Duration::from_millis(10)));
+
+        let msgs = debouncer.poll(start + Duration::from_millis(100));
+        assert_eq!(msgs.len(), 1);
+        assert!(msgs[0].is::<ResizeSettledMsg>());
+    }
+
+    /// Records the sizes and settle notifications it receives.
+    #[derive(Default)]
+    struct ResizeModel {
+        sizes: Vec<(u16, u16)>,
+        settled: Vec<(u16, u16)>,
+    }
+
+    impl Model for ResizeModel {
+        fn init(&self) -> Option<Cmd> {
+            None
+        }
+
+        fn update(&mut self, msg: Message) -> Option<Cmd> {
+            if let Some(msg) = msg.downcast_ref::<WindowSizeMsg>() {
+                self.sizes.push((msg.width, msg.height));
+            } else if let Some(msg) = msg.downcast_ref::<ResizeSettledMsg>() {
+                self.settled.push((msg.width, msg.height));
+            }
+            None
+        }
+
+        fn view(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn test_program_debounces_resize_storm() {
+        let handle = Program::new(ResizeModel::default())
+            .with_output(io::sink())
+            .with_resize_debounce(Duration::from_millis(100))
+            .start();
+        for width in 80..100 {
+            handle.send(Message::new(size(width, 24)));
+        }
+        thread::sleep(Duration::from_millis(400));
+        handle.quit();
+
+        let model = handle.wait().expect("program should complete");
+        assert_eq!(model.sizes, vec![(80, 24), (99, 24)]);
+        assert_eq!(model.settled, vec![(99, 24)]);
+    }
+
+    #[test]
+    fn test_program_traces_updates_and_renders() {
+        let tracer = Tracer::ring(64);
+        let handle = Program::new(ResizeModel::default())
+            .with_output(io::sink())
+            .with_tracer(tracer.clone())
+            .start();
+        handle.send(Message::new(size(80, 24)));
+        thread::sleep(Duration::from_millis(100));
+        handle.quit();
+        handle.wait().expect("program should complete");
+
+        let events = tracer.events();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            TraceEvent::Update { message, .. } if message.ends_with("WindowSizeMsg")
+        )));
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, TraceEvent::Render { .. }))
+        );
+    }
+
+    #[test]
+    fn test_program_runs_on_web_terminal() {
+        let terminal = crate::WebTerminal::new(80, 24);
+        let output = terminal.output();
+        let program_terminal = terminal.clone();
+        let tracer = Tracer::ring(64);
+        let program_tracer = tracer.clone();
+        let program = thread::spawn(move || {
+            Program::new(ResizeModel::default())
+                .with_terminal(program_terminal)
+                .with_tracer(program_tracer)
+                .run_with_writer(output)
+        });
+        let updates = || {
+            tracer
+                .events()
+                .iter()
+                .filter(|event| matches!(event, TraceEvent::Update { .. }))
+                .count()
+        };
+
+        // The initial size is read before it is delivered, so resizing
+        // after that can't race with it
+        wait_until(|| updates() == 1);
+        assert!(terminal.is_raw_mode());
+        terminal.resize(120, 40);
+        wait_until(|| updates() == 2);
+        // Ctrl+C interrupts the program
+        terminal.write_input(b"\x03");
+
+        let model = program.join().unwrap().expect("program should complete");
+        assert_eq!(model.sizes, vec![(80, 24), (120, 40)]);
+        assert!(!terminal.is_raw_mode());
+        let output = String::from_utf8(terminal.take_output()).unwrap();
+        assert!(output.contains("\x1b[?25l"));
+    }
+
+    #[test]
+    fn test_program_runs_quit_hooks_before_restoring_terminal() {
+        let terminal = crate::WebTerminal::new(80, 24);
+        let output = terminal.output();
+        let program_terminal = terminal.clone();
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let (first, second) = (Arc::clone(&order), Arc::clone(&order));
+        let program = thread::spawn(move || {
+            Program::new(ResizeModel::default())
+                .with_terminal(program_terminal)
+                .with_alt_screen()
+                .with_shutdown_timeout(Duration::from_millis(200))
+                .on_quit(move |model: &ResizeModel| {
+                    first.lock().unwrap().push(1);
+                    Some(crate::println(format!("{} sizes", model.sizes.len())))
+                })
+                .on_quit(move |_: &ResizeModel| {
+                    second.lock().unwrap().push(2);
+                    // Overruns the shutdown timeout, so is abandoned
+                    Some(Cmd::new_optional(|| {
+                        thread::sleep(Duration::from_secs(10));
+                        crate::println("too late").execute()
+                    }))
+                })
+                .run_with_writer(output)
+        });
+
+        wait_until(|| terminal.is_raw_mode());
+        terminal.write_input(b"\x03");
+        let started = Instant::now();
+        program.join().unwrap().expect("program should complete");
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(*order.lock().unwrap(), [1, 2]);
+
+        // The summary follows the alternate screen, before raw mode ends
+        let output = String::from_utf8(terminal.take_output()).unwrap();
+        assert!(output.ends_with("\x1b[?1049l1 sizes\r\n"), "{output:?}");
+        assert!(!terminal.is_raw_mode());
+    }
+
+    /// Waits for `condition`, failing the test if it doesn't hold within a
+    /// few seconds.
+    fn wait_until(mut condition: impl FnMut() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !condition() {
+            assert!(Instant::now() < deadline, "timed out waiting for program");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    /// Panics on the first key press.
+    #[derive(Debug)]
+    struct PanicModel;
+
+    impl Model for PanicModel {
+        fn init(&self) -> Option<Cmd> {
+            None
+        }
+
+        fn update(&mut self, msg: Message) -> Option<Cmd> {
+            if msg.is::<KeyMsg>() {
+                panic!("update panicked");
+            }
+            None
+        }
+
+        fn view(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn test_program_restores_terminal_on_panic() {
+        let terminal = crate::WebTerminal::new(80, 24);
+        let output = terminal.output();
+        let program_terminal = terminal.clone();
+        let program = thread::spawn(move || {
+            Program::new(PanicModel)
+                .with_terminal(program_terminal)
+                .with_alt_screen()
+                .run_with_writer(output)
+        });
+
+        wait_until(|| terminal.is_raw_mode());
+        terminal.write_input(b"x");
+
+        // The panic carries on past the program once the terminal is restored
+        let payload = program.join().expect_err("program should panic");
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"update panicked"));
+        assert!(!terminal.is_raw_mode());
+        let output = String::from_utf8(terminal.take_output()).unwrap();
+        assert!(output.ends_with("\x1b[?25h\x1b[?1049l"), "{output:?}");
+    }
+
+    struct ExecDoneMsg;
+
+    /// Runs an exec command on start and quits once its result arrives.
+    #[derive(Default)]
+    struct ExecModel {
+        done: bool,
+    }
+
+    impl Model for ExecModel {
+        fn init(&self) -> Option<Cmd> {
+            Some(crate::screen::exec(|| Message::new(ExecDoneMsg)))
+        }
+
+        fn update(&mut self, msg: Message) -> Option<Cmd> {
+            if msg.is::<ExecDoneMsg>() {
+                self.done = true;
+                return Some(crate::quit());
+            }
+            None
+        }
+
+        fn view(&self) -> String {
+            String::new()
+        }
+    }
+
+    /// Suspends on start and quits once resumed.
+    #[derive(Default)]
+    struct SuspendModel {
+        resumed: bool,
+    }
+
+    impl Model for SuspendModel {
+        fn init(&self) -> Option<Cmd> {
+            Some(crate::suspend())
+        }
+
+        fn update(&mut self, msg: Message) -> Option<Cmd> {
+            if msg.is::<ResumeMsg>() {
+                self.resumed = true;
+                return Some(crate::quit());
+            }
+            None
+        }
+
+        fn view(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn test_program_resumes_after_suspend_with_custom_io() {
+        let handle = Program::new(SuspendModel::default())
+            .with_output(io::sink())
+            .start();
+        let model = handle.wait().expect("program should complete");
+        assert!(model.resumed);
+    }
+
+    #[test]
+    fn test_program_delivers_exec_result() {
+        let handle = Program::new(ExecModel::default())
+            .with_output(io::sink())
+            .start();
+        let model = handle.wait().expect("program should complete");
+        assert!(model.done);
+    }
+
+    /// Records the color profile it is given, then quits.
+    #[derive(Default)]
+    struct ProfileModel {
+        profile: Option<ColorProfile>,
+    }
+
+    impl Model for ProfileModel {
+        fn init(&self) -> Option<Cmd> {
+            None
+        }
+
+        fn update(&mut self, msg: Message) -> Option<Cmd> {
+            let ColorProfileMsg(profile) = msg.downcast::<ColorProfileMsg>()?;
+            self.profile = Some(profile);
+            Some(crate::quit())
+        }
+
+        fn view(&self) -> String {
+            String::new()
+        }
+    }
+
+    /// Requests the input latencies on `q` and quits once they arrive.
+    #[derive(Default)]
+    struct LatencyModel {
+        latency: Option<LatencyHistogram>,
+    }
+
+    impl Model for LatencyModel {
+        fn init(&self) -> Option<Cmd> {
+            None
+        }
+
+        fn update(&mut self, msg: Message) -> Option<Cmd> {
+            if let Some(InputLatencyMsg(histogram)) = msg.downcast_ref::<InputLatencyMsg>() {
+                self.latency = Some(histogram.clone());
+                return Some(crate::quit());
+            }
+            let key = msg.downcast::<KeyMsg>()?;
+            (key.runes == ['q']).then(crate::input_latency)
+        }
+
+        fn view(&self) -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn test_program_records_input_latency() {
+        let handle = Program::new(LatencyModel::default())
+            .with_input(&b"a\x1b[Aq"[..])
+            .with_output(io::sink())
+            .with_latency_metrics()
+            .start();
+        let model = handle.wait().expect("program should complete");
+        let latency = model.latency.expect("latency should be reported");
+        assert_eq!(latency.count(), 3);
+        assert!(latency.max().unwrap() >= latency.mean().unwrap());
+    }
+
+    #[test]
+    fn test_program_latency_metrics_off_by_default() {
+        let handle = Program::new(LatencyModel::default())
+            .with_input(&b"a\x1b[Aq"[..])
+            .with_output(io::sink())
+            .start();
+        let model = handle.wait().expect("program should complete");
+        assert!(
+            model
+                .latency
+                .expect("latency should be reported")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_program_sends_color_profile_override() {
+        let options = ProgramOptions {
+            color_profile: Some(ColorProfile::Ansi256),
+            fps: 500,
+            ..ProgramOptions::default()
+        };
+        let program = Program::new(ProfileModel::default())
+            .with_output(io::sink())
+            .with_options(options);
+        assert!(program.options.custom_io);
+        assert_eq!(program.options.fps, 120);
+
+        let model = program.start().wait().expect("program should complete");
+        assert_eq!(model.profile, Some(ColorProfile::Ansi256));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_program_options_from_toml() {
+        let options: ProgramOptions = toml::from_str(
+            r#"
+            alt_screen = true
+            fps = 30
+            report_focus = true
+            color_profile = "truecolor"
+            "#,
+        )
+        .unwrap();
+        assert!(options.alt_screen);
+        assert!(options.report_focus);
+        assert_eq!(options.fps, 30);
+        assert_eq!(options.color_profile, Some(ColorProfile::TrueColor));
+        // Unset keys keep their defaults
+        assert!(options.bracketed_paste);
+        assert!(!options.mouse_all_motion);
+    }
+
+    // === Frame Tests ===
+
+    fn draw(frame: &mut Frame, view: &str) -> String {
+        let mut out = Vec::new();
+        frame.draw(&mut out, view.to_string()).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_inline_frame_replaces_last_rows() {
+        let mut frame = Frame::new(false);
+        assert_eq!(draw(&mut frame, "a\nb\nc"), "a\r\nb\r\nc");
+        assert_eq!(frame.rows, 3);
+        assert_eq!(draw(&mut frame, "a\nb\nc"), "");
+        assert_eq!(
+            draw(&mut frame, "x"),
+            "\x1b[2A\x1b[1Gx\x1b[K\x1b[1B\x1b[1G\x1b[J\x1b[1A"
+        );
+        assert_eq!(frame.rows, 1);
+
+        let mut out = Vec::new();
+        frame.print_above(&mut out, "log").unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "\x1b[1G\x1b[Jlog\r\n");
+        assert_eq!(draw(&mut frame, "x"), "x");
+
+        let mut out = Vec::new();
+        frame.finish(&mut out).unwrap();
+        assert_eq!(out, b"\r\n");
+    }
+
+    #[test]
+    fn test_inline_frame_writes_only_changed_lines() {
+        let mut frame = Frame::new(false);
+        frame.resize(size(4, 24));
+        draw(&mut frame, "a\nb\nc");
+
+        assert_eq!(draw(&mut frame, "a\nB\nc"), "\x1b[1A\x1b[1GB\x1b[K\x1b[1B");
+        assert_eq!(draw(&mut frame, "a\nB\nc\nd"), "\r\n\x1b[1Gd\x1b[K");
+        assert_eq!(frame.rows, 4);
+
+        // A line as wide as the terminal isn't followed by a clear
+        assert_eq!(
+            draw(&mut frame, "abcd\nB\nc\nd"),
+            "\x1b[3A\x1b[1Gabcd\x1b[3B"
+        );
+
+        // Wrapping lines are drawn in full
+        assert_eq!(draw(&mut frame, "abcdef"), "\x1b[3A\x1b[1G\x1b[Jabcdef");
+        assert_eq!(frame.rows, 2);
+    }
+
+    #[test]
+    fn test_alt_screen_frame_writes_only_changed_lines() {
+        let mut frame = Frame::new(true);
+        frame.resize(size(10, 3));
+        draw(&mut frame, "a\nb\nc");
+
+        assert_eq!(draw(&mut frame, "a\nX"), "\x1b[2;1HX\x1b[K\x1b[3;1H\x1b[J");
+
+        // Frames taller than the screen scroll it, so are drawn in full
+        assert_eq!(
+            draw(&mut frame, "1\n2\n3\n4"),
+            "\x1b[1;1H\x1b[2J1\r\n2\r\n3\r\n4"
+        );
+        assert!(draw(&mut frame, "1\n2").starts_with("\x1b[1;1H\x1b[2J"));
+    }
+
+    #[test]
+    fn test_alt_screen_frame_scrolls_moved_lines() {
+        let mut frame = Frame::new(true);
+        frame.scroll_regions = true;
+        frame.resize(size(10, 6));
+        draw(&mut frame, "head\n1\n2\n3\n4\n0%");
+
+        // The body scrolls up a line and only the new last line and footer
+        // are written
+        assert_eq!(
+            draw(&mut frame, "head\n2\n3\n4\n5\n50%"),
+            "\x1b[2;5r\x1b[1S\x1b[r\x1b[5;1H5\x1b[K\x1b[6;1H50%\x1b[K"
+        );
+        assert_eq!(
+            draw(&mut frame, "head\n1\n2\n3\n4\n0%"),
+            "\x1b[2;5r\x1b[1T\x1b[r\x1b[2;1H1\x1b[K\x1b[6;1H0%\x1b[K"
+        );
+
+        // Lines that didn't move are written as before
+        assert_eq!(draw(&mut frame, "head\n1\nX\n3\n4\n0%"), "\x1b[3;1HX\x1b[K");
+        assert_eq!(Scroll::find(&["a", "b"], &["b", "c"]), None);
+        assert_eq!(
+            Scroll::find(&["a", "b", "c", "d", "e"], &["c", "d", "e", "f", "g"]),
+            Some(Scroll {
+                top: 0,
+                bottom: 4,
+                by: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_inline_frame_counts_wrapped_rows() {
+        assert_eq!(visible_width("\x1b[1mab\x1b[0m日\x1b]8;;x\x07"), 4);
+
+        let mut frame = Frame::new(false);
+        frame.resize(size(8, 24));
+        draw(&mut frame, "abcdefgh\nij");
+        assert_eq!(frame.rows, 2);
+
+        // A narrower terminal rewraps the frame, which is then drawn again
+        frame.resize(size(4, 24));
+        assert_eq!(frame.rows, 3);
+        assert_eq!(
+            draw(&mut frame, "abcdefgh\nij"),
+            "\x1b[2A\x1b[1G\x1b[Jabcdefgh\r\nij"
+        );
+
+        // Only the rows that fit in the terminal are drawn
+        frame.resize(size(4, 2));
+        draw(&mut frame, "a\nb\nc");
+        assert_eq!(frame.rows, 2);
+        assert!(frame.clip("a\nb\nc").ends_with("b\nc"));
+    }
+
+    #[test]
+    fn test_frame_switches_to_alt_screen_and_back() {
+        let mut frame = Frame::new(false);
+        draw(&mut frame, "inline");
+
+        let mut out = Vec::new();
+        frame.set_alt_screen(&mut out, true).unwrap();
+        assert_eq!(out, b"\x1b[?1049h");
+        assert_eq!(draw(&mut frame, "a\nb"), "\x1b[1;1H\x1b[2Ja\r\nb");
+
+        // The inline frame is still below the restored cursor
+        let mut out = Vec::new();
+        frame.set_alt_screen(&mut out, false).unwrap();
+        assert_eq!(out, b"\x1b[?1049l");
+        assert_eq!(draw(&mut frame, "inline"), "\x1b[1G\x1b[Jinline");
+
+        let mut frame = Frame::new(true);
+        let mut out = Vec::new();
+        frame.finish(&mut out).unwrap();
+        assert_eq!(out, b"\x1b[?1049l");
+    }
+
+    #[test]
+    fn test_input_modes_switch_at_runtime() {
+        let mut modes = InputModes::new(&ProgramOptions::default());
+        let mut out = Vec::new();
+
+        let msg = Message::new(EnableReportFocusMsg);
+        assert!(modes.apply(&mut out, &msg).unwrap());
+        assert_eq!(out, b"\x1b[?1004h");
+        assert!(modes.report_focus);
+
+        // Already enabled, so nothing is written
+        out.clear();
+        let msg = Message::new(EnableBracketedPasteMsg);
+        assert!(modes.apply(&mut out, &msg).unwrap());
+        assert!(out.is_empty());
+
+        let msg = Message::new(DisableBracketedPasteMsg);
+        assert!(modes.apply(&mut out, &msg).unwrap());
+        assert_eq!(out, b"\x1b[?2004l");
+        assert!(!modes.bracketed_paste);
+
+        // Cleanup turns off only the modes still active
+        out.clear();
+        modes.disable(&mut out);
+        assert_eq!(out, b"\x1b[?1004l");
+
+        assert!(!modes.apply(&mut out, &Message::new(QuitMsg)).unwrap());
+    }
+
+    #[test]
+    fn test_parse_kitty_keys() {
+        let mut parser = InputParser::new();
+        let msgs = parser.push_bytes(b"\x1b[13;2u\x1b[105;5u\x1b[97;5:3u\x1b[27u", false);
+        let keys: Vec<String> = msgs
+            .iter()
+            .map(|msg| msg.downcast_ref::<KeyMsg>().unwrap().to_string())
+            .collect();
+        // The release of ctrl+a is dropped
+        assert_eq!(keys, ["shift+enter", "ctrl+i", "esc"]);
+    }
+
+    #[test]
+    fn test_parse_kitty_key_waits_for_rest() {
+        assert!(matches!(
+            parse_kitty_key(b"\x1b[13;2", true),
+            Some(ParseOutcome::NeedMore)
+        ));
+        assert!(parse_kitty_key(b"\x1b[1;5A", true).is_none());
+        assert!(matches!(
+            parse_kitty_key(b"\x1b[99;5u", false),
+            Some(ParseOutcome::Parsed(7, Some(_)))
+        ));
+        // Ctrl+C is still an interrupt
+        let mut parser = InputParser::new();
+        let msgs = parser.push_bytes(b"\x1b[99;5u", false);
+        assert!(msgs[0].is::<InterruptMsg>());
+    }
+
     // === Bracketed Paste Parsing Tests ===
 
     #[test]
@@ -1991,6 +3922,67 @@ mod tests {
         }
     }
 
+    // === Malformed Input Resilience Tests ===
+
+    fn keys(msgs: &[Message]) -> Vec<String> {
+        msgs.iter()
+            .map(|m| match m.downcast_ref::<KeyMsg>() {
+                Some(key) => key.to_string(),
+                None => "<other>".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unknown_csi_is_not_typed_as_text() {
+        let mut parser = InputParser::new();
+        let msgs = parser.push_bytes(b"\x1b[99;99qx", false);
+        assert_eq!(keys(&msgs), vec!["x"]);
+    }
+
+    #[test]
+    fn test_broken_csi_resyncs_at_next_escape() {
+        let mut parser = InputParser::new();
+        // A CSI cut off by a new sequence: the fragment is dropped and the
+        // arrow key still arrives.
+        let msgs = parser.push_bytes(b"\x1b[12\x1b[A", false);
+        assert_eq!(keys(&msgs), vec!["up"]);
+    }
+
+    #[test]
+    fn test_lone_csi_introducer_is_alt_bracket() {
+        let mut parser = InputParser::new();
+        let msgs = parser.push_bytes(b"\x1b[", false);
+        assert_eq!(keys(&msgs), vec!["alt+["]);
+    }
+
+    #[test]
+    fn test_unterminated_csi_is_bounded() {
+        let mut parser = InputParser::new();
+        let garbage = [b'1'; MAX_SEQUENCE_LEN * 2];
+        assert!(parser.push_bytes(b"\x1b[", true).is_empty());
+        parser.push_bytes(&garbage, true);
+        assert!(!parser.has_pending());
+        assert_eq!(keys(&parser.push_bytes(b"q", true)), vec!["q"]);
+    }
+
+    #[test]
+    fn test_malformed_sgr_mouse_is_discarded() {
+        let mut parser = InputParser::new();
+        let msgs = parser.push_bytes(b"\x1b[<0;1xq", false);
+        assert_eq!(keys(&msgs), vec!["xq"]);
+
+        let msgs = parser.push_bytes(b"\x1b[<0;99999999999;1Ma", false);
+        assert_eq!(keys(&msgs), vec!["a"]);
+    }
+
+    #[test]
+    fn test_sequence_before_invalid_utf8_still_parses() {
+        let mut parser = InputParser::new();
+        let msgs = parser.push_bytes(b"\x1b[A\xff", false);
+        assert_eq!(keys(&msgs), vec!["up", "\u{fffd}"]);
+    }
+
     // === Thread Pool / spawn_batch Tests (bd-3ut7) ===
 
     #[test]
@@ -22,6 +22,19 @@ pub(crate) struct DisableReportFocusMsg;
 pub(crate) struct ReleaseTerminalMsg;
 pub(crate) struct RestoreTerminalMsg;
 
+/// Message carrying a blocking function to run with the terminal released.
+///
+/// Produced by [`exec`]. The program handles it itself; custom event loops
+/// and tests can call [`ExecMsg::run`] directly.
+pub struct ExecMsg(Box<dyn FnOnce() -> Message + Send>);
+
+impl ExecMsg {
+    /// Runs the function and returns the message it produced.
+    pub fn run(self) -> Message {
+        (self.0)()
+    }
+}
+
 /// Command to clear the screen.
 pub fn clear_screen() -> Cmd {
     Cmd::new(|| Message::new(ClearScreenMsg))
@@ -147,6 +160,32 @@ pub fn restore_terminal() -> Cmd {
     Cmd::new(|| Message::new(RestoreTerminalMsg))
 }
 
+/// Command to run a blocking external process, such as a text editor.
+///
+/// The program releases the terminal as with `release_terminal()`, stops
+/// reading input, runs `f`, restores the terminal and then delivers the
+/// message `f` returns to the model. Unlike sequencing the release and
+/// restore commands by hand, the process is guaranteed the terminal to
+/// itself for as long as it runs.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use bubbletea::{Message, screen};
+/// use std::process::Command;
+///
+/// let cmd = screen::exec(|| {
+///     let status = Command::new("vim").arg("notes.txt").status();
+///     Message::new(EditorFinishedMsg(status.map(|s| s.success())))
+/// });
+/// ```
+pub fn exec<F>(f: F) -> Cmd
+where
+    F: FnOnce() -> Message + Send + 'static,
+{
+    Cmd::new(move || Message::new(ExecMsg(Box::new(f))))
+}
+
 // Note: execute_screen_command could be used in the future for handling
 // screen commands dynamically. For now, screen control is handled directly
 // in the Program struct.
@@ -172,5 +211,13 @@ mod tests {
         let _ = disable_report_focus();
         let _ = release_terminal();
         let _ = restore_terminal();
+        let _ = exec(|| Message::new(()));
+    }
+
+    #[test]
+    fn test_exec_runs_function_when_executed() {
+        let msg = exec(|| Message::new(42u32)).execute().unwrap();
+        let exec = msg.downcast::<ExecMsg>().expect("exec message");
+        assert_eq!(exec.run().downcast::<u32>(), Some(42));
     }
 }
@@ -0,0 +1,378 @@
+//! The terminal a [`Program`](crate::Program) reads input from.
+//!
+//! Programs drive the process's own terminal through crossterm unless
+//! [`Program::with_terminal`](crate::Program::with_terminal) hands them
+//! another [`Terminal`]. Rendering always goes to the program's writer; a
+//! terminal only covers input, raw mode and the window size.
+//!
+//! [`WebTerminal`] is an experimental terminal fed by a host instead of a
+//! tty, meant for relaying a program to a terminal emulator such as
+//! xterm.js, for example over a WebSocket:
+//!
+//! ```rust,ignore
+//! use bubbletea::{Program, WebTerminal};
+//!
+//! let terminal = WebTerminal::new(80, 24);
+//! let output = terminal.output();
+//! let handle = terminal.clone();
+//!
+//! // data from xterm.js `onData` → handle.write_input(data.as_bytes())
+//! // sizes from xterm.js `onResize` → handle.resize(cols, rows)
+//! // periodically → send handle.take_output() to xterm.write
+//!
+//! Program::new(MyModel::default())
+//!     .with_terminal(terminal)
+//!     .run_with_writer(output)?;
+//! ```
+//!
+//! The program itself runs natively here. To run it inside the browser,
+//! compiled to `wasm32`, drive the model with a
+//! [`WebProgram`](crate::web::WebProgram) instead.
+
+use std::io::{self, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use parking_lot::{Condvar, Mutex};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::key::from_crossterm_key;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::message::{BlurMsg, FocusMsg, InterruptMsg};
+use crate::message::{Message, WindowSizeMsg};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::mouse::from_crossterm_mouse;
+use crate::program::InputParser;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{KeyMsg, KeyType};
+
+/// Input, raw mode and size of the terminal a program runs in.
+///
+/// Methods take `&self` so that input can be polled on one thread while the
+/// event loop queries the size or releases the terminal on another.
+pub trait Terminal: Send + Sync + 'static {
+    /// Switches to raw mode, so input arrives unbuffered and unechoed.
+    fn enable_raw_mode(&self) -> io::Result<()>;
+
+    /// Restores the normal input mode.
+    fn disable_raw_mode(&self) -> io::Result<()>;
+
+    /// Returns the size as `(columns, rows)`.
+    fn size(&self) -> io::Result<(u16, u16)>;
+
+    /// Waits up to `timeout` for input, returning the messages it produced.
+    ///
+    /// An empty result means the timeout passed without input.
+    fn poll(&self, timeout: Duration) -> io::Result<Vec<Message>>;
+
+    /// Stops the process until it is continued, as the shell does on
+    /// ctrl+z. The program releases the terminal before calling this.
+    ///
+    /// The default returns at once, for terminals without job control.
+    fn suspend(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The process's own terminal, driven through crossterm.
+///
+/// This is the terminal programs use unless told otherwise. On Windows,
+/// console input records are decoded by crossterm, and entering raw mode
+/// also turns on the console's virtual terminal processing, so that the
+/// escape sequences in styled views are interpreted instead of printed.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrosstermTerminal;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Terminal for CrosstermTerminal {
+    fn enable_raw_mode(&self) -> io::Result<()> {
+        // Consoles that can't process escape sequences, such as the legacy
+        // Windows console, still get crossterm's console API fallbacks
+        #[cfg(windows)]
+        crossterm::ansi_support::supports_ansi();
+        crossterm::terminal::enable_raw_mode()
+    }
+
+    fn disable_raw_mode(&self) -> io::Result<()> {
+        crossterm::terminal::disable_raw_mode()
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        crossterm::terminal::size()
+    }
+
+    fn poll(&self, timeout: Duration) -> io::Result<Vec<Message>> {
+        if !event::poll(timeout)? {
+            return Ok(Vec::new());
+        }
+        Ok(message_from_event(event::read()?).into_iter().collect())
+    }
+
+    /// Stops the process group with `SIGTSTP`, returning once the shell
+    /// continues it.
+    #[cfg(unix)]
+    fn suspend(&self) -> io::Result<()> {
+        use rustix::process::{Signal, kill_current_process_group};
+        kill_current_process_group(Signal::TSTP)?;
+        Ok(())
+    }
+}
+
+/// Translates a crossterm event, dropping key releases.
+#[cfg(not(target_arch = "wasm32"))]
+fn message_from_event(event: Event) -> Option<Message> {
+    match event {
+        Event::Key(mut key_event) => {
+            if key_event.kind != KeyEventKind::Press {
+                return None;
+            }
+            if cfg!(windows) {
+                key_event = strip_altgr(key_event);
+            }
+            let key_msg = from_crossterm_key(key_event.code, key_event.modifiers);
+            if key_msg.key_type == KeyType::CtrlC {
+                Some(Message::new(InterruptMsg))
+            } else {
+                Some(Message::new(key_msg))
+            }
+        }
+        Event::Mouse(mouse_event) => Some(Message::new(from_crossterm_mouse(mouse_event))),
+        Event::Resize(width, height) => Some(Message::new(WindowSizeMsg { width, height })),
+        Event::FocusGained => Some(Message::new(FocusMsg)),
+        Event::FocusLost => Some(Message::new(BlurMsg)),
+        Event::Paste(text) => Some(Message::new(
+            KeyMsg::from_runes(text.chars().collect()).with_paste(),
+        )),
+    }
+}
+
+/// Drops the ctrl and alt modifiers Windows reports with characters typed
+/// using AltGr, such as `@` or `€` on a German layout, which would
+/// otherwise read as ctrl+alt shortcuts.
+#[cfg(not(target_arch = "wasm32"))]
+fn strip_altgr(mut event: KeyEvent) -> KeyEvent {
+    let altgr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+    if let KeyCode::Char(c) = event.code
+        && event.modifiers.contains(altgr)
+        && (c.is_ascii_punctuation() || !c.is_ascii())
+    {
+        event.modifiers.remove(altgr);
+    }
+    event
+}
+
+/// An experimental terminal whose input and output are exchanged with a
+/// host, such as a terminal emulator running in a web page. The program
+/// itself runs natively; [`web`](crate::web) runs programs in the page.
+///
+/// Clones share the same terminal: keep one to feed input with
+/// [`write_input`](Self::write_input) and [`resize`](Self::resize) and to
+/// collect rendered output with [`take_output`](Self::take_output), and give
+/// another to [`Program::with_terminal`](crate::Program::with_terminal)
+/// together with [`output`](Self::output) as the program's writer. Input is
+/// raw terminal bytes, so escape sequences for arrows, mouse reports and
+/// bracketed paste are decoded like those read from a real tty.
+#[derive(Clone)]
+pub struct WebTerminal {
+    shared: Arc<WebShared>,
+}
+
+struct WebShared {
+    state: Mutex<WebState>,
+    input_ready: Condvar,
+}
+
+struct WebState {
+    input: Vec<u8>,
+    parser: InputParser,
+    size: (u16, u16),
+    resized: bool,
+    raw_mode: bool,
+    output: Vec<u8>,
+}
+
+impl WebTerminal {
+    /// Creates a terminal of `width` columns and `height` rows.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            shared: Arc::new(WebShared {
+                state: Mutex::new(WebState {
+                    input: Vec::new(),
+                    parser: InputParser::new(),
+                    size: (width, height),
+                    resized: false,
+                    raw_mode: false,
+                    output: Vec::new(),
+                }),
+                input_ready: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Queues input typed into the host, such as the data passed to
+    /// xterm.js's `onData` handler.
+    pub fn write_input(&self, data: &[u8]) {
+        self.shared.state.lock().input.extend_from_slice(data);
+        self.shared.input_ready.notify_all();
+    }
+
+    /// Reports a new size, delivering a [`WindowSizeMsg`] to the program.
+    pub fn resize(&self, width: u16, height: u16) {
+        let mut state = self.shared.state.lock();
+        state.size = (width, height);
+        state.resized = true;
+        drop(state);
+        self.shared.input_ready.notify_all();
+    }
+
+    /// Returns a writer that collects the program's output.
+    pub fn output(&self) -> WebOutput {
+        WebOutput {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+
+    /// Takes the output written since the last call, ready to be passed to
+    /// the host's write function.
+    pub fn take_output(&self) -> Vec<u8> {
+        std::mem::take(&mut self.shared.state.lock().output)
+    }
+
+    /// Returns whether the program has switched the terminal to raw mode.
+    pub fn is_raw_mode(&self) -> bool {
+        self.shared.state.lock().raw_mode
+    }
+}
+
+impl std::fmt::Debug for WebTerminal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.shared.state.lock();
+        f.debug_struct("WebTerminal")
+            .field("size", &state.size)
+            .field("raw_mode", &state.raw_mode)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Terminal for WebTerminal {
+    fn enable_raw_mode(&self) -> io::Result<()> {
+        self.shared.state.lock().raw_mode = true;
+        Ok(())
+    }
+
+    fn disable_raw_mode(&self) -> io::Result<()> {
+        self.shared.state.lock().raw_mode = false;
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok(self.shared.state.lock().size)
+    }
+
+    fn poll(&self, timeout: Duration) -> io::Result<Vec<Message>> {
+        let mut state = self.shared.state.lock();
+        if state.input.is_empty() && !state.resized {
+            self.shared.input_ready.wait_for(&mut state, timeout);
+        }
+
+        let mut messages = Vec::new();
+        if std::mem::take(&mut state.resized) {
+            let (width, height) = state.size;
+            messages.push(Message::new(WindowSizeMsg { width, height }));
+        }
+        let input = std::mem::take(&mut state.input);
+        if !input.is_empty() {
+            messages.extend(state.parser.push_bytes(&input, true));
+        } else if state.parser.has_pending() {
+            // Nothing followed within the timeout, so a lone escape is a key
+            messages.extend(state.parser.flush());
+        }
+        Ok(messages)
+    }
+}
+
+/// Writer returned by [`WebTerminal::output`].
+pub struct WebOutput {
+    shared: Arc<WebShared>,
+}
+
+impl Write for WebOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.shared.state.lock().output.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyMsg, KeyType};
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn altgr_characters_lose_ctrl_alt() {
+        let key = |c, modifiers| strip_altgr(KeyEvent::new(KeyCode::Char(c), modifiers));
+        let altgr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+
+        assert_eq!(key('@', altgr).modifiers, KeyModifiers::NONE);
+        assert_eq!(key('€', altgr).modifiers, KeyModifiers::NONE);
+        // Ctrl+alt shortcuts on letters are kept
+        assert_eq!(key('a', altgr).modifiers, altgr);
+        assert_eq!(
+            key('@', KeyModifiers::CONTROL).modifiers,
+            KeyModifiers::CONTROL
+        );
+    }
+
+    #[test]
+    fn web_terminal_decodes_input_and_resizes() {
+        let terminal = WebTerminal::new(80, 24);
+        terminal.write_input(b"a\x1b[A");
+        terminal.resize(100, 30);
+
+        let messages = terminal.poll(Duration::ZERO).unwrap();
+        assert_eq!(
+            messages[0].downcast_ref::<WindowSizeMsg>(),
+            Some(&WindowSizeMsg {
+                width: 100,
+                height: 30
+            })
+        );
+        let keys: Vec<KeyType> = messages[1..]
+            .iter()
+            .map(|msg| msg.downcast_ref::<KeyMsg>().unwrap().key_type)
+            .collect();
+        assert_eq!(keys, [KeyType::Runes, KeyType::Up]);
+        assert_eq!(terminal.size().unwrap(), (100, 30));
+        assert!(terminal.poll(Duration::ZERO).unwrap().is_empty());
+    }
+
+    #[test]
+    fn web_terminal_flushes_lone_escape_after_timeout() {
+        let terminal = WebTerminal::new(80, 24);
+        terminal.write_input(b"\x1b");
+        assert!(terminal.poll(Duration::ZERO).unwrap().is_empty());
+
+        let messages = terminal.poll(Duration::from_millis(1)).unwrap();
+        assert_eq!(
+            messages[0].downcast_ref::<KeyMsg>().unwrap().key_type,
+            KeyType::Esc
+        );
+    }
+
+    #[test]
+    fn web_terminal_collects_output() {
+        let terminal = WebTerminal::new(80, 24);
+        let mut output = terminal.output();
+        write!(output, "hello").unwrap();
+        assert_eq!(terminal.take_output(), b"hello");
+        assert!(terminal.take_output().is_empty());
+    }
+}
@@ -0,0 +1,218 @@
+//! Message tracing for debugging running programs.
+//!
+//! A program's terminal is taken over by its view, so printing is no way to
+//! find out why it flickers or lags. A [`Tracer`] given to
+//! [`Program::with_tracer`](crate::Program::with_tracer) records every
+//! message the model handles with the time its update took, and every frame
+//! drawn with its size, either into a ring buffer to inspect later or into a
+//! log file to watch with `tail -f` from another terminal.
+//!
+//! Setting the `BUBBLETEA_LOG` environment variable to a file path traces a
+//! program into that file without changing its code.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bubbletea::trace::{TraceEvent, Tracer};
+//!
+//! let tracer = Tracer::ring(100);
+//! tracer.log("loading config");
+//! // Program::new(model).with_tracer(tracer.clone()).run()?;
+//!
+//! assert!(matches!(&tracer.events()[0], TraceEvent::Log(line) if line == "loading config"));
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use tracing::trace;
+
+/// Environment variable naming a file to trace programs into.
+pub const LOG_ENV_VAR: &str = "BUBBLETEA_LOG";
+
+/// Something a [`Tracer`] recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+    /// The model handled a message.
+    Update {
+        /// Type name of the message.
+        message: &'static str,
+        /// Time `update` took.
+        elapsed: Duration,
+    },
+    /// A frame was drawn.
+    Render {
+        /// Lines in the view.
+        lines: usize,
+        /// Bytes in the view, including styling.
+        bytes: usize,
+        /// Time `view` and drawing took.
+        elapsed: Duration,
+    },
+    /// A line logged with [`Tracer::log`].
+    Log(String),
+}
+
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Update { message, elapsed } => write!(f, "update {message} {elapsed:?}"),
+            Self::Render {
+                lines,
+                bytes,
+                elapsed,
+            } => write!(f, "render {lines} lines {bytes} bytes {elapsed:?}"),
+            Self::Log(line) => write!(f, "log {line}"),
+        }
+    }
+}
+
+/// Where a tracer's events go.
+enum Sink {
+    Ring {
+        events: VecDeque<TraceEvent>,
+        capacity: usize,
+    },
+    Writer(Box<dyn Write + Send>),
+}
+
+/// Records what a program does, for debugging.
+///
+/// Clones share their events, so keep one to read the events of a tracer
+/// given to a program.
+#[derive(Clone)]
+pub struct Tracer {
+    sink: Arc<Mutex<Sink>>,
+}
+
+impl fmt::Debug for Tracer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tracer").finish_non_exhaustive()
+    }
+}
+
+impl Tracer {
+    /// Creates a tracer that keeps the last `capacity` events in memory.
+    pub fn ring(capacity: usize) -> Self {
+        Self::new(Sink::Ring {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+        })
+    }
+
+    /// Creates a tracer that writes each event to `writer` as a line.
+    pub fn to_writer<W: Write + Send + 'static>(writer: W) -> Self {
+        Self::new(Sink::Writer(Box::new(writer)))
+    }
+
+    /// Creates a tracer that appends each event to the file at `path` as a
+    /// line, creating the file if needed.
+    pub fn to_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self::to_writer(file))
+    }
+
+    /// Creates a tracer writing to the file named by `BUBBLETEA_LOG`, or
+    /// returns `None` if the variable is unset or the file can't be opened.
+    pub fn from_env() -> Option<Self> {
+        let path = std::env::var_os(LOG_ENV_VAR).filter(|path| !path.is_empty())?;
+        Self::to_file(path).ok()
+    }
+
+    fn new(sink: Sink) -> Self {
+        Self {
+            sink: Arc::new(Mutex::new(sink)),
+        }
+    }
+
+    /// Logs a line of your own among the program's events.
+    pub fn log(&self, line: impl fmt::Display) {
+        self.record(TraceEvent::Log(line.to_string()));
+    }
+
+    /// Returns the events kept by a [`ring`](Self::ring) tracer, oldest
+    /// first. Tracers that write their events keep none.
+    pub fn events(&self) -> Vec<TraceEvent> {
+        match &*self.lock() {
+            Sink::Ring { events, .. } => events.iter().cloned().collect(),
+            Sink::Writer(_) => Vec::new(),
+        }
+    }
+
+    /// Records `event`, also emitting it as a `tracing` event with target
+    /// `bubbletea::trace`.
+    pub(crate) fn record(&self, event: TraceEvent) {
+        trace!(target: "bubbletea::trace", "{event}");
+        match &mut *self.lock() {
+            Sink::Ring { events, capacity } => {
+                if *capacity == 0 {
+                    return;
+                }
+                if events.len() == *capacity {
+                    events.pop_front();
+                }
+                events.push_back(event);
+            }
+            Sink::Writer(writer) => {
+                // Tracing is best effort and never fails the program
+                let _ = writeln!(writer, "{event}").and_then(|()| writer.flush());
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Sink> {
+        self.sink.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Returns a tracer that appends to the file at `path`; see
+/// [`Tracer::to_file`].
+///
+/// The returned tracer's [`log`](Tracer::log) writes your own debug lines
+/// to the same file while the program owns the terminal.
+pub fn log_to_file(path: impl AsRef<Path>) -> io::Result<Tracer> {
+    Tracer::to_file(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_keeps_last_events() {
+        let tracer = Tracer::ring(2);
+        tracer.log("a");
+        tracer.log("b");
+        tracer.log("c");
+        assert_eq!(
+            tracer.events(),
+            [TraceEvent::Log("b".into()), TraceEvent::Log("c".into())]
+        );
+    }
+
+    #[test]
+    fn test_writer_writes_lines() {
+        let path = std::env::temp_dir().join(format!("bubbletea-trace-{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let tracer = log_to_file(&path).unwrap();
+        tracer.record(TraceEvent::Update {
+            message: "KeyMsg",
+            elapsed: Duration::from_millis(2),
+        });
+        tracer.record(TraceEvent::Render {
+            lines: 3,
+            bytes: 40,
+            elapsed: Duration::from_micros(500),
+        });
+        assert!(tracer.events().is_empty());
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(log, "update KeyMsg 2ms\nrender 3 lines 40 bytes 500µs\n");
+    }
+}
@@ -0,0 +1,799 @@
+//! Running programs in the browser (experimental).
+//!
+//! A [`Program`](crate::Program) needs threads and a terminal, which a
+//! `wasm32` module in a web page doesn't have. A [`WebProgram`] runs the
+//! same model step by step instead: its host, usually the JavaScript shim
+//! shipped in the crate's `web/` directory, hands it the input typed into a
+//! terminal emulator such as xterm.js, the emulator's size and the time
+//! that passed, and writes the output back to the emulator. Commands run
+//! inline on the host's thread, and the program waits out the delays of
+//! [`after`](crate::after) commands on the host's timers.
+//!
+//! With the `web` feature, `WebApp` exports a program to JavaScript:
+//!
+//! ```rust,ignore
+//! use bubbletea::web::{WebApp, WebProgram};
+//! use wasm_bindgen::prelude::*;
+//!
+//! #[wasm_bindgen]
+//! pub fn app() -> WebApp {
+//!     WebApp::new(WebProgram::new(Counter::default()).with_alt_screen())
+//! }
+//! ```
+//!
+//! ```js
+//! import init, { app } from "./pkg/counter.js";
+//! import { attach } from "./bubbletea.js";
+//!
+//! await init();
+//! const term = new Terminal();
+//! term.open(document.getElementById("terminal"));
+//! attach(app(), term);
+//! ```
+//!
+//! Commands that block, such as those made by [`tick`](crate::tick) or
+//! [`every`](crate::every), which sleep and read the system clock, stop the
+//! page or panic in the browser; use [`after`](crate::after) for timers.
+//! There is no process to suspend or terminal to release there, so
+//! [`suspend`](crate::suspend) resumes at once and
+//! [`screen::exec`](crate::screen::exec) runs its function directly.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::command::Cmd;
+use crate::message::{
+    BatchMsg, ColorProfileMsg, InputLatencyMsg, InterruptMsg, LatencyHistogram, Message, NotifyMsg,
+    PrintLineMsg, QuitMsg, RequestInputLatencyMsg, RequestWindowSizeMsg, ResumeMsg, SequenceMsg,
+    SetWindowTitleMsg, SuspendMsg, WindowSizeMsg,
+};
+use crate::program::{Frame, InputModes, InputParser, Model, ProgramOptions};
+use crate::screen::{
+    EnterAltScreenMsg, ExecMsg, ExitAltScreenMsg, ReleaseTerminalMsg, RestoreTerminalMsg,
+};
+
+#[cfg(target_arch = "wasm32")]
+use crate::ansi::{
+    cursor::{Hide, Show},
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{EnterAlternateScreen, SetTitle},
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crossterm::{
+    cursor::{Hide, Show},
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{EnterAlternateScreen, SetTitle},
+};
+
+/// How long a lone escape waits for the rest of a sequence before it is
+/// read as the escape key.
+const ESCAPE_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// A program driven step by step by its host, for running in the browser.
+///
+/// Each call that feeds the program runs the model until it has handled
+/// everything that call produced and draws the view if it changed, so the
+/// host collects the output with [`take_output`](Self::take_output) after
+/// each. Output goes to a buffer and can't fail to write.
+///
+/// # Example
+///
+/// ```rust
+/// use bubbletea::web::WebProgram;
+/// use bubbletea::{Cmd, KeyMsg, Message, Model, quit};
+///
+/// struct Echo(String);
+///
+/// impl Model for Echo {
+///     fn init(&self) -> Option<Cmd> {
+///         None
+///     }
+///
+///     fn update(&mut self, msg: Message) -> Option<Cmd> {
+///         let key = msg.downcast::<KeyMsg>()?;
+///         if key.runes == ['q'] {
+///             return Some(quit());
+///         }
+///         self.0.extend(key.runes);
+///         None
+///     }
+///
+///     fn view(&self) -> String {
+///         self.0.clone()
+///     }
+/// }
+///
+/// let mut program = WebProgram::new(Echo(String::new()));
+/// program.start(80, 24);
+/// program.write_input(b"hi");
+/// assert!(String::from_utf8(program.take_output()).unwrap().contains("hi"));
+///
+/// program.write_input(b"q");
+/// assert!(program.is_finished());
+/// assert_eq!(program.model().0, "hi");
+/// ```
+pub struct WebProgram<M: Model> {
+    model: M,
+    options: ProgramOptions,
+    frame: Frame,
+    modes: InputModes,
+    parser: InputParser,
+    /// Messages waiting for the model.
+    queue: VecDeque<Message>,
+    /// Commands waiting out the delay of their first command.
+    timers: Vec<Timer>,
+    /// Time since the program started, as the host reports it.
+    now: Duration,
+    /// When the parser was left holding what may start an escape sequence.
+    pending_since: Option<Duration>,
+    size: (u16, u16),
+    output: Vec<u8>,
+    window_title_set: bool,
+    needs_render: bool,
+    started: bool,
+    finished: bool,
+}
+
+/// Commands to run in order once `at` is reached.
+struct Timer {
+    at: Duration,
+    cmds: VecDeque<Cmd>,
+}
+
+impl<M: Model> WebProgram<M> {
+    /// Creates a program for `model`, which starts on [`start`](Self::start).
+    pub fn new(model: M) -> Self {
+        let options = ProgramOptions::default();
+        Self {
+            model,
+            frame: Frame::new(options.alt_screen),
+            modes: InputModes::new(&options),
+            options,
+            parser: InputParser::new(),
+            queue: VecDeque::new(),
+            timers: Vec::new(),
+            now: Duration::ZERO,
+            pending_since: None,
+            size: (0, 0),
+            output: Vec::new(),
+            window_title_set: false,
+            needs_render: false,
+            started: false,
+            finished: false,
+        }
+    }
+
+    /// Use the alternate screen buffer.
+    pub fn with_alt_screen(mut self) -> Self {
+        self.options.alt_screen = true;
+        self
+    }
+
+    /// Enable mouse cell motion tracking.
+    pub fn with_mouse_cell_motion(mut self) -> Self {
+        self.options.mouse_cell_motion = true;
+        self
+    }
+
+    /// Enable mouse all motion tracking.
+    pub fn with_mouse_all_motion(mut self) -> Self {
+        self.options.mouse_all_motion = true;
+        self
+    }
+
+    /// Enable focus reporting, delivering
+    /// [`FocusMsg`](crate::FocusMsg) and [`BlurMsg`](crate::BlurMsg).
+    pub fn with_report_focus(mut self) -> Self {
+        self.options.report_focus = true;
+        self
+    }
+
+    /// Disable bracketed paste mode.
+    pub fn without_bracketed_paste(mut self) -> Self {
+        self.options.bracketed_paste = false;
+        self
+    }
+
+    /// Replace all options at once.
+    ///
+    /// Options about threads, signals, the frame rate and the process's
+    /// terminal don't apply in the browser and are ignored.
+    pub fn with_options(mut self, options: ProgramOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Starts the program on a terminal of `width` columns and `height`
+    /// rows: sets up the terminal, delivers the size and runs the model's
+    /// `init`. Later calls do nothing.
+    pub fn start(&mut self, width: u16, height: u16) {
+        if self.started {
+            return;
+        }
+        self.started = true;
+        self.frame = Frame::new(self.options.alt_screen);
+        self.frame.scroll_regions = self.options.scroll_regions;
+        self.modes = InputModes::new(&self.options);
+
+        let writer = &mut self.output;
+        if self.options.alt_screen {
+            let _ = execute!(writer, EnterAlternateScreen);
+        }
+        let _ = execute!(writer, Hide);
+        if self.options.mouse_all_motion || self.options.mouse_cell_motion {
+            let _ = execute!(writer, EnableMouseCapture);
+        }
+        let _ = self.modes.enable(writer);
+
+        self.size = (width, height);
+        self.queue
+            .push_back(Message::new(WindowSizeMsg { width, height }));
+        if let Some(profile) = self.options.color_profile {
+            self.queue.push_back(Message::new(ColorProfileMsg(profile)));
+        }
+        if let Some(cmd) = self.model.init() {
+            self.run_commands(VecDeque::from([cmd]));
+        }
+        self.needs_render = true;
+        self.process();
+    }
+
+    /// Feeds input typed into the terminal, such as the data passed to
+    /// xterm.js's `onData` handler, as raw bytes.
+    pub fn write_input(&mut self, data: &[u8]) {
+        if !self.is_running() {
+            return;
+        }
+        let messages = self.parser.push_bytes(data, true);
+        self.queue.extend(messages);
+        self.pending_since = self.parser.has_pending().then_some(self.now);
+        self.process();
+    }
+
+    /// Reports a new terminal size, delivering a [`WindowSizeMsg`].
+    pub fn resize(&mut self, width: u16, height: u16) {
+        if !self.is_running() {
+            return;
+        }
+        self.size = (width, height);
+        self.queue
+            .push_back(Message::new(WindowSizeMsg { width, height }));
+        self.process();
+    }
+
+    /// Sends a message to the model, as
+    /// [`ProgramHandle::send`](crate::ProgramHandle::send) does.
+    pub fn send(&mut self, msg: Message) {
+        if !self.is_running() {
+            return;
+        }
+        self.queue.push_back(msg);
+        self.process();
+    }
+
+    /// Moves the program's clock on by `elapsed`, running the commands
+    /// whose delay has passed in the order they were due.
+    pub fn advance(&mut self, elapsed: Duration) {
+        if !self.is_running() {
+            return;
+        }
+        let target = self.now + elapsed;
+        while !self.finished
+            && let Some(i) = self.next_timer()
+            && self.timers[i].at <= target
+        {
+            // Delays set while a timer fires count from when it was due
+            let timer = self.timers.remove(i);
+            self.now = self.now.max(timer.at);
+            self.run_commands(timer.cmds);
+            self.handle_queue();
+        }
+        self.now = target;
+        if let Some(since) = self.pending_since
+            && self.now >= since + ESCAPE_TIMEOUT
+        {
+            // Nothing followed in time, so a lone escape is a key
+            self.pending_since = None;
+            let messages = self.parser.flush();
+            self.queue.extend(messages);
+        }
+        self.process();
+    }
+
+    /// Returns how long the host should wait before calling
+    /// [`advance`](Self::advance), or `None` if nothing is waiting.
+    pub fn next_deadline(&self) -> Option<Duration> {
+        if !self.is_running() {
+            return None;
+        }
+        let timer = self.next_timer().map(|i| self.timers[i].at);
+        let escape = self.pending_since.map(|since| since + ESCAPE_TIMEOUT);
+        let at = timer.into_iter().chain(escape).min()?;
+        Some(at.saturating_sub(self.now))
+    }
+
+    /// Takes the output written since the last call, ready to be passed to
+    /// the terminal's write function.
+    pub fn take_output(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.output)
+    }
+
+    /// Returns whether the program has quit, after which it ignores input.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Returns the model.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Consumes the program, returning the model.
+    pub fn into_model(self) -> M {
+        self.model
+    }
+
+    fn is_running(&self) -> bool {
+        self.started && !self.finished
+    }
+
+    /// Returns the index of the timer due first, the earliest set on ties.
+    fn next_timer(&self) -> Option<usize> {
+        (0..self.timers.len()).min_by_key(|&i| self.timers[i].at)
+    }
+
+    /// Handles the queued messages and draws the view if it changed.
+    fn process(&mut self) {
+        self.handle_queue();
+        if self.needs_render && !self.finished {
+            let view = self.model.view();
+            let _ = self.frame.draw(&mut self.output, view);
+            self.needs_render = false;
+        }
+    }
+
+    fn handle_queue(&mut self) {
+        while !self.finished
+            && let Some(msg) = self.queue.pop_front()
+        {
+            self.handle(msg);
+        }
+    }
+
+    /// Runs `cmds` in order, queueing their messages. A command with a
+    /// delay is set on a timer together with the commands after it.
+    fn run_commands(&mut self, mut cmds: VecDeque<Cmd>) {
+        while let Some(mut cmd) = cmds.pop_front() {
+            if let Some(delay) = cmd.take_delay() {
+                cmds.push_front(cmd);
+                self.timers.push(Timer {
+                    at: self.now + delay,
+                    cmds,
+                });
+                return;
+            }
+            if let Some(msg) = cmd.execute() {
+                self.queue.push_back(msg);
+            }
+        }
+    }
+
+    fn handle(&mut self, msg: Message) {
+        let writer = &mut self.output;
+
+        if msg.is::<QuitMsg>() || msg.is::<InterruptMsg>() {
+            self.quit();
+            return;
+        }
+
+        // Batched commands run independently, so each waits on its own
+        if msg.is::<BatchMsg>() {
+            if let Some(batch) = msg.downcast::<BatchMsg>() {
+                for cmd in batch.0 {
+                    self.run_commands(VecDeque::from([cmd]));
+                }
+            }
+            return;
+        }
+        if msg.is::<SequenceMsg>() {
+            if let Some(seq) = msg.downcast::<SequenceMsg>() {
+                self.run_commands(seq.0.into());
+            }
+            return;
+        }
+
+        if let Some(title_msg) = msg.downcast_ref::<SetWindowTitleMsg>() {
+            let _ = execute!(writer, SetTitle(&title_msg.0));
+            self.window_title_set = true;
+            return;
+        }
+        if matches!(self.modes.apply(writer, &msg), Ok(true)) {
+            return;
+        }
+        if msg.is::<EnterAltScreenMsg>() || msg.is::<ExitAltScreenMsg>() {
+            let _ = self
+                .frame
+                .set_alt_screen(writer, msg.is::<EnterAltScreenMsg>());
+            self.needs_render = true;
+            return;
+        }
+        // The page's environment says nothing about the emulator, so
+        // notifications need an explicit protocol
+        if let Some(notify) = msg.downcast_ref::<NotifyMsg>() {
+            if let Some(protocol) = self.options.notifications {
+                let sequence = protocol.sequence(&notify.title, &notify.body);
+                writer.extend_from_slice(sequence.as_bytes());
+            }
+            return;
+        }
+        if let Some(print_msg) = msg.downcast_ref::<PrintLineMsg>() {
+            if !self.frame.alt_screen {
+                let _ = self.frame.print_above(writer, &print_msg.0);
+                self.needs_render = true;
+            }
+            return;
+        }
+        if msg.is::<RequestWindowSizeMsg>() {
+            let (width, height) = self.size;
+            self.queue
+                .push_back(Message::new(WindowSizeMsg { width, height }));
+            return;
+        }
+        // There is no clock to time input with
+        if msg.is::<RequestInputLatencyMsg>() {
+            let report = InputLatencyMsg(LatencyHistogram::new());
+            self.queue.push_back(Message::new(report));
+            return;
+        }
+        // Nothing holds the terminal but the program
+        if msg.is::<ReleaseTerminalMsg>() || msg.is::<RestoreTerminalMsg>() {
+            return;
+        }
+        if msg.is::<ExecMsg>() {
+            if let Some(exec) = msg.downcast::<ExecMsg>() {
+                self.queue.push_back(exec.run());
+            }
+            self.needs_render = true;
+            return;
+        }
+        if msg.is::<SuspendMsg>() {
+            self.queue.push_back(Message::new(ResumeMsg));
+            return;
+        }
+
+        if let Some(size) = msg.downcast_ref::<WindowSizeMsg>() {
+            self.frame.resize(*size);
+        }
+        if let Some(cmd) = self.model.update(msg) {
+            self.run_commands(VecDeque::from([cmd]));
+        }
+        self.needs_render = true;
+    }
+
+    /// Draws the final view and restores the terminal, leaving the view on
+    /// screen as native programs do.
+    fn quit(&mut self) {
+        let view = self.model.view();
+        let writer = &mut self.output;
+        let _ = self.frame.draw(writer, view);
+        if self.window_title_set {
+            let _ = execute!(writer, SetTitle(""));
+        }
+        self.modes.disable(writer);
+        if self.options.mouse_all_motion || self.options.mouse_cell_motion {
+            let _ = execute!(writer, DisableMouseCapture);
+        }
+        let _ = execute!(writer, Show);
+        let _ = self.frame.finish(writer);
+        self.finished = true;
+        self.queue.clear();
+        self.timers.clear();
+        self.pending_since = None;
+    }
+}
+
+#[cfg(feature = "web")]
+pub use bindings::WebApp;
+
+#[cfg(feature = "web")]
+mod bindings {
+    use std::time::Duration;
+
+    use wasm_bindgen::prelude::wasm_bindgen;
+
+    use super::WebProgram;
+    use crate::program::Model;
+
+    /// A [`WebProgram`] exported to JavaScript.
+    ///
+    /// wasm-bindgen can't export generic types, so applications export a
+    /// function that wraps their program in one, as shown in the
+    /// [module docs](crate::web). The methods take sizes in cells and times
+    /// in milliseconds, as xterm.js and the browser's timers do.
+    #[wasm_bindgen]
+    pub struct WebApp {
+        program: Box<dyn Driver>,
+    }
+
+    impl WebApp {
+        /// Wraps `program` for JavaScript.
+        pub fn new<M: Model>(program: WebProgram<M>) -> Self {
+            Self {
+                program: Box::new(program),
+            }
+        }
+    }
+
+    #[wasm_bindgen]
+    impl WebApp {
+        /// Starts the program on a terminal of `cols` by `rows` cells.
+        pub fn start(&mut self, cols: u16, rows: u16) {
+            self.program.start(cols, rows);
+        }
+
+        /// Feeds input typed into the terminal, encoded as UTF-8.
+        #[wasm_bindgen(js_name = writeInput)]
+        pub fn write_input(&mut self, data: &[u8]) {
+            self.program.write_input(data);
+        }
+
+        /// Reports a new terminal size.
+        pub fn resize(&mut self, cols: u16, rows: u16) {
+            self.program.resize(cols, rows);
+        }
+
+        /// Moves the program's clock on by `elapsed_ms` milliseconds.
+        pub fn advance(&mut self, elapsed_ms: f64) {
+            let elapsed = Duration::from_secs_f64(elapsed_ms.max(0.0) / 1000.0);
+            self.program.advance(elapsed);
+        }
+
+        /// Returns the milliseconds until [`advance`](Self::advance) should
+        /// be called, or `undefined` if nothing is waiting.
+        #[wasm_bindgen(js_name = nextDeadline)]
+        pub fn next_deadline(&self) -> Option<f64> {
+            self.program
+                .next_deadline()
+                .map(|deadline| deadline.as_secs_f64() * 1000.0)
+        }
+
+        /// Takes the output written since the last call.
+        #[wasm_bindgen(js_name = takeOutput)]
+        pub fn take_output(&mut self) -> Vec<u8> {
+            self.program.take_output()
+        }
+
+        /// Returns whether the program has quit.
+        #[wasm_bindgen(js_name = isFinished)]
+        pub fn is_finished(&self) -> bool {
+            self.program.is_finished()
+        }
+    }
+
+    /// The methods of [`WebProgram`] that don't depend on the model type.
+    trait Driver {
+        fn start(&mut self, width: u16, height: u16);
+        fn write_input(&mut self, data: &[u8]);
+        fn resize(&mut self, width: u16, height: u16);
+        fn advance(&mut self, elapsed: Duration);
+        fn next_deadline(&self) -> Option<Duration>;
+        fn take_output(&mut self) -> Vec<u8>;
+        fn is_finished(&self) -> bool;
+    }
+
+    impl<M: Model> Driver for WebProgram<M> {
+        fn start(&mut self, width: u16, height: u16) {
+            Self::start(self, width, height);
+        }
+
+        fn write_input(&mut self, data: &[u8]) {
+            Self::write_input(self, data);
+        }
+
+        fn resize(&mut self, width: u16, height: u16) {
+            Self::resize(self, width, height);
+        }
+
+        fn advance(&mut self, elapsed: Duration) {
+            Self::advance(self, elapsed);
+        }
+
+        fn next_deadline(&self) -> Option<Duration> {
+            Self::next_deadline(self)
+        }
+
+        fn take_output(&mut self) -> Vec<u8> {
+            Self::take_output(self)
+        }
+
+        fn is_finished(&self) -> bool {
+            Self::is_finished(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::{after, batch, quit, sequence, window_size};
+    use crate::{KeyMsg, KeyType};
+
+    /// Records what it's sent, and runs the command made for a key.
+    struct Recorder {
+        keys: Vec<KeyType>,
+        sizes: Vec<WindowSizeMsg>,
+        events: Vec<&'static str>,
+        init: Option<fn() -> Cmd>,
+        on_key: fn(&KeyMsg) -> Option<Cmd>,
+    }
+
+    impl Recorder {
+        fn new() -> Self {
+            Self {
+                keys: Vec::new(),
+                sizes: Vec::new(),
+                events: Vec::new(),
+                init: None,
+                on_key: |_| None,
+            }
+        }
+    }
+
+    impl Model for Recorder {
+        fn init(&self) -> Option<Cmd> {
+            self.init.map(|init| init())
+        }
+
+        fn update(&mut self, msg: Message) -> Option<Cmd> {
+            if let Some(size) = msg.downcast_ref::<WindowSizeMsg>() {
+                self.sizes.push(*size);
+            }
+            if let Some(event) = msg.downcast_ref::<&'static str>() {
+                self.events.push(event);
+            }
+            let key = msg.downcast::<KeyMsg>()?;
+            self.keys.push(key.key_type);
+            (self.on_key)(&key)
+        }
+
+        fn view(&self) -> String {
+            format!("events: {}", self.events.join(","))
+        }
+    }
+
+    fn output(program: &mut WebProgram<Recorder>) -> String {
+        String::from_utf8(program.take_output()).unwrap()
+    }
+
+    #[test]
+    fn start_sets_up_terminal_and_draws() {
+        let mut program = WebProgram::new(Recorder::new()).with_alt_screen();
+        program.write_input(b"a");
+        assert!(program.take_output().is_empty(), "input before start");
+
+        program.start(80, 24);
+        let out = output(&mut program);
+        assert!(out.starts_with("\x1b[?1049h\x1b[?25l"), "{out:?}");
+        assert!(out.ends_with("events: "), "{out:?}");
+        assert_eq!(
+            program.model().sizes,
+            [WindowSizeMsg {
+                width: 80,
+                height: 24
+            }]
+        );
+        assert_eq!(program.next_deadline(), None);
+    }
+
+    #[test]
+    fn input_is_decoded_and_quit_restores_terminal() {
+        let mut model = Recorder::new();
+        model.on_key = |key| (key.key_type == KeyType::Enter).then(quit);
+        let mut program = WebProgram::new(model);
+        program.start(80, 24);
+        program.take_output();
+
+        program.write_input(b"\x1b[Ax\r");
+        assert!(program.is_finished());
+        assert_eq!(
+            program.model().keys,
+            [KeyType::Up, KeyType::Runes, KeyType::Enter]
+        );
+        let out = output(&mut program);
+        assert!(out.contains("\x1b[?25h"), "cursor shown: {out:?}");
+
+        program.write_input(b"y");
+        program.resize(100, 30);
+        assert_eq!(program.model().keys.len(), 3);
+        assert!(program.take_output().is_empty());
+    }
+
+    #[test]
+    fn lone_escape_is_flushed_after_timeout() {
+        let mut program = WebProgram::new(Recorder::new());
+        program.start(80, 24);
+        program.write_input(b"\x1b");
+        assert!(program.model().keys.is_empty());
+        assert_eq!(program.next_deadline(), Some(ESCAPE_TIMEOUT));
+
+        program.advance(ESCAPE_TIMEOUT / 2);
+        assert!(program.model().keys.is_empty());
+        program.advance(ESCAPE_TIMEOUT / 2);
+        assert_eq!(program.model().keys, [KeyType::Esc]);
+        assert_eq!(program.next_deadline(), None);
+    }
+
+    #[test]
+    fn after_waits_for_the_host_clock() {
+        let mut model = Recorder::new();
+        model.init = Some(|| after(Duration::from_millis(100), || Message::new("tick")));
+        let mut program = WebProgram::new(model);
+        program.start(80, 24);
+        program.take_output();
+        assert_eq!(program.next_deadline(), Some(Duration::from_millis(100)));
+
+        program.advance(Duration::from_millis(60));
+        assert!(program.model().events.is_empty());
+        assert_eq!(program.next_deadline(), Some(Duration::from_millis(40)));
+
+        program.advance(Duration::from_millis(40));
+        assert_eq!(program.model().events, ["tick"]);
+        assert!(output(&mut program).contains("events: tick"));
+        assert_eq!(program.next_deadline(), None);
+    }
+
+    #[test]
+    fn sequence_waits_for_delayed_command() {
+        let mut model = Recorder::new();
+        model.init = Some(|| {
+            sequence(vec![
+                Some(Cmd::new(|| Message::new("first"))),
+                Some(after(Duration::from_millis(10), || Message::new("second"))),
+                Some(Cmd::new(|| Message::new("third"))),
+            ])
+            .unwrap()
+        });
+        let mut program = WebProgram::new(model);
+        program.start(80, 24);
+        assert_eq!(program.model().events, ["first"]);
+
+        // Both timers are due within one advance and fire in order
+        program.advance(Duration::from_millis(50));
+        assert_eq!(program.model().events, ["first", "second", "third"]);
+    }
+
+    #[test]
+    fn timers_fire_in_order_of_due_time() {
+        let mut model = Recorder::new();
+        model.init = Some(|| {
+            batch(vec![
+                Some(after(Duration::from_millis(30), || Message::new("slow"))),
+                Some(after(Duration::from_millis(10), || Message::new("fast"))),
+            ])
+            .unwrap()
+        });
+        let mut program = WebProgram::new(model);
+        program.start(80, 24);
+
+        program.advance(Duration::from_millis(30));
+        assert_eq!(program.model().events, ["fast", "slow"]);
+    }
+
+    #[test]
+    fn resize_and_window_size_request_report_size() {
+        let mut model = Recorder::new();
+        model.on_key = |_| Some(window_size());
+        let mut program = WebProgram::new(model);
+        program.start(80, 24);
+        program.resize(100, 30);
+        program.write_input(b"a");
+
+        let size = WindowSizeMsg {
+            width: 100,
+            height: 30,
+        };
+        assert_eq!(program.model().sizes[1..], [size, size]);
+    }
+}
@@ -1,10 +1,11 @@
 //! Property-based tests for bubbletea message routing and command composition.
 //!
 //! bd-10x1: Verify message type system invariants, batch/sequence behavior,
-//! and command composition using proptest.
+//! and command composition using proptest, and that the input parser
+//! survives arbitrary byte streams.
 
 use bubbletea::message::{BatchMsg, Message, SequenceMsg};
-use bubbletea::{Cmd, batch, sequence};
+use bubbletea::{Cmd, InputParser, InterruptMsg, KeyMsg, QuitMsg, batch, sequence};
 use proptest::prelude::*;
 
 // =============================================================================
@@ -402,3 +403,86 @@ fn sequence_commands_preserve_payload_values() {
     // Sequence preserves order
     assert_eq!(results, vec![0, 10, 20, 30, 40]);
 }
+
+// =============================================================================
+// Input Parsing Resilience
+// =============================================================================
+
+/// Byte streams biased towards escape sequence fragments.
+fn terminal_bytes() -> impl Strategy<Value = Vec<u8>> {
+    let fragment = prop_oneof![
+        any::<u8>().prop_map(|b| vec![b]),
+        Just(b"\x1b".to_vec()),
+        Just(b"\x1b[".to_vec()),
+        Just(b"\x1b[<".to_vec()),
+        Just(b"\x1b[M".to_vec()),
+        Just(b"\x1b[200~".to_vec()),
+        Just(b"\x1b[201~".to_vec()),
+        Just(b"\x1bO".to_vec()),
+        "[0-9;]{0,8}".prop_map(String::into_bytes),
+        "[A-Za-z~]".prop_map(String::into_bytes),
+    ];
+    prop::collection::vec(fragment, 0..40).prop_map(|parts| parts.concat())
+}
+
+/// Parses `bytes` split into chunks at `cuts`, then flushes.
+fn parse_chunked(bytes: &[u8], cuts: &[usize]) -> Vec<Message> {
+    let mut parser = InputParser::new();
+    let mut msgs = Vec::new();
+    let mut start = 0;
+    for &cut in cuts {
+        let end = (start + cut).min(bytes.len());
+        msgs.extend(parser.push_bytes(&bytes[start..end], true));
+        start = end;
+    }
+    msgs.extend(parser.push_bytes(&bytes[start..], true));
+    msgs.extend(parser.flush());
+    assert!(!parser.has_pending());
+    msgs
+}
+
+proptest! {
+    /// Arbitrary bytes never panic, in one chunk or many, and leave nothing
+    /// buffered after a flush
+    #[test]
+    fn input_parser_never_panics(
+        bytes in terminal_bytes(),
+        cuts in prop::collection::vec(0usize..8, 0..16),
+    ) {
+        parse_chunked(&bytes, &[]);
+        parse_chunked(&bytes, &cuts);
+    }
+
+    /// Only a literal Ctrl+C byte can produce an interrupt, and input never
+    /// produces a quit
+    #[test]
+    fn input_parser_never_invents_quit(
+        bytes in terminal_bytes(),
+        cuts in prop::collection::vec(0usize..8, 0..16),
+    ) {
+        let msgs = parse_chunked(&bytes, &cuts);
+        prop_assert!(!msgs.iter().any(Message::is::<QuitMsg>));
+        if !bytes.contains(&0x03) {
+            prop_assert!(!msgs.iter().any(Message::is::<InterruptMsg>));
+        }
+    }
+
+    /// Garbage CSI sequences are swallowed whole instead of leaking their
+    /// parameter and final bytes as typed text
+    #[test]
+    fn unknown_csi_never_leaks_text(
+        params in "[0-9;]{1,12}",
+        final_byte in prop::sample::select(b"bcefghijklnopqrstvwxyz".to_vec()),
+    ) {
+        let mut bytes = b"\x1b[9".to_vec();
+        bytes.extend_from_slice(params.as_bytes());
+        bytes.push(final_byte);
+        bytes.push(b'!');
+        let msgs = parse_chunked(&bytes, &[]);
+        let keys: Vec<String> = msgs
+            .iter()
+            .filter_map(|m| m.downcast_ref::<KeyMsg>().map(ToString::to_string))
+            .collect();
+        prop_assert_eq!(keys, vec!["!".to_string()]);
+    }
+}
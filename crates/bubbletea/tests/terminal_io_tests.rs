@@ -6,7 +6,10 @@
 use std::io::Cursor;
 use std::sync::{Arc, Mutex};
 
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Model, Program, quit};
+use bubbletea::{
+    Cmd, KeyMsg, KeyType, Message, Model, NotificationProtocol, Program, notify, println, quit,
+    screen, sequence, set_window_title,
+};
 
 // ===========================================================================
 // Helpers
@@ -671,3 +674,203 @@ fn counter_model_final_state() {
 
     assert_eq!(model.count, 5, "Counter should be 5");
 }
+
+// ===========================================================================
+// 10. Window Title
+// ===========================================================================
+
+/// Model that sets the window title and quits on `q`.
+struct TitleModel;
+
+impl Model for TitleModel {
+    fn init(&self) -> Option<Cmd> {
+        None
+    }
+
+    fn update(&mut self, msg: Message) -> Option<Cmd> {
+        if msg.downcast_ref::<KeyMsg>().is_some() {
+            return sequence(vec![
+                Some(set_window_title("notes.md - editor")),
+                Some(quit()),
+            ]);
+        }
+        None
+    }
+
+    fn view(&self) -> String {
+        "editing".to_string()
+    }
+}
+
+#[test]
+fn window_title_is_set_and_cleared_on_exit() {
+    let input = Cursor::new(b"q".to_vec());
+    let writer = CaptureWriter::new();
+    let output_ref = writer.clone();
+
+    Program::new(TitleModel)
+        .with_input(input)
+        .with_output(writer)
+        .run()
+        .expect("program should complete");
+
+    let output = output_ref.output();
+    let set = output
+        .find("\x1b]0;notes.md - editor\x07")
+        .expect("title should be set");
+    let cleared = output
+        .rfind("\x1b]0;\x07")
+        .expect("title should be cleared on exit");
+    assert!(set < cleared, "title cleared after being set: {output:?}");
+}
+
+#[test]
+fn window_title_untouched_when_never_set() {
+    let input = Cursor::new(b"q".to_vec());
+    let writer = CaptureWriter::new();
+    let output_ref = writer.clone();
+
+    Program::new(EchoModel::default())
+        .with_input(input)
+        .with_output(writer)
+        .run()
+        .expect("program should complete");
+
+    assert!(!output_ref.output().contains("\x1b]0;"));
+}
+
+// ===========================================================================
+// 11. Notifications
+// ===========================================================================
+
+/// Model that sends a notification and quits on `q`.
+struct NotifyModel;
+
+impl Model for NotifyModel {
+    fn init(&self) -> Option<Cmd> {
+        None
+    }
+
+    fn update(&mut self, msg: Message) -> Option<Cmd> {
+        if msg.downcast_ref::<KeyMsg>().is_some() {
+            return sequence(vec![Some(notify("Build", "finished")), Some(quit())]);
+        }
+        None
+    }
+
+    fn view(&self) -> String {
+        "building".to_string()
+    }
+}
+
+#[test]
+fn notification_uses_configured_protocol() {
+    let input = Cursor::new(b"q".to_vec());
+    let writer = CaptureWriter::new();
+    let output_ref = writer.clone();
+
+    Program::new(NotifyModel)
+        .with_input(input)
+        .with_output(writer)
+        .with_notifications(NotificationProtocol::Osc777)
+        .run()
+        .expect("program should complete");
+
+    assert!(
+        output_ref
+            .output()
+            .contains("\x1b]777;notify;Build;finished\x07")
+    );
+}
+
+#[test]
+fn notification_ignored_without_protocol() {
+    let input = Cursor::new(b"q".to_vec());
+    let writer = CaptureWriter::new();
+    let output_ref = writer.clone();
+
+    Program::new(NotifyModel)
+        .with_input(input)
+        .with_output(writer)
+        .run()
+        .expect("program should complete");
+
+    let output = output_ref.output();
+    assert!(!output.contains("\x1b]777;"));
+    assert!(!output.contains("\x1b]9;"));
+}
+
+// ===========================================================================
+// 12. Inline and Alternate Screen Rendering
+// ===========================================================================
+
+/// Model that switches to the alternate screen on `a`, prints a line on
+/// `p`, and quits after either, or on `q`.
+struct ScreenModel;
+
+impl Model for ScreenModel {
+    fn init(&self) -> Option<Cmd> {
+        None
+    }
+
+    fn update(&mut self, msg: Message) -> Option<Cmd> {
+        let key = msg.downcast::<KeyMsg>()?;
+        if key.runes.contains(&'a') {
+            sequence(vec![Some(screen::enter_alt_screen()), Some(quit())])
+        } else if key.runes.contains(&'p') {
+            sequence(vec![Some(println("printed")), Some(quit())])
+        } else if key.runes.contains(&'q') {
+            Some(quit())
+        } else {
+            None
+        }
+    }
+
+    fn view(&self) -> String {
+        "header\nfooter".to_string()
+    }
+}
+
+fn run_screen_model(program: Program<ScreenModel>, input: &[u8]) -> String {
+    let writer = CaptureWriter::new();
+    let output_ref = writer.clone();
+    program
+        .with_input(Cursor::new(input.to_vec()))
+        .with_output(writer)
+        .run()
+        .expect("program should complete");
+    output_ref.output()
+}
+
+#[test]
+fn inline_rendering_keeps_the_screen_and_ends_on_a_new_line() {
+    let output = run_screen_model(Program::new(ScreenModel), b"p");
+
+    assert!(!output.contains("\x1b[2J"), "{output:?}");
+    assert!(!output.contains("\x1b[?1049h"), "{output:?}");
+    // Printed lines replace the frame, which is drawn again below them
+    assert!(
+        output.contains("header\r\nfooter\x1b[1A\x1b[1G\x1b[Jprinted\r\nheader\r\nfooter"),
+        "{output:?}"
+    );
+    assert!(output.ends_with("\r\n"), "{output:?}");
+}
+
+#[test]
+fn alt_screen_is_entered_and_left() {
+    let output = run_screen_model(Program::new(ScreenModel).with_alt_screen(), b"q");
+
+    assert!(output.starts_with("\x1b[?1049h"), "{output:?}");
+    assert!(output.contains("\x1b[2J"), "{output:?}");
+    assert!(output.ends_with("\x1b[?1049l"), "{output:?}");
+}
+
+#[test]
+fn alt_screen_command_switches_screens_until_exit() {
+    let output = run_screen_model(Program::new(ScreenModel), b"a");
+
+    let entered = output.find("\x1b[?1049h").expect("alt screen entered");
+    let left = output.rfind("\x1b[?1049l").expect("alt screen left");
+    assert!(entered < left);
+    assert!(output[entered..].contains("\x1b[2J"), "{output:?}");
+}
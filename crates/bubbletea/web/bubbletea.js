@@ -0,0 +1,72 @@
+// Runs a bubbletea program compiled to wasm32 on an xterm.js terminal.
+//
+// `app` is a `WebApp` returned by the application's wasm-bindgen exports
+// (see `bubbletea::web`), `term` an opened xterm.js `Terminal`. Input and
+// resizes go to the program, its output to the terminal, and the program's
+// timers run on `setTimeout`. Returns an object whose `dispose()` detaches
+// the program; it is detached by itself once the program quits, after which
+// `onExit` is called.
+export function attach(app, term, { onExit } = {}) {
+  const encoder = new TextEncoder();
+  let last = performance.now();
+  let timer;
+
+  // Moves the program's clock up to now, so delays count from the present.
+  const advance = () => {
+    const now = performance.now();
+    app.advance(now - last);
+    last = now;
+  };
+
+  const schedule = () => {
+    clearTimeout(timer);
+    const delay = app.nextDeadline();
+    if (delay !== undefined) {
+      timer = setTimeout(() => {
+        advance();
+        flush();
+      }, delay);
+    }
+  };
+
+  const flush = () => {
+    const output = app.takeOutput();
+    if (output.length > 0) {
+      term.write(output);
+    }
+    if (app.isFinished()) {
+      dispose();
+      onExit?.();
+    } else {
+      schedule();
+    }
+  };
+
+  const subscriptions = [
+    term.onData((data) => {
+      advance();
+      app.writeInput(encoder.encode(data));
+      flush();
+    }),
+    // Some mouse reports arrive as binary strings, one byte per character
+    term.onBinary((data) => {
+      advance();
+      app.writeInput(Uint8Array.from(data, (c) => c.charCodeAt(0) & 0xff));
+      flush();
+    }),
+    term.onResize(({ cols, rows }) => {
+      advance();
+      app.resize(cols, rows);
+      flush();
+    }),
+  ];
+
+  function dispose() {
+    clearTimeout(timer);
+    subscriptions.forEach((subscription) => subscription.dispose());
+  }
+
+  app.start(term.cols, term.rows);
+  flush();
+  return { dispose };
+}
@@ -6,7 +6,7 @@
 //! - App chrome rendering (header, sidebar, footer)
 
 use bubbletea::{
-    BlurMsg, Cmd, FocusMsg, KeyMsg, KeyType, Message, Model, WindowSizeMsg, batch, println, quit,
+    BlurMsg, Cmd, FocusMsg, KeyMod, KeyMsg, KeyType, Message, Model, WindowSizeMsg, batch, println, quit,
     set_window_title,
 };
 use lipgloss::{Position, Style};
@@ -2421,6 +2421,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         }));
         let tab_processed = sim.run_until_empty();
         eprintln!("After Tab: processed {} messages", tab_processed);
@@ -2436,6 +2437,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         }));
 
         // Process the Enter key, which should return a batch command
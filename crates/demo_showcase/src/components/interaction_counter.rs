@@ -31,7 +31,7 @@
 //! }
 //! ```
 
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Model};
+use bubbletea::{Cmd, KeyMod, KeyMsg, KeyType, Message, Model};
 
 use crate::theme::Theme;
 
@@ -340,6 +340,7 @@ mod tests {
             runes: vec!['+'],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         };
         counter.update(Message::new(key));
         assert_eq!(counter.count(), 1);
@@ -354,6 +355,7 @@ mod tests {
             runes: vec!['-'],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         };
         counter.update(Message::new(key));
         assert_eq!(counter.count(), 4);
@@ -368,6 +370,7 @@ mod tests {
             runes: vec!['0'],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         };
         counter.update(Message::new(key));
         assert_eq!(counter.count(), 0);
@@ -91,7 +91,7 @@
 // Allow dead code - this module defines the keymap spec for future implementation
 #![allow(dead_code)]
 
-use bubbletea::{KeyMsg, KeyType};
+use bubbletea::{KeyMod, KeyMsg, KeyType};
 
 /// Key binding categories for documentation and routing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -544,6 +544,7 @@ mod tests {
             runes: vec![c],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         }
     }
 
@@ -553,6 +554,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         }
     }
 
@@ -1138,7 +1138,7 @@ mod tests {
 // =============================================================================
 
 use bubbletea::simulator::ProgramSimulator;
-use bubbletea::{KeyMsg, KeyType, Message, MouseAction, MouseButton, MouseMsg, WindowSizeMsg};
+use bubbletea::{KeyMod, KeyMsg, KeyType, Message, MouseAction, MouseButton, MouseMsg, WindowSizeMsg};
 
 use crate::app::App;
 use crate::config::Config;
@@ -1238,6 +1238,7 @@ impl E2ERunner {
             runes: vec![c],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         });
         self.sim.send(msg);
         self.step_with_cmd(); // Process message and execute any returned command
@@ -1285,6 +1286,7 @@ impl E2ERunner {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         });
         self.sim.send(msg);
         self.step_with_cmd();
@@ -1301,6 +1303,7 @@ impl E2ERunner {
             runes: vec![c],
             alt: true,
             paste: false,
+            modifiers: KeyMod::NONE,
         });
         self.sim.send(msg);
         self.step_with_cmd();
@@ -1319,6 +1322,7 @@ impl E2ERunner {
             runes: text.chars().collect(),
             alt: false,
             paste: true,
+            modifiers: KeyMod::NONE,
         });
         self.sim.send(msg);
         self.step_with_cmd();
@@ -0,0 +1,44 @@
+//! Live preview - re-render a markdown file every time it is saved
+//!
+//! Run with: `cargo run -p glamour --features watch --example live_preview -- README.md`
+
+use std::io::Write;
+
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "README.md".to_string());
+
+    let _watcher = glamour::watch(&path, 80, |update| {
+        let update = match update {
+            Ok(update) => update,
+            Err(err) => {
+                eprintln!("failed to read file: {err}");
+                return;
+            }
+        };
+
+        let mut stdout = std::io::stdout().lock();
+        if update.is_initial() {
+            // Clear the screen once; later updates only repaint changed lines.
+            let _ = write!(stdout, "\x1b[2J");
+        }
+        let lines: Vec<&str> = update.output.lines().collect();
+        for &idx in &update.changed_lines {
+            let _ = write!(stdout, "\x1b[{};1H\x1b[2K{}", idx + 1, lines[idx]);
+        }
+        for idx in lines.len()..update.previous_line_count {
+            let _ = write!(stdout, "\x1b[{};1H\x1b[2K", idx + 1);
+        }
+        let _ = write!(stdout, "\x1b[{};1H", lines.len() + 1);
+        let _ = stdout.flush();
+    })
+    .unwrap_or_else(|err| {
+        eprintln!("failed to watch {path}: {err}");
+        std::process::exit(1);
+    });
+
+    loop {
+        std::thread::park();
+    }
+}
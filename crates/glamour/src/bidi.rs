@@ -0,0 +1,325 @@
+//! Reordering of right-to-left and mixed-direction text for display.
+//!
+//! Terminals lay out characters left to right in the order they are
+//! written, so Arabic or Hebrew text comes out backwards unless it is put in
+//! visual order first. [`reorder_lines`] runs the Unicode Bidirectional
+//! Algorithm over a wrapped paragraph and rewrites each line in the order it
+//! should appear on screen, keeping the styling and hyperlinks of every
+//! character.
+//!
+//! Embedding levels are resolved over the whole paragraph and lines are
+//! reordered one at a time, so reordering after wrapping keeps the logical
+//! first words on the first line. Brackets inside right-to-left runs are
+//! mirrored. Terminals that implement bidi themselves, such as VTE-based
+//! ones, should have reordering turned off with
+//! [`TermRenderer::with_bidi`](crate::TermRenderer::with_bidi).
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use unicode_bidi::BidiInfo;
+use unicode_width::UnicodeWidthChar;
+
+const RESET: &str = "\x1b[0m";
+const LINK_CLOSE: &str = "\x1b]8;;\x1b\\";
+
+/// Styling in effect at a character.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Styling {
+    /// SGR and other CSI sequences since the last reset.
+    sgr: String,
+    /// The OSC 8 sequence opening the current hyperlink.
+    link: String,
+}
+
+impl Styling {
+    /// Writes the escapes that switch from `self` to `to`.
+    fn switch_to(&self, to: &Styling, out: &mut String) {
+        if self.link != to.link {
+            if !self.link.is_empty() {
+                out.push_str(LINK_CLOSE);
+            }
+            out.push_str(&to.link);
+        }
+        if self.sgr != to.sgr {
+            if !self.sgr.is_empty() {
+                out.push_str(RESET);
+            }
+            out.push_str(&to.sgr);
+        }
+    }
+
+    /// Applies an escape sequence to the styling.
+    fn apply(&mut self, escape: &str) {
+        if let Some(body) = escape.strip_prefix("\x1b]8;") {
+            let url = body.split_once(';').map_or("", |(_, rest)| {
+                rest.trim_end_matches(['\x07', '\\', '\x1b'])
+            });
+            self.link = if url.is_empty() {
+                String::new()
+            } else {
+                escape.to_string()
+            };
+        } else if escape == RESET || escape == "\x1b[m" {
+            self.sgr.clear();
+        } else if escape.starts_with("\x1b[") {
+            self.sgr.push_str(escape);
+        }
+    }
+}
+
+/// A visible character with the zero-width characters that follow it.
+#[derive(Debug)]
+struct Cell {
+    /// Byte range of the characters in the line.
+    range: Range<usize>,
+    /// Index into the line's styling table.
+    styling: usize,
+}
+
+/// A line split into cells, with the styling in effect at each.
+#[derive(Debug)]
+struct Line<'a> {
+    text: &'a str,
+    cells: Vec<Cell>,
+    stylings: Vec<Styling>,
+    /// Styling in effect after the line.
+    end: Styling,
+}
+
+impl<'a> Line<'a> {
+    /// Splits `text` into cells, starting from the styling `start`.
+    fn parse(text: &'a str, start: Styling) -> Self {
+        let mut cells: Vec<Cell> = Vec::new();
+        let mut stylings = vec![start.clone()];
+        let mut current = start;
+        let mut offset = 0;
+        while let Some(c) = text[offset..].chars().next() {
+            if c == '\x1b' {
+                let len = escape_len(&text[offset..]);
+                current.apply(&text[offset..offset + len]);
+                offset += len;
+                continue;
+            }
+
+            let end = offset + c.len_utf8();
+            // Combining marks stay with the character before them
+            let combining = c.width() == Some(0) && !c.is_control();
+            match cells.last_mut() {
+                Some(cell) if combining && cell.range.end == offset => cell.range.end = end,
+                _ => {
+                    if stylings.last() != Some(&current) {
+                        stylings.push(current.clone());
+                    }
+                    cells.push(Cell {
+                        range: offset..end,
+                        styling: stylings.len() - 1,
+                    });
+                }
+            }
+            offset = end;
+        }
+        Self {
+            text,
+            cells,
+            stylings,
+            end: current,
+        }
+    }
+
+    /// Returns the characters of a cell.
+    fn cell(&self, index: usize) -> &'a str {
+        &self.text[self.cells[index].range.clone()]
+    }
+
+    /// Returns the text of the line without escapes.
+    fn plain(&self) -> String {
+        (0..self.cells.len()).map(|i| self.cell(i)).collect()
+    }
+}
+
+/// Returns `text` with every line in visual order.
+///
+/// `text` is one paragraph, already wrapped into lines. Lines without
+/// right-to-left characters are returned as they are.
+pub(crate) fn reorder_lines(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut styling = Styling::default();
+    let lines: Vec<Line<'_>> = text
+        .split('\n')
+        .map(|line| {
+            let parsed = Line::parse(line, styling.clone());
+            styling = parsed.end.clone();
+            parsed
+        })
+        .collect();
+
+    // Resolve levels over the whole paragraph, joining lines where they were
+    // broken at spaces
+    let mut plain = String::new();
+    let mut ranges: Vec<Range<usize>> = Vec::with_capacity(lines.len());
+    for line in &lines {
+        if !plain.is_empty() {
+            plain.push(' ');
+        }
+        let start = plain.len();
+        plain.push_str(&line.plain());
+        ranges.push(start..plain.len());
+    }
+    let info = BidiInfo::new(&plain, None);
+    if !info.has_rtl() {
+        return Cow::Borrowed(text);
+    }
+    let Some(para) = info.paragraphs.first() else {
+        return Cow::Borrowed(text);
+    };
+
+    let mut out = String::with_capacity(text.len());
+    // Styling to restore at the start of a line after a reordered one, which
+    // ends with everything closed
+    let mut reopen = Styling::default();
+    for (i, (parsed, range)) in lines.iter().zip(&ranges).enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let levels = &info.levels[range.clone()];
+        if range.is_empty() || !levels.iter().any(|level| level.is_rtl()) {
+            Styling::default().switch_to(&reopen, &mut out);
+            out.push_str(parsed.text);
+            reopen = Styling::default();
+            continue;
+        }
+
+        let (levels, runs) = info.visual_runs(para, range.clone());
+        let mut offsets = Vec::with_capacity(parsed.cells.len());
+        let mut offset = range.start;
+        for cell in &parsed.cells {
+            offsets.push(offset);
+            offset += cell.range.len();
+        }
+
+        let mut current = Styling::default();
+        for run in runs {
+            let rtl = levels[run.start].is_rtl();
+            let in_run = |index: &usize| run.contains(&offsets[*index]);
+            let order: Vec<usize> = if rtl {
+                (0..parsed.cells.len()).rev().filter(in_run).collect()
+            } else {
+                (0..parsed.cells.len()).filter(in_run).collect()
+            };
+            for index in order {
+                let styling = &parsed.stylings[parsed.cells[index].styling];
+                let cell = parsed.cell(index);
+                current.switch_to(styling, &mut out);
+                current = styling.clone();
+                let first = cell.chars().next().filter(|_| rtl);
+                match first.and_then(|c| mirror(c).map(|mirrored| (c, mirrored))) {
+                    Some((c, mirrored)) => {
+                        out.push(mirrored);
+                        out.push_str(&cell[c.len_utf8()..]);
+                    }
+                    None => out.push_str(cell),
+                }
+            }
+        }
+        current.switch_to(&Styling::default(), &mut out);
+        reopen = parsed.end.clone();
+    }
+    Cow::Owned(out)
+}
+
+/// Returns the length of the escape sequence at the start of `s`.
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    match bytes.get(1) {
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map_or(s.len(), |i| i + 3),
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    b'\x07' => return i + 1,
+                    b'\x1b' if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    _ => i += 1,
+                }
+            }
+            s.len()
+        }
+        Some(_) => 1 + s[1..].chars().next().map_or(0, char::len_utf8),
+        None => 1,
+    }
+}
+
+/// Returns the mirror image of a bracket, for display in right-to-left text.
+fn mirror(c: char) -> Option<char> {
+    Some(match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rtl_line_is_reversed() {
+        assert_eq!(reorder_lines("שלום עולם"), "םלוע םולש");
+        assert_eq!(reorder_lines("hello world"), "hello world");
+    }
+
+    #[test]
+    fn test_mixed_line_keeps_latin_order() {
+        // The paragraph starts with Latin, so the Hebrew run sits in place
+        assert_eq!(reorder_lines("see שלום עולם here"), "see םלוע םולש here");
+        // A right-to-left paragraph puts the Latin run on the left
+        assert_eq!(reorder_lines("שלום abc def"), "abc def םולש");
+    }
+
+    #[test]
+    fn test_lines_reordered_separately_with_paragraph_direction() {
+        // The second line is Latin only but belongs to a Hebrew paragraph
+        let text = "אבג דהו\nabc";
+        assert_eq!(reorder_lines(text), "והד גבא\nabc");
+    }
+
+    #[test]
+    fn test_brackets_mirrored_in_rtl_runs() {
+        assert_eq!(reorder_lines("אב (גד)"), "(דג) בא");
+        // Guillemets are two bytes wide in UTF-8
+        assert_eq!(reorder_lines("אב «גד»"), "«דג» בא");
+    }
+
+    #[test]
+    fn test_styling_follows_characters() {
+        let text = "\x1b[1mאב\x1b[0m גד";
+        assert_eq!(reorder_lines(text), "דג \x1b[1mבא\x1b[0m");
+    }
+
+    #[test]
+    fn test_styling_carried_to_next_line() {
+        let text = "\x1b[1mאב\ncd\x1b[0m";
+        assert_eq!(reorder_lines(text), "\x1b[1mבא\x1b[0m\n\x1b[1mcd\x1b[0m");
+    }
+
+    #[test]
+    fn test_combining_marks_stay_on_their_base() {
+        // Arabic with a shadda on the second letter
+        let text = "بدّ";
+        assert_eq!(reorder_lines(text), "دّب");
+    }
+}
@@ -0,0 +1,153 @@
+//! The parsed block structure of a markdown document, before layout.
+//!
+//! [`TermRenderer::blocks`](crate::TermRenderer::blocks) runs the same
+//! parsing and section filtering as
+//! [`render`](crate::TermRenderer::render) but stops before wrapping,
+//! indenting and block styling. Text in each [`Block`] already carries the
+//! renderer's inline styling (emphasis, strong, inline code, link URLs), so
+//! a custom layout engine only has to decide where the blocks go: split
+//! them into pages, lay them out side by side, and so on.
+//!
+//! ```rust
+//! use glamour::Renderer;
+//! use glamour::blocks::Block;
+//!
+//! let blocks = Renderer::new().blocks("# Title\n\nSome text.\n\n```rust\nfn main() {}\n```");
+//! assert!(matches!(&blocks[0], Block::Heading { text, .. } if text == "Title"));
+//! assert!(matches!(&blocks[2], Block::CodeBlock { language: Some(lang), .. } if lang == "rust"));
+//! ```
+
+use pulldown_cmark::HeadingLevel;
+
+use crate::table::ParsedTable;
+
+/// A block-level element of a markdown document.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum Block {
+    /// A heading, without the style's heading prefix.
+    Heading {
+        /// The heading level.
+        level: HeadingLevel,
+        /// The inline-styled heading text.
+        text: String,
+    },
+    /// A paragraph, unwrapped.
+    Paragraph {
+        /// The inline-styled paragraph text.
+        text: String,
+        /// How many block quotes the paragraph is nested in.
+        quote_depth: usize,
+    },
+    /// A single list item. Nested lists follow their parent item as items
+    /// with a greater depth.
+    ListItem {
+        /// Nesting depth, starting at 1 for top-level lists.
+        depth: usize,
+        /// The bullet, number or task marker from the style, such as `"• "`
+        /// or `"2. "`.
+        marker: String,
+        /// The inline-styled item text.
+        text: String,
+    },
+    /// A code block, with tabs expanded and without highlighting.
+    CodeBlock {
+        /// The language from the opening fence, if any.
+        language: Option<String>,
+        /// The code.
+        code: String,
+    },
+    /// A table, with inline-styled cells.
+    Table(ParsedTable),
+    /// An image.
+    Image {
+        /// The alt text.
+        alt: String,
+        /// The image URL.
+        url: String,
+    },
+    /// A horizontal rule.
+    Rule,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Renderer, Style};
+
+    fn blocks(markdown: &str) -> Vec<Block> {
+        Renderer::new().with_style(Style::Ascii).blocks(markdown)
+    }
+
+    #[test]
+    fn headings_and_paragraphs_keep_inline_styling() {
+        let blocks = blocks("## Intro\n\nSome *soft* and **loud** [text](https://x.dev).");
+        assert!(matches!(
+            &blocks[0],
+            Block::Heading { level: HeadingLevel::H2, text } if text == "Intro"
+        ));
+        assert!(matches!(
+            &blocks[1],
+            Block::Paragraph { text, quote_depth: 0 }
+                if text == "Some *soft* and **loud** text https://x.dev."
+        ));
+        assert_eq!(blocks.len(), 2);
+    }
+
+    #[test]
+    fn code_blocks_tables_lists_and_rules() {
+        let markdown = "```rust\nfn main() {}\n```\n\n    indented\n\n\
+                        | a | b |\n|---|--:|\n| 1 | 2 |\n\n\
+                        1. one\n   - nested\n2. two\n\n---\n\n> quoted";
+        let blocks = blocks(markdown);
+
+        assert!(matches!(
+            &blocks[0],
+            Block::CodeBlock { language: Some(lang), code }
+                if lang == "rust" && code == "fn main() {}\n"
+        ));
+        assert!(matches!(
+            &blocks[1],
+            Block::CodeBlock { language: None, .. }
+        ));
+
+        let Block::Table(table) = &blocks[2] else {
+            panic!("expected a table, got {:?}", blocks[2]);
+        };
+        assert_eq!(table.header[1].content, "b");
+        assert_eq!(table.rows[0][1].content, "2");
+        assert_eq!(table.alignments[1], pulldown_cmark::Alignment::Right);
+
+        let items: Vec<(usize, &str, &str)> = blocks[3..6]
+            .iter()
+            .map(|block| match block {
+                Block::ListItem {
+                    depth,
+                    marker,
+                    text,
+                } => (*depth, marker.as_str(), text.as_str()),
+                other => panic!("expected a list item, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            items,
+            [(1, "1. ", "one"), (2, "• ", "nested"), (1, "2. ", "two")]
+        );
+
+        assert!(matches!(blocks[6], Block::Rule));
+        assert!(matches!(
+            &blocks[7],
+            Block::Paragraph { text, quote_depth: 1 } if text == "quoted"
+        ));
+    }
+
+    #[test]
+    fn section_filter_applies_to_blocks() {
+        let renderer = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_section_filter(|path| path.first().is_some_and(|h| h == "Keep"));
+        let blocks = renderer.blocks("# Drop\n\ngone\n\n# Keep\n\nkept");
+        assert_eq!(blocks.len(), 2);
+        assert!(matches!(&blocks[1], Block::Paragraph { text, .. } if text == "kept"));
+    }
+}
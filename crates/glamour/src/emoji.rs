@@ -0,0 +1,530 @@
+//! Replacement of emoji shortcodes such as `:tada:` with the emoji they
+//! name.
+//!
+//! The names follow GitHub's gemoji set, covering its commonly used
+//! shortcodes. Unknown shortcodes are left as they are.
+
+use std::borrow::Cow;
+
+/// Shortcodes and their emoji, sorted by name for binary search.
+const EMOJI: &[(&str, &str)] = &[
+    ("+1", "👍"),
+    ("-1", "👎"),
+    ("100", "💯"),
+    ("airplane", "✈\u{fe0f}"),
+    ("alarm_clock", "⏰"),
+    ("alien", "👽"),
+    ("angel", "👼"),
+    ("angry", "😠"),
+    ("ant", "🐜"),
+    ("apple", "🍎"),
+    ("arrow_down", "⬇\u{fe0f}"),
+    ("arrow_forward", "▶\u{fe0f}"),
+    ("arrow_left", "⬅\u{fe0f}"),
+    ("arrow_right", "➡\u{fe0f}"),
+    ("arrow_right_hook", "↪\u{fe0f}"),
+    ("arrow_up", "⬆\u{fe0f}"),
+    ("arrows_counterclockwise", "🔄"),
+    ("art", "🎨"),
+    ("astonished", "😲"),
+    ("avocado", "🥑"),
+    ("baby", "👶"),
+    ("baby_chick", "🐤"),
+    ("balloon", "🎈"),
+    ("ballot_box_with_check", "☑\u{fe0f}"),
+    ("banana", "🍌"),
+    ("bangbang", "‼\u{fe0f}"),
+    ("bar_chart", "📊"),
+    ("basketball", "🏀"),
+    ("bat", "🦇"),
+    ("battery", "🔋"),
+    ("bear", "🐻"),
+    ("bee", "🐝"),
+    ("beer", "🍺"),
+    ("beers", "🍻"),
+    ("bell", "🔔"),
+    ("bike", "🚲"),
+    ("bird", "🐦"),
+    ("birthday", "🎂"),
+    ("black_circle", "⚫"),
+    ("black_heart", "🖤"),
+    ("blue_heart", "💙"),
+    ("blush", "😊"),
+    ("bomb", "💣"),
+    ("book", "📖"),
+    ("bookmark", "🔖"),
+    ("books", "📚"),
+    ("boom", "💥"),
+    ("bouquet", "💐"),
+    ("bow", "🙇"),
+    ("boy", "👦"),
+    ("brain", "🧠"),
+    ("bread", "🍞"),
+    ("broken_heart", "💔"),
+    ("bug", "🐛"),
+    ("bulb", "💡"),
+    ("bus", "🚌"),
+    ("butterfly", "🦋"),
+    ("cactus", "🌵"),
+    ("cake", "🍰"),
+    ("calendar", "📆"),
+    ("camel", "🐫"),
+    ("camera", "📷"),
+    ("car", "🚗"),
+    ("carrot", "🥕"),
+    ("cat", "🐱"),
+    ("chains", "⛓\u{fe0f}"),
+    ("champagne", "🍾"),
+    ("chart_with_downwards_trend", "📉"),
+    ("chart_with_upwards_trend", "📈"),
+    ("checkered_flag", "🏁"),
+    ("cherries", "🍒"),
+    ("cherry_blossom", "🌸"),
+    ("chicken", "🐔"),
+    ("clap", "👏"),
+    ("clapper", "🎬"),
+    ("clipboard", "📋"),
+    ("closed_lock_with_key", "🔐"),
+    ("cloud", "☁\u{fe0f}"),
+    ("clown_face", "🤡"),
+    ("cocktail", "🍸"),
+    ("coffee", "☕"),
+    ("collision", "💥"),
+    ("comet", "☄\u{fe0f}"),
+    ("computer", "💻"),
+    ("confetti_ball", "🎊"),
+    ("confused", "😕"),
+    ("construction", "🚧"),
+    ("cookie", "🍪"),
+    ("cool", "🆒"),
+    ("copyright", "©\u{fe0f}"),
+    ("corn", "🌽"),
+    ("cow", "🐮"),
+    ("crab", "🦀"),
+    ("crescent_moon", "🌙"),
+    ("crocodile", "🐊"),
+    ("crossed_fingers", "🤞"),
+    ("crown", "👑"),
+    ("cry", "😢"),
+    ("crystal_ball", "🔮"),
+    ("cyclone", "🌀"),
+    ("dart", "🎯"),
+    ("dash", "💨"),
+    ("date", "📅"),
+    ("desktop_computer", "🖥\u{fe0f}"),
+    ("disappointed", "😞"),
+    ("dizzy", "💫"),
+    ("dna", "🧬"),
+    ("dog", "🐶"),
+    ("dollar", "💵"),
+    ("dolphin", "🐬"),
+    ("door", "🚪"),
+    ("doughnut", "🍩"),
+    ("dragon", "🐉"),
+    ("droplet", "💧"),
+    ("duck", "🦆"),
+    ("eagle", "🦅"),
+    ("earth_africa", "🌍"),
+    ("earth_americas", "🌎"),
+    ("earth_asia", "🌏"),
+    ("egg", "🥚"),
+    ("eight", "8\u{fe0f}\u{20e3}"),
+    ("electric_plug", "🔌"),
+    ("elephant", "🐘"),
+    ("email", "📧"),
+    ("envelope", "✉\u{fe0f}"),
+    ("evergreen_tree", "🌲"),
+    ("exclamation", "❗"),
+    ("exploding_head", "🤯"),
+    ("eye", "👁\u{fe0f}"),
+    ("eyes", "👀"),
+    ("facepalm", "🤦"),
+    ("factory", "🏭"),
+    ("fallen_leaf", "🍂"),
+    ("fire", "🔥"),
+    ("fireworks", "🎆"),
+    ("fish", "🐟"),
+    ("fist", "✊"),
+    ("five", "5\u{fe0f}\u{20e3}"),
+    ("flamingo", "🦩"),
+    ("floppy_disk", "💾"),
+    ("flushed", "😳"),
+    ("football", "🏈"),
+    ("four", "4\u{fe0f}\u{20e3}"),
+    ("four_leaf_clover", "🍀"),
+    ("fox_face", "🦊"),
+    ("fries", "🍟"),
+    ("frog", "🐸"),
+    ("full_moon", "🌕"),
+    ("game_die", "🎲"),
+    ("gear", "⚙\u{fe0f}"),
+    ("gem", "💎"),
+    ("ghost", "👻"),
+    ("gift", "🎁"),
+    ("giraffe", "🦒"),
+    ("girl", "👧"),
+    ("globe_with_meridians", "🌐"),
+    ("goat", "🐐"),
+    ("golf", "⛳"),
+    ("grapes", "🍇"),
+    ("green_circle", "🟢"),
+    ("green_heart", "💚"),
+    ("grey_question", "❔"),
+    ("grimacing", "😬"),
+    ("grin", "😁"),
+    ("grinning", "😀"),
+    ("guitar", "🎸"),
+    ("hamburger", "🍔"),
+    ("hammer", "🔨"),
+    ("hammer_and_wrench", "🛠\u{fe0f}"),
+    ("hamster", "🐹"),
+    ("hand", "✋"),
+    ("handshake", "🤝"),
+    ("hankey", "💩"),
+    ("hash", "#\u{fe0f}\u{20e3}"),
+    ("headphones", "🎧"),
+    ("heart", "❤\u{fe0f}"),
+    ("heart_eyes", "😍"),
+    ("heavy_check_mark", "✔\u{fe0f}"),
+    ("heavy_minus_sign", "➖"),
+    ("heavy_multiplication_x", "✖\u{fe0f}"),
+    ("heavy_plus_sign", "➕"),
+    ("hedgehog", "🦔"),
+    ("herb", "🌿"),
+    ("hibiscus", "🌺"),
+    ("horse", "🐴"),
+    ("hospital", "🏥"),
+    ("hot_pepper", "🌶\u{fe0f}"),
+    ("hotdog", "🌭"),
+    ("hotel", "🏨"),
+    ("hourglass", "⌛"),
+    ("hourglass_flowing_sand", "⏳"),
+    ("house", "🏠"),
+    ("hugs", "🤗"),
+    ("hushed", "😯"),
+    ("ice_cream", "🍨"),
+    ("inbox_tray", "📥"),
+    ("infinity", "♾\u{fe0f}"),
+    ("information_source", "ℹ\u{fe0f}"),
+    ("innocent", "😇"),
+    ("jack_o_lantern", "🎃"),
+    ("joy", "😂"),
+    ("key", "🔑"),
+    ("keyboard", "⌨\u{fe0f}"),
+    ("kiss", "💋"),
+    ("kissing_heart", "😘"),
+    ("knife", "🔪"),
+    ("koala", "🐨"),
+    ("label", "🏷\u{fe0f}"),
+    ("ladybug", "🐞"),
+    ("laughing", "😆"),
+    ("leaves", "🍃"),
+    ("leftwards_arrow_with_hook", "↩\u{fe0f}"),
+    ("lemon", "🍋"),
+    ("link", "🔗"),
+    ("lipstick", "💄"),
+    ("lizard", "🦎"),
+    ("llama", "🦙"),
+    ("lobster", "🦞"),
+    ("lock", "🔒"),
+    ("lock_with_ink_pen", "🔏"),
+    ("loudspeaker", "📢"),
+    ("love_letter", "💌"),
+    ("mag", "🔍"),
+    ("mag_right", "🔎"),
+    ("magnet", "🧲"),
+    ("mailbox", "📫"),
+    ("man", "👨"),
+    ("maple_leaf", "🍁"),
+    ("mask", "😷"),
+    ("mega", "📣"),
+    ("memo", "📝"),
+    ("microbe", "🦠"),
+    ("microphone", "🎤"),
+    ("microscope", "🔬"),
+    ("milky_way", "🌌"),
+    ("money_mouth_face", "🤑"),
+    ("moneybag", "💰"),
+    ("monkey", "🐒"),
+    ("moon", "🌔"),
+    ("mortar_board", "🎓"),
+    ("mountain", "⛰\u{fe0f}"),
+    ("mouse", "🐭"),
+    ("movie_camera", "🎥"),
+    ("muscle", "💪"),
+    ("mushroom", "🍄"),
+    ("musical_keyboard", "🎹"),
+    ("musical_note", "🎵"),
+    ("mute", "🔇"),
+    ("nauseated_face", "🤢"),
+    ("negative_squared_cross_mark", "❎"),
+    ("nerd_face", "🤓"),
+    ("neutral_face", "😐"),
+    ("new", "🆕"),
+    ("new_moon", "🌑"),
+    ("newspaper", "📰"),
+    ("nine", "9\u{fe0f}\u{20e3}"),
+    ("no_entry", "⛔"),
+    ("no_entry_sign", "🚫"),
+    ("notebook", "📓"),
+    ("nut_and_bolt", "🔩"),
+    ("o", "⭕"),
+    ("ocean", "🌊"),
+    ("octopus", "🐙"),
+    ("office", "🏢"),
+    ("ok", "🆗"),
+    ("ok_hand", "👌"),
+    ("one", "1\u{fe0f}\u{20e3}"),
+    ("open_mouth", "😮"),
+    ("orange_circle", "🟠"),
+    ("orange_heart", "🧡"),
+    ("outbox_tray", "📤"),
+    ("owl", "🦉"),
+    ("package", "📦"),
+    ("page_facing_up", "📄"),
+    ("palm_tree", "🌴"),
+    ("panda_face", "🐼"),
+    ("paperclip", "📎"),
+    ("parrot", "🦜"),
+    ("partly_sunny", "⛅"),
+    ("partying_face", "🥳"),
+    ("peach", "🍑"),
+    ("peacock", "🦚"),
+    ("pear", "🍐"),
+    ("pencil", "📝"),
+    ("pencil2", "✏\u{fe0f}"),
+    ("penguin", "🐧"),
+    ("pensive", "😔"),
+    ("phone", "☎\u{fe0f}"),
+    ("pick", "⛏\u{fe0f}"),
+    ("pig", "🐷"),
+    ("pill", "💊"),
+    ("pizza", "🍕"),
+    ("point_down", "👇"),
+    ("point_left", "👈"),
+    ("point_right", "👉"),
+    ("point_up", "☝\u{fe0f}"),
+    ("point_up_2", "👆"),
+    ("poop", "💩"),
+    ("popcorn", "🍿"),
+    ("potato", "🥔"),
+    ("pray", "🙏"),
+    ("punch", "👊"),
+    ("purple_circle", "🟣"),
+    ("purple_heart", "💜"),
+    ("pushpin", "📌"),
+    ("question", "❓"),
+    ("rabbit", "🐰"),
+    ("rage", "😡"),
+    ("rainbow", "🌈"),
+    ("raised_hands", "🙌"),
+    ("ramen", "🍜"),
+    ("rat", "🐀"),
+    ("recycle", "♻\u{fe0f}"),
+    ("red_circle", "🔴"),
+    ("registered", "®\u{fe0f}"),
+    ("relaxed", "☺\u{fe0f}"),
+    ("relieved", "😌"),
+    ("repeat", "🔁"),
+    ("rice", "🍚"),
+    ("robot", "🤖"),
+    ("rocket", "🚀"),
+    ("rofl", "🤣"),
+    ("rooster", "🐓"),
+    ("rose", "🌹"),
+    ("rotating_light", "🚨"),
+    ("runner", "🏃"),
+    ("santa", "🎅"),
+    ("satellite", "📡"),
+    ("school", "🏫"),
+    ("scissors", "✂\u{fe0f}"),
+    ("scorpion", "🦂"),
+    ("scream", "😱"),
+    ("see_no_evil", "🙈"),
+    ("seedling", "🌱"),
+    ("seven", "7\u{fe0f}\u{20e3}"),
+    ("shark", "🦈"),
+    ("sheep", "🐑"),
+    ("shield", "🛡\u{fe0f}"),
+    ("ship", "🚢"),
+    ("shrimp", "🦐"),
+    ("shrug", "🤷"),
+    ("six", "6\u{fe0f}\u{20e3}"),
+    ("skull", "💀"),
+    ("skull_and_crossbones", "☠\u{fe0f}"),
+    ("sleeping", "😴"),
+    ("sleepy", "😪"),
+    ("slightly_smiling_face", "🙂"),
+    ("sloth", "🦥"),
+    ("smile", "😄"),
+    ("smiley", "😃"),
+    ("smirk", "😏"),
+    ("snail", "🐌"),
+    ("snake", "🐍"),
+    ("snowflake", "❄\u{fe0f}"),
+    ("snowman", "⛄"),
+    ("sob", "😭"),
+    ("soccer", "⚽"),
+    ("sos", "🆘"),
+    ("sound", "🔉"),
+    ("sparkles", "✨"),
+    ("sparkling_heart", "💖"),
+    ("speech_balloon", "💬"),
+    ("spider", "🕷\u{fe0f}"),
+    ("squid", "🦑"),
+    ("star", "⭐"),
+    ("star2", "🌟"),
+    ("star_struck", "🤩"),
+    ("stars", "🌠"),
+    ("stop_sign", "🛑"),
+    ("stopwatch", "⏱\u{fe0f}"),
+    ("strawberry", "🍓"),
+    ("stuck_out_tongue", "😛"),
+    ("sun_with_face", "🌞"),
+    ("sunflower", "🌻"),
+    ("sunglasses", "😎"),
+    ("sunny", "☀\u{fe0f}"),
+    ("sunrise", "🌅"),
+    ("sushi", "🍣"),
+    ("swan", "🦢"),
+    ("sweat", "😓"),
+    ("sweat_drops", "💦"),
+    ("sweat_smile", "😅"),
+    ("syringe", "💉"),
+    ("t-rex", "🦖"),
+    ("taco", "🌮"),
+    ("tada", "🎉"),
+    ("tea", "🍵"),
+    ("telescope", "🔭"),
+    ("tennis", "🎾"),
+    ("tent", "⛺"),
+    ("test_tube", "🧪"),
+    ("thermometer", "🌡\u{fe0f}"),
+    ("thinking", "🤔"),
+    ("thought_balloon", "💭"),
+    ("three", "3\u{fe0f}\u{20e3}"),
+    ("thumbsdown", "👎"),
+    ("thumbsup", "👍"),
+    ("tiger", "🐯"),
+    ("tired_face", "😫"),
+    ("tm", "™\u{fe0f}"),
+    ("tomato", "🍅"),
+    ("tongue", "👅"),
+    ("toolbox", "🧰"),
+    ("triangular_flag_on_post", "🚩"),
+    ("trophy", "🏆"),
+    ("tropical_fish", "🐠"),
+    ("truck", "🚚"),
+    ("tulip", "🌷"),
+    ("turtle", "🐢"),
+    ("tv", "📺"),
+    ("two", "2\u{fe0f}\u{20e3}"),
+    ("two_hearts", "💕"),
+    ("umbrella", "☔"),
+    ("unamused", "😒"),
+    ("unicorn", "🦄"),
+    ("unlock", "🔓"),
+    ("upside_down_face", "🙃"),
+    ("v", "✌\u{fe0f}"),
+    ("vertical_traffic_light", "🚦"),
+    ("video_game", "🎮"),
+    ("volcano", "🌋"),
+    ("warning", "⚠\u{fe0f}"),
+    ("watch", "⌚"),
+    ("watermelon", "🍉"),
+    ("wave", "👋"),
+    ("weary", "😩"),
+    ("whale", "🐳"),
+    ("white_check_mark", "✅"),
+    ("white_circle", "⚪"),
+    ("white_flag", "🏳\u{fe0f}"),
+    ("white_heart", "🤍"),
+    ("wine_glass", "🍷"),
+    ("wink", "😉"),
+    ("wolf", "🐺"),
+    ("woman", "👩"),
+    ("worried", "😟"),
+    ("wrench", "🔧"),
+    ("writing_hand", "✍\u{fe0f}"),
+    ("x", "❌"),
+    ("yellow_circle", "🟡"),
+    ("yellow_heart", "💛"),
+    ("yum", "😋"),
+    ("zap", "⚡"),
+    ("zebra", "🦓"),
+    ("zero", "0\u{fe0f}\u{20e3}"),
+    ("zipper_mouth_face", "🤐"),
+    ("zzz", "💤"),
+];
+
+/// Returns the emoji for a shortcode name, given without its colons.
+pub(crate) fn lookup(name: &str) -> Option<&'static str> {
+    EMOJI
+        .binary_search_by(|(candidate, _)| candidate.cmp(&name))
+        .ok()
+        .map(|i| EMOJI[i].1)
+}
+
+/// Replaces the known `:name:` shortcodes in `text` with their emoji.
+pub(crate) fn replace_shortcodes(text: &str) -> Cow<'_, str> {
+    let mut output = String::new();
+    let mut copied = 0;
+    let mut search = 0;
+    while let Some(open) = text[search..].find(':').map(|i| search + i) {
+        let Some(close) = text[open + 1..].find(':').map(|i| open + 1 + i) else {
+            break;
+        };
+        let name = &text[open + 1..close];
+        let valid = !name.is_empty()
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'+' | b'-'));
+        match lookup(name).filter(|_| valid) {
+            Some(emoji) => {
+                output.push_str(&text[copied..open]);
+                output.push_str(emoji);
+                copied = close + 1;
+                search = close + 1;
+            }
+            // The closing colon may open the next shortcode
+            None => search = close,
+        }
+    }
+    if copied == 0 {
+        return Cow::Borrowed(text);
+    }
+    output.push_str(&text[copied..]);
+    Cow::Owned(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_sorted() {
+        assert!(EMOJI.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup("tada"), Some("🎉"));
+        assert_eq!(lookup("+1"), Some("👍"));
+        assert_eq!(lookup("t-rex"), Some("🦖"));
+        assert_eq!(lookup("heart"), Some("\u{2764}\u{fe0f}"));
+        assert_eq!(lookup("not_an_emoji"), None);
+    }
+
+    #[test]
+    fn test_replace_shortcodes() {
+        assert_eq!(replace_shortcodes("Done :tada:!"), "Done 🎉!");
+        assert_eq!(replace_shortcodes(":+1::rocket:"), "👍🚀");
+        assert_eq!(
+            replace_shortcodes("At 10:30 :white_check_mark: shipped"),
+            "At 10:30 ✅ shipped"
+        );
+        assert_eq!(replace_shortcodes(":unknown: :tada"), ":unknown: :tada");
+        assert_eq!(replace_shortcodes("a :: b : c"), "a :: b : c");
+        assert!(matches!(replace_shortcodes("no codes"), Cow::Borrowed(_)));
+    }
+}
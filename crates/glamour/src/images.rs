@@ -0,0 +1,638 @@
+//! Inline images for terminals with a graphics protocol.
+//!
+//! With an [`ImageProtocol`] set through
+//! [`TermRenderer::with_images`](crate::TermRenderer::with_images), images
+//! whose source is a local file are drawn in place instead of being rendered
+//! as the `Image: alt → url` placeholder:
+//!
+//! - [`Kitty`](ImageProtocol::Kitty) sends PNG files with the kitty graphics
+//!   protocol.
+//! - [`Iterm2`](ImageProtocol::Iterm2) sends files of any format the terminal
+//!   decodes with iTerm2's inline image escape.
+//! - [`Sixel`](ImageProtocol::Sixel) decodes 8-bit PNG files and draws them as
+//!   sixels with a 216-color palette.
+//!
+//! Relative paths are resolved against the renderer's base URL when it names
+//! a local directory. Remote images, files over the size limit and formats
+//! the protocol can't draw keep the text placeholder.
+//!
+//! ```rust,no_run
+//! use glamour::Renderer;
+//! use glamour::images::ImageProtocol;
+//!
+//! let output = Renderer::new()
+//!     .with_images(ImageProtocol::detect())
+//!     .with_base_url("docs/")
+//!     .render("![Architecture](architecture.png)");
+//! print!("{output}");
+//! ```
+
+use std::fs::File;
+use std::io::{IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+/// Largest image file that is loaded, in bytes, unless set otherwise with
+/// [`TermRenderer::with_image_size_limit`](crate::TermRenderer::with_image_size_limit).
+pub const DEFAULT_IMAGE_SIZE_LIMIT: u64 = 5 * 1024 * 1024;
+
+/// Width of a terminal cell in pixels, assumed when sizing images.
+const CELL_WIDTH_PX: usize = 10;
+
+/// Largest number of pixels a PNG may have to be decoded for sixels.
+const MAX_DECODED_PIXELS: usize = 4096 * 4096;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// An escape sequence protocol for drawing images in a terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageProtocol {
+    /// The kitty graphics protocol, also spoken by Ghostty and WezTerm.
+    Kitty,
+    /// iTerm2's inline images, also spoken by WezTerm and VS Code.
+    Iterm2,
+    /// DEC sixel graphics, spoken by foot, mlterm, xterm and others.
+    Sixel,
+}
+
+impl ImageProtocol {
+    /// Returns the protocol the terminal on stdout supports, if any.
+    ///
+    /// The terminal is recognized from its environment variables; `None` is
+    /// returned when stdout is not a terminal or the terminal is unknown.
+    pub fn detect() -> Option<Self> {
+        detect_protocol(
+            |name| std::env::var(name).ok(),
+            std::io::stdout().is_terminal(),
+        )
+    }
+}
+
+fn detect_protocol(
+    var: impl Fn(&str) -> Option<String>,
+    is_terminal: bool,
+) -> Option<ImageProtocol> {
+    if !is_terminal {
+        return None;
+    }
+    let program = var("TERM_PROGRAM").unwrap_or_default();
+    let term = var("TERM").unwrap_or_default();
+    if var("KITTY_WINDOW_ID").is_some() || term == "xterm-kitty" || program == "ghostty" {
+        return Some(ImageProtocol::Kitty);
+    }
+    if matches!(program.as_str(), "iTerm.app" | "WezTerm" | "vscode") {
+        return Some(ImageProtocol::Iterm2);
+    }
+    if term.contains("sixel") || term.starts_with("foot") || term.starts_with("mlterm") {
+        return Some(ImageProtocol::Sixel);
+    }
+    None
+}
+
+/// Loads the image at `url` and encodes it for `protocol`, at most
+/// `max_columns` cells wide.
+///
+/// Returns `None` when the image can't be drawn, in which case the caller
+/// renders the text placeholder.
+pub(crate) fn render_image(
+    protocol: ImageProtocol,
+    url: &str,
+    base_url: Option<&str>,
+    size_limit: u64,
+    max_columns: usize,
+) -> Option<String> {
+    let path = local_path(url, base_url)?;
+    let metadata = std::fs::metadata(&path).ok()?;
+    if !metadata.is_file() || metadata.len() > size_limit {
+        return None;
+    }
+    // The file may have grown since it was checked
+    let mut data = Vec::new();
+    File::open(&path)
+        .ok()?
+        .take(size_limit.saturating_add(1))
+        .read_to_end(&mut data)
+        .ok()?;
+    if data.len() as u64 > size_limit {
+        return None;
+    }
+    let max_columns = max_columns.max(1);
+    match protocol {
+        ImageProtocol::Kitty => {
+            let (width, _) = png_size(&data)?;
+            Some(encode_kitty(&data, columns_for(width, max_columns)))
+        }
+        ImageProtocol::Iterm2 => {
+            let columns = png_size(&data).map(|(width, _)| columns_for(width, max_columns));
+            Some(encode_iterm2(&data, columns))
+        }
+        ImageProtocol::Sixel => {
+            let image = decode_png(&data)?;
+            Some(encode_sixel(&image.scaled_to(max_columns * CELL_WIDTH_PX)))
+        }
+    }
+}
+
+/// Returns the file an image URL refers to, or `None` for remote images.
+fn local_path(url: &str, base_url: Option<&str>) -> Option<PathBuf> {
+    if let Some(path) = url.strip_prefix("file://") {
+        return Some(PathBuf::from(path));
+    }
+    if url.contains("://") || url.starts_with("data:") {
+        return None;
+    }
+    let path = Path::new(url);
+    match base_url {
+        Some(base) if path.is_relative() => {
+            let base = base.strip_prefix("file://").unwrap_or(base);
+            (!base.contains("://")).then(|| Path::new(base).join(path))
+        }
+        _ => Some(path.to_path_buf()),
+    }
+}
+
+/// Returns how many cells an image `width` pixels wide takes.
+fn columns_for(width: usize, max_columns: usize) -> usize {
+    width.div_ceil(CELL_WIDTH_PX).clamp(1, max_columns)
+}
+
+/// Encodes a PNG file as kitty graphics commands, transmitted in chunks of
+/// at most 4096 bytes as the protocol requires.
+fn encode_kitty(png: &[u8], columns: usize) -> String {
+    let encoded = STANDARD.encode(png);
+    let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(4096).collect();
+    let mut out = String::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 < chunks.len());
+        if i == 0 {
+            out.push_str(&format!("\x1b_Ga=T,f=100,q=2,c={columns},m={more};"));
+        } else {
+            out.push_str(&format!("\x1b_Gm={more};"));
+        }
+        out.push_str(std::str::from_utf8(chunk).unwrap_or_default());
+        out.push_str("\x1b\\");
+    }
+    out
+}
+
+/// Encodes an image file with iTerm2's inline image escape, `columns` cells
+/// wide when known.
+fn encode_iterm2(data: &[u8], columns: Option<usize>) -> String {
+    let width = columns.map_or_else(|| "auto".to_string(), |columns| columns.to_string());
+    format!(
+        "\x1b]1337;File=inline=1;size={};width={width};preserveAspectRatio=1:{}\x07",
+        data.len(),
+        STANDARD.encode(data)
+    )
+}
+
+/// Decoded image pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rgba {
+    width: usize,
+    height: usize,
+    /// Four bytes per pixel, row by row.
+    pixels: Vec<u8>,
+}
+
+impl Rgba {
+    fn pixel(&self, x: usize, y: usize) -> [u8; 4] {
+        let i = (y * self.width + x) * 4;
+        [
+            self.pixels[i],
+            self.pixels[i + 1],
+            self.pixels[i + 2],
+            self.pixels[i + 3],
+        ]
+    }
+
+    /// Shrinks the image to at most `max_width` pixels wide, keeping its
+    /// aspect ratio.
+    fn scaled_to(&self, max_width: usize) -> Self {
+        if self.width <= max_width {
+            return self.clone();
+        }
+        let width = max_width.max(1);
+        let height = (self.height * width / self.width).max(1);
+        let mut pixels = Vec::with_capacity(width * height * 4);
+        for y in 0..height {
+            for x in 0..width {
+                pixels.extend(self.pixel(x * self.width / width, y * self.height / height));
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+/// Returns the width and height of a PNG file.
+fn png_size(data: &[u8]) -> Option<(usize, usize)> {
+    if !data.starts_with(PNG_SIGNATURE) || data.get(12..16)? != b"IHDR" {
+        return None;
+    }
+    Some((
+        be_u32(data.get(16..)?)? as usize,
+        be_u32(data.get(20..)?)? as usize,
+    ))
+}
+
+fn be_u32(bytes: &[u8]) -> Option<u32> {
+    Some(u32::from_be_bytes(bytes.get(..4)?.try_into().ok()?))
+}
+
+/// Decodes a non-interlaced PNG file with 8 bits per channel.
+fn decode_png(data: &[u8]) -> Option<Rgba> {
+    let (width, height) = png_size(data)?;
+    let header = data.get(24..29)?;
+    let (bit_depth, color_type, interlace) = (header[0], header[1], header[4]);
+    let channels = match color_type {
+        0 => 1,
+        2 => 3,
+        3 => 1,
+        4 => 2,
+        6 => 4,
+        _ => return None,
+    };
+    if bit_depth != 8 || interlace != 0 || width == 0 || height == 0 {
+        return None;
+    }
+    if width.checked_mul(height)? > MAX_DECODED_PIXELS {
+        return None;
+    }
+
+    let mut palette: &[u8] = &[];
+    let mut alphas: &[u8] = &[];
+    let mut compressed = Vec::new();
+    let mut rest = &data[PNG_SIGNATURE.len()..];
+    while rest.len() >= 12 {
+        let len = be_u32(rest)? as usize;
+        let kind = &rest[4..8];
+        let body = rest.get(8..8 + len)?;
+        match kind {
+            b"PLTE" => palette = body,
+            b"tRNS" => alphas = body,
+            b"IDAT" => compressed.extend_from_slice(body),
+            b"IEND" => break,
+            _ => {}
+        }
+        rest = rest.get(12 + len..)?;
+    }
+
+    let stride = width * channels;
+    let raw =
+        miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(&compressed, (stride + 1) * height)
+            .ok()?;
+    let rows = unfilter(&raw, stride, height, channels)?;
+
+    let mut pixels = Vec::with_capacity(width * height * 4);
+    for px in rows.chunks_exact(channels) {
+        let rgba = match color_type {
+            0 => [px[0], px[0], px[0], 255],
+            2 => [px[0], px[1], px[2], 255],
+            3 => {
+                let i = px[0] as usize;
+                let rgb = palette.get(i * 3..i * 3 + 3)?;
+                [
+                    rgb[0],
+                    rgb[1],
+                    rgb[2],
+                    alphas.get(i).copied().unwrap_or(255),
+                ]
+            }
+            4 => [px[0], px[0], px[0], px[1]],
+            _ => [px[0], px[1], px[2], px[3]],
+        };
+        pixels.extend(rgba);
+    }
+    Some(Rgba {
+        width,
+        height,
+        pixels,
+    })
+}
+
+/// Reverses the per-row PNG filters, returning the rows without their
+/// filter bytes.
+fn unfilter(raw: &[u8], stride: usize, height: usize, bpp: usize) -> Option<Vec<u8>> {
+    if raw.len() < (stride + 1) * height {
+        return None;
+    }
+    let mut out = vec![0u8; stride * height];
+    for y in 0..height {
+        let filter = raw[y * (stride + 1)];
+        let line = &raw[y * (stride + 1) + 1..(y + 1) * (stride + 1)];
+        let (done, row) = out.split_at_mut(y * stride);
+        let prev = if y > 0 {
+            &done[(y - 1) * stride..]
+        } else {
+            &[][..]
+        };
+        let row = &mut row[..stride];
+        for x in 0..stride {
+            let a = if x >= bpp { row[x - bpp] } else { 0 };
+            let b = prev.get(x).copied().unwrap_or(0);
+            let c = if x >= bpp {
+                prev.get(x - bpp).copied().unwrap_or(0)
+            } else {
+                0
+            };
+            let predicted = match filter {
+                0 => 0,
+                1 => a,
+                2 => b,
+                3 => ((u16::from(a) + u16::from(b)) / 2) as u8,
+                4 => paeth(a, b, c),
+                _ => return None,
+            };
+            row[x] = line[x].wrapping_add(predicted);
+        }
+    }
+    Some(out)
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = i16::from(a) + i16::from(b) - i16::from(c);
+    let (pa, pb, pc) = (
+        (p - i16::from(a)).abs(),
+        (p - i16::from(b)).abs(),
+        (p - i16::from(c)).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+/// Encodes an image as sixels, mapping each pixel to a 6×6×6 color cube
+/// and leaving mostly transparent pixels unpainted.
+fn encode_sixel(image: &Rgba) -> String {
+    let index = |[r, g, b, a]: [u8; 4]| {
+        (a >= 128).then(|| {
+            let level = |v: u8| (usize::from(v) * 5 + 127) / 255;
+            level(r) * 36 + level(g) * 6 + level(b)
+        })
+    };
+
+    let mut out = format!("\x1bP0;1;0q\"1;1;{};{}", image.width, image.height);
+    for i in 0..216 {
+        let percent = |level: usize| level * 100 / 5;
+        out.push_str(&format!(
+            "#{i};2;{};{};{}",
+            percent(i / 36),
+            percent(i / 6 % 6),
+            percent(i % 6)
+        ));
+    }
+
+    for band in (0..image.height).step_by(6) {
+        let rows = (image.height - band).min(6);
+        // Sixel bits per color for every column of the band
+        let mut colors: Vec<(usize, Vec<u8>)> = Vec::new();
+        for x in 0..image.width {
+            for dy in 0..rows {
+                let Some(color) = index(image.pixel(x, band + dy)) else {
+                    continue;
+                };
+                let bits = match colors.iter_mut().find(|(c, _)| *c == color) {
+                    Some((_, bits)) => bits,
+                    None => {
+                        colors.push((color, vec![0; image.width]));
+                        &mut colors.last_mut().expect("just pushed").1
+                    }
+                };
+                bits[x] |= 1 << dy;
+            }
+        }
+        for (i, (color, bits)) in colors.iter().enumerate() {
+            if i > 0 {
+                out.push('$');
+            }
+            out.push_str(&format!("#{color}"));
+            push_sixel_runs(&mut out, bits);
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out
+}
+
+/// Appends a row of sixels, run-length encoding repeats.
+fn push_sixel_runs(out: &mut String, bits: &[u8]) {
+    let mut i = 0;
+    while i < bits.len() {
+        let run = bits[i..].iter().take_while(|&&b| b == bits[i]).count();
+        let ch = char::from(0x3f + bits[i]);
+        if run > 3 {
+            out.push_str(&format!("!{run}{ch}"));
+        } else {
+            (0..run).for_each(|_| out.push(ch));
+        }
+        i += run;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a PNG of RGBA pixels stored without compression.
+    fn png(width: u32, height: u32, rgba: &[u8]) -> Vec<u8> {
+        fn chunk(out: &mut Vec<u8>, kind: &[u8], body: &[u8]) {
+            out.extend((body.len() as u32).to_be_bytes());
+            out.extend(kind);
+            out.extend(body);
+            out.extend([0; 4]); // CRCs are not checked
+        }
+        let mut raw = Vec::new();
+        for row in rgba.chunks(width as usize * 4) {
+            raw.push(0);
+            raw.extend(row);
+        }
+        let mut ihdr = Vec::new();
+        ihdr.extend(width.to_be_bytes());
+        ihdr.extend(height.to_be_bytes());
+        ihdr.extend([8, 6, 0, 0, 0]);
+
+        let mut out = PNG_SIGNATURE.to_vec();
+        chunk(&mut out, b"IHDR", &ihdr);
+        chunk(
+            &mut out,
+            b"IDAT",
+            &miniz_oxide::deflate::compress_to_vec_zlib(&raw, 6),
+        );
+        chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    #[test]
+    fn test_detect_protocol() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            detect_protocol(env(&[("TERM", "xterm-kitty")]), true),
+            Some(ImageProtocol::Kitty)
+        );
+        assert_eq!(
+            detect_protocol(env(&[("TERM_PROGRAM", "iTerm.app")]), true),
+            Some(ImageProtocol::Iterm2)
+        );
+        assert_eq!(
+            detect_protocol(env(&[("TERM", "foot")]), true),
+            Some(ImageProtocol::Sixel)
+        );
+        assert_eq!(
+            detect_protocol(env(&[("TERM", "xterm-256color")]), true),
+            None
+        );
+        assert_eq!(
+            detect_protocol(env(&[("TERM", "xterm-kitty")]), false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_local_path() {
+        assert_eq!(
+            local_path("img.png", Some("docs")),
+            Some(PathBuf::from("docs/img.png"))
+        );
+        assert_eq!(
+            local_path("file:///tmp/a.png", None),
+            Some(PathBuf::from("/tmp/a.png"))
+        );
+        assert_eq!(local_path("https://example.com/a.png", None), None);
+        assert_eq!(local_path("img.png", Some("https://example.com/")), None);
+    }
+
+    #[test]
+    fn test_decode_png() {
+        let pixels = [255, 0, 0, 255, 0, 255, 0, 128, 0, 0, 255, 0, 9, 9, 9, 255];
+        let image = decode_png(&png(2, 2, &pixels)).unwrap();
+        assert_eq!((image.width, image.height), (2, 2));
+        assert_eq!(image.pixels, pixels);
+        assert_eq!(decode_png(b"GIF89a"), None);
+        // A header cut short after the size
+        assert_eq!(decode_png(&png(2, 2, &pixels)[..26]), None);
+        assert_eq!(png_size(&png(2, 2, &pixels)[..18]), None);
+    }
+
+    #[test]
+    fn test_unfilter_paeth_and_average() {
+        // Three 2-pixel grayscale rows, filtered with `Sub`, `Paeth` and
+        // `Average`
+        let raw = [1, 10, 5, 4, 3, 7, 3, 1, 1];
+        let rows = unfilter(&raw, 2, 3, 1).unwrap();
+        assert_eq!(rows, [10, 15, 13, 22, 7, 15]);
+    }
+
+    #[test]
+    fn test_encode_sixel() {
+        // Red above a transparent pixel, then white below green
+        let pixels = [
+            255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 0, 0, 255, 255, 255, 255,
+        ];
+        let sixel = encode_sixel(&decode_png(&png(2, 2, &pixels)).unwrap());
+        assert!(sixel.starts_with("\x1bP0;1;0q\"1;1;2;2#0;2;0;0;0"));
+        assert!(sixel.ends_with("#180@?$#30?@$#215?A-\x1b\\"), "{sixel}");
+    }
+
+    #[test]
+    fn test_sixel_runs() {
+        let mut out = String::new();
+        push_sixel_runs(&mut out, &[1, 1, 1, 1, 1, 0, 0, 63]);
+        assert_eq!(out, "!5@??~");
+    }
+
+    #[test]
+    fn test_scaled_to() {
+        let image = Rgba {
+            width: 40,
+            height: 20,
+            pixels: vec![7; 40 * 20 * 4],
+        };
+        let scaled = image.scaled_to(10);
+        assert_eq!((scaled.width, scaled.height), (10, 5));
+        assert_eq!(image.scaled_to(100), image);
+    }
+
+    #[test]
+    fn test_render_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = png(25, 1, &[200; 25 * 4]);
+        std::fs::write(dir.path().join("a.png"), &data).unwrap();
+        std::fs::write(dir.path().join("a.gif"), b"GIF89a").unwrap();
+        let base = dir.path().to_str();
+
+        let kitty = render_image(ImageProtocol::Kitty, "a.png", base, 1 << 20, 80).unwrap();
+        assert!(kitty.starts_with("\x1b_Ga=T,f=100,q=2,c=3,m=0;"));
+        assert!(kitty.contains(&STANDARD.encode(&data)));
+
+        let iterm = render_image(ImageProtocol::Iterm2, "a.gif", base, 1 << 20, 2).unwrap();
+        assert!(iterm.starts_with("\x1b]1337;File=inline=1;size=6;width=auto;"));
+        let iterm = render_image(ImageProtocol::Iterm2, "a.png", base, 1 << 20, 2).unwrap();
+        assert!(iterm.contains(";width=2;"));
+
+        let sixel = render_image(ImageProtocol::Sixel, "a.png", base, 1 << 20, 1).unwrap();
+        assert!(sixel.starts_with("\x1bP0;1;0q\"1;1;10;1"));
+
+        // Kitty takes only PNG, and files over the limit are skipped
+        assert_eq!(
+            render_image(ImageProtocol::Kitty, "a.gif", base, 1 << 20, 80),
+            None
+        );
+        assert_eq!(
+            render_image(ImageProtocol::Kitty, "a.png", base, 10, 80),
+            None
+        );
+        assert_eq!(
+            render_image(ImageProtocol::Sixel, "missing.png", base, 1 << 20, 80),
+            None
+        );
+        // Only regular files are read
+        std::fs::create_dir(dir.path().join("dir.png")).unwrap();
+        assert_eq!(
+            render_image(ImageProtocol::Iterm2, "dir.png", base, 1 << 20, 80),
+            None
+        );
+    }
+
+    #[test]
+    fn test_renderer_draws_local_images() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.png"), png(1, 1, &[0; 4])).unwrap();
+        let renderer = crate::Renderer::new()
+            .with_style(crate::Style::Ascii)
+            .with_images(Some(ImageProtocol::Kitty))
+            .with_base_url(dir.path().to_string_lossy());
+
+        let output = renderer.render("![Logo](a.png)");
+        assert!(output.contains("\x1b_Ga=T,f=100"));
+        assert!(!output.contains("Image: Logo"));
+
+        let output = renderer.render("![Logo](https://example.com/a.png)");
+        assert!(output.contains("Image: Logo"));
+        assert!(!output.contains("\x1b_G"));
+    }
+
+    #[test]
+    fn test_kitty_chunks() {
+        let out = encode_kitty(&[0; 6000], 4);
+        assert_eq!(out.matches("\x1b_G").count(), 2);
+        assert!(out.contains("m=1;"));
+        assert!(out.contains("\x1b\\\x1b_Gm=0;"));
+    }
+}
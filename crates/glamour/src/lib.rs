@@ -13,6 +13,7 @@
 //! - Styled headings, lists, and tables
 //! - Code block formatting with optional syntax highlighting
 //! - Link and image handling
+//! - Footnotes, collected in a section at the end of the document
 //! - Customizable themes (Dark, Light, ASCII, Pink)
 //!
 //! ## Example
@@ -31,6 +32,48 @@
 //! let output = renderer.render("# Heading\n\nParagraph text.");
 //! ```
 //!
+//! ## Streaming
+//!
+//! [`TermRenderer::stream`] renders markdown that arrives in chunks, such as
+//! the reply of a language model, without rendering the whole document
+//! again for every chunk. See [`stream`] for details.
+//!
+//! ## Links
+//!
+//! Links are rendered as `text URL` by default.
+//! [`TermRenderer::with_hyperlinks`] renders them as clickable OSC 8
+//! hyperlinks instead; pass [`supports_hyperlinks`] to enable them on
+//! terminals that support them.
+//!
+//! ## Table of Contents
+//!
+//! [`TermRenderer::with_toc`] lists the document's headings at the top of
+//! the output, and [`extract_toc`] returns them with GitHub-style anchor
+//! slugs for building a jump list. A heading's `{#id}` attribute replaces
+//! its slug; classes are accepted and ignored.
+//!
+//! ## HTML
+//!
+//! Raw HTML in the markdown is reduced to its text by default. Set
+//! [`AnsiOptions::html_mode`] with [`TermRenderer::with_html_mode`] to drop
+//! it, pass it through verbatim, or render common tags such as `<b>` and
+//! `<details>` like their markdown equivalents; see [`HtmlMode`].
+//!
+//! ## Emoji
+//!
+//! [`TermRenderer::with_emoji`] replaces shortcodes such as `:tada:` with
+//! the emoji they name, outside of code spans and code blocks. With
+//! [`EmojiMode::Auto`], shortcodes are kept on terminals that likely can't
+//! draw emoji; see [`supports_emoji`].
+//!
+//! ## Untrusted Input
+//!
+//! Escape sequences and other control characters in the markdown are
+//! removed before rendering, so text such as an issue body can't retitle
+//! the terminal or move the cursor. Use
+//! [`TermRenderer::with_sanitized_input`] to pass them through for trusted
+//! content.
+//!
 //! ## Feature Flags
 //!
 //! - `syntax-highlighting`: Enable syntax highlighting for code blocks using
@@ -47,6 +90,25 @@
 //! When enabled, code blocks with language annotations (e.g., ` ```rust `)
 //! will be rendered with syntax highlighting using the configured theme.
 //! See `docs/SYNTAX_HIGHLIGHTING_RESEARCH.md` for implementation details.
+//!
+//! - `watch`: Enable [`watch()`] for re-rendering a markdown file whenever it
+//!   changes on disk, for live previews and editor integrations.
+//!
+//! - `blocks`: Enable [`TermRenderer::blocks`], which returns the parsed
+//!   [`blocks::Block`]s of a document before layout, for custom pagination
+//!   or side-by-side layouts.
+//!
+//! - `images`: Enable [`TermRenderer::with_images`], which draws local
+//!   images with the kitty, iTerm2 or sixel graphics protocols instead of
+//!   the `Image:` placeholder. See [`images`] for details.
+//!
+//! - `bidi`: Reorder paragraphs containing right-to-left scripts such as
+//!   Arabic and Hebrew into display order after wrapping, using the Unicode
+//!   Bidirectional Algorithm. See [`TermRenderer::with_bidi`].
+//!
+//! - `serde`: Enable serialization of the style types, and
+//!   [`StyleConfig::from_json`] for loading Go glamour style files such as
+//!   `dracula.json`.
 
 // Syntax highlighting module (optional feature)
 #[cfg(feature = "syntax-highlighting")]
@@ -55,21 +117,52 @@ pub mod syntax;
 // Table parsing module for markdown tables
 pub mod table;
 
+// File watching for live re-rendering (optional feature)
+#[cfg(feature = "watch")]
+pub mod watch;
+#[cfg(feature = "watch")]
+pub use watch::{MarkdownWatcher, RenderUpdate, watch, watch_with_renderer};
+
+// Incremental rendering of streamed markdown
+pub mod stream;
+pub use stream::{StreamRenderer, StreamUpdate};
+
+// Parsed block model for custom layouts (optional feature)
+#[cfg(feature = "blocks")]
+pub mod blocks;
+
+// Inline images through terminal graphics protocols (optional feature)
+#[cfg(feature = "images")]
+pub mod images;
+
+// Bidirectional text reordering (optional feature)
+#[cfg(feature = "bidi")]
+mod bidi;
+
+// Emoji shortcode table
+mod emoji;
+
 use lipgloss::Style as LipglossStyle;
-use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
-use std::collections::HashMap;
-#[cfg(feature = "syntax-highlighting")]
-use std::collections::HashSet;
+pub(crate) use lipgloss::visible_width;
+use pulldown_cmark::{
+    BlockQuoteKind, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, OnceLock};
+use unicode_width::UnicodeWidthChar;
 
 // Conditional serde import
-#[cfg(all(feature = "syntax-highlighting", feature = "serde"))]
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Default width for word wrapping.
 const DEFAULT_WIDTH: usize = 80;
 const DEFAULT_MARGIN: usize = 2;
+/// Tab stop interval used when expanding tabs in code blocks.
+const CODE_TAB_WIDTH: usize = 4;
 const DEFAULT_LIST_INDENT: usize = 2;
 const DEFAULT_LIST_LEVEL_INDENT: usize = 4;
+const DEFAULT_BULLETS: [&str; 3] = ["• ", "◦ ", "▪ "];
 
 // ============================================================================
 // Style Configuration Types
@@ -77,30 +170,43 @@ const DEFAULT_LIST_LEVEL_INDENT: usize = 4;
 
 /// Primitive style settings for text elements.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct StylePrimitive {
     /// Prefix added before the block.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "String::is_empty"))]
     pub block_prefix: String,
     /// Suffix added after the block.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "String::is_empty"))]
     pub block_suffix: String,
     /// Prefix added before text.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "String::is_empty"))]
     pub prefix: String,
     /// Suffix added after text.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "String::is_empty"))]
     pub suffix: String,
     /// Foreground color (ANSI color code or hex).
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub color: Option<String>,
     /// Background color (ANSI color code or hex).
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub background_color: Option<String>,
     /// Whether text is underlined.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub underline: Option<bool>,
     /// Whether text is bold.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub bold: Option<bool>,
     /// Whether text is italic.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub italic: Option<bool>,
     /// Whether text has strikethrough.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub crossed_out: Option<bool>,
     /// Whether text is faint.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub faint: Option<bool>,
     /// Format string for special elements (e.g., "Image: {{.text}}").
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "String::is_empty"))]
     pub format: String,
 }
 
@@ -214,15 +320,28 @@ impl StylePrimitive {
 
 /// Block-level style settings.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct StyleBlock {
     /// Primitive style settings.
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub style: StylePrimitive,
     /// Indentation level.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub indent: Option<usize>,
     /// Token used for indentation.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub indent_token: Option<String>,
     /// Margin around the block.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub margin: Option<usize>,
+    /// Blank lines above the block. Only headings use it, and it defaults
+    /// to one.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub margin_top: Option<usize>,
+    /// Blank lines below the block. Only headings use it, and it defaults
+    /// to one.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub margin_bottom: Option<usize>,
 }
 
 impl StyleBlock {
@@ -254,14 +373,29 @@ impl StyleBlock {
         self.margin = Some(m);
         self
     }
+
+    /// Sets the number of blank lines above the block.
+    pub fn margin_top(mut self, lines: usize) -> Self {
+        self.margin_top = Some(lines);
+        self
+    }
+
+    /// Sets the number of blank lines below the block.
+    pub fn margin_bottom(mut self, lines: usize) -> Self {
+        self.margin_bottom = Some(lines);
+        self
+    }
 }
 
 /// Code block style settings.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct StyleCodeBlock {
     /// Block style settings.
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub block: StyleBlock,
     /// Syntax highlighting theme name.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub theme: Option<String>,
 }
 
@@ -286,11 +420,120 @@ impl StyleCodeBlock {
 
 /// List style settings.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct StyleList {
     /// Block style settings.
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub block: StyleBlock,
     /// Additional indent per nesting level.
     pub level_indent: usize,
+    /// Bullet prefixes for unordered items by nesting depth, starting at the
+    /// top level. Deeper levels reuse the last entry; when empty every level
+    /// uses `item.block_prefix`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub bullets: Vec<String>,
+    /// Numbering of ordered items by nesting depth, starting at the top
+    /// level. Deeper levels reuse the last entry; when empty every level is
+    /// numbered with arabic numerals.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub numbering: Vec<ListNumbering>,
+}
+
+/// How the items of an ordered list are numbered.
+///
+/// Numbers that a style can't represent, such as 0 in letters or roman
+/// numerals, fall back to arabic numerals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+pub enum ListNumbering {
+    /// `1`, `2`, `3`.
+    #[default]
+    Arabic,
+    /// `a`, `b`, `c`, continuing with `aa` after `z`.
+    LowerAlpha,
+    /// `A`, `B`, `C`, continuing with `AA` after `Z`.
+    UpperAlpha,
+    /// `i`, `ii`, `iii`, up to 3999.
+    LowerRoman,
+    /// `I`, `II`, `III`, up to 3999.
+    UpperRoman,
+}
+
+impl ListNumbering {
+    /// Formats item number `n`.
+    pub fn format(self, n: usize) -> String {
+        let numeral = match self {
+            Self::Arabic => None,
+            Self::LowerAlpha | Self::UpperAlpha => alpha_numeral(n),
+            Self::LowerRoman | Self::UpperRoman => roman_numeral(n),
+        };
+        match (numeral, self) {
+            (None, _) => n.to_string(),
+            (Some(numeral), Self::UpperAlpha | Self::UpperRoman) => numeral.to_ascii_uppercase(),
+            (Some(numeral), _) => numeral,
+        }
+    }
+
+    /// Returns the width of the widest number from `first` to `last`.
+    fn width(self, first: usize, last: usize) -> usize {
+        match self {
+            Self::Arabic => self.format(last.max(first)).len(),
+            _ => (first..=last.max(first))
+                .map(|n| self.format(n).len())
+                .max()
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// Returns `n` in bijective base 26 (`a`, ..., `z`, `aa`, ...), or `None`
+/// for 0.
+fn alpha_numeral(mut n: usize) -> Option<String> {
+    if n == 0 {
+        return None;
+    }
+    let mut letters = Vec::new();
+    while n > 0 {
+        n -= 1;
+        letters.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    letters.reverse();
+    String::from_utf8(letters).ok()
+}
+
+/// Returns `n` in lowercase roman numerals, or `None` outside 1 to 3999.
+fn roman_numeral(mut n: usize) -> Option<String> {
+    const NUMERALS: [(usize, &str); 13] = [
+        (1000, "m"),
+        (900, "cm"),
+        (500, "d"),
+        (400, "cd"),
+        (100, "c"),
+        (90, "xc"),
+        (50, "l"),
+        (40, "xl"),
+        (10, "x"),
+        (9, "ix"),
+        (5, "v"),
+        (4, "iv"),
+        (1, "i"),
+    ];
+    if !(1..4000).contains(&n) {
+        return None;
+    }
+    let mut numeral = String::new();
+    for (value, symbol) in NUMERALS {
+        while n >= value {
+            numeral.push_str(symbol);
+            n -= value;
+        }
+    }
+    Some(numeral)
 }
 
 impl StyleList {
@@ -313,19 +556,43 @@ impl StyleList {
         self.level_indent = i;
         self
     }
+
+    /// Sets the bullet prefixes used at each nesting depth.
+    pub fn bullets<S: Into<String>>(mut self, bullets: impl IntoIterator<Item = S>) -> Self {
+        self.bullets = bullets.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the numbering of ordered items at each nesting depth.
+    pub fn numbering(mut self, numbering: impl IntoIterator<Item = ListNumbering>) -> Self {
+        self.numbering = numbering.into_iter().collect();
+        self
+    }
 }
 
 /// Table style settings.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct StyleTable {
     /// Block style settings.
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub block: StyleBlock,
     /// Center separator character.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub center_separator: Option<String>,
     /// Column separator character.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub column_separator: Option<String>,
     /// Row separator character.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub row_separator: Option<String>,
+    /// How cells wider than their column are fitted when the table is
+    /// narrowed to the word wrap width. Defaults to truncation.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub overflow: Option<table::TableOverflow>,
+    /// Ending of truncated cells. Defaults to `…`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub ellipsis: Option<String>,
 }
 
 impl StyleTable {
@@ -346,16 +613,32 @@ impl StyleTable {
         self.row_separator = Some(row.into());
         self
     }
+
+    /// Sets how cells wider than their column are fitted.
+    pub fn overflow(mut self, overflow: table::TableOverflow) -> Self {
+        self.overflow = Some(overflow);
+        self
+    }
+
+    /// Sets the ending of truncated cells.
+    pub fn ellipsis(mut self, ellipsis: impl Into<String>) -> Self {
+        self.ellipsis = Some(ellipsis.into());
+        self
+    }
 }
 
 /// Task item style settings.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct StyleTask {
     /// Primitive style settings.
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub style: StylePrimitive,
     /// Marker for checked items.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "String::is_empty"))]
     pub ticked: String,
     /// Marker for unchecked items.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "String::is_empty"))]
     pub unticked: String,
 }
 
@@ -382,6 +665,74 @@ impl StyleTask {
     }
 }
 
+/// Callout style settings for GitHub-style alerts (`> [!NOTE]`, `> [!WARNING]`, ...).
+///
+/// Each style colors the callout's title and its quote bar.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct StyleCallouts {
+    /// Style for `[!NOTE]` callouts.
+    pub note: StylePrimitive,
+    /// Style for `[!TIP]` callouts.
+    pub tip: StylePrimitive,
+    /// Style for `[!IMPORTANT]` callouts.
+    pub important: StylePrimitive,
+    /// Style for `[!WARNING]` callouts.
+    pub warning: StylePrimitive,
+    /// Style for `[!CAUTION]` callouts.
+    pub caution: StylePrimitive,
+}
+
+impl StyleCallouts {
+    /// Creates a new callout style.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the style for a callout kind.
+    fn get(&self, kind: BlockQuoteKind) -> &StylePrimitive {
+        match kind {
+            BlockQuoteKind::Note => &self.note,
+            BlockQuoteKind::Tip => &self.tip,
+            BlockQuoteKind::Important => &self.important,
+            BlockQuoteKind::Warning => &self.warning,
+            BlockQuoteKind::Caution => &self.caution,
+        }
+    }
+}
+
+/// Footnote style settings.
+///
+/// References such as `[^1]` are styled in the text, and each footnote at
+/// the end of the document starts with its styled label.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
+pub struct StyleFootnotes {
+    /// Style for references in the text.
+    pub reference: StylePrimitive,
+    /// Style for the labels in the footnote section.
+    pub label: StylePrimitive,
+}
+
+impl StyleFootnotes {
+    /// Creates a new footnote style.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the reference style.
+    pub fn reference(mut self, r: StylePrimitive) -> Self {
+        self.reference = r;
+        self
+    }
+
+    /// Sets the label style.
+    pub fn label(mut self, l: StylePrimitive) -> Self {
+        self.label = l;
+        self
+    }
+}
+
 // ============================================================================
 // Syntax Highlighting Configuration (optional feature)
 // ============================================================================
@@ -412,7 +763,7 @@ impl StyleTask {
 /// ```
 #[cfg(feature = "syntax-highlighting")]
 #[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct SyntaxThemeConfig {
     /// Theme name (e.g., "base16-ocean.dark", "Solarized (dark)").
     /// Use `SyntaxTheme::available_themes()` to see all options.
@@ -618,12 +969,19 @@ impl SyntaxThemeConfig {
 
 /// Complete style configuration for rendering.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
 pub struct StyleConfig {
     // Document
     pub document: StyleBlock,
 
     // Block elements
     pub block_quote: StyleBlock,
+    /// Per-depth overrides for nested block quotes, cycled when quotes nest
+    /// deeper than the list. Each entry's color and indent token style the
+    /// bar for that depth; when empty every depth uses `block_quote`.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub block_quote_levels: Vec<StyleBlock>,
+    pub callouts: StyleCallouts,
     pub paragraph: StyleBlock,
     pub list: StyleList,
 
@@ -635,12 +993,16 @@ pub struct StyleConfig {
     pub h4: StyleBlock,
     pub h5: StyleBlock,
     pub h6: StyleBlock,
+    /// Style of the glyph added after headings by
+    /// [`TermRenderer::with_heading_anchors`].
+    pub heading_anchor: StylePrimitive,
 
     // Inline elements
     pub text: StylePrimitive,
     pub strikethrough: StylePrimitive,
     pub emph: StylePrimitive,
     pub strong: StylePrimitive,
+    #[cfg_attr(feature = "serde", serde(rename = "hr"))]
     pub horizontal_rule: StylePrimitive,
 
     // List items
@@ -654,6 +1016,9 @@ pub struct StyleConfig {
     pub image: StylePrimitive,
     pub image_text: StylePrimitive,
 
+    // Footnotes
+    pub footnotes: StyleFootnotes,
+
     // Code
     pub code: StyleBlock,
     pub code_block: StyleCodeBlock,
@@ -677,6 +1042,61 @@ impl StyleConfig {
         Self::default()
     }
 
+    /// Parses a style config from JSON in the format of Go glamour's style
+    /// files.
+    ///
+    /// Settings missing from the JSON are left empty, and settings this
+    /// renderer doesn't support, such as `chroma` or `html_block`, are
+    /// ignored.
+    ///
+    /// This method is only available when the `serde` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use glamour::StyleConfig;
+    ///
+    /// let config = StyleConfig::from_json(
+    ///     r##"{"h1": {"prefix": "# ", "color": "228", "bold": true}, "hr": {"format": "---"}}"##,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(config.h1.style.color.as_deref(), Some("228"));
+    /// assert_eq!(config.horizontal_rule.format, "---");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` isn't a valid style config.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Reads a style config from a JSON style file, as with
+    /// [`StyleConfig::from_json`].
+    ///
+    /// This method is only available when the `serde` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read, or with kind
+    /// [`std::io::ErrorKind::InvalidData`] if it isn't a valid style config.
+    #[cfg(feature = "serde")]
+    pub fn from_json_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Self::from_json(&json)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
+
+    /// Writes the style config as pretty-printed JSON that
+    /// [`StyleConfig::from_json`] reads back, leaving out empty settings.
+    ///
+    /// This method is only available when the `serde` feature is enabled.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("style configs serialize to JSON")
+    }
+
     /// Gets the style for a heading level.
     pub fn heading_style(&self, level: HeadingLevel) -> &StyleBlock {
         match level {
@@ -689,6 +1109,60 @@ impl StyleConfig {
         }
     }
 
+    /// Gets the blank lines kept above and below a heading, from the level's
+    /// style, falling back to `heading` and then to one line each.
+    ///
+    /// Where a heading directly follows another, the larger of the first
+    /// one's bottom margin and the second one's top margin is used.
+    pub fn heading_margins(&self, level: HeadingLevel) -> (usize, usize) {
+        let level_style = self.heading_style(level);
+        let top = level_style.margin_top.or(self.heading.margin_top);
+        let bottom = level_style.margin_bottom.or(self.heading.margin_bottom);
+        (top.unwrap_or(1), bottom.unwrap_or(1))
+    }
+
+    /// Gets the bullet prefix for an unordered item at a nesting depth
+    /// (starting at 1).
+    pub fn bullet(&self, depth: usize) -> &str {
+        let bullets = &self.list.bullets;
+        match bullets.get(depth.max(1) - 1).or(bullets.last()) {
+            Some(bullet) => bullet,
+            None => &self.item.block_prefix,
+        }
+    }
+
+    /// Gets the numbering for an ordered item at a nesting depth (starting
+    /// at 1).
+    pub fn numbering(&self, depth: usize) -> ListNumbering {
+        let numbering = &self.list.numbering;
+        numbering
+            .get(depth.max(1) - 1)
+            .or(numbering.last())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Sets per-depth styles for nested block quotes.
+    pub fn block_quote_levels(mut self, levels: impl IntoIterator<Item = StyleBlock>) -> Self {
+        self.block_quote_levels = levels.into_iter().collect();
+        self
+    }
+
+    /// Sets the footnote styles.
+    pub fn footnotes(mut self, footnotes: StyleFootnotes) -> Self {
+        self.footnotes = footnotes;
+        self
+    }
+
+    /// Gets the style override for a block quote nesting depth (starting at 1).
+    pub fn block_quote_level(&self, depth: usize) -> Option<&StyleBlock> {
+        if self.block_quote_levels.is_empty() || depth == 0 {
+            return None;
+        }
+        self.block_quote_levels
+            .get((depth - 1) % self.block_quote_levels.len())
+    }
+
     /// Sets the syntax highlighting theme.
     ///
     /// This method is only available when the `syntax-highlighting` feature is enabled.
@@ -802,12 +1276,15 @@ pub enum Style {
     TokyoNight,
     /// No TTY style (for non-terminal output).
     NoTty,
-    /// Auto-detect based on terminal.
+    /// Picked from the terminal by [`TermRenderer::with_style`], see
+    /// [`Style::detect`].
     Auto,
 }
 
 impl Style {
     /// Gets the style configuration for this style.
+    ///
+    /// The terminal is never queried: [`Style::Auto`] gives the dark style.
     pub fn config(&self) -> StyleConfig {
         match self {
             Style::Ascii | Style::NoTty => ascii_style(),
@@ -864,7 +1341,16 @@ pub fn dark_style() -> StyleConfig {
             )
             .margin(DEFAULT_MARGIN),
         block_quote: StyleBlock::new().indent(1).indent_token("│ "),
-        list: StyleList::new().level_indent(DEFAULT_LIST_INDENT),
+        callouts: StyleCallouts {
+            note: StylePrimitive::new().color("39"),
+            tip: StylePrimitive::new().color("42"),
+            important: StylePrimitive::new().color("141"),
+            warning: StylePrimitive::new().color("220"),
+            caution: StylePrimitive::new().color("203"),
+        },
+        list: StyleList::new()
+            .level_indent(DEFAULT_LIST_INDENT)
+            .bullets(DEFAULT_BULLETS),
         heading: StyleBlock::new().style(
             StylePrimitive::new()
                 .block_suffix("\n")
@@ -889,6 +1375,7 @@ pub fn dark_style() -> StyleConfig {
                 .color("35")
                 .bold(false),
         ),
+        heading_anchor: StylePrimitive::new().color("240"),
         strikethrough: StylePrimitive::new().crossed_out(true),
         emph: StylePrimitive::new().italic(true),
         strong: StylePrimitive::new().bold(true),
@@ -902,6 +1389,9 @@ pub fn dark_style() -> StyleConfig {
         image_text: StylePrimitive::new()
             .color("243")
             .format("Image: {{.text}} →"),
+        footnotes: StyleFootnotes::new()
+            .reference(StylePrimitive::new().color("35"))
+            .label(StylePrimitive::new().color("35").bold(true)),
         code: StyleBlock::new().style(
             StylePrimitive::new()
                 .prefix(" ")
@@ -931,7 +1421,16 @@ pub fn light_style() -> StyleConfig {
             )
             .margin(DEFAULT_MARGIN),
         block_quote: StyleBlock::new().indent(1).indent_token("│ "),
-        list: StyleList::new().level_indent(DEFAULT_LIST_INDENT),
+        callouts: StyleCallouts {
+            note: StylePrimitive::new().color("27"),
+            tip: StylePrimitive::new().color("28"),
+            important: StylePrimitive::new().color("91"),
+            warning: StylePrimitive::new().color("136"),
+            caution: StylePrimitive::new().color("160"),
+        },
+        list: StyleList::new()
+            .level_indent(DEFAULT_LIST_INDENT)
+            .bullets(DEFAULT_BULLETS),
         heading: StyleBlock::new().style(
             StylePrimitive::new()
                 .block_suffix("\n")
@@ -951,6 +1450,7 @@ pub fn light_style() -> StyleConfig {
         h4: StyleBlock::new().style(StylePrimitive::new().prefix("#### ")),
         h5: StyleBlock::new().style(StylePrimitive::new().prefix("##### ")),
         h6: StyleBlock::new().style(StylePrimitive::new().prefix("###### ").bold(false)),
+        heading_anchor: StylePrimitive::new().color("249"),
         strikethrough: StylePrimitive::new().crossed_out(true),
         emph: StylePrimitive::new().italic(true),
         strong: StylePrimitive::new().bold(true),
@@ -964,6 +1464,9 @@ pub fn light_style() -> StyleConfig {
         image_text: StylePrimitive::new()
             .color("243")
             .format("Image: {{.text}} →"),
+        footnotes: StyleFootnotes::new()
+            .reference(StylePrimitive::new().color("29"))
+            .label(StylePrimitive::new().color("29").bold(true)),
         code: StyleBlock::new().style(
             StylePrimitive::new()
                 .prefix(" ")
@@ -984,9 +1487,13 @@ pub fn light_style() -> StyleConfig {
 /// Creates the pink style configuration.
 pub fn pink_style() -> StyleConfig {
     StyleConfig {
-        document: StyleBlock::new().margin(DEFAULT_MARGIN),
+        document: StyleBlock::new()
+            .style(StylePrimitive::new().block_prefix("\n").block_suffix("\n"))
+            .margin(DEFAULT_MARGIN),
         block_quote: StyleBlock::new().indent(1).indent_token("│ "),
-        list: StyleList::new().level_indent(DEFAULT_LIST_INDENT),
+        list: StyleList::new()
+            .level_indent(DEFAULT_LIST_INDENT)
+            .bullets(DEFAULT_BULLETS),
         heading: StyleBlock::new().style(
             StylePrimitive::new()
                 .block_suffix("\n")
@@ -999,6 +1506,7 @@ pub fn pink_style() -> StyleConfig {
         h4: StyleBlock::new().style(StylePrimitive::new().prefix("│ ")),
         h5: StyleBlock::new().style(StylePrimitive::new().prefix("┆ ")),
         h6: StyleBlock::new().style(StylePrimitive::new().prefix("┊ ").bold(false)),
+        heading_anchor: StylePrimitive::new().color("212"),
         strikethrough: StylePrimitive::new().crossed_out(true),
         emph: StylePrimitive::new().italic(true),
         strong: StylePrimitive::new().bold(true),
@@ -1010,6 +1518,9 @@ pub fn pink_style() -> StyleConfig {
         link_text: StylePrimitive::new().bold(true),
         image: StylePrimitive::new().underline(true),
         image_text: StylePrimitive::new().format("Image: {{.text}}"),
+        footnotes: StyleFootnotes::new()
+            .reference(StylePrimitive::new().color("99"))
+            .label(StylePrimitive::new().color("99").bold(true)),
         code: StyleBlock::new().style(
             StylePrimitive::new()
                 .prefix(" ")
@@ -1046,7 +1557,8 @@ pub fn dracula_style() -> StyleConfig {
             .indent(DEFAULT_MARGIN),
         list: StyleList::new()
             .block(StyleBlock::new().style(StylePrimitive::new().color("#f8f8f2")))
-            .level_indent(DEFAULT_MARGIN),
+            .level_indent(DEFAULT_MARGIN)
+            .bullets(DEFAULT_BULLETS),
         heading: StyleBlock::new().style(
             StylePrimitive::new()
                 .block_suffix("\n")
@@ -1060,6 +1572,7 @@ pub fn dracula_style() -> StyleConfig {
         h4: StyleBlock::new().style(StylePrimitive::new().prefix("#### ")),
         h5: StyleBlock::new().style(StylePrimitive::new().prefix("##### ")),
         h6: StyleBlock::new().style(StylePrimitive::new().prefix("###### ")),
+        heading_anchor: StylePrimitive::new().color("#6272A4"),
         strikethrough: StylePrimitive::new().crossed_out(true),
         emph: StylePrimitive::new().italic(true).color("#f1fa8c"),
         strong: StylePrimitive::new().bold(true).color("#ffb86c"),
@@ -1075,6 +1588,9 @@ pub fn dracula_style() -> StyleConfig {
         image_text: StylePrimitive::new()
             .color("#ff79c6")
             .format("Image: {{.text}} →"),
+        footnotes: StyleFootnotes::new()
+            .reference(StylePrimitive::new().color("#ff79c6"))
+            .label(StylePrimitive::new().color("#ff79c6").bold(true)),
         code: StyleBlock::new().style(StylePrimitive::new().color("#50fa7b")),
         code_block: StyleCodeBlock::new().block(
             StyleBlock::new()
@@ -1106,7 +1622,8 @@ pub fn tokyo_night_style() -> StyleConfig {
         block_quote: StyleBlock::new().indent(1).indent_token("│ "),
         list: StyleList::new()
             .block(StyleBlock::new().style(StylePrimitive::new().color("#a9b1d6")))
-            .level_indent(DEFAULT_LIST_INDENT),
+            .level_indent(DEFAULT_LIST_INDENT)
+            .bullets(DEFAULT_BULLETS),
         heading: StyleBlock::new().style(
             StylePrimitive::new()
                 .block_suffix("\n")
@@ -1119,6 +1636,7 @@ pub fn tokyo_night_style() -> StyleConfig {
         h4: StyleBlock::new().style(StylePrimitive::new().prefix("#### ")),
         h5: StyleBlock::new().style(StylePrimitive::new().prefix("##### ")),
         h6: StyleBlock::new().style(StylePrimitive::new().prefix("###### ")),
+        heading_anchor: StylePrimitive::new().color("#565f89"),
         strikethrough: StylePrimitive::new().crossed_out(true),
         emph: StylePrimitive::new().italic(true),
         strong: StylePrimitive::new().bold(true),
@@ -1134,6 +1652,9 @@ pub fn tokyo_night_style() -> StyleConfig {
         image_text: StylePrimitive::new()
             .color("#2ac3de")
             .format("Image: {{.text}} →"),
+        footnotes: StyleFootnotes::new()
+            .reference(StylePrimitive::new().color("#2ac3de"))
+            .label(StylePrimitive::new().color("#2ac3de").bold(true)),
         code: StyleBlock::new().style(StylePrimitive::new().color("#9ece6a")),
         code_block: StyleCodeBlock::new().block(
             StyleBlock::new()
@@ -1162,6 +1683,93 @@ pub struct AnsiOptions {
     pub preserve_newlines: bool,
     /// Style configuration.
     pub styles: StyleConfig,
+    /// Deepest heading level to render; deeper sections are omitted.
+    pub max_heading_depth: Option<usize>,
+    /// Whether a table of contents is rendered at the top of the document.
+    pub toc: bool,
+    /// Predicate selecting which sections to render.
+    pub section_filter: Option<SectionFilter>,
+    /// Whether links are rendered as OSC 8 hyperlinks instead of being
+    /// followed by their URL. Defaults to false; see [`supports_hyperlinks`].
+    pub hyperlinks: bool,
+    /// Widest a displayed link or image URL may be before its middle is
+    /// replaced with `…`, or `None` to show URLs in full.
+    pub max_url_width: Option<usize>,
+    /// Code blocks longer than this many lines are folded after it.
+    pub code_fold_lines: Option<usize>,
+    /// Indices of code blocks that are shown in full despite folding.
+    pub expanded_folds: HashSet<usize>,
+    /// Whether escape sequences and control characters are removed from
+    /// the markdown before rendering. Defaults to true.
+    pub sanitize_input: bool,
+    /// How raw HTML in the markdown is rendered.
+    pub html_mode: HtmlMode,
+    /// Whether emoji shortcodes such as `:tada:` are replaced.
+    pub emoji: EmojiMode,
+    /// Protocol images are drawn with, or `None` for the text placeholder.
+    /// Defaults to `None`; see [`images::ImageProtocol::detect`].
+    #[cfg(feature = "images")]
+    pub images: Option<images::ImageProtocol>,
+    /// Largest image file drawn, in bytes.
+    #[cfg(feature = "images")]
+    pub image_size_limit: u64,
+    /// Whether paragraphs with right-to-left text are reordered for
+    /// display. Defaults to true.
+    #[cfg(feature = "bidi")]
+    pub bidi: bool,
+    /// Whether output is kept independent of the terminal and environment.
+    /// See [`TermRenderer::deterministic`].
+    pub deterministic: bool,
+    /// Glyph added after each top-level heading, linked to the heading's
+    /// anchor when hyperlinks are enabled.
+    pub heading_anchor: Option<String>,
+}
+
+/// How raw HTML in the markdown is rendered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum HtmlMode {
+    /// HTML blocks and inline tags are left out. Markdown text between
+    /// inline tags is still rendered.
+    Drop,
+    /// Tags and comments are removed, keeping the text between them with
+    /// entities such as `&amp;` decoded.
+    #[default]
+    Strip,
+    /// HTML is written out as it appears in the markdown.
+    Verbatim,
+    /// `<br>`, `<b>`, `<strong>`, `<i>`, `<em>`, `<code>` and `<kbd>` are
+    /// rendered like line breaks, strong and emphasized text and inline
+    /// code, and the `<summary>` of a `<details>` block becomes a `▸`
+    /// title line. Other tags are removed as with [`Strip`](Self::Strip).
+    Convert,
+}
+
+/// Whether emoji shortcodes such as `:tada:` are replaced with emoji.
+///
+/// Shortcodes in code spans and code blocks are never replaced, and
+/// unknown shortcodes are left as they are.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EmojiMode {
+    /// Shortcodes are left as they are.
+    #[default]
+    Off,
+    /// Shortcodes are replaced with emoji.
+    On,
+    /// Shortcodes are replaced when [`supports_emoji`] reports that the
+    /// terminal can draw emoji, and kept otherwise.
+    Auto,
+}
+
+impl EmojiMode {
+    /// Returns whether shortcodes are replaced. Deterministic output
+    /// assumes emoji work rather than asking the environment.
+    fn is_enabled(self, deterministic: bool) -> bool {
+        match self {
+            Self::Off => false,
+            Self::On => true,
+            Self::Auto => deterministic || supports_emoji(),
+        }
+    }
 }
 
 /// Backwards-compatible type alias for [`AnsiOptions`].
@@ -1174,10 +1782,90 @@ impl Default for AnsiOptions {
             base_url: None,
             preserve_newlines: false,
             styles: dark_style(),
+            max_heading_depth: None,
+            toc: false,
+            section_filter: None,
+            hyperlinks: false,
+            max_url_width: None,
+            code_fold_lines: None,
+            expanded_folds: HashSet::new(),
+            sanitize_input: true,
+            html_mode: HtmlMode::default(),
+            emoji: EmojiMode::default(),
+            #[cfg(feature = "images")]
+            images: None,
+            #[cfg(feature = "images")]
+            image_size_limit: images::DEFAULT_IMAGE_SIZE_LIMIT,
+            #[cfg(feature = "bidi")]
+            bidi: true,
+            deterministic: false,
+            heading_anchor: None,
         }
     }
 }
 
+impl AnsiOptions {
+    /// Returns whether the heading at `level` with section `path` is
+    /// rendered under the heading depth limit and section filter.
+    fn renders_section(&self, level: usize, path: &[String]) -> bool {
+        self.max_heading_depth.is_none_or(|depth| level <= depth)
+            && self
+                .section_filter
+                .as_ref()
+                .is_none_or(|filter| filter.matches(path))
+    }
+}
+
+/// Predicate deciding which sections of a document are rendered.
+///
+/// A section starts at a heading and runs until the next heading of the same
+/// or a shallower level. The predicate receives the section's heading path:
+/// the text of each enclosing heading from the outermost down to the section
+/// itself, so `## Installation` under `# Guide` is `["Guide", "Installation"]`.
+/// Content before the first heading is passed an empty path.
+#[derive(Clone)]
+pub struct SectionFilter(Arc<SectionPredicate>);
+
+type SectionPredicate = dyn Fn(&[String]) -> bool + Send + Sync;
+
+impl SectionFilter {
+    /// Creates a section filter from a predicate.
+    pub fn new<F>(filter: F) -> Self
+    where
+        F: Fn(&[String]) -> bool + Send + Sync + 'static,
+    {
+        Self(Arc::new(filter))
+    }
+
+    /// Returns whether the section at `path` should be rendered.
+    pub fn matches(&self, path: &[String]) -> bool {
+        (self.0)(path)
+    }
+}
+
+impl std::fmt::Debug for SectionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SectionFilter").finish_non_exhaustive()
+    }
+}
+
+/// A code block that is long enough to fold, as reported by
+/// [`TermRenderer::render_with_folds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CodeFold {
+    /// Index of the code block in the document, counting every code block
+    /// from 0. Pass it to [`TermRenderer::with_expanded_folds`] to unfold
+    /// the block.
+    pub index: usize,
+    /// Output line of the fold marker, or of the first line the fold hides
+    /// when it is expanded.
+    pub line: usize,
+    /// Number of lines the fold hides.
+    pub hidden_lines: usize,
+    /// Whether the block is shown in full.
+    pub expanded: bool,
+}
+
 /// Markdown renderer for terminal output (Go API: `TermRenderer`).
 ///
 /// This struct is also exported as `Renderer` for backwards compatibility.
@@ -1207,8 +1895,14 @@ impl TermRenderer {
     }
 
     /// Sets the style for rendering.
+    ///
+    /// [`Style::Auto`] is replaced by [`Style::detect`], unless the renderer
+    /// is [`deterministic`](Self::deterministic).
     pub fn with_style(mut self, style: Style) -> Self {
-        self.options.styles = style.config();
+        self.options.styles = match style {
+            Style::Auto if !self.options.deterministic => Style::detect().config(),
+            style => style.config(),
+        };
         self
     }
 
@@ -1236,89 +1930,384 @@ impl TermRenderer {
         self
     }
 
-    /// Renders markdown to styled terminal output.
-    pub fn render(&self, markdown: &str) -> String {
-        let mut ctx = RenderContext::new(&self.options);
-        ctx.render(markdown)
+    /// Sets whether links become clickable OSC 8 hyperlinks.
+    ///
+    /// When enabled, link text is emitted as a hyperlink to its URL and the
+    /// URL is no longer printed after it. When disabled, links render as
+    /// `text URL`, the default. Pass [`supports_hyperlinks`] to follow the
+    /// terminal.
+    pub fn with_hyperlinks(mut self, enabled: bool) -> Self {
+        self.options.hyperlinks = enabled;
+        self
     }
 
-    /// Renders markdown bytes to styled terminal output.
-    pub fn render_bytes(&self, markdown: &[u8]) -> Result<String, std::str::Utf8Error> {
-        let text = std::str::from_utf8(markdown)?;
-        Ok(self.render(text))
+    /// Sets the widest a displayed URL may be. Longer URLs printed after
+    /// link text or image placeholders keep their start and end, with the
+    /// middle replaced by `…`.
+    ///
+    /// With hyperlinks enabled, shortened URLs and autolinks still link to
+    /// the full URL. Without them, autolinks are shown in full, since their
+    /// text is the only place the URL appears.
+    ///
+    /// ```rust
+    /// use glamour::{Renderer, Style};
+    ///
+    /// let output = Renderer::new()
+    ///     .with_style(Style::Ascii)
+    ///     .with_hyperlinks(false)
+    ///     .with_max_url_width(20)
+    ///     .render("[docs](https://example.com/a/very/long/path)");
+    /// assert!(output.contains("docs https://ex…long/path"));
+    /// ```
+    pub fn with_max_url_width(mut self, width: usize) -> Self {
+        self.options.max_url_width = Some(width);
+        self
     }
 
-    /// Changes the syntax highlighting theme at runtime.
+    /// Sets the protocol images are drawn with, or `None` to render them as
+    /// the `Image:` placeholder, the default. Pass
+    /// [`ImageProtocol::detect`](images::ImageProtocol::detect) to use what
+    /// the terminal supports.
+    #[cfg(feature = "images")]
+    pub fn with_images(mut self, protocol: Option<images::ImageProtocol>) -> Self {
+        self.options.images = protocol;
+        self
+    }
+
+    /// Sets the largest image file that is drawn, in bytes. Larger images
+    /// are rendered as the placeholder.
+    #[cfg(feature = "images")]
+    pub fn with_image_size_limit(mut self, bytes: u64) -> Self {
+        self.options.image_size_limit = bytes;
+        self
+    }
+
+    /// Sets whether paragraphs containing right-to-left text are reordered
+    /// into display order after wrapping.
     ///
-    /// This allows switching themes without creating a new Renderer instance.
+    /// Enabled by default. Disable it for terminals that reorder text
+    /// themselves, such as VTE-based ones, which would otherwise reverse it
+    /// a second time.
+    #[cfg(feature = "bidi")]
+    pub fn with_bidi(mut self, enabled: bool) -> Self {
+        self.options.bidi = enabled;
+        self
+    }
+
+    /// Sets how raw HTML in the markdown is rendered. Defaults to
+    /// [`HtmlMode::Strip`].
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `theme` - Theme name (e.g., "base16-ocean.dark", "Solarized (dark)")
+    /// ```rust
+    /// use glamour::{HtmlMode, Renderer, Style};
     ///
-    /// # Returns
+    /// let renderer = Renderer::new().with_style(Style::Ascii);
+    /// let markdown = "Press <kbd>q</kbd> to <b>quit</b>.";
     ///
-    /// `Ok(())` if the theme exists and was applied, or an error message if the theme
-    /// was not found.
+    /// let output = renderer.clone().render(markdown);
+    /// assert!(output.contains("Press q to quit."));
     ///
-    /// # Example
+    /// let output = renderer.with_html_mode(HtmlMode::Convert).render(markdown);
+    /// assert!(output.contains("Press q to **quit**."));
+    /// ```
+    pub fn with_html_mode(mut self, mode: HtmlMode) -> Self {
+        self.options.html_mode = mode;
+        self
+    }
+
+    /// Sets whether emoji shortcodes such as `:tada:` are replaced with
+    /// emoji. Off by default.
     ///
-    /// ```rust,ignore
-    /// use glamour::Renderer;
+    /// ```rust
+    /// use glamour::{EmojiMode, Renderer, Style};
     ///
-    /// let mut renderer = Renderer::new();
-    /// renderer.set_syntax_theme("Solarized (dark)")?;
-    /// let output = renderer.render("```rust\nfn main() {}\n```");
+    /// let renderer = Renderer::new()
+    ///     .with_style(Style::Ascii)
+    ///     .with_emoji(EmojiMode::On);
+    /// let output = renderer.render("Shipped :rocket: with `:tada:`");
+    /// assert!(output.contains("Shipped 🚀 with :tada:"));
     /// ```
-    #[cfg(feature = "syntax-highlighting")]
-    pub fn set_syntax_theme(&mut self, theme: impl Into<String>) -> Result<(), String> {
-        let theme_name = theme.into();
-
-        // Validate the theme exists before setting it
-        use crate::syntax::SyntaxTheme;
-        if SyntaxTheme::from_name(&theme_name).is_none() {
-            let available = SyntaxTheme::available_themes().join(", ");
-            return Err(format!(
-                "Unknown syntax theme '{}'. Available themes: {}",
-                theme_name, available
-            ));
-        }
+    pub fn with_emoji(mut self, mode: EmojiMode) -> Self {
+        self.options.emoji = mode;
+        self
+    }
 
-        self.options.styles.syntax_config.theme_name = theme_name;
-        Ok(())
+    /// Sets whether escape sequences and control characters other than tabs
+    /// and line breaks are removed from the markdown before rendering.
+    ///
+    /// Enabled by default, so that untrusted text can't control the
+    /// terminal. Disable it for trusted content that embeds its own styling.
+    pub fn with_sanitized_input(mut self, sanitize: bool) -> Self {
+        self.options.sanitize_input = sanitize;
+        self
     }
 
-    /// Enables or disables line numbers in code blocks at runtime.
+    /// Makes the output independent of the terminal and environment, so that
+    /// it can be compared against snapshots in tests on any machine.
     ///
-    /// # Example
+    /// Hyperlinks and images are turned off, a later
+    /// [`Style::Auto`] renders as [`Style::Dark`], and [`EmojiMode::Auto`]
+    /// replaces shortcodes as if emoji were supported. Colors need no
+    /// fixing, as they are always written as true color. Options set after
+    /// this one still apply.
     ///
-    /// ```rust,ignore
-    /// use glamour::Renderer;
+    /// ```rust
+    /// use glamour::{Renderer, Style};
     ///
-    /// let mut renderer = Renderer::new();
-    /// renderer.set_line_numbers(true);
+    /// let markdown = "See [docs](https://example.com).";
+    /// let output = Renderer::new()
+    ///     .deterministic()
+    ///     .with_style(Style::Auto)
+    ///     .render(markdown);
+    /// assert_eq!(output, glamour::render(markdown, Style::Dark).unwrap());
+    /// assert!(output.contains("docs https://example.com"));
     /// ```
-    #[cfg(feature = "syntax-highlighting")]
-    pub fn set_line_numbers(&mut self, enabled: bool) {
-        self.options.styles.syntax_config.line_numbers = enabled;
+    pub fn deterministic(mut self) -> Self {
+        self.options.deterministic = true;
+        self.options.hyperlinks = false;
+        #[cfg(feature = "images")]
+        {
+            self.options.images = None;
+        }
+        self
     }
 
-    /// Returns a reference to the current syntax configuration.
+    /// Folds code blocks longer than `max_lines` lines, showing their first
+    /// `max_lines` lines followed by a `… 42 more lines` marker.
     ///
-    /// This method is only available when the `syntax-highlighting` feature is enabled.
-    #[cfg(feature = "syntax-highlighting")]
-    pub fn syntax_config(&self) -> &SyntaxThemeConfig {
-        &self.options.styles.syntax_config
+    /// Use [`render_with_folds`](Self::render_with_folds) to find the folds
+    /// and [`with_expanded_folds`](Self::with_expanded_folds) to unfold some
+    /// of them, as a pager does when the reader asks to see a block.
+    pub fn with_code_folding(mut self, max_lines: usize) -> Self {
+        self.options.code_fold_lines = Some(max_lines);
+        self
     }
 
-    /// Returns a mutable reference to the current syntax configuration.
+    /// Shows the code blocks with the given [`CodeFold::index`] values in
+    /// full when code folding is enabled.
+    pub fn with_expanded_folds(mut self, folds: impl IntoIterator<Item = usize>) -> Self {
+        self.options.expanded_folds = folds.into_iter().collect();
+        self
+    }
+
+    /// Limits rendering to headings of at most `depth` levels (1 for `#`,
+    /// 2 for `##`, ...).
     ///
-    /// This allows runtime modification of all syntax highlighting settings.
+    /// Sections under deeper headings are omitted along with their content.
+    pub fn with_max_heading_depth(mut self, depth: usize) -> Self {
+        self.options.max_heading_depth = Some(depth);
+        self
+    }
+
+    /// Sets whether a table of contents is rendered at the top of the
+    /// document, listing the headings as a list indented by level.
+    ///
+    /// Only the headings of rendered sections are listed, so the contents
+    /// follow [`with_max_heading_depth`](Self::with_max_heading_depth) and
+    /// [`with_section_filter`](Self::with_section_filter). Streamed output
+    /// has no table of contents.
     ///
     /// # Example
     ///
-    /// ```rust,ignore
-    /// use glamour::Renderer;
+    /// ```rust
+    /// use glamour::{Renderer, Style};
+    ///
+    /// let output = Renderer::new()
+    ///     .with_style(Style::Ascii)
+    ///     .with_toc(true)
+    ///     .render("# Guide\n\n## Install\n\nRun cargo.");
+    ///
+    /// let contents = output.find("• Install").unwrap();
+    /// assert!(contents < output.find("## Install").unwrap());
+    /// ```
+    pub fn with_toc(mut self, enabled: bool) -> Self {
+        self.options.toc = enabled;
+        self
+    }
+
+    /// Adds `glyph`, such as `§` or `#`, after each top-level heading,
+    /// styled with [`StyleConfig::heading_anchor`].
+    ///
+    /// When hyperlinks are enabled the glyph links to the heading's anchor,
+    /// the base URL followed by `#` and the same slug as
+    /// [`TocEntry::slug`], so documentation browsers can offer linkable
+    /// sections. Streamed output shows the glyph without a link.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glamour::{Renderer, Style};
+    ///
+    /// let output = Renderer::new()
+    ///     .with_style(Style::Ascii)
+    ///     .with_hyperlinks(true)
+    ///     .with_base_url("https://docs.rs/guide")
+    ///     .with_heading_anchors("§")
+    ///     .render("# Guide\n\n## Getting Started");
+    ///
+    /// assert!(output.contains("\x1b]8;;https://docs.rs/guide#getting-started\x1b\\§"));
+    /// ```
+    pub fn with_heading_anchors(mut self, glyph: impl Into<String>) -> Self {
+        self.options.heading_anchor = Some(glyph.into());
+        self
+    }
+
+    /// Renders only the sections accepted by `filter`.
+    ///
+    /// The filter is called with the heading path of each section; see
+    /// [`SectionFilter`]. A section's subsections are checked separately, so
+    /// match on any element of the path to keep them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glamour::{Renderer, Style};
+    ///
+    /// let markdown = "# Guide\n\nIntro.\n\n## Installation\n\nRun cargo.\n\n## Usage\n\nCall it.";
+    /// let output = Renderer::new()
+    ///     .with_style(Style::Ascii)
+    ///     .with_section_filter(|path: &[String]| path.iter().any(|h| h == "Installation"))
+    ///     .render(markdown);
+    ///
+    /// assert!(output.contains("Run cargo."));
+    /// assert!(!output.contains("Intro."));
+    /// assert!(!output.contains("Call it."));
+    /// ```
+    pub fn with_section_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&[String]) -> bool + Send + Sync + 'static,
+    {
+        self.options.section_filter = Some(SectionFilter::new(filter));
+        self
+    }
+
+    /// Renders markdown to styled terminal output.
+    pub fn render(&self, markdown: &str) -> String {
+        let mut ctx = RenderContext::new(&self.options);
+        ctx.render(markdown)
+    }
+
+    /// Renders markdown like [`render`](Self::render), also returning the
+    /// code blocks that are folded or could be folded again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use glamour::{Renderer, Style};
+    ///
+    /// let markdown = "```\n1\n2\n3\n4\n5\n```";
+    /// let renderer = Renderer::new().with_style(Style::Ascii).with_code_folding(2);
+    ///
+    /// let (output, folds) = renderer.render_with_folds(markdown);
+    /// assert!(output.contains("… 3 more lines"));
+    /// assert_eq!(folds[0].hidden_lines, 3);
+    /// assert!(output.lines().nth(folds[0].line).unwrap().contains("… 3 more lines"));
+    ///
+    /// let (output, _) = renderer
+    ///     .with_expanded_folds([folds[0].index])
+    ///     .render_with_folds(markdown);
+    /// assert!(output.contains('5'));
+    /// ```
+    pub fn render_with_folds(&self, markdown: &str) -> (String, Vec<CodeFold>) {
+        let mut ctx = RenderContext::new(&self.options);
+        let output = ctx.render(markdown);
+        (output, ctx.folds)
+    }
+
+    /// Starts rendering markdown that arrives in chunks. See [`stream`] for
+    /// details.
+    pub fn stream(&self) -> StreamRenderer<'_> {
+        StreamRenderer::new(&self.options)
+    }
+
+    /// Parses markdown into blocks with inline styling applied, leaving
+    /// wrapping and layout to the caller. See [`blocks`] for details.
+    #[cfg(feature = "blocks")]
+    pub fn blocks(&self, markdown: &str) -> Vec<blocks::Block> {
+        let mut ctx = RenderContext::new(&self.options);
+        ctx.blocks = Some(Vec::new());
+        ctx.feed(markdown);
+        ctx.blocks.unwrap_or_default()
+    }
+
+    /// Renders markdown bytes to styled terminal output.
+    pub fn render_bytes(&self, markdown: &[u8]) -> Result<String, std::str::Utf8Error> {
+        let text = std::str::from_utf8(markdown)?;
+        Ok(self.render(text))
+    }
+
+    /// Changes the syntax highlighting theme at runtime.
+    ///
+    /// This allows switching themes without creating a new Renderer instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - Theme name (e.g., "base16-ocean.dark", "Solarized (dark)")
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the theme exists and was applied, or an error message if the theme
+    /// was not found.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use glamour::Renderer;
+    ///
+    /// let mut renderer = Renderer::new();
+    /// renderer.set_syntax_theme("Solarized (dark)")?;
+    /// let output = renderer.render("```rust\nfn main() {}\n```");
+    /// ```
+    #[cfg(feature = "syntax-highlighting")]
+    pub fn set_syntax_theme(&mut self, theme: impl Into<String>) -> Result<(), String> {
+        let theme_name = theme.into();
+
+        // Validate the theme exists before setting it
+        use crate::syntax::SyntaxTheme;
+        if SyntaxTheme::from_name(&theme_name).is_none() {
+            let available = SyntaxTheme::available_themes().join(", ");
+            return Err(format!(
+                "Unknown syntax theme '{}'. Available themes: {}",
+                theme_name, available
+            ));
+        }
+
+        self.options.styles.syntax_config.theme_name = theme_name;
+        Ok(())
+    }
+
+    /// Enables or disables line numbers in code blocks at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use glamour::Renderer;
+    ///
+    /// let mut renderer = Renderer::new();
+    /// renderer.set_line_numbers(true);
+    /// ```
+    #[cfg(feature = "syntax-highlighting")]
+    pub fn set_line_numbers(&mut self, enabled: bool) {
+        self.options.styles.syntax_config.line_numbers = enabled;
+    }
+
+    /// Returns a reference to the current syntax configuration.
+    ///
+    /// This method is only available when the `syntax-highlighting` feature is enabled.
+    #[cfg(feature = "syntax-highlighting")]
+    pub fn syntax_config(&self) -> &SyntaxThemeConfig {
+        &self.options.styles.syntax_config
+    }
+
+    /// Returns a mutable reference to the current syntax configuration.
+    ///
+    /// This allows runtime modification of all syntax highlighting settings.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use glamour::Renderer;
     ///
     /// let mut renderer = Renderer::new();
     /// renderer.syntax_config_mut()
@@ -1331,10 +2320,236 @@ impl TermRenderer {
     }
 }
 
+/// Drops the events of sections rejected by the section filter or nested
+/// deeper than the maximum heading depth.
+///
+/// Only top-level headings start sections; headings inside block quotes or
+/// lists follow the section they appear in, which keeps start and end events
+/// balanced.
+fn select_sections<'e>(
+    events: impl Iterator<Item = Event<'e>>,
+    options: &AnsiOptions,
+) -> Vec<Event<'e>> {
+    let accepts = |path: &[String]| {
+        options
+            .section_filter
+            .as_ref()
+            .is_none_or(|filter| filter.matches(path))
+    };
+
+    let mut selected = Vec::new();
+    let mut levels: Vec<HeadingLevel> = Vec::new();
+    let mut path: Vec<String> = Vec::new();
+    let mut include = accepts(&path);
+    let mut nesting = 0usize;
+    // The heading being buffered until its text is known.
+    let mut heading_level: Option<HeadingLevel> = None;
+    let mut heading_events: Vec<Event<'e>> = Vec::new();
+    let mut heading_text = String::new();
+
+    for event in events {
+        if let Some(level) = heading_level {
+            if let Event::Text(t) | Event::Code(t) = &event {
+                heading_text.push_str(t);
+            }
+            let is_end = matches!(event, Event::End(TagEnd::Heading(_)));
+            heading_events.push(event);
+            if is_end {
+                while levels.last().is_some_and(|l| *l >= level) {
+                    levels.pop();
+                    path.pop();
+                }
+                levels.push(level);
+                path.push(heading_text.trim().to_string());
+                include = options
+                    .max_heading_depth
+                    .is_none_or(|depth| level as usize <= depth)
+                    && accepts(&path);
+                if include {
+                    selected.append(&mut heading_events);
+                }
+                heading_events.clear();
+                heading_text.clear();
+                heading_level = None;
+            }
+            continue;
+        }
+
+        match &event {
+            Event::Start(Tag::Heading { level, .. }) if nesting == 0 => {
+                heading_level = Some(*level);
+                heading_events.push(event);
+                continue;
+            }
+            Event::Start(_) => nesting += 1,
+            Event::End(_) => nesting = nesting.saturating_sub(1),
+            _ => {}
+        }
+        if include {
+            selected.push(event);
+        }
+    }
+
+    selected
+}
+
+/// A heading listed in a table of contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TocEntry {
+    /// Heading level, 1 for `#` to 6 for `######`.
+    pub level: usize,
+    /// Plain text of the heading.
+    pub text: String,
+    /// Anchor of the heading: its `{#id}` attribute if it has one, and
+    /// otherwise a GitHub-style slug, unique within the document: the
+    /// lowercase text with punctuation removed and spaces replaced by `-`,
+    /// and `-1`, `-2`, ... appended to repeats.
+    pub slug: String,
+}
+
+/// Returns the headings of a document in order, with their anchor slugs.
+///
+/// Headings nested in block quotes or lists are not listed. A heading's
+/// `{#id .class}` attributes are left out of its text, and its id, if any,
+/// is its slug.
+///
+/// # Example
+///
+/// ```rust
+/// use glamour::extract_toc;
+///
+/// let toc = extract_toc("# Guide\n\n## Getting Started!\n\n## Getting started\n\n## FAQ {#faq-top}");
+/// assert_eq!(toc[1].level, 2);
+/// assert_eq!(toc[1].text, "Getting Started!");
+/// assert_eq!(toc[1].slug, "getting-started");
+/// assert_eq!(toc[2].slug, "getting-started-1");
+/// assert_eq!(toc[3].text, "FAQ");
+/// assert_eq!(toc[3].slug, "faq-top");
+/// ```
+pub fn extract_toc(markdown: &str) -> Vec<TocEntry> {
+    toc_entries(markdown, |_, _| true)
+}
+
+/// Collects the top-level headings of `markdown` whose level and section
+/// path are accepted by `include`. Slugs count every heading, listed or
+/// not, so they match the anchors of the full document.
+fn toc_entries(markdown: &str, include: impl Fn(usize, &[String]) -> bool) -> Vec<TocEntry> {
+    let mut entries = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut levels: Vec<usize> = Vec::new();
+    let mut path: Vec<String> = Vec::new();
+    let mut nesting = 0usize;
+    let mut heading: Option<(usize, Option<String>, String)> = None;
+
+    for event in Parser::new_ext(markdown, parser_options()) {
+        if let Some((level, id, text)) = &mut heading {
+            match event {
+                Event::Text(t) | Event::Code(t) => text.push_str(&t),
+                Event::SoftBreak | Event::HardBreak => text.push(' '),
+                Event::End(TagEnd::Heading(_)) => {
+                    let level = *level;
+                    let text = strip_controls(text.trim()).into_owned();
+                    while levels.last().is_some_and(|l| *l >= level) {
+                        levels.pop();
+                        path.pop();
+                    }
+                    levels.push(level);
+                    path.push(text.clone());
+
+                    let slug = match id.take() {
+                        Some(id) => {
+                            // Later slugs are numbered past an id they repeat
+                            *seen.entry(id.clone()).or_insert(0) += 1;
+                            id
+                        }
+                        None => {
+                            let base = slugify(&text);
+                            let count = seen.entry(base.clone()).or_insert(0);
+                            let slug = match *count {
+                                0 => base,
+                                n => format!("{base}-{n}"),
+                            };
+                            *count += 1;
+                            slug
+                        }
+                    };
+                    if include(level, &path) {
+                        entries.push(TocEntry { level, text, slug });
+                    }
+                    heading = None;
+                }
+                _ => {}
+            }
+            continue;
+        }
+        match event {
+            Event::Start(Tag::Heading { level, id, .. }) if nesting == 0 => {
+                heading = Some((level as usize, id.map(|id| id.into_string()), String::new()));
+            }
+            Event::Start(_) => nesting += 1,
+            Event::End(_) => nesting = nesting.saturating_sub(1),
+            _ => {}
+        }
+    }
+    entries
+}
+
+/// Returns the GitHub anchor slug of a heading's text.
+fn slugify(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Writes table of contents entries as a nested markdown list, indented by
+/// level relative to the shallowest heading.
+fn toc_markdown(entries: &[TocEntry]) -> String {
+    let min_level = entries.iter().map(|e| e.level).min().unwrap_or(1);
+    let mut markdown = String::new();
+    let mut depth = 0;
+    for (i, entry) in entries.iter().enumerate() {
+        // A list can only nest one level below the item before it
+        let max_depth = if i == 0 { 0 } else { depth + 1 };
+        depth = (entry.level - min_level).min(max_depth);
+        markdown.push_str(&"  ".repeat(depth));
+        markdown.push_str("- ");
+        for c in entry.text.chars() {
+            if c.is_ascii_punctuation() {
+                markdown.push('\\');
+            }
+            markdown.push(c);
+        }
+        markdown.push('\n');
+    }
+    markdown
+}
+
+/// A block quote that is open while rendering.
+#[derive(Clone)]
+struct QuoteLevel {
+    kind: Option<BlockQuoteKind>,
+    /// List depth the quote opened at; its bar sits under that list's item
+    /// marker.
+    list_depth: usize,
+}
+
 /// Render context that tracks state during rendering.
+#[derive(Clone)]
 struct RenderContext<'a> {
     options: &'a AnsiOptions,
+    /// Whether emoji shortcodes in text are replaced.
+    emoji: bool,
     output: String,
+    /// Where the body starts in `output`, after the document prefix.
+    body_start: usize,
+    /// Blank lines before the next top-level block, left by a heading's
+    /// bottom margin.
+    next_gap: Option<usize>,
+    /// Anchor slugs of the top-level headings still to be rendered, when
+    /// heading anchors are enabled.
+    heading_slugs: VecDeque<String>,
     // Track element nesting
     in_heading: Option<HeadingLevel>,
     in_emphasis: bool,
@@ -1343,7 +2558,12 @@ struct RenderContext<'a> {
     in_link: bool,
     in_image: bool,
     in_code_block: bool,
-    block_quote_depth: usize,
+    /// Raw HTML of the HTML block being read.
+    html_block: Option<String>,
+    /// Text of an HTML `<code>` or `<kbd>` element being converted.
+    html_code: Option<String>,
+    /// Open block quotes, outermost first.
+    block_quotes: Vec<QuoteLevel>,
     block_quote_pending_separator: Option<usize>,
     pending_block_quote_decrement: usize,
     in_paragraph: bool,
@@ -1351,6 +2571,10 @@ struct RenderContext<'a> {
     ordered_list_stack: Vec<bool>,
     list_depth: usize,
     list_item_number: Vec<usize>,
+    /// Width numbers are right-aligned to in each open list.
+    list_number_width: Vec<usize>,
+    /// Last item number of each ordered list not started yet, in order.
+    ordered_list_ends: VecDeque<usize>,
     in_table: bool,
     table_alignments: Vec<pulldown_cmark::Alignment>,
     table_row: Vec<String>,
@@ -1362,18 +2586,40 @@ struct RenderContext<'a> {
     text_buffer: String,
     link_url: String,
     link_title: String,
+    /// Where the current link's text starts in its buffer.
+    link_start: usize,
     link_is_autolink_email: bool,
     image_url: String,
     image_title: String,
     code_block_language: String,
     code_block_content: String,
+    /// Number of code blocks seen so far.
+    code_blocks: usize,
+    /// Code blocks long enough to fold.
+    folds: Vec<CodeFold>,
+    /// Footnote numbers by label, in order of first reference.
+    footnote_numbers: HashMap<String, usize>,
+    /// Rendered footnote definitions with their numbers.
+    footnotes: Vec<(usize, String)>,
+    /// Number of the footnote definition being rendered, with the output and
+    /// body start set aside while it renders.
+    footnote: Option<(usize, String, usize)>,
+    /// Cells taken by the label of the footnote definition being rendered.
+    footnote_indent: usize,
+    /// Collects blocks instead of rendering them when set.
+    #[cfg(feature = "blocks")]
+    blocks: Option<Vec<blocks::Block>>,
 }
 
 impl<'a> RenderContext<'a> {
     fn new(options: &'a AnsiOptions) -> Self {
         Self {
             options,
+            emoji: options.emoji.is_enabled(options.deterministic),
             output: String::new(),
+            body_start: 0,
+            next_gap: None,
+            heading_slugs: VecDeque::new(),
             in_heading: None,
             in_emphasis: false,
             in_strong: false,
@@ -1381,7 +2627,9 @@ impl<'a> RenderContext<'a> {
             in_link: false,
             in_image: false,
             in_code_block: false,
-            block_quote_depth: 0,
+            html_block: None,
+            html_code: None,
+            block_quotes: Vec::new(),
             block_quote_pending_separator: None,
             pending_block_quote_decrement: 0,
             in_paragraph: false,
@@ -1389,6 +2637,8 @@ impl<'a> RenderContext<'a> {
             ordered_list_stack: Vec::new(),
             list_depth: 0,
             list_item_number: Vec::new(),
+            list_number_width: Vec::new(),
+            ordered_list_ends: VecDeque::new(),
             in_table: false,
             table_alignments: Vec::new(),
             table_row: Vec::new(),
@@ -1399,32 +2649,116 @@ impl<'a> RenderContext<'a> {
             text_buffer: String::new(),
             link_url: String::new(),
             link_title: String::new(),
+            link_start: 0,
             link_is_autolink_email: false,
             image_url: String::new(),
             image_title: String::new(),
             code_block_language: String::new(),
             code_block_content: String::new(),
+            code_blocks: 0,
+            folds: Vec::new(),
+            footnote_numbers: HashMap::new(),
+            footnotes: Vec::new(),
+            footnote: None,
+            footnote_indent: 0,
+            #[cfg(feature = "blocks")]
+            blocks: None,
         }
     }
 
-    fn render(&mut self, markdown: &str) -> String {
-        // Enable tables and other extensions
-        let mut opts = Options::empty();
-        opts.insert(Options::ENABLE_TABLES);
-        opts.insert(Options::ENABLE_STRIKETHROUGH);
-        opts.insert(Options::ENABLE_TASKLISTS);
+    /// Parses `markdown` and handles the events of the selected sections.
+    fn feed(&mut self, markdown: &str) {
+        let sanitized;
+        let markdown = if self.options.sanitize_input {
+            sanitized = strip_controls(markdown);
+            &sanitized
+        } else {
+            markdown
+        };
+        let opts = parser_options();
+
+        // Footnotes are numbered in order of reference, which may come after
+        // their definitions
+        if markdown.contains("[^") {
+            for event in Parser::new_ext(markdown, opts) {
+                if let Event::FootnoteReference(label) = event {
+                    self.footnote_number(&label);
+                }
+            }
+        }
+
+        if self.options.heading_anchor.is_some() {
+            let options = self.options;
+            let entries = toc_entries(markdown, |level, path| options.renders_section(level, path));
+            self.heading_slugs
+                .extend(entries.into_iter().map(|entry| entry.slug));
+        }
 
         let parser = Parser::new_ext(markdown, opts);
 
-        // Document prefix
+        let events =
+            if self.options.section_filter.is_some() || self.options.max_heading_depth.is_some() {
+                select_sections(parser, self.options)
+            } else {
+                parser.collect()
+            };
+        // Numbers are aligned to the widest in their list, so each list's
+        // length is needed before its first item
+        self.ordered_list_ends.extend(ordered_list_ends(&events));
+        for event in events {
+            self.handle_event(event);
+        }
+    }
+
+    /// Hands a finished block to the block collector, returning `false` if
+    /// the block should be rendered instead.
+    #[cfg(feature = "blocks")]
+    fn collect(&mut self, block: impl FnOnce() -> blocks::Block) -> bool {
+        match &mut self.blocks {
+            Some(blocks) => {
+                blocks.push(block());
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn render(&mut self, markdown: &str) -> String {
+        self.begin_document();
+        if self.options.toc {
+            self.feed_toc(markdown);
+        }
+        self.feed(markdown);
+        self.end_document()
+    }
+
+    /// Renders the table of contents of the sections that will be rendered.
+    fn feed_toc(&mut self, markdown: &str) {
+        let options = self.options;
+        let entries = toc_entries(markdown, |level, path| options.renders_section(level, path));
+        if entries.is_empty() {
+            return;
+        }
+        // Not fed through the section filter, which would drop the list
+        // along with any preamble
+        for event in Parser::new_ext(&toc_markdown(&entries), parser_options()) {
+            self.handle_event(event);
+        }
+    }
+
+    fn begin_document(&mut self) {
         self.output
             .push_str(&self.options.styles.document.style.block_prefix);
+        self.body_start = self.output.len();
+    }
 
-        // Add margin
-        let margin = self.options.styles.document.margin.unwrap_or(0);
+    /// Closes the document, returning the finished output.
+    fn end_document(&mut self) -> String {
+        self.flush_footnotes();
 
-        for event in parser {
-            self.handle_event(event);
+        // Close the last block with exactly one blank line
+        if self.trim_trailing_newlines() {
+            self.output.push_str("\n\n");
         }
 
         // Document suffix
@@ -1432,6 +2766,7 @@ impl<'a> RenderContext<'a> {
             .push_str(&self.options.styles.document.style.block_suffix);
 
         // Apply margin
+        let margin = self.options.styles.document.margin.unwrap_or(0);
         if margin > 0 {
             let margin_str = " ".repeat(margin);
             self.output = self
@@ -1449,6 +2784,9 @@ impl<'a> RenderContext<'a> {
         match event {
             // Block elements
             Event::Start(Tag::Heading { level, .. }) => {
+                let (top, _) = self.options.styles.heading_margins(level);
+                let gap = self.next_gap.take().map_or(top, |gap| gap.max(top));
+                self.begin_block_with_gap(gap);
                 self.in_heading = Some(level);
                 self.text_buffer.clear();
             }
@@ -1458,71 +2796,65 @@ impl<'a> RenderContext<'a> {
             }
 
             Event::Start(Tag::Paragraph) => {
+                self.begin_block();
                 if let Some(depth) = self.block_quote_pending_separator.take()
                     && depth > 0
                 {
-                    let indent_token = self
-                        .options
-                        .styles
-                        .block_quote
-                        .indent_token
-                        .as_deref()
-                        .unwrap_or("│ ");
-                    let prefix = indent_token.repeat(depth);
+                    let prefix = self.block_quote_prefix(depth);
                     self.output.push_str(&prefix);
                     self.output.push('\n');
                 }
-                if !self.in_list {
+                if !self.in_list_item() {
                     self.text_buffer.clear();
                 }
                 self.in_paragraph = true;
             }
             Event::End(TagEnd::Paragraph) => {
-                if !self.in_list && !self.in_table {
+                if !self.in_list_item() && !self.in_table {
                     self.flush_paragraph();
                 }
                 self.in_paragraph = false;
                 if self.pending_block_quote_decrement > 0 {
-                    self.block_quote_depth = self
-                        .block_quote_depth
+                    let depth = self
+                        .block_quotes
+                        .len()
                         .saturating_sub(self.pending_block_quote_decrement);
                     self.pending_block_quote_decrement = 0;
-                    if let Some(ref mut sep_depth) = self.block_quote_pending_separator
-                        && *sep_depth > self.block_quote_depth
-                    {
-                        *sep_depth = self.block_quote_depth;
-                    }
-                }
-                if self.block_quote_depth == 0 {
-                    self.block_quote_pending_separator = None;
+                    self.close_block_quotes(depth);
                 }
             }
 
-            Event::Start(Tag::BlockQuote(_kind)) => {
-                if self.block_quote_depth == 0 {
+            Event::Start(Tag::BlockQuote(kind)) => {
+                // A quote inside a list item follows the item's own text
+                if self.in_list_item() && !self.text_buffer.is_empty() {
+                    self.flush_list_item();
+                }
+                self.begin_block();
+                // A nested quote opens with a line of the enclosing bars
+                if !self.block_quotes.is_empty() {
+                    self.block_quote_pending_separator = None;
+                    let prefix = self.block_quote_prefix(self.block_quotes.len());
+                    self.output.push_str(&prefix);
                     self.output.push('\n');
                 }
-                self.block_quote_depth += 1;
+                self.block_quotes.push(QuoteLevel {
+                    kind,
+                    list_depth: self.list_depth,
+                });
+                if let Some(kind) = kind {
+                    self.flush_callout_title(kind);
+                }
             }
             Event::End(TagEnd::BlockQuote(_)) => {
                 if self.in_paragraph {
                     self.pending_block_quote_decrement += 1;
                 } else {
-                    self.block_quote_depth = self.block_quote_depth.saturating_sub(1);
-                    // Update pending separator to match new depth (prevents stale
-                    // high depth values from nested blockquotes)
-                    if let Some(ref mut sep_depth) = self.block_quote_pending_separator
-                        && *sep_depth > self.block_quote_depth
-                    {
-                        *sep_depth = self.block_quote_depth;
-                    }
-                    if self.block_quote_depth == 0 {
-                        self.block_quote_pending_separator = None;
-                    }
+                    self.close_block_quotes(self.block_quotes.len().saturating_sub(1));
                 }
             }
 
             Event::Start(Tag::CodeBlock(kind)) => {
+                self.begin_block();
                 self.in_code_block = true;
                 self.code_block_content.clear();
                 match kind {
@@ -1546,22 +2878,34 @@ impl<'a> RenderContext<'a> {
                 if self.list_depth > 0 && !self.text_buffer.is_empty() {
                     self.flush_list_item();
                 }
+                self.begin_block();
                 self.in_list = true;
                 self.list_depth += 1;
                 // Track ordered/unordered state per list level
                 self.ordered_list_stack.push(first_item.is_some());
                 self.list_item_number.push(first_item.unwrap_or(1) as usize);
-                if self.list_depth == 1 {
-                    self.output.push('\n');
-                }
+                let width = first_item.map_or(0, |first| {
+                    let first = first as usize;
+                    let last = self.ordered_list_ends.pop_front().unwrap_or(first);
+                    let numbering = self.options.styles.numbering(self.list_depth);
+                    numbering.width(first, last)
+                });
+                self.list_number_width.push(width);
             }
             Event::End(TagEnd::List(_)) => {
                 self.list_depth = self.list_depth.saturating_sub(1);
                 self.list_item_number.pop();
+                self.list_number_width.pop();
                 self.ordered_list_stack.pop();
                 if self.list_depth == 0 {
                     self.in_list = false;
                 }
+                // Separate a list inside a quote from the quote's next block
+                if let Some(quote) = self.block_quotes.last()
+                    && quote.list_depth == self.list_depth
+                {
+                    self.block_quote_pending_separator = Some(self.block_quotes.len());
+                }
             }
 
             Event::Start(Tag::Item) => {
@@ -1573,6 +2917,7 @@ impl<'a> RenderContext<'a> {
 
             // Tables
             Event::Start(Tag::Table(alignments)) => {
+                self.begin_block();
                 self.in_table = true;
                 self.table_alignments = alignments;
                 self.table_rows.clear();
@@ -1685,6 +3030,11 @@ impl<'a> RenderContext<'a> {
                 self.link_url = dest_url.to_string();
                 self.link_title = title.to_string();
                 self.link_is_autolink_email = matches!(link_type, pulldown_cmark::LinkType::Email);
+                self.link_start = if self.in_table {
+                    self.current_cell.len()
+                } else {
+                    self.text_buffer.len()
+                };
             }
             Event::End(TagEnd::Link) => {
                 // Append URL after link text, like Go glamour does
@@ -1695,9 +3045,21 @@ impl<'a> RenderContext<'a> {
                 {
                     self.link_url = format!("mailto:{}", self.link_url);
                 }
-                if !self.link_url.is_empty() && !self.text_buffer.ends_with(&self.link_url) {
+                if self.options.hyperlinks && !self.link_url.is_empty() {
+                    let buffer = if self.in_table {
+                        &mut self.current_cell
+                    } else {
+                        &mut self.text_buffer
+                    };
+                    let mut text = buffer.split_off(self.link_start.min(buffer.len()));
+                    if text == self.link_url {
+                        text = truncate_url(&text, self.options.max_url_width).into_owned();
+                    }
+                    buffer.push_str(&hyperlink(&self.link_url, &text));
+                } else if !self.link_url.is_empty() && !self.text_buffer.ends_with(&self.link_url) {
+                    let url = truncate_url(&self.link_url, self.options.max_url_width).into_owned();
                     self.text_buffer.push(' ');
-                    self.text_buffer.push_str(&self.link_url);
+                    self.text_buffer.push_str(&url);
                 }
                 self.in_link = false;
                 self.link_is_autolink_email = false;
@@ -1717,10 +3079,36 @@ impl<'a> RenderContext<'a> {
                 self.in_image = false;
             }
 
+            Event::Start(Tag::HtmlBlock) => {
+                self.html_block = Some(String::new());
+            }
+            Event::Html(html) => {
+                if let Some(block) = &mut self.html_block {
+                    block.push_str(&html);
+                }
+            }
+            Event::End(TagEnd::HtmlBlock) => {
+                if let Some(html) = self.html_block.take() {
+                    self.flush_html_block(&html);
+                }
+            }
+            Event::InlineHtml(html) => match self.options.html_mode {
+                HtmlMode::Drop => {}
+                HtmlMode::Verbatim => self.handle_event(Event::Text(html)),
+                HtmlMode::Strip | HtmlMode::Convert => self.push_html(&html),
+            },
+
             // Text content
             Event::Text(text) => {
+                let text = if self.emoji && !self.in_code_block && self.html_code.is_none() {
+                    emoji::replace_shortcodes(&text).into_owned().into()
+                } else {
+                    text
+                };
                 if self.in_code_block {
                     self.code_block_content.push_str(&text);
+                } else if let Some(code) = &mut self.html_code {
+                    code.push_str(&text);
                 } else if self.in_table {
                     self.current_cell.push_str(&text);
                 } else if self.in_image {
@@ -1763,8 +3151,47 @@ impl<'a> RenderContext<'a> {
             }
 
             Event::Rule => {
-                self.output
-                    .push_str(&self.options.styles.horizontal_rule.format);
+                #[cfg(feature = "blocks")]
+                if self.collect(|| blocks::Block::Rule) {
+                    return;
+                }
+                let rule = &self.options.styles.horizontal_rule.format;
+                if self.is_top_level() {
+                    self.begin_block();
+                    self.output.push_str(rule.trim_matches('\n'));
+                    self.output.push('\n');
+                } else {
+                    self.output.push_str(rule);
+                }
+            }
+
+            Event::FootnoteReference(label) => {
+                let number = self.footnote_number(&label);
+                let style = self.options.styles.footnotes.reference.to_lipgloss();
+                let marker = style.render(&format!("[^{number}]"));
+                if self.in_table {
+                    self.current_cell.push_str(&marker);
+                } else {
+                    self.text_buffer.push_str(&marker);
+                }
+            }
+
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                // Definitions render on their own and move to the end
+                let number = self.footnote_number(&label);
+                let outer = std::mem::take(&mut self.output);
+                self.footnote = Some((number, outer, self.body_start));
+                self.body_start = 0;
+                self.footnote_indent = visible_width(&footnote_label(number));
+            }
+            Event::End(TagEnd::FootnoteDefinition) => {
+                if let Some((number, outer, body_start)) = self.footnote.take() {
+                    self.trim_trailing_newlines();
+                    let body = std::mem::replace(&mut self.output, outer);
+                    self.body_start = body_start;
+                    self.footnote_indent = 0;
+                    self.footnotes.push((number, body));
+                }
             }
 
             Event::TaskListMarker(checked) => {
@@ -1781,18 +3208,187 @@ impl<'a> RenderContext<'a> {
         }
     }
 
-    fn flush_heading(&mut self) {
-        if let Some(level) = self.in_heading {
-            let heading_style = self.options.styles.heading_style(level);
-            let base_heading = &self.options.styles.heading;
-
-            // Build the heading text
-            let mut heading_text = String::new();
-            heading_text.push_str(&heading_style.style.prefix);
-            heading_text.push_str(&self.text_buffer);
-            heading_text.push_str(&heading_style.style.suffix);
+    /// Returns the number of the footnote `label`, numbering it after the
+    /// footnotes seen so far if it is new.
+    fn footnote_number(&mut self, label: &str) -> usize {
+        let next = self.footnote_numbers.len() + 1;
+        *self
+            .footnote_numbers
+            .entry(label.to_string())
+            .or_insert(next)
+    }
 
-            // Apply lipgloss styling
+    /// Writes the footnote section, listing the footnotes in order of
+    /// reference with their bodies hanging under their labels.
+    fn flush_footnotes(&mut self) {
+        let mut footnotes = std::mem::take(&mut self.footnotes);
+        if footnotes.is_empty() {
+            return;
+        }
+        footnotes.sort_by_key(|(number, _)| *number);
+
+        self.begin_block();
+        let style = self.options.styles.footnotes.label.to_lipgloss();
+        for (number, body) in footnotes {
+            let label = footnote_label(number);
+            let indent = " ".repeat(visible_width(&label));
+            let first = format!("{} ", style.render(label.trim_end()));
+            for (i, line) in body.lines().enumerate() {
+                if i == 0 {
+                    self.output.push_str(&first);
+                } else if !line.is_empty() {
+                    self.output.push_str(&indent);
+                }
+                self.output.push_str(line);
+                self.output.push('\n');
+            }
+        }
+    }
+
+    /// Returns whether the renderer is outside any list or block quote,
+    /// where blocks follow each other in the document body.
+    fn is_top_level(&self) -> bool {
+        self.list_depth == 0 && self.block_quotes.is_empty()
+    }
+
+    /// Returns whether the innermost open container is a list item, whose
+    /// paragraphs collect into the item's text.
+    fn in_list_item(&self) -> bool {
+        let quote_list_depth = self.block_quotes.last().map_or(0, |quote| quote.list_depth);
+        self.list_depth > quote_list_depth
+    }
+
+    /// Closes block quotes until `depth` remain open.
+    fn close_block_quotes(&mut self, depth: usize) {
+        self.block_quotes.truncate(depth);
+        // Keep a pending separator from drawing bars for closed quotes
+        if let Some(ref mut sep_depth) = self.block_quote_pending_separator
+            && *sep_depth > depth
+        {
+            *sep_depth = depth;
+        }
+        if depth == 0 {
+            self.block_quote_pending_separator = None;
+        }
+    }
+
+    /// Removes trailing newlines from the body, returning whether any body
+    /// content remains.
+    fn trim_trailing_newlines(&mut self) -> bool {
+        let body = &self.output[self.body_start..];
+        let end = self.body_start + body.trim_end_matches('\n').len();
+        self.output.truncate(end);
+        end > self.body_start
+    }
+
+    /// Starts a top-level block.
+    ///
+    /// Every top-level block is separated from the previous one by exactly
+    /// one blank line, whatever trailing newlines the previous block left,
+    /// and the first block starts right after the document prefix. Blocks
+    /// nested in lists and block quotes keep their own spacing. Headings
+    /// set their own gaps through their margins.
+    /// Renders a block of raw HTML according to the HTML mode, as a
+    /// paragraph of its text unless written out verbatim.
+    fn flush_html_block(&mut self, html: &str) {
+        let html = html.trim_end();
+        match self.options.html_mode {
+            HtmlMode::Drop => {}
+            HtmlMode::Verbatim if self.in_list_item() => self.text_buffer.push_str(html),
+            HtmlMode::Verbatim => {
+                self.begin_block();
+                self.output.push_str(html);
+                self.output.push_str("\n\n");
+            }
+            HtmlMode::Strip | HtmlMode::Convert => {
+                let has_text = html_tokens(html).iter().any(|token| match token {
+                    HtmlToken::Text(text) => !text.trim().is_empty(),
+                    HtmlToken::Tag { name, closing } => {
+                        self.options.html_mode == HtmlMode::Convert && name == "summary" && !closing
+                    }
+                });
+                if !has_text {
+                    return;
+                }
+                self.handle_event(Event::Start(Tag::Paragraph));
+                self.push_html(html);
+                if let Some(code) = self.html_code.take() {
+                    self.handle_event(Event::Text(code.into()));
+                }
+                self.handle_event(Event::End(TagEnd::Paragraph));
+            }
+        }
+    }
+
+    /// Renders the text of a piece of raw HTML, converting the tags
+    /// [`HtmlMode::Convert`] knows into the matching markdown events.
+    fn push_html(&mut self, html: &str) {
+        let convert = self.options.html_mode == HtmlMode::Convert;
+        for token in html_tokens(html) {
+            let (name, closing) = match token {
+                HtmlToken::Text(text) => {
+                    self.handle_event(Event::Text(text.into()));
+                    continue;
+                }
+                HtmlToken::Tag { .. } if !convert => continue,
+                HtmlToken::Tag { name, closing } => (name, closing),
+            };
+            match (name.as_str(), closing) {
+                ("br", _) => self.handle_event(Event::HardBreak),
+                ("b" | "strong", false) => self.handle_event(Event::Start(Tag::Strong)),
+                ("b" | "strong", true) => self.handle_event(Event::End(TagEnd::Strong)),
+                ("i" | "em", false) => self.handle_event(Event::Start(Tag::Emphasis)),
+                ("i" | "em", true) => self.handle_event(Event::End(TagEnd::Emphasis)),
+                ("code" | "kbd", false) => self.html_code = Some(String::new()),
+                ("code" | "kbd", true) => {
+                    if let Some(code) = self.html_code.take() {
+                        self.handle_event(Event::Code(code.into()));
+                    }
+                }
+                ("summary", false) => {
+                    self.handle_event(Event::Text("▸ ".into()));
+                    self.handle_event(Event::Start(Tag::Strong));
+                }
+                ("summary", true) => self.handle_event(Event::End(TagEnd::Strong)),
+                _ => {}
+            }
+        }
+    }
+
+    fn begin_block(&mut self) {
+        let gap = self.next_gap.take().unwrap_or(1);
+        self.begin_block_with_gap(gap);
+    }
+
+    /// Starts a top-level block `gap` blank lines below the previous one.
+    fn begin_block_with_gap(&mut self, gap: usize) {
+        if !self.is_top_level() {
+            return;
+        }
+        if self.trim_trailing_newlines() {
+            self.output.push_str(&"\n".repeat(gap + 1));
+        }
+    }
+
+    fn flush_heading(&mut self) {
+        if let Some(level) = self.in_heading {
+            #[cfg(feature = "blocks")]
+            if self.blocks.is_some() {
+                let text = std::mem::take(&mut self.text_buffer);
+                self.collect(|| blocks::Block::Heading { level, text });
+                return;
+            }
+
+            let heading_style = self.options.styles.heading_style(level);
+            let base_heading = &self.options.styles.heading;
+
+            // Build the heading text
+            let mut heading_text = String::new();
+            heading_text.push_str(&heading_style.style.prefix);
+            heading_text.push_str(&self.text_buffer);
+            heading_text.push_str(&heading_style.style.suffix);
+
+            // Apply lipgloss styling
             let mut style = base_heading.style.to_lipgloss();
 
             // Merge heading-level specific styles
@@ -1809,38 +3405,111 @@ impl<'a> RenderContext<'a> {
                 style = style.italic();
             }
 
-            let rendered = style.render(&heading_text);
+            let mut rendered = style.render(&heading_text);
+            if let Some(glyph) = &self.options.heading_anchor
+                && self.is_top_level()
+                && self.footnote.is_none()
+            {
+                let glyph = self
+                    .options
+                    .styles
+                    .heading_anchor
+                    .to_lipgloss()
+                    .render(glyph);
+                rendered.push(' ');
+                match self.heading_slugs.pop_front() {
+                    Some(slug) if self.options.hyperlinks => {
+                        let base = self.options.base_url.as_deref().unwrap_or("");
+                        rendered.push_str(&hyperlink(&format!("{base}#{slug}"), &glyph));
+                    }
+                    _ => rendered.push_str(&glyph),
+                }
+            }
 
-            self.output.push_str(&heading_style.style.block_prefix);
-            self.output.push('\n');
+            if self.is_top_level() {
+                // Blank lines between top-level blocks come from begin_block
+                let prefix = heading_style.style.block_prefix.trim_start_matches('\n');
+                self.output.push_str(prefix);
+            } else {
+                self.output.push_str(&heading_style.style.block_prefix);
+                self.output.push('\n');
+            }
             self.output.push_str(&rendered);
             self.output.push_str(&base_heading.style.block_suffix);
+            if self.is_top_level() {
+                self.next_gap = Some(self.options.styles.heading_margins(level).1);
+            }
 
             self.text_buffer.clear();
         }
     }
 
+    /// Builds the quote bar prefix for the given nesting depth.
+    ///
+    /// Each depth uses its entry from `block_quote_levels` when set, and
+    /// callouts color their own bar. A quote opened inside a list item is
+    /// indented to the column of the item's marker.
+    fn block_quote_prefix(&self, depth: usize) -> String {
+        let styles = &self.options.styles;
+        let base_token = styles.block_quote.indent_token.as_deref().unwrap_or("│ ");
+        let mut prefix = String::new();
+        let mut list_depth = 0;
+        for (index, quote) in self.block_quotes.iter().take(depth).enumerate() {
+            let level = index + 1;
+            let nested_lists = quote.list_depth.saturating_sub(list_depth);
+            prefix.push_str(&" ".repeat(nested_lists.saturating_sub(1) * styles.list.level_indent));
+            list_depth = quote.list_depth;
+
+            let level_style = styles.block_quote_level(level);
+            let token = level_style
+                .and_then(|block| block.indent_token.as_deref())
+                .unwrap_or(base_token);
+            let callout = quote.kind.map(|kind| styles.callouts.get(kind));
+            match callout.or(level_style.map(|block| &block.style)) {
+                Some(style) => prefix.push_str(&style.to_lipgloss().render(token)),
+                None => prefix.push_str(token),
+            }
+        }
+        prefix
+    }
+
+    /// Writes the title line that opens a callout.
+    fn flush_callout_title(&mut self, kind: BlockQuoteKind) {
+        let title = match kind {
+            BlockQuoteKind::Note => "Note",
+            BlockQuoteKind::Tip => "Tip",
+            BlockQuoteKind::Important => "Important",
+            BlockQuoteKind::Warning => "Warning",
+            BlockQuoteKind::Caution => "Caution",
+        };
+        let style = self.options.styles.callouts.get(kind).to_lipgloss().bold();
+        let prefix = self.block_quote_prefix(self.block_quotes.len());
+        self.output.push_str(&prefix);
+        self.output.push_str(&style.render(title));
+        self.output.push('\n');
+    }
+
     fn flush_paragraph(&mut self) {
         if !self.text_buffer.is_empty() {
             let text = std::mem::take(&mut self.text_buffer);
 
-            // Apply word wrap
-            let wrapped = self.word_wrap(&text);
+            #[cfg(feature = "blocks")]
+            if self.blocks.is_some() {
+                let quote_depth = self.block_quotes.len();
+                self.collect(|| blocks::Block::Paragraph { text, quote_depth });
+                return;
+            }
+
+            // Wrap to fit after the block quote bars, if any
+            let prefix = self.block_quote_prefix(self.block_quotes.len());
+            let wrapped = self.word_wrap(&text, visible_width(&prefix));
 
             // Apply paragraph styling
             let style = self.options.styles.paragraph.style.to_lipgloss();
             let rendered = style.render(&wrapped);
 
             // Add block quote indent if needed
-            if self.block_quote_depth > 0 {
-                let indent_token = self
-                    .options
-                    .styles
-                    .block_quote
-                    .indent_token
-                    .as_deref()
-                    .unwrap_or("│ ");
-                let prefix = indent_token.repeat(self.block_quote_depth);
+            if !self.block_quotes.is_empty() {
                 let indented = rendered
                     .lines()
                     .map(|line| format!("{}{}", prefix, line))
@@ -1848,7 +3517,7 @@ impl<'a> RenderContext<'a> {
                     .join("\n");
                 self.output.push_str(&indented);
                 self.output.push('\n');
-                self.block_quote_pending_separator = Some(self.block_quote_depth);
+                self.block_quote_pending_separator = Some(self.block_quotes.len());
             } else {
                 self.output.push_str(&rendered);
                 self.output.push_str("\n\n");
@@ -1874,8 +3543,15 @@ impl<'a> RenderContext<'a> {
             }
         }
 
-        let indent = (self.list_depth - 1) * self.options.styles.list.level_indent;
-        let indent_str = " ".repeat(indent);
+        // Lists inside a quote are indented from the quote's bar
+        let quote_list_depth = self.block_quotes.last().map_or(0, |quote| quote.list_depth);
+        let indent =
+            (self.list_depth - quote_list_depth - 1) * self.options.styles.list.level_indent;
+        let indent_str = format!(
+            "{}{}",
+            self.block_quote_prefix(self.block_quotes.len()),
+            " ".repeat(indent)
+        );
 
         let is_ordered = self.ordered_list_stack.last().copied().unwrap_or(false);
         let mut prefix = if is_ordered {
@@ -1883,26 +3559,62 @@ impl<'a> RenderContext<'a> {
             if let Some(last) = self.list_item_number.last_mut() {
                 *last += 1;
             }
-            format!("{}{}", num, &self.options.styles.enumeration.block_prefix)
+            let number = self.options.styles.numbering(self.list_depth).format(num);
+            let width = self.list_number_width.last().copied().unwrap_or(0);
+            format!(
+                "{number:>width$}{}",
+                self.options.styles.enumeration.block_prefix
+            )
         } else {
-            self.options.styles.item.block_prefix.clone()
+            self.options.styles.bullet(self.list_depth).to_string()
         };
         if let Some(marker) = task_marker {
             prefix = marker;
         }
 
-        self.output.push_str(&indent_str);
-        self.output.push_str(&prefix);
-        self.output.push_str(text.trim());
+        #[cfg(feature = "blocks")]
+        if self.blocks.is_some() {
+            let depth = self.list_depth;
+            self.collect(|| blocks::Block::ListItem {
+                depth,
+                marker: prefix,
+                text: text.trim().to_string(),
+            });
+            return;
+        }
+
+        // Wrapped lines hang under the item's text, past the bullet
+        let first = format!("{indent_str}{prefix}");
+        let rest = format!("{indent_str}{}", " ".repeat(visible_width(&prefix)));
+        let item = self.wrap_hanging(text.trim(), &first, &rest);
+        self.output.push_str(&item);
         self.output.push('\n');
     }
 
     fn flush_code_block(&mut self) {
-        let content = std::mem::take(&mut self.code_block_content);
+        // Expand tabs up front so margins, line numbers and highlighting all
+        // see the same column widths the terminal will.
+        let content = lipgloss::expand_tabs(
+            &std::mem::take(&mut self.code_block_content),
+            CODE_TAB_WIDTH,
+        );
         let language = std::mem::take(&mut self.code_block_language);
+
+        #[cfg(feature = "blocks")]
+        if self.blocks.is_some() {
+            let language = (!language.is_empty()).then_some(language);
+            self.collect(|| blocks::Block::CodeBlock {
+                language,
+                code: content,
+            });
+            return;
+        }
+
         let style = &self.options.styles.code_block;
 
-        self.output.push('\n');
+        if !self.is_top_level() {
+            self.output.push('\n');
+        }
 
         // Apply margin
         let margin = style.block.margin.unwrap_or(0);
@@ -1934,16 +3646,8 @@ impl<'a> RenderContext<'a> {
                     let highlighted = highlight_code(&content, resolved_lang, &theme);
 
                     // Output with optional line numbers
-                    for (idx, line) in highlighted.lines().enumerate() {
-                        self.output.push_str(&margin_str);
-                        if syntax_config.line_numbers {
-                            // Format line number with right-aligned padding
-                            let line_num = idx + 1;
-                            self.output.push_str(&format!("{:4} │ ", line_num));
-                        }
-                        self.output.push_str(line);
-                        self.output.push('\n');
-                    }
+                    let line_numbers = syntax_config.line_numbers;
+                    self.push_code_lines(&highlighted, &margin_str, line_numbers);
 
                     self.output.push('\n');
                     return;
@@ -1955,19 +3659,53 @@ impl<'a> RenderContext<'a> {
         let _ = &language;
 
         // Fallback: no syntax highlighting
-        for line in content.lines() {
-            self.output.push_str(&margin_str);
+        self.push_code_lines(&content, &margin_str, false);
+
+        self.output.push('\n');
+    }
+
+    /// Writes the lines of a code block, folding it after
+    /// [`AnsiOptions::code_fold_lines`] unless it has been expanded.
+    fn push_code_lines(&mut self, code: &str, margin: &str, line_numbers: bool) {
+        let index = self.code_blocks;
+        self.code_blocks += 1;
+        let total = code.lines().count();
+        let fold_at = self.options.code_fold_lines.filter(|&limit| total > limit);
+        let expanded = self.options.expanded_folds.contains(&index);
+
+        for (idx, line) in code.lines().enumerate() {
+            if fold_at == Some(idx) {
+                let hidden_lines = total - idx;
+                self.folds.push(CodeFold {
+                    index,
+                    line: self.output.matches('\n').count(),
+                    hidden_lines,
+                    expanded,
+                });
+                if !expanded {
+                    let noun = if hidden_lines == 1 { "line" } else { "lines" };
+                    let marker = format!("… {hidden_lines} more {noun}");
+                    let style = self.options.styles.code_block.block.style.to_lipgloss();
+                    self.output.push_str(margin);
+                    self.output.push_str(&style.faint().render(&marker));
+                    self.output.push('\n');
+                    break;
+                }
+            }
+            self.output.push_str(margin);
+            if line_numbers {
+                // Format line number with right-aligned padding
+                self.output.push_str(&format!("{:4} │ ", idx + 1));
+            }
             self.output.push_str(line);
             self.output.push('\n');
         }
-
-        self.output.push('\n');
     }
 
     fn flush_table(&mut self) {
         use crate::table::{
             ColumnWidthConfig, MINIMAL_ASCII_BORDER, MINIMAL_BORDER, ParsedTable, TableCell,
-            calculate_column_widths, render_minimal_row, render_minimal_separator,
+            calculate_column_widths, render_minimal_row_lines, render_minimal_separator,
         };
 
         // Collect all rows (header + body) to count columns
@@ -2014,6 +3752,11 @@ impl<'a> RenderContext<'a> {
             return;
         }
 
+        #[cfg(feature = "blocks")]
+        if self.collect(|| blocks::Block::Table(parsed_table.clone())) {
+            return;
+        }
+
         // Determine border style - use minimal borders to match Go glamour
         // Go glamour only renders internal separators (no outer borders)
         let col_sep = self
@@ -2047,21 +3790,30 @@ impl<'a> RenderContext<'a> {
 
         let column_widths = calculate_column_widths(&parsed_table, &width_config);
         let widths = &column_widths.widths;
+        let overflow = self.options.styles.table.overflow.unwrap_or_default();
+        let ellipsis = self.options.styles.table.ellipsis.as_deref().unwrap_or("…");
 
-        // Output a blank styled line first (matching Go behavior)
         let doc_style = &self.options.styles.document.style;
         let lipgloss = doc_style.to_lipgloss();
-        // Just a newline with background if set
-        self.output.push('\n');
+        if !self.is_top_level() {
+            self.output.push('\n');
+        }
 
         // No top border - Go glamour doesn't render outer borders
 
         // Header row (rendered without outer borders)
         if !parsed_table.header.is_empty() {
-            let rendered_header =
-                render_minimal_row(&parsed_table.header, widths, &border, cell_padding);
-            self.output.push_str(&lipgloss.render(&rendered_header));
-            self.output.push('\n');
+            for line in render_minimal_row_lines(
+                &parsed_table.header,
+                widths,
+                &border,
+                cell_padding,
+                overflow,
+                ellipsis,
+            ) {
+                self.output.push_str(&lipgloss.render(&line));
+                self.output.push('\n');
+            }
 
             // Header separator (internal only)
             let sep = render_minimal_separator(widths, &border, cell_padding);
@@ -2073,9 +3825,12 @@ impl<'a> RenderContext<'a> {
 
         // Body rows (rendered without outer borders)
         for row in parsed_table.rows.iter() {
-            let rendered_row = render_minimal_row(row, widths, &border, cell_padding);
-            self.output.push_str(&lipgloss.render(&rendered_row));
-            self.output.push('\n');
+            for line in
+                render_minimal_row_lines(row, widths, &border, cell_padding, overflow, ellipsis)
+            {
+                self.output.push_str(&lipgloss.render(&line));
+                self.output.push('\n');
+            }
         }
 
         // No bottom border - Go glamour doesn't render outer borders
@@ -2087,6 +3842,27 @@ impl<'a> RenderContext<'a> {
         let alt_text = std::mem::take(&mut self.text_buffer);
         let url = std::mem::take(&mut self.image_url);
 
+        #[cfg(feature = "blocks")]
+        if self.blocks.is_some() {
+            self.collect(|| blocks::Block::Image { alt: alt_text, url });
+            return;
+        }
+
+        #[cfg(feature = "images")]
+        if let Some(image) = self.options.images.and_then(|protocol| {
+            images::render_image(
+                protocol,
+                &url,
+                self.options.base_url.as_deref(),
+                self.options.image_size_limit,
+                self.options.word_wrap,
+            )
+        }) {
+            self.output.push_str(&image);
+            self.output.push('\n');
+            return;
+        }
+
         let style = &self.options.styles.image_text;
         let format = if style.format.is_empty() {
             "Image: {{.text}} →"
@@ -2097,7 +3873,12 @@ impl<'a> RenderContext<'a> {
         let text = format.replace("{{.text}}", &alt_text);
 
         let link_style = self.options.styles.image.to_lipgloss();
-        let rendered_url = link_style.render(&url);
+        let shown = truncate_url(&url, self.options.max_url_width);
+        let rendered_url = if self.options.hyperlinks && shown != url {
+            hyperlink(&url, &link_style.render(&shown))
+        } else {
+            link_style.render(&shown)
+        };
 
         self.output.push_str(&text);
         self.output.push(' ');
@@ -2114,96 +3895,473 @@ impl<'a> RenderContext<'a> {
         lipgloss_style.render(&code_with_padding)
     }
 
-    /// Calculate the visible width of a string (excluding ANSI escapes).
-    /// Copied from lipgloss to handle ANSI-aware wrapping.
-    #[allow(dead_code)]
-    fn visible_width(&self, s: &str) -> usize {
-        visible_width(s)
+    /// Wraps paragraph text at spaces to fit after a prefix `indent` cells
+    /// wide. Tokens wider than a line, such as long URLs, are broken after a
+    /// `/` where possible and otherwise wherever the line fills up.
+    ///
+    /// Widths are measured like lipgloss measures them, so styling escapes
+    /// take no space and wide characters take two cells. With the `bidi`
+    /// feature, the wrapped lines are then put in display order.
+    fn word_wrap(&self, text: &str, indent: usize) -> String {
+        let wrapped = if self.options.word_wrap == 0 {
+            text.to_string()
+        } else {
+            let width = self
+                .options
+                .word_wrap
+                .saturating_sub(indent + self.footnote_indent)
+                .max(1);
+            let words: Vec<&str> = text.split_whitespace().collect();
+            lipgloss::wrap_at(&words.join(" "), width, "/")
+        };
+
+        #[cfg(feature = "bidi")]
+        if self.options.bidi {
+            return bidi::reorder_lines(&wrapped).into_owned();
+        }
+        wrapped
     }
 
-    fn word_wrap(&self, text: &str) -> String {
-        let width = self.options.word_wrap;
-        if width == 0 {
-            return text.to_string();
+    /// Wraps `text` after `first`, aligning continuation lines under the
+    /// start of the text by prefixing them with `rest`.
+    fn wrap_hanging(&self, text: &str, first: &str, rest: &str) -> String {
+        let wrapped = self.word_wrap(text, visible_width(first));
+        let mut out = String::new();
+        for (i, line) in wrapped.lines().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str(if i == 0 { first } else { rest });
+            out.push_str(line);
         }
+        out
+    }
+}
 
-        let mut result = String::new();
-        let mut current_line = String::new();
+/// Returns the parser options for the markdown extensions glamour renders.
+fn parser_options() -> Options {
+    let mut opts = Options::empty();
+    opts.insert(Options::ENABLE_TABLES);
+    opts.insert(Options::ENABLE_STRIKETHROUGH);
+    opts.insert(Options::ENABLE_TASKLISTS);
+    opts.insert(Options::ENABLE_GFM);
+    opts.insert(Options::ENABLE_FOOTNOTES);
+    opts.insert(Options::ENABLE_HEADING_ATTRIBUTES);
+    opts
+}
 
-        for word in text.split_whitespace() {
-            if current_line.is_empty() {
-                current_line.push_str(word);
-            } else if visible_width(&current_line) + 1 + visible_width(word) <= width {
-                current_line.push(' ');
-                current_line.push_str(word);
-            } else {
-                result.push_str(&current_line);
-                result.push('\n');
-                current_line = word.to_string();
+/// Returns the last item number of each ordered list in `events`, in the
+/// order the lists start.
+fn ordered_list_ends(events: &[Event<'_>]) -> Vec<usize> {
+    let mut ends = Vec::new();
+    // Index into `ends` of each open list, `None` for unordered lists
+    let mut open: Vec<Option<usize>> = Vec::new();
+    for event in events {
+        match event {
+            Event::Start(Tag::List(first_item)) => {
+                open.push(first_item.map(|first| {
+                    // One less than the first number, counted up per item
+                    ends.push((first as usize).wrapping_sub(1));
+                    ends.len() - 1
+                }));
             }
+            Event::End(TagEnd::List(_)) => {
+                open.pop();
+            }
+            Event::Start(Tag::Item) => {
+                if let Some(Some(list)) = open.last() {
+                    ends[*list] = ends[*list].wrapping_add(1);
+                }
+            }
+            _ => {}
         }
+    }
+    ends
+}
 
-        if !current_line.is_empty() {
-            result.push_str(&current_line);
+/// Removes escape sequences and C0 and C1 control characters from `text`,
+/// keeping tabs and line breaks.
+///
+/// CSI sequences end at their final byte; OSC, DCS and the other string
+/// sequences end at a string terminator or BEL, or with the text.
+fn strip_controls(text: &str) -> std::borrow::Cow<'_, str> {
+    let is_stripped = |c: char| c.is_control() && !matches!(c, '\t' | '\n' | '\r');
+    if !text.chars().any(is_stripped) && !text.contains("\r") {
+        return text.into();
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        // Normalize escape sequences to their C1 form
+        let c = match c {
+            '\x1b' => match chars.peek() {
+                Some(&next @ '@'..='_') => {
+                    chars.next();
+                    char::from_u32(u32::from(next) + 0x40).unwrap_or('\u{80}')
+                }
+                Some(' '..='~') => {
+                    chars.next();
+                    continue;
+                }
+                _ => continue,
+            },
+            _ => c,
+        };
+        match c {
+            '\r' if chars.peek() == Some(&'\n') => out.push(c),
+            '\u{9b}' => {
+                // Parameters and intermediates up to the final byte
+                for next in chars.by_ref() {
+                    if ('@'..='~').contains(&next) {
+                        break;
+                    }
+                }
+            }
+            '\u{90}' | '\u{98}' | '\u{9d}' | '\u{9e}' | '\u{9f}' => {
+                while let Some(next) = chars.next() {
+                    if matches!(next, '\x07' | '\u{9c}') {
+                        break;
+                    }
+                    if next == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            c if is_stripped(c) || c == '\r' => {}
+            c => out.push(c),
         }
-
-        result
     }
+    out.into()
 }
 
-/// Calculate the visible width of a string (excluding ANSI escapes).
-pub(crate) fn visible_width(s: &str) -> usize {
-    let mut width = 0;
-    #[derive(Clone, Copy, PartialEq)]
-    enum State {
-        Normal,
-        Esc,
-        Csi,
-        Osc,
+/// A tag or run of text in raw HTML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum HtmlToken {
+    /// A tag, with its lowercase name.
+    Tag { name: String, closing: bool },
+    /// Text with entities decoded.
+    Text(String),
+}
+
+/// Splits raw HTML into tags and text, leaving out comments.
+fn html_tokens(html: &str) -> Vec<HtmlToken> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        if rest.starts_with('<')
+            && let Some(end) = rest.find('>')
+        {
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+            let closing = tag.starts_with('/');
+            let name = tag
+                .trim_start_matches('/')
+                .chars()
+                .take_while(char::is_ascii_alphanumeric)
+                .collect::<String>()
+                .to_ascii_lowercase();
+            tokens.push(HtmlToken::Tag { name, closing });
+            continue;
+        }
+        let end = rest[1..].find('<').map_or(rest.len(), |i| i + 1);
+        tokens.push(HtmlToken::Text(decode_entities(&rest[..end])));
+        rest = &rest[end..];
     }
-    let mut state = State::Normal;
+    tokens
+}
 
-    for c in s.chars() {
-        match state {
-            State::Normal => {
-                if c == '\x1b' {
-                    state = State::Esc;
-                } else {
-                    width += unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+/// Decodes the common named HTML entities and numeric character
+/// references, leaving unknown ones as they are.
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => '\u{a0}',
+                _ => {
+                    let code = entity.strip_prefix('#')?;
+                    let value = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+                    char::from_u32(value).filter(|c| !c.is_control())?
                 }
+            };
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
             }
-            State::Esc => {
-                if c == '[' {
-                    state = State::Csi;
-                } else if c == ']' {
-                    state = State::Osc;
-                } else {
-                    // Handle simple escapes like \x1b7 (save cursor) or \x1b> (keypad)
-                    // They are single char after ESC.
-                    state = State::Normal;
-                }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
             }
-            State::Csi => {
-                // CSI sequence: [params] [intermediate] final
-                // Final byte is 0x40-0x7E (@ to ~)
-                if ('@'..='~').contains(&c) {
-                    state = State::Normal;
-                }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Returns the label that starts footnote `number` in the footnote section.
+fn footnote_label(number: usize) -> String {
+    format!("[^{number}]: ")
+}
+
+/// Shortens `url` to at most `max_width` cells by replacing its middle
+/// with `…`, keeping the scheme and host at the start and the last path
+/// segment at the end. Without a maximum the URL is returned as it is.
+fn truncate_url(url: &str, max_width: Option<usize>) -> std::borrow::Cow<'_, str> {
+    let Some(max_width) = max_width.filter(|&max| visible_width(url) > max) else {
+        return std::borrow::Cow::Borrowed(url);
+    };
+    let budget = max_width.saturating_sub(1);
+    let take = |chars: &mut dyn Iterator<Item = char>, budget: usize| {
+        let mut width = 0;
+        let mut taken = Vec::new();
+        for c in chars {
+            width += c.width().unwrap_or(0);
+            if width > budget {
+                break;
             }
-            State::Osc => {
-                // OSC sequence: ] [params] ; [text] BEL/ST
-                // Handle BEL (\x07)
-                if c == '\x07' {
-                    state = State::Normal;
-                } else if c == '\x1b' {
-                    // Handle ST (ESC \) - we see ESC, transition to Esc to handle the backslash
-                    state = State::Esc;
-                }
+            taken.push(c);
+        }
+        taken
+    };
+    let head = take(&mut url.chars(), budget.div_ceil(2));
+    let mut tail = take(&mut url.chars().rev(), budget / 2);
+    tail.reverse();
+    let mut shortened: String = head.into_iter().collect();
+    shortened.push('…');
+    shortened.extend(tail);
+    std::borrow::Cow::Owned(shortened)
+}
+
+/// Wraps `text` in OSC 8 hyperlinks to `url`.
+///
+/// Each word is linked on its own, so that wrapping never leaves a link
+/// open across a line break, where it would also cover the indentation of
+/// the next line.
+fn hyperlink(url: &str, text: &str) -> String {
+    text.split(' ')
+        .map(|word| {
+            if word.is_empty() {
+                String::new()
+            } else {
+                format!("\x1b]8;;{url}\x1b\\{word}\x1b]8;;\x1b\\")
             }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl Style {
+    /// Picks the style [`Style::Auto`] stands for on this terminal.
+    ///
+    /// Returns [`Style::NoTty`] when stdout is not a terminal. Otherwise the
+    /// terminal's background color decides between [`Style::Dark`] and
+    /// [`Style::Light`]. The terminal is asked for it with an OSC 11 query,
+    /// falling back to the `COLORFGBG` variable set by some terminals, and
+    /// the background is assumed dark when neither tells. The result is
+    /// worked out once and reused.
+    pub fn detect() -> Style {
+        use std::io::IsTerminal;
+        static DETECTED: OnceLock<Style> = OnceLock::new();
+        *DETECTED.get_or_init(|| {
+            detect_style(std::io::stdout().is_terminal(), query_background, |name| {
+                std::env::var(name).ok()
+            })
+        })
+    }
+}
+
+/// How long to wait for the terminal to report its background color.
+#[cfg(unix)]
+const BACKGROUND_QUERY_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(100);
+
+fn detect_style(
+    is_terminal: bool,
+    query: impl FnOnce() -> Option<bool>,
+    var: impl Fn(&str) -> Option<String>,
+) -> Style {
+    if !is_terminal {
+        return Style::NoTty;
+    }
+    let dark = query()
+        .or_else(|| var("COLORFGBG").and_then(|value| colorfgbg_is_dark(&value)))
+        .unwrap_or(true);
+    if dark { Style::Dark } else { Style::Light }
+}
+
+/// Reads the background from `COLORFGBG`, whose last field is the ANSI
+/// color number of the background.
+fn colorfgbg_is_dark(value: &str) -> Option<bool> {
+    let bg: u8 = value.rsplit(';').next()?.parse().ok()?;
+    Some(matches!(bg, 0..=6 | 8))
+}
+
+/// Reads the background from a terminal's answer to an OSC 11 query, such
+/// as `ESC ] 11 ; rgb:1e1e/1e1e/2e2e BEL`.
+fn osc11_is_dark(response: &str) -> Option<bool> {
+    let (_, rest) = response.split_once("\x1b]11;rgb:")?;
+    let end = rest.find(['\x07', '\x1b']).unwrap_or(rest.len());
+    let mut channels = rest[..end].split('/').map(|hex| {
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        let max = 16u32.checked_pow(u32::try_from(hex.len()).ok()?)? - 1;
+        (max > 0).then(|| f64::from(value) / f64::from(max))
+    });
+    let (r, g, b) = (channels.next()??, channels.next()??, channels.next()??);
+    Some(0.2126 * r + 0.7152 * g + 0.0722 * b < 0.5)
+}
+
+/// Asks the controlling terminal for its background color.
+#[cfg(unix)]
+fn query_background() -> Option<bool> {
+    use rustix::termios::{OptionalActions, tcgetattr, tcsetattr};
+
+    let mut tty = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    let saved = tcgetattr(&tty).ok()?;
+    let mut raw = saved.clone();
+    raw.make_raw();
+    tcsetattr(&tty, OptionalActions::Now, &raw).ok()?;
+    let response = read_background_response(&mut tty);
+    let _ = tcsetattr(&tty, OptionalActions::Now, &saved);
+    osc11_is_dark(&response?)
+}
+
+#[cfg(not(unix))]
+fn query_background() -> Option<bool> {
+    None
+}
+
+/// Sends the OSC 11 query and collects the answer.
+///
+/// A device attributes query follows, which every terminal answers, so
+/// terminals that ignore OSC 11 don't leave us waiting for the timeout.
+#[cfg(unix)]
+fn read_background_response(tty: &mut std::fs::File) -> Option<String> {
+    use rustix::event::{PollFd, PollFlags, Timespec, poll};
+    use std::io::{Read, Write};
+
+    tty.write_all(b"\x1b]11;?\x07\x1b[c").ok()?;
+    let deadline = std::time::Instant::now() + BACKGROUND_QUERY_TIMEOUT;
+    let mut response = Vec::new();
+    let mut buf = [0u8; 64];
+    loop {
+        let left = deadline.checked_duration_since(std::time::Instant::now())?;
+        let timeout = Timespec::try_from(left).ok()?;
+        let mut fds = [PollFd::new(&*tty, PollFlags::IN)];
+        if poll(&mut fds, Some(&timeout)).ok()? == 0 {
+            return None;
+        }
+        let n = tty.read(&mut buf).ok()?;
+        if n == 0 {
+            return None;
+        }
+        response.extend_from_slice(&buf[..n]);
+
+        // The device attributes come last, as `ESC [ ? ... c`
+        let text = String::from_utf8_lossy(&response);
+        if let Some((_, attributes)) = text.rsplit_once("\x1b[?")
+            && attributes.contains('c')
+        {
+            return Some(text.into_owned());
         }
     }
+}
+
+/// Reports whether the terminal on stdout is likely to support OSC 8
+/// hyperlinks.
+///
+/// Detection goes by environment variables, since terminfo has no entry
+/// for hyperlinks. `FORCE_HYPERLINK` overrides it: `0` disables hyperlinks
+/// and any other value enables them, even when stdout is not a terminal.
+/// Otherwise hyperlinks are enabled for terminals known to support them
+/// (iTerm2, WezTerm, kitty, Alacritty, foot, Ghostty, Windows Terminal,
+/// VS Code, Konsole and VTE-based terminals) and disabled in CI and when
+/// stdout is redirected.
+pub fn supports_hyperlinks() -> bool {
+    use std::io::IsTerminal;
+    detect_hyperlinks(
+        |name| std::env::var(name).ok(),
+        std::io::stdout().is_terminal(),
+    )
+}
 
-    width
+fn detect_hyperlinks(var: impl Fn(&str) -> Option<String>, is_terminal: bool) -> bool {
+    if let Some(force) = var("FORCE_HYPERLINK") {
+        return force != "0";
+    }
+    if !is_terminal || var("CI").is_some() {
+        return false;
+    }
+    if var("DOMTERM").is_some() || var("WT_SESSION").is_some() || var("KONSOLE_VERSION").is_some() {
+        return true;
+    }
+    if var("VTE_VERSION")
+        .and_then(|version| version.parse::<u32>().ok())
+        .is_some_and(|version| version >= 5000)
+    {
+        return true;
+    }
+    let program = var("TERM_PROGRAM").unwrap_or_default();
+    let term = var("TERM").unwrap_or_default();
+    matches!(
+        program.as_str(),
+        "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper" | "terminology"
+    ) || matches!(
+        term.as_str(),
+        "xterm-kitty" | "alacritty" | "alacritty-direct" | "foot" | "xterm-ghostty" | "contour"
+    )
+}
+
+/// Reports whether the terminal is likely to draw emoji.
+///
+/// Emoji are assumed missing when the locale set by `LC_ALL`, `LC_CTYPE`
+/// or `LANG` is not UTF-8, on the Linux console and other terminals
+/// without color glyphs (`TERM` of `linux`, `dumb` or `vt*`), and in the
+/// legacy Windows console. Everywhere else, emoji are assumed to work.
+pub fn supports_emoji() -> bool {
+    detect_emoji(|name| std::env::var(name).ok(), cfg!(windows))
+}
+
+fn detect_emoji(var: impl Fn(&str) -> Option<String>, windows: bool) -> bool {
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .find_map(|name| var(name).filter(|value| !value.is_empty()));
+    if let Some(locale) = locale {
+        let locale = locale.to_ascii_lowercase();
+        if !locale.contains("utf-8") && !locale.contains("utf8") {
+            return false;
+        }
+    }
+    let term = var("TERM").unwrap_or_default();
+    if term == "linux" || term == "dumb" || term.starts_with("vt") {
+        return false;
+    }
+    // The legacy console has no emoji font; Windows Terminal and the
+    // terminals that set TERM_PROGRAM do
+    !windows || var("WT_SESSION").is_some() || var("TERM_PROGRAM").is_some()
 }
 
 // ============================================================================
@@ -2235,68 +4393,500 @@ pub fn render_with_environment_config(markdown: &str) -> String {
     Renderer::new().with_style(style).render(markdown)
 }
 
-/// Available style names for configuration.
-pub fn available_styles() -> HashMap<&'static str, Style> {
-    let mut styles = HashMap::new();
-    styles.insert("ascii", Style::Ascii);
-    styles.insert("dark", Style::Dark);
-    styles.insert("dracula", Style::Dracula);
-    styles.insert("light", Style::Light);
-    styles.insert("pink", Style::Pink);
-    styles.insert("notty", Style::NoTty);
-    styles.insert("auto", Style::Auto);
-    styles
-}
+/// A document using every element glamour renders, shown by [`preview`].
+pub const PREVIEW_MARKDOWN: &str = r#"# Heading 1
+
+A paragraph with *emphasis*, **strong text**, ~~strikethrough~~, `inline
+code` and a [link](https://github.com/charmbracelet/glamour), plus a
+footnote[^1].
+
+## Heading 2
+
+### Heading 3
+
+#### Heading 4
+
+##### Heading 5
+
+###### Heading 6
+
+> A block quote
+> spanning two lines.
+
+> [!NOTE]
+> A callout with a note.
+
+> [!WARNING]
+> A callout with a warning.
+
+- A bullet list
+- with items
+  - and a nested item
+
+1. A numbered list
+2. with items
+
+- [x] A finished task
+- [ ] An open task
+
+```rust
+fn main() {
+    println!("Hello, glamour!");
+}
+```
+
+| Left | Center | Right |
+|:-----|:------:|------:|
+| one  | two    | three |
+| 1    | 2      | 3     |
+
+---
+
+![An image](glamour.png)
+
+[^1]: A footnote.
+"#;
+
+/// Renders [`PREVIEW_MARKDOWN`] with `style`, for a theme picker or for
+/// comparing styles.
+///
+/// Links are rendered as `text URL` and images as placeholders whatever the
+/// terminal supports, so the output is the same everywhere.
+///
+/// ```rust
+/// use glamour::{Style, preview};
+///
+/// let output = preview(Style::Ascii);
+/// assert!(output.contains("Heading 1"));
+/// assert!(output.contains("Hello, glamour!"));
+/// ```
+pub fn preview(style: Style) -> String {
+    let renderer = Renderer::new().with_style(style).with_hyperlinks(false);
+    #[cfg(feature = "images")]
+    let renderer = renderer.with_images(None);
+    renderer.render(PREVIEW_MARKDOWN)
+}
+
+/// Available style names for configuration.
+pub fn available_styles() -> HashMap<&'static str, Style> {
+    let mut styles = HashMap::new();
+    styles.insert("ascii", Style::Ascii);
+    styles.insert("dark", Style::Dark);
+    styles.insert("dracula", Style::Dracula);
+    styles.insert("light", Style::Light);
+    styles.insert("pink", Style::Pink);
+    styles.insert("notty", Style::NoTty);
+    styles.insert("auto", Style::Auto);
+    styles
+}
+
+/// Prelude module for convenient imports.
+pub mod prelude {
+    pub use crate::{
+        AnsiOptions, EmojiMode, HtmlMode, ListNumbering, Renderer, RendererOptions, SectionFilter,
+        Style, StyleBlock, StyleCallouts, StyleCodeBlock, StyleConfig, StyleList, StylePrimitive,
+        StyleTable, StyleTask, TermRenderer, ascii_style, available_styles, dark_style,
+        dracula_style, light_style, pink_style, preview, render, render_with_environment_config,
+    };
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renderer_new() {
+        let renderer = Renderer::new();
+        assert_eq!(renderer.options.word_wrap, DEFAULT_WIDTH);
+    }
+
+    #[test]
+    fn test_renderer_with_word_wrap() {
+        let renderer = Renderer::new().with_word_wrap(120);
+        assert_eq!(renderer.options.word_wrap, 120);
+    }
+
+    #[test]
+    fn test_renderer_with_style() {
+        let renderer = Renderer::new().with_style(Style::Light);
+        // Light style has different document color
+        assert!(renderer.options.styles.document.style.color.is_some());
+    }
+
+    #[test]
+    fn test_render_simple_text() {
+        let renderer = Renderer::new().with_style(Style::Ascii);
+        let output = renderer.render("Hello, world!");
+        assert!(output.contains("Hello, world!"));
+    }
+
+    #[test]
+    fn test_render_heading() {
+        let renderer = Renderer::new().with_style(Style::Ascii);
+        let output = renderer.render("# Heading");
+        assert!(output.contains("# Heading"));
+    }
+
+    #[test]
+    fn test_render_heading_margins() {
+        // Blank-line layout of the body: `true` for lines with content
+        let layout = |config: StyleConfig| {
+            let output = Renderer::new()
+                .with_style_config(config)
+                .render("Intro.\n\n# One\n\n## Two\n\nBody.");
+            output
+                .trim()
+                .lines()
+                .map(|line| !line.trim().is_empty())
+                .collect::<Vec<_>>()
+        };
+        let (x, o) = (true, false);
+        assert_eq!(layout(ascii_style()), [x, o, x, o, x, o, x]);
+
+        let mut config = ascii_style();
+        config.heading = config.heading.margin_top(2);
+        config.h1 = config.h1.margin_bottom(0);
+        config.h2 = config.h2.margin_bottom(0);
+        assert_eq!(config.heading_margins(HeadingLevel::H2), (2, 0));
+        // The second heading's top margin wins over the first one's bottom
+        assert_eq!(layout(config), [x, o, o, x, o, o, x, x]);
+    }
+
+    const SECTIONED: &str = "Preamble.\n\n# Guide\n\nIntro.\n\n## Installation\n\nRun cargo.\n\n### From source\n\nClone it.\n\n## Usage\n\nCall it.\n\n> # Quoted\n>\n> Quoted body.\n";
+
+    #[test]
+    fn test_render_section_filter() {
+        let output = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_section_filter(|path: &[String]| path.iter().any(|h| h == "Installation"))
+            .render(SECTIONED);
+        assert!(output.contains("## Installation"));
+        assert!(output.contains("Run cargo."));
+        assert!(output.contains("Clone it."));
+        assert!(!output.contains("Preamble."));
+        assert!(!output.contains("Intro."));
+        assert!(!output.contains("Call it."));
+    }
+
+    #[test]
+    fn test_render_section_filter_receives_heading_path() {
+        let paths = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = std::sync::Arc::clone(&paths);
+        Renderer::new()
+            .with_section_filter(move |path: &[String]| {
+                seen.lock().unwrap().push(path.to_vec());
+                true
+            })
+            .render(SECTIONED);
+        let paths = paths.lock().unwrap();
+        assert_eq!(paths[0], Vec::<String>::new());
+        assert!(paths.contains(&vec![
+            "Guide".to_string(),
+            "Installation".to_string(),
+            "From source".to_string()
+        ]));
+        assert!(paths.contains(&vec!["Guide".to_string(), "Usage".to_string()]));
+        // Headings nested in block quotes do not start sections.
+        assert!(!paths.iter().any(|p| p.contains(&"Quoted".to_string())));
+    }
+
+    #[test]
+    fn test_render_max_heading_depth() {
+        let output = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_max_heading_depth(2)
+            .render(SECTIONED);
+        assert!(output.contains("Preamble."));
+        assert!(output.contains("Run cargo."));
+        assert!(output.contains("Call it."));
+        assert!(output.contains("Quoted body."));
+        assert!(!output.contains("From source"));
+        assert!(!output.contains("Clone it."));
+    }
+
+    #[test]
+    fn test_extract_toc() {
+        let toc = extract_toc(SECTIONED);
+        let summary: Vec<(usize, &str, &str)> = toc
+            .iter()
+            .map(|e| (e.level, e.text.as_str(), e.slug.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (1, "Guide", "guide"),
+                (2, "Installation", "installation"),
+                (3, "From source", "from-source"),
+                (2, "Usage", "usage"),
+            ]
+        );
+
+        let toc = extract_toc("# `render()` & *Friends*\n\n# Q: What's new?\n\n# q what's new");
+        assert_eq!(toc[0].text, "render() & Friends");
+        assert_eq!(toc[0].slug, "render--friends");
+        assert_eq!(toc[1].slug, "q-whats-new");
+        assert_eq!(toc[2].slug, "q-whats-new-1");
+    }
+
+    #[test]
+    fn test_heading_attributes_and_setext_headings() {
+        let markdown = "Guide {#top .intro}\n=====\n\nSetup\n-----\n\n## Top\n\n## Usage {.wide}\n";
+        let toc = extract_toc(markdown);
+        let summary: Vec<(usize, &str, &str)> = toc
+            .iter()
+            .map(|e| (e.level, e.text.as_str(), e.slug.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (1, "Guide", "top"),
+                (2, "Setup", "setup"),
+                (2, "Top", "top-1"),
+                (2, "Usage", "usage"),
+            ]
+        );
+
+        // Setext headings render like ATX ones, and attributes are dropped
+        let renderer = Renderer::new().with_style(Style::Ascii);
+        let atx = renderer.render("# Guide\n\n## Setup\n\n## Usage\n");
+        let output =
+            renderer.render("Guide {#top .intro}\n=====\n\nSetup\n-----\n\n## Usage {.wide}\n");
+        assert_eq!(output, atx);
+        assert!(!output.contains('{'));
+    }
+
+    #[test]
+    fn test_toc_markdown_nests_by_level() {
+        let entry = |level: usize, text: &str| TocEntry {
+            level,
+            text: text.to_string(),
+            slug: slugify(text),
+        };
+        let markdown = toc_markdown(&[
+            entry(2, "A*"),
+            entry(4, "Deep"),
+            entry(3, "B"),
+            entry(2, "C"),
+        ]);
+        assert_eq!(markdown, "- A\\*\n  - Deep\n  - B\n- C\n");
+    }
+
+    #[test]
+    fn test_render_with_toc() {
+        let renderer = Renderer::new().with_style(Style::Ascii).with_toc(true);
+        let output = renderer.render(SECTIONED);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        let contents: Vec<&str> = lines
+            .iter()
+            .copied()
+            .skip_while(|line| line.is_empty())
+            .take(4)
+            .collect();
+        assert_eq!(
+            contents,
+            [
+                "  • Guide",
+                "      • Installation",
+                "          • From source",
+                "      • Usage"
+            ]
+        );
+        assert!(output.find("Preamble.").unwrap() > output.find("• Usage").unwrap());
+
+        let output = renderer
+            .clone()
+            .with_max_heading_depth(2)
+            .with_section_filter(|path: &[String]| path.iter().any(|h| h == "Installation"))
+            .render(SECTIONED);
+        assert!(output.contains("• Installation"));
+        assert!(!output.contains("• Guide"));
+        assert!(!output.contains("• From source"));
+
+        let plain = Renderer::new().with_style(Style::Ascii);
+        assert_eq!(
+            renderer.render("No headings."),
+            plain.render("No headings.")
+        );
+    }
+
+    #[test]
+    fn test_html_tokens() {
+        assert_eq!(
+            html_tokens("<B class=\"x\">a &amp; b</b><!-- note --><br/>&#x263A;&bogus;"),
+            [
+                HtmlToken::Tag {
+                    name: "b".to_string(),
+                    closing: false
+                },
+                HtmlToken::Text("a & b".to_string()),
+                HtmlToken::Tag {
+                    name: "b".to_string(),
+                    closing: true
+                },
+                HtmlToken::Tag {
+                    name: "br".to_string(),
+                    closing: false
+                },
+                HtmlToken::Text("☺&bogus;".to_string()),
+            ]
+        );
+        assert_eq!(html_tokens("<!-- open"), []);
+        assert_eq!(decode_entities("&#0;&lt;"), "&#0;<");
+    }
+
+    #[test]
+    fn test_render_html_modes() {
+        let markdown = "Press <kbd>q</kbd> to <b>quit</b>.\n\n<div align=\"center\">\n  Centered &amp; bold\n</div>\n\n<!-- hidden -->\n\nEnd.\n";
+        let render = |mode| {
+            Renderer::new()
+                .with_style(Style::Ascii)
+                .with_html_mode(mode)
+                .render(markdown)
+        };
 
-/// Prelude module for convenient imports.
-pub mod prelude {
-    pub use crate::{
-        AnsiOptions, Renderer, RendererOptions, Style, StyleBlock, StyleCodeBlock, StyleConfig,
-        StyleList, StylePrimitive, StyleTable, StyleTask, TermRenderer, ascii_style,
-        available_styles, dark_style, dracula_style, light_style, pink_style, render,
-        render_with_environment_config,
-    };
-}
+        let output = render(HtmlMode::Drop);
+        assert!(output.contains("Press q to quit."));
+        assert!(!output.contains("Centered"));
 
-// ============================================================================
-// Tests
-// ============================================================================
+        let output = render(HtmlMode::Strip);
+        assert!(output.contains("Press q to quit."));
+        assert!(output.contains("Centered & bold"));
+        assert!(!output.contains("hidden"));
+        assert!(!output.contains('<'));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let output = render(HtmlMode::Verbatim);
+        assert!(output.contains("Press <kbd>q</kbd> to <b>quit</b>."));
+        assert!(output.contains("<div align=\"center\">"));
+        assert!(output.contains("<!-- hidden -->"));
 
-    #[test]
-    fn test_renderer_new() {
-        let renderer = Renderer::new();
-        assert_eq!(renderer.options.word_wrap, DEFAULT_WIDTH);
+        let output = render(HtmlMode::Convert);
+        assert!(output.contains("Press q to **quit**."), "{output}");
+        assert!(output.contains("Centered & bold"));
+        assert!(!output.contains("hidden"));
     }
 
     #[test]
-    fn test_renderer_with_word_wrap() {
-        let renderer = Renderer::new().with_word_wrap(120);
-        assert_eq!(renderer.options.word_wrap, 120);
+    fn test_render_html_details() {
+        let markdown =
+            "<details>\n<summary>More <i>info</i></summary>\n\nHidden text.\n\n</details>\n";
+        let output = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_html_mode(HtmlMode::Convert)
+            .render(markdown);
+        let lines: Vec<&str> = output
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        assert_eq!(lines, ["▸ **More *info***", "Hidden text."]);
     }
 
     #[test]
-    fn test_renderer_with_style() {
-        let renderer = Renderer::new().with_style(Style::Light);
-        // Light style has different document color
-        assert!(renderer.options.styles.document.style.color.is_some());
+    fn test_preview_covers_every_element() {
+        let mut kinds = HashSet::new();
+        for event in Parser::new_ext(PREVIEW_MARKDOWN, parser_options()) {
+            let kind = match event {
+                Event::Start(Tag::Heading { level, .. }) => format!("{level:?}"),
+                Event::Start(Tag::BlockQuote(kind)) => format!("quote {kind:?}"),
+                Event::Start(Tag::List(start)) => format!("list {}", start.is_some()),
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(_))) => "code block".into(),
+                Event::Start(tag) => format!("{tag:?}")
+                    .split([' ', '(', '{'])
+                    .next()
+                    .unwrap()
+                    .into(),
+                Event::Code(_) => "code".into(),
+                Event::TaskListMarker(_) => "task".into(),
+                Event::FootnoteReference(_) => "footnote".into(),
+                Event::Rule => "rule".into(),
+                _ => continue,
+            };
+            kinds.insert(kind);
+        }
+        for kind in [
+            "H1",
+            "H2",
+            "H3",
+            "H4",
+            "H5",
+            "H6",
+            "quote None",
+            "quote Some(Note)",
+            "quote Some(Warning)",
+            "list true",
+            "list false",
+            "code block",
+            "Table",
+            "Emphasis",
+            "Strong",
+            "Strikethrough",
+            "Link",
+            "Image",
+            "FootnoteDefinition",
+            "code",
+            "task",
+            "footnote",
+            "rule",
+        ] {
+            assert!(kinds.contains(kind), "preview lacks {kind}");
+        }
+
+        for (name, style) in available_styles() {
+            let output = preview(style);
+            for text in [
+                "Heading 6",
+                "spanning two lines",
+                "nested item",
+                "Hello, glamour!",
+            ] {
+                assert!(output.contains(text), "{name} preview lacks {text:?}");
+            }
+        }
+        assert_eq!(preview(Style::Ascii), preview(Style::NoTty));
     }
 
     #[test]
-    fn test_render_simple_text() {
+    fn test_render_emoji() {
+        let markdown = "# Release :tada:\n\n\
+            - :white_check_mark: tests, :x: docs, :not_an_emoji:\n\n\
+            Run `cargo :rocket:`.\n\n```\n:fire:\n```\n";
         let renderer = Renderer::new().with_style(Style::Ascii);
-        let output = renderer.render("Hello, world!");
-        assert!(output.contains("Hello, world!"));
+
+        let output = renderer.clone().render(markdown);
+        assert!(output.contains("Release :tada:"));
+
+        let output = renderer.with_emoji(EmojiMode::On).render(markdown);
+        assert!(output.contains("Release 🎉"));
+        assert!(output.contains("✅ tests, ❌ docs, :not_an_emoji:"));
+        assert!(output.contains("cargo :rocket:"));
+        assert!(output.contains(":fire:"));
     }
 
     #[test]
-    fn test_render_heading() {
-        let renderer = Renderer::new().with_style(Style::Ascii);
-        let output = renderer.render("# Heading");
-        assert!(output.contains("# Heading"));
+    fn test_deterministic_ignores_environment() {
+        let renderer = Renderer::new()
+            .with_hyperlinks(true)
+            .deterministic()
+            .with_style(Style::Auto)
+            .with_emoji(EmojiMode::Auto);
+        assert!(!renderer.options.hyperlinks);
+        assert!(renderer.options.deterministic);
+
+        let output = renderer.render("# Hi :wave:\n\n[docs](https://example.com)");
+        assert!(output.contains("👋"));
+        assert!(output.contains("docs https://example.com"));
+        assert_eq!(
+            output,
+            Renderer::new()
+                .with_style(Style::Dark)
+                .with_hyperlinks(false)
+                .with_emoji(EmojiMode::On)
+                .render("# Hi :wave:\n\n[docs](https://example.com)")
+        );
     }
 
     #[test]
@@ -2386,6 +4976,191 @@ mod tests {
         assert_eq!(mailto_count, 1, "Email autolink should include mailto once");
     }
 
+    #[test]
+    fn test_render_hyperlinks() {
+        let markdown = "See [the docs](https://x.dev) or <https://y.dev>.";
+        let output = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_hyperlinks(true)
+            .render(markdown);
+        assert!(output.contains("\x1b]8;;https://x.dev\x1b\\the\x1b]8;;\x1b\\"));
+        assert!(output.contains("\x1b]8;;https://x.dev\x1b\\docs\x1b]8;;\x1b\\"));
+        let visible = strip_osc(&output);
+        assert!(!visible.contains("https://x.dev"));
+        assert_eq!(visible.matches("https://y.dev").count(), 1);
+
+        // Plain links are the default, whatever the terminal supports
+        let plain = Renderer::new().with_style(Style::Ascii).render(markdown);
+        assert!(plain.contains("the docs https://x.dev"));
+        assert!(!plain.contains("\x1b]8"));
+    }
+
+    #[test]
+    fn test_truncate_url() {
+        assert_eq!(
+            truncate_url("https://example.com/path", Some(16)),
+            "https://…om/path"
+        );
+        assert_eq!(truncate_url("https://x.dev", Some(13)), "https://x.dev");
+        assert_eq!(truncate_url("https://x.dev", Some(1)), "…");
+    }
+
+    #[test]
+    fn test_render_max_url_width() {
+        let url = "https://example.com/docs/guide/getting-started";
+        let markdown = format!("[guide]({url})\n\n![logo]({url})");
+        let plain = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_hyperlinks(false)
+            .with_max_url_width(24)
+            .render(&markdown);
+        assert!(plain.contains("guide https://exam…ing-started"), "{plain}");
+        assert!(!plain.contains(url));
+
+        let linked = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_hyperlinks(true)
+            .with_max_url_width(24)
+            .render(&format!("<{url}>"));
+        assert!(linked.contains(&format!("\x1b]8;;{url}\x1b\\")));
+        assert!(strip_osc(&linked).contains("https://exam…ing-started"));
+    }
+
+    #[test]
+    fn test_render_heading_anchors() {
+        let markdown = "# Setup\n\n- # Nested\n\n## Setup\n\n## Usage {#use}";
+        let plain = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_hyperlinks(false)
+            .with_heading_anchors("§")
+            .render(markdown);
+        assert!(plain.contains("# Setup §"), "{plain}");
+        assert!(!plain.contains("Nested §"));
+        assert!(!plain.contains("\x1b]8"));
+
+        // Slugs count the headings left out by the section filter
+        let linked = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_hyperlinks(true)
+            .with_heading_anchors("#")
+            .with_section_filter(|path: &[String]| path.len() > 1)
+            .render(markdown);
+        assert!(!linked.contains("\x1b]8;;#setup\x1b\\"));
+        assert!(
+            linked.contains("\x1b]8;;#setup-1\x1b\\#\x1b]8;;\x1b\\"),
+            "{linked}"
+        );
+        assert!(linked.contains("\x1b]8;;#use\x1b\\"));
+        assert_eq!(strip_osc(&linked).matches("Setup #").count(), 1);
+    }
+
+    /// Removes OSC sequences, leaving the visible text.
+    fn strip_osc(s: &str) -> String {
+        let mut out = String::new();
+        let mut rest = s;
+        while let Some(start) = rest.find("\x1b]") {
+            out.push_str(&rest[..start]);
+            let end = rest[start..]
+                .find("\x1b\\")
+                .map_or(rest.len(), |e| start + e + 2);
+            rest = &rest[end..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    #[test]
+    fn test_detect_hyperlinks() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert!(detect_hyperlinks(env(&[("TERM_PROGRAM", "WezTerm")]), true));
+        assert!(detect_hyperlinks(env(&[("VTE_VERSION", "7600")]), true));
+        assert!(!detect_hyperlinks(env(&[("VTE_VERSION", "4800")]), true));
+        assert!(!detect_hyperlinks(env(&[("TERM", "xterm-kitty")]), false));
+        assert!(!detect_hyperlinks(
+            env(&[("TERM", "xterm-kitty"), ("CI", "true")]),
+            true
+        ));
+        assert!(!detect_hyperlinks(env(&[("TERM", "xterm-256color")]), true));
+        assert!(detect_hyperlinks(env(&[("FORCE_HYPERLINK", "1")]), false));
+        assert!(!detect_hyperlinks(
+            env(&[("FORCE_HYPERLINK", "0"), ("TERM_PROGRAM", "iTerm.app")]),
+            true
+        ));
+    }
+
+    #[test]
+    fn test_detect_emoji() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert!(detect_emoji(env(&[("LANG", "en_US.UTF-8")]), false));
+        assert!(detect_emoji(env(&[]), false));
+        assert!(!detect_emoji(env(&[("LANG", "C")]), false));
+        assert!(!detect_emoji(
+            env(&[("LC_ALL", "POSIX"), ("LANG", "en_US.UTF-8")]),
+            false
+        ));
+        assert!(detect_emoji(
+            env(&[("LC_ALL", ""), ("LANG", "de_DE.utf8")]),
+            false
+        ));
+        assert!(!detect_emoji(
+            env(&[("LANG", "en_US.UTF-8"), ("TERM", "linux")]),
+            false
+        ));
+        assert!(!detect_emoji(env(&[]), true));
+        assert!(detect_emoji(env(&[("WT_SESSION", "1")]), true));
+    }
+
+    #[test]
+    fn test_detect_style() {
+        let env = |value: Option<&'static str>| {
+            move |name: &str| (name == "COLORFGBG").then(|| value.map(str::to_string))?
+        };
+        assert_eq!(detect_style(false, || Some(false), env(None)), Style::NoTty);
+        assert_eq!(detect_style(true, || Some(false), env(None)), Style::Light);
+        assert_eq!(
+            detect_style(true, || Some(true), env(Some("0;15"))),
+            Style::Dark
+        );
+        assert_eq!(detect_style(true, || None, env(Some("0;15"))), Style::Light);
+        assert_eq!(
+            detect_style(true, || None, env(Some("15;default;0"))),
+            Style::Dark
+        );
+        assert_eq!(detect_style(true, || None, env(Some("bogus"))), Style::Dark);
+        assert_eq!(detect_style(true, || None, env(None)), Style::Dark);
+        assert_eq!(
+            format!("{:?}", Style::Auto.config()),
+            format!("{:?}", Style::Dark.config())
+        );
+    }
+
+    #[test]
+    fn test_osc11_is_dark() {
+        assert_eq!(
+            osc11_is_dark("\x1b]11;rgb:1e1e/1e1e/2e2e\x07\x1b[?62;22c"),
+            Some(true)
+        );
+        assert_eq!(
+            osc11_is_dark("\x1b]11;rgb:ffff/fafa/f0f0\x1b\\"),
+            Some(false)
+        );
+        assert_eq!(osc11_is_dark("\x1b]11;rgb:f/f/f\x07"), Some(false));
+        assert_eq!(osc11_is_dark("\x1b[?62;22c"), None);
+        assert_eq!(osc11_is_dark("\x1b]11;rgb:zz/00/00\x07"), None);
+    }
+
     #[test]
     fn test_render_ordered_list() {
         let renderer = Renderer::new().with_style(Style::Ascii);
@@ -2557,6 +5332,101 @@ mod tests {
         assert!(output.len() > 0);
     }
 
+    #[cfg(feature = "bidi")]
+    #[test]
+    fn test_word_wrap_reorders_rtl_lines() {
+        let render = |renderer: Renderer| -> Vec<String> {
+            renderer
+                .with_style(Style::Ascii)
+                .with_word_wrap(14)
+                .render("אבג דהו זחט יכל **מנס** end")
+                .lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty())
+                .collect()
+        };
+
+        // Lines wrap in logical order and each reads right to left
+        let lines = render(Renderer::new());
+        assert_eq!(lines, ["טחז והד גבא", "**סנמ** לכי", "end"]);
+
+        let lines = render(Renderer::new().with_bidi(false));
+        assert_eq!(lines, ["אבג דהו זחט", "יכל **מנס**", "end"]);
+    }
+
+    #[test]
+    fn test_word_wrap_hangs_under_prefixes() {
+        let render = |markdown: &str| -> Vec<String> {
+            let output = Renderer::new()
+                .with_style(Style::Ascii)
+                .with_word_wrap(20)
+                .render(markdown);
+            output
+                .lines()
+                .map(|line| {
+                    line.strip_prefix("  ")
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string()
+                })
+                .filter(|line| !line.is_empty())
+                .collect()
+        };
+
+        assert_eq!(
+            render("- one two three four five six"),
+            ["• one two three four", "  five six"]
+        );
+        assert_eq!(
+            render("> > one two three four five"),
+            ["|", "| | one two three", "| | four five"]
+        );
+        // Wide characters count as two cells, and styling as none
+        assert_eq!(
+            render("日本語の文章を折り返す"),
+            ["日本語の文章を折り返", "す"]
+        );
+        let styled = Renderer::new()
+            .with_style(Style::Dark)
+            .with_word_wrap(20)
+            .render("**bold words** and more words");
+        let lines: Vec<&str> = styled.lines().filter(|l| !l.trim().is_empty()).collect();
+        assert_eq!(lines.len(), 2, "{styled:?}");
+        assert!(visible_width(lines[0].trim()) > 15, "{styled:?}");
+    }
+
+    #[test]
+    fn test_word_wrap_breaks_long_urls() {
+        let segments: Vec<String> = (0..8)
+            .map(|i| format!("segment-{i}-{}", "x".repeat(20)))
+            .collect();
+        let url = format!(
+            "https://example.com/{}/{}",
+            segments.join("/"),
+            "b".repeat(100)
+        );
+        let renderer = Renderer::new().with_style(Style::Ascii).with_word_wrap(40);
+        let output = renderer.render(&format!("See {url} for details."));
+
+        // Lines are wrapped to 40 cells before the document margin is added
+        let lines: Vec<&str> = output.lines().filter(|l| !l.trim().is_empty()).collect();
+        for line in &lines {
+            assert!(
+                visible_width(line.trim_start()) <= 40,
+                "line too wide: {line:?}"
+            );
+        }
+        // The URL breaks after its slashes before falling back to cells
+        assert!(lines.iter().any(|l| l.trim() == "https://example.com/"));
+        assert!(
+            lines
+                .iter()
+                .any(|l| l.trim() == format!("{}/", segments[0]))
+        );
+        let joined: String = lines.iter().map(|l| l.trim()).collect();
+        assert!(joined.contains(&url));
+    }
+
     #[test]
     fn test_render_code_block() {
         let renderer = Renderer::new().with_style(Style::Ascii);
@@ -2567,6 +5437,41 @@ mod tests {
         assert!(output.contains("main"));
     }
 
+    #[test]
+    fn test_code_folding() {
+        let long: String = (1..=6).map(|i| format!("line{i}\n")).collect();
+        let markdown = format!("```\nshort\n```\n\n```\n{long}```");
+        let renderer = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_code_folding(3);
+
+        let (output, folds) = renderer.render_with_folds(&markdown);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(folds.len(), 1);
+        assert_eq!((folds[0].index, folds[0].hidden_lines), (1, 3));
+        assert!(!folds[0].expanded);
+        assert!(
+            lines[folds[0].line].contains("… 3 more lines"),
+            "{output:?}"
+        );
+        assert!(lines[folds[0].line - 1].contains("line3"));
+        assert!(!output.contains("line4"));
+        assert_eq!(output, renderer.render(&markdown));
+
+        let (output, folds) = renderer
+            .with_expanded_folds([1])
+            .render_with_folds(&markdown);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(folds[0].expanded);
+        assert!(lines[folds[0].line].contains("line4"), "{output:?}");
+        assert!(output.contains("line6") && !output.contains("more lines"));
+
+        let unfolded = Renderer::new()
+            .with_style(Style::Ascii)
+            .render_with_folds(&markdown);
+        assert!(unfolded.1.is_empty());
+    }
+
     #[test]
     fn test_render_blockquote() {
         let renderer = Renderer::new().with_style(Style::Dark);
@@ -2574,6 +5479,138 @@ mod tests {
         assert!(output.contains("quoted"));
     }
 
+    #[test]
+    fn test_blockquote_levels_vary_by_depth() {
+        let config = ascii_style().block_quote_levels([
+            StyleBlock::new().indent_token("> "),
+            StyleBlock::new().indent_token("| "),
+        ]);
+        let renderer = Renderer::new().with_style_config(config);
+        let output = renderer.render("> one\n>> two\n>>> three");
+        assert!(output.contains("> one"), "{output:?}");
+        assert!(output.contains("> | two"), "{output:?}");
+        assert!(output.contains("> | > three"), "{output:?}");
+    }
+
+    #[test]
+    fn test_blockquote_level_colors_bar() {
+        let config = ascii_style()
+            .block_quote_levels([StyleBlock::new().style(StylePrimitive::new().color("#ff0000"))]);
+        let styled = config.block_quote_level(3).unwrap().style.to_lipgloss();
+        let renderer = Renderer::new().with_style_config(config);
+        let output = renderer.render("> quoted");
+        assert!(output.contains(&styled.render("| ")), "{output:?}");
+        assert!(output.contains("quoted"));
+    }
+
+    /// Renders with the ASCII style, dropping the document margin.
+    fn render_ascii_body(markdown: &str) -> Vec<String> {
+        let output = Renderer::new().with_style(Style::Ascii).render(markdown);
+        output
+            .trim_matches(|c| c == ' ' || c == '\n')
+            .lines()
+            .map(|line| {
+                line.strip_prefix("  ")
+                    .unwrap_or(line)
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_footnotes() {
+        let markdown =
+            "Second[^b] and first[^a].\n\n[^a]: Defined first.\n\n[^b]: Defined second.\n\n# After";
+        assert_eq!(
+            render_ascii_body(markdown),
+            [
+                "Second[^1] and first[^2].",
+                "",
+                "# After",
+                "",
+                "[^1]: Defined second.",
+                "[^2]: Defined first."
+            ]
+        );
+
+        let config = ascii_style()
+            .footnotes(StyleFootnotes::new().label(StylePrimitive::new().color("#ff0000")));
+        let styled = config.footnotes.label.to_lipgloss().render("[^1]:");
+        let output = Renderer::new()
+            .with_style_config(config)
+            .render("Text[^n].\n\n[^n]: Note.");
+        assert!(output.contains(&format!("{styled} Note.")), "{output:?}");
+    }
+
+    #[test]
+    fn test_footnote_bodies_hang_under_labels() {
+        let body = "word ".repeat(30);
+        let output = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_word_wrap(40)
+            .render(&format!("Text[^1].\n\n[^1]: {body}"));
+        let lines: Vec<&str> = output.lines().filter(|l| l.contains("word")).collect();
+        assert!(lines.len() > 1, "{output:?}");
+        assert!(lines[0].trim_start().starts_with("[^1]: word"));
+        for line in &lines[1..] {
+            assert!(line.starts_with("        word"), "{output:?}");
+        }
+        // The document margin comes on top of the wrap width
+        assert!(
+            lines
+                .iter()
+                .all(|line| visible_width(line.trim_end()) <= 2 + 40)
+        );
+    }
+
+    #[test]
+    fn test_nested_blockquotes() {
+        assert_eq!(
+            render_ascii_body("> outer\n>\n> > inner\n>\n> back out"),
+            ["| outer", "|", "| | inner", "|", "| back out"]
+        );
+        // Go glamour opens a nested quote with a line of the outer bar
+        assert_eq!(
+            render_ascii_body("> > deep\n> > two"),
+            ["|", "| | deep two"]
+        );
+    }
+
+    #[test]
+    fn test_blockquote_inside_list() {
+        assert_eq!(
+            render_ascii_body("- item\n\n  > quoted in list\n\n- next"),
+            ["• item", "| quoted in list", "• next"]
+        );
+        assert_eq!(
+            render_ascii_body("- item\n  - nested\n\n    > > deep"),
+            ["• item", "    • nested", "    |", "    | | deep"]
+        );
+    }
+
+    #[test]
+    fn test_list_inside_blockquote() {
+        assert_eq!(
+            render_ascii_body("> - a\n>   - b\n>\n> after"),
+            ["| • a", "|     • b", "|", "| after"]
+        );
+    }
+
+    #[test]
+    fn test_callout_title() {
+        let renderer = Renderer::new().with_style(Style::Ascii);
+        let output = renderer.render("> [!WARNING]\n> Mind the gap.");
+        let lines: Vec<&str> = output.lines().map(str::trim).collect();
+        let title = lines.iter().position(|l| l.contains("Warning")).unwrap();
+        assert!(lines[title].starts_with("| "), "{output:?}");
+        assert_eq!(lines[title + 1], "| Mind the gap.");
+        assert!(!output.contains("[!WARNING]"));
+
+        let output = renderer.render("> [!NOTE]\n> Plain");
+        assert!(output.contains("Note") && !output.contains("[!NOTE]"));
+    }
+
     #[test]
     fn test_strikethrough() {
         let renderer = Renderer::new().with_style(Style::Ascii);
@@ -2582,6 +5619,88 @@ mod tests {
         assert!(output.contains("deleted"));
     }
 
+    #[test]
+    fn test_nested_list_bullets_by_depth() {
+        let renderer = Renderer::new().with_style(Style::Dark);
+        let output = renderer.render("- one\n  - two\n    - three\n      - four");
+        assert!(output.contains("• one"), "{output:?}");
+        assert!(output.contains("◦ two"), "{output:?}");
+        assert!(output.contains("▪ three"), "{output:?}");
+        // Deeper levels reuse the last bullet
+        assert!(output.contains("▪ four"), "{output:?}");
+    }
+
+    #[test]
+    fn test_bullet_falls_back_to_item_prefix() {
+        let config = ascii_style();
+        assert!(config.list.bullets.is_empty());
+        assert_eq!(config.bullet(1), config.item.block_prefix);
+        assert_eq!(config.bullet(3), config.item.block_prefix);
+
+        let config = StyleConfig {
+            list: config.list.bullets(["- ", "+ "]),
+            ..config
+        };
+        assert_eq!(config.bullet(1), "- ");
+        assert_eq!(config.bullet(2), "+ ");
+        assert_eq!(config.bullet(5), "+ ");
+    }
+
+    #[test]
+    fn test_ordered_list_start_and_alignment() {
+        let renderer = Renderer::new().with_style(Style::Ascii);
+        let output = renderer.render("3. three\n4. four");
+        assert!(output.contains("3. three"), "{output:?}");
+        assert!(output.contains("4. four"), "{output:?}");
+
+        // Numbers are right-aligned to the widest in the list
+        let output = renderer.render("8. a\n9. b\n10. c\n11. d");
+        assert!(output.contains(" 8. a"), "{output:?}");
+        assert!(output.contains(" 9. b"), "{output:?}");
+        assert!(output.contains("10. c"), "{output:?}");
+        let column = |text: &str| output.lines().find_map(|line| line.find(text)).unwrap();
+        assert_eq!(column("a"), column("c"));
+    }
+
+    #[test]
+    fn test_ordered_list_numbering_by_depth() {
+        let config = StyleConfig {
+            list: ascii_style().list.numbering([
+                ListNumbering::Arabic,
+                ListNumbering::LowerAlpha,
+                ListNumbering::LowerRoman,
+            ]),
+            ..ascii_style()
+        };
+        assert_eq!(config.numbering(1), ListNumbering::Arabic);
+        assert_eq!(config.numbering(4), ListNumbering::LowerRoman);
+        assert_eq!(ascii_style().numbering(2), ListNumbering::Arabic);
+
+        let renderer = Renderer::new().with_style_config(config);
+        let output = renderer.render(
+            "1. one\n   1. a\n   2. b\n   3. c\n      1. i\n      2. ii\n      3. iii\n      4. iv",
+        );
+        assert!(output.contains("1. one"), "{output:?}");
+        assert!(output.contains("c. c"), "{output:?}");
+        assert!(output.contains("  i. i\n"), "{output:?}");
+        assert!(output.contains("iii. iii"), "{output:?}");
+        assert!(output.contains(" iv. iv"), "{output:?}");
+    }
+
+    #[test]
+    fn test_list_numbering_format() {
+        assert_eq!(ListNumbering::Arabic.format(42), "42");
+        assert_eq!(ListNumbering::LowerAlpha.format(1), "a");
+        assert_eq!(ListNumbering::LowerAlpha.format(26), "z");
+        assert_eq!(ListNumbering::UpperAlpha.format(28), "AB");
+        assert_eq!(ListNumbering::LowerRoman.format(1994), "mcmxciv");
+        assert_eq!(ListNumbering::UpperRoman.format(9), "IX");
+        // Numbers without a letter or roman numeral stay arabic
+        assert_eq!(ListNumbering::LowerAlpha.format(0), "0");
+        assert_eq!(ListNumbering::LowerRoman.format(4000), "4000");
+        assert_eq!(ListNumbering::LowerRoman.width(1, 8), 4);
+    }
+
     #[test]
     fn test_task_list() {
         let renderer = Renderer::new().with_style(Style::Ascii);
@@ -2591,6 +5710,73 @@ mod tests {
         assert!(!output.contains("* [ ]"));
     }
 
+    // ========================================================================
+    // Style JSON Tests (feature-gated)
+    // ========================================================================
+
+    #[cfg(feature = "serde")]
+    mod style_json_tests {
+        use super::*;
+
+        /// An excerpt of Go glamour's `dracula.json`.
+        const DRACULA: &str = r##"{
+          "document": {"block_prefix": "\n", "block_suffix": "\n", "color": "#f8f8f2", "margin": 2},
+          "block_quote": {"color": "#f1fa8c", "italic": true, "indent": 2},
+          "list": {"color": "#f8f8f2", "level_indent": 2},
+          "heading": {"block_suffix": "\n", "color": "#bd93f9", "bold": true},
+          "h1": {"prefix": "# "},
+          "hr": {"color": "#6272A4", "format": "\n--------\n"},
+          "item": {"block_prefix": "• "},
+          "task": {"ticked": "[✓] ", "unticked": "[ ] "},
+          "code_block": {"color": "#ffb86c", "margin": 2, "chroma": {"text": {"color": "#f8f8f2"}}},
+          "table": {"center_separator": "┼", "column_separator": "│", "row_separator": "─"},
+          "html_block": {},
+          "html_span": {}
+        }"##;
+
+        #[test]
+        fn test_style_config_from_go_json() {
+            let config = StyleConfig::from_json(DRACULA).unwrap();
+            assert_eq!(config.document.margin, Some(2));
+            assert_eq!(config.document.style.color.as_deref(), Some("#f8f8f2"));
+            assert_eq!(config.block_quote.style.italic, Some(true));
+            assert_eq!(config.list.level_indent, 2);
+            assert_eq!(config.horizontal_rule.format, "\n--------\n");
+            assert_eq!(config.task.ticked, "[✓] ");
+            assert_eq!(config.code_block.block.margin, Some(2));
+            assert_eq!(config.table.center_separator.as_deref(), Some("┼"));
+
+            let output = Renderer::new()
+                .with_style_config(config)
+                .render("# Title\n\n- item");
+            assert!(output.contains("# Title"), "{output:?}");
+            assert!(output.contains("• item"), "{output:?}");
+        }
+
+        #[test]
+        fn test_style_config_json_round_trip() {
+            for style in [Style::Ascii, Style::Dark, Style::Dracula] {
+                let json = style.config().to_json();
+                let parsed = StyleConfig::from_json(&json).unwrap();
+                assert_eq!(parsed.to_json(), json);
+            }
+            assert!(Style::Dark.config().to_json().contains("\"hr\""));
+        }
+
+        #[test]
+        fn test_style_config_from_json_file() {
+            let dir = tempfile::tempdir().unwrap();
+            let path = dir.path().join("style.json");
+            std::fs::write(&path, DRACULA).unwrap();
+            let config = StyleConfig::from_json_file(&path).unwrap();
+            assert_eq!(config.h1.style.prefix, "# ");
+
+            std::fs::write(&path, "{").unwrap();
+            let err = StyleConfig::from_json_file(&path).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+    }
+
     // ========================================================================
     // Syntax Theme Config Tests (feature-gated)
     // ========================================================================
@@ -3202,6 +6388,20 @@ fn main() {
         assert!(output.len() > 0, "Should produce some output");
     }
 
+    #[test]
+    fn test_code_block_expands_tabs_to_tab_stops() {
+        let renderer = Renderer::new().with_style(Style::Ascii);
+        let output = renderer.render("```\na\tb\nabc\td\n```");
+        assert!(!output.contains('\t'));
+        assert!(output.contains("a   b"));
+        assert!(output.contains("abc d"));
+
+        let output = Renderer::new()
+            .with_style(Style::Dark)
+            .render("```rust\nfn main() {\n\tlet x = 1;\n}\n```");
+        assert!(!output.contains('\t'));
+    }
+
     #[test]
     fn test_code_block_with_only_whitespace() {
         let markdown = "```rust\n   \n\t\n   \n```";
@@ -3479,6 +6679,40 @@ mod table_spacing_tests {
         }
     }
 
+    #[test]
+    fn test_table_overflow_strategies() {
+        let markdown = "| Name | Description |\n|---|---|\n\
+            | glamour | Stylesheet-based markdown rendering for terminals |\n";
+        let width = 32;
+        let renderer = Renderer::new()
+            .with_word_wrap(width)
+            .with_style(Style::Ascii);
+        let fits = |output: &str| output.lines().all(|line| visible_width(line) <= width);
+
+        let output = renderer.render(markdown);
+        assert!(fits(&output), "{output}");
+        assert!(output.contains("…"));
+        assert!(!output.contains("terminals"));
+
+        let mut config = Style::Ascii.config();
+        config.table = config.table.overflow(table::TableOverflow::Wrap);
+        let output = renderer.clone().with_style_config(config).render(markdown);
+        assert!(fits(&output), "{output}");
+        assert!(!output.contains("…"));
+        for word in ["Stylesheet-based", "markdown", "rendering", "terminals"] {
+            assert!(output.contains(word), "{word} missing from {output}");
+        }
+        // Every line of the wrapped row keeps the column separator
+        let rows = output.lines().filter(|line| line.contains(" | ")).count();
+        assert!(rows > 3, "{output}");
+
+        let mut config = Style::Ascii.config();
+        config.table = config.table.ellipsis("...");
+        let output = renderer.with_style_config(config).render(markdown);
+        assert!(output.contains("..."));
+        assert!(!output.contains("…"));
+    }
+
     #[test]
     fn test_table_respects_word_wrap() {
         let markdown = "| A | B |\n|---|---|\n| 1 | 2 |";
@@ -3520,6 +6754,44 @@ mod table_spacing_tests {
         );
     }
 
+    #[test]
+    fn test_strip_controls() {
+        assert_eq!(strip_controls("plain\ttext\r\n"), "plain\ttext\r\n");
+        assert!(matches!(
+            strip_controls("plain"),
+            std::borrow::Cow::Borrowed(_)
+        ));
+        assert_eq!(strip_controls("\x1b[1;31mred\x1b[0m"), "red");
+        assert_eq!(strip_controls("a\x1b]0;title\x07b"), "ab");
+        assert_eq!(strip_controls("a\x1b]8;;http://x\x1b\\b"), "ab");
+        assert_eq!(strip_controls("a\x1bPq#0;2;0\x1b\\b"), "ab");
+        assert_eq!(strip_controls("a\u{9b}2Jb\u{9d}0;t\u{9c}c"), "abc");
+        assert_eq!(strip_controls("a\x1b7b\x1bc"), "ab");
+        assert_eq!(
+            strip_controls("bell\x07 back\x08 del\x7f cr\rx"),
+            "bell back del crx"
+        );
+        assert_eq!(strip_controls("a\x1b]0;unterminated"), "a");
+        assert_eq!(strip_controls("é\u{85}ü"), "éü");
+    }
+
+    #[test]
+    fn test_render_strips_escape_sequences() {
+        let markdown = "# \x1b]0;pwned\x07Title\n\nText \x1b[2J`\x1b[31mcode`\n";
+        let output = Renderer::new().with_style(Style::Ascii).render(markdown);
+        assert!(!output.contains("pwned"));
+        assert!(!output.contains("\x1b[2J"));
+        assert!(!output.contains("\x1b[31m"));
+        assert!(output.contains("Title"));
+        assert!(output.contains("code"));
+
+        let output = Renderer::new()
+            .with_style(Style::Ascii)
+            .with_sanitized_input(false)
+            .render(markdown);
+        assert!(output.contains("\x1b[31m"));
+    }
+
     #[test]
     fn test_image_link_arrow_glyph() {
         // Verify image links use Unicode arrow (→) matching Go behavior
@@ -0,0 +1,273 @@
+//! Incremental rendering of markdown that arrives in chunks, such as the
+//! reply of a language model as it is generated.
+//!
+//! A [`StreamRenderer`] holds on to the text that later chunks may still
+//! change and renders everything before it once. Each
+//! [`push`](StreamRenderer::push) returns the lines that became final, to be
+//! printed once, and the rendering of the tail, which the next push replaces.
+//! Only the tail is parsed again when more text arrives.
+//!
+//! ```rust
+//! use glamour::{Renderer, Style};
+//!
+//! let renderer = Renderer::new().with_style(Style::Ascii);
+//! let mut stream = renderer.stream();
+//!
+//! let update = stream.push("# Title\n\nSome te");
+//! assert!(update.stable.is_empty());
+//! assert!(update.pending.contains("Some te"));
+//!
+//! let update = stream.push("xt.\n\nMore");
+//! assert!(update.stable.contains("Title"));
+//! assert!(update.pending.contains("Some text."));
+//!
+//! let rest = stream.finish();
+//! assert!(rest.contains("Some text."));
+//! assert!(rest.contains("More"));
+//! ```
+//!
+//! A block is settled once the next top-level block starts on a complete
+//! line. Reference links and footnotes whose definitions come after a
+//! settled block that uses them render as if the definition were missing.
+
+use pulldown_cmark::{Event, Parser};
+
+use crate::{AnsiOptions, RenderContext, parser_options};
+
+/// What a [`StreamRenderer::push`] produced.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamUpdate {
+    /// Lines that became final, each ending with a newline. They are never
+    /// returned again.
+    pub stable: String,
+    /// The rendering of the rest of the input so far, which more input may
+    /// still change. Each update's pending output replaces the previous one.
+    pub pending: String,
+}
+
+/// Renders markdown incrementally as it is pushed in chunks.
+///
+/// Created with [`TermRenderer::stream`](crate::TermRenderer::stream). The
+/// stable output of every push followed by the result of
+/// [`finish`](Self::finish) is the rendering of the whole document.
+#[derive(Clone)]
+pub struct StreamRenderer<'a> {
+    ctx: RenderContext<'a>,
+    /// Input that is not settled yet.
+    tail: String,
+    /// Lines at the start of the context's output that were already
+    /// returned as stable.
+    emitted: usize,
+}
+
+impl<'a> StreamRenderer<'a> {
+    pub(crate) fn new(options: &'a AnsiOptions) -> Self {
+        let mut ctx = RenderContext::new(options);
+        ctx.begin_document();
+        Self {
+            ctx,
+            tail: String::new(),
+            emitted: 0,
+        }
+    }
+
+    /// Appends a chunk of markdown, returning the newly final lines and the
+    /// rendering of the unsettled rest.
+    pub fn push(&mut self, chunk: &str) -> StreamUpdate {
+        self.tail.push_str(chunk);
+        let settled = settled_len(&self.tail);
+        if settled > 0 {
+            let blocks: String = self.tail.drain(..settled).collect();
+            self.ctx.feed(&blocks);
+        }
+        StreamUpdate {
+            stable: self.take_stable(),
+            pending: self.pending(),
+        }
+    }
+
+    /// Returns the markdown that is not settled yet.
+    pub fn tail(&self) -> &str {
+        &self.tail
+    }
+
+    /// Ends the input, returning the rest of the rendered document.
+    pub fn finish(mut self) -> String {
+        let tail = std::mem::take(&mut self.tail);
+        self.ctx.feed(&tail);
+        let output = self.ctx.end_document();
+        skip_lines(&output, self.emitted).to_string()
+    }
+
+    /// Renders the unsettled input on a copy of the context.
+    fn pending(&self) -> String {
+        let mut ctx = self.ctx.clone();
+        ctx.feed(&self.tail);
+        let output = ctx.end_document();
+        skip_lines(&output, self.emitted).to_string()
+    }
+
+    /// Returns the lines of rendered output that later blocks can no longer
+    /// change, and drops all but the last of them from the context.
+    fn take_stable(&mut self) -> String {
+        let ctx = &mut self.ctx;
+        let body = &ctx.output[ctx.body_start..];
+        let end = ctx.body_start + body.trim_end_matches('\n').len();
+        if end == ctx.body_start {
+            return String::new();
+        }
+
+        let margin = " ".repeat(ctx.options.styles.document.margin.unwrap_or(0));
+        let mut stable = String::new();
+        for line in ctx.output[..end].split('\n').skip(self.emitted) {
+            stable.push_str(&margin);
+            stable.push_str(line);
+            stable.push('\n');
+        }
+
+        // Keep the last line, so that the next block is still spaced from it
+        let last_line = ctx.output[..end].rfind('\n').map_or(0, |i| i + 1);
+        ctx.output.drain(..last_line);
+        ctx.body_start = ctx.body_start.saturating_sub(last_line);
+        self.emitted = 1;
+        stable
+    }
+}
+
+impl std::fmt::Debug for StreamRenderer<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamRenderer")
+            .field("tail", &self.tail)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the length of the leading blocks of `markdown` that more input
+/// can no longer change.
+///
+/// Only complete lines count, and the last block is always left out, since
+/// the lines that follow may still continue it.
+fn settled_len(markdown: &str) -> usize {
+    let Some(end) = markdown.rfind('\n') else {
+        return 0;
+    };
+    let mut depth = 0usize;
+    let mut blocks = 0;
+    let mut last_start = 0;
+    for (event, range) in Parser::new_ext(&markdown[..=end], parser_options()).into_offset_iter() {
+        let starts_block = match event {
+            Event::Start(_) => {
+                depth += 1;
+                depth == 1
+            }
+            Event::End(_) => {
+                depth -= 1;
+                false
+            }
+            Event::Rule => depth == 0,
+            _ => false,
+        };
+        if starts_block {
+            blocks += 1;
+            last_start = range.start;
+        }
+    }
+    if blocks > 1 { last_start } else { 0 }
+}
+
+/// Returns `text` without its first `n` lines.
+fn skip_lines(text: &str, n: usize) -> &str {
+    if n == 0 {
+        return text;
+    }
+    text.match_indices('\n')
+        .nth(n - 1)
+        .map_or("", |(i, _)| &text[i + 1..])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Renderer, Style};
+
+    const DOC: &str = "# Title\n\nA paragraph with **bold** text\nthat spans lines.\n\n\
+        - one\n- two\n  - nested\n\n> quoted\n> text\n\n```rust\nfn main() {}\n\nlet x = 1;\n```\n\n\
+        | a | b |\n|---|---|\n| 1 | 2 |\n\n---\n\n## Next\n\n1. first\n2. second\n\nThe end.\n";
+
+    /// Streams `doc` in chunks of `size` bytes, checking at every step that
+    /// the stable output so far plus the pending output is what rendering
+    /// the input so far gives.
+    fn check_stream(renderer: &Renderer, doc: &str, size: usize) {
+        let mut stream = renderer.stream();
+        let mut stable = String::new();
+        let mut pushed = 0;
+        while pushed < doc.len() {
+            let mut end = (pushed + size).min(doc.len());
+            while !doc.is_char_boundary(end) {
+                end += 1;
+            }
+            let update = stream.push(&doc[pushed..end]);
+            pushed = end;
+            stable.push_str(&update.stable);
+            assert_eq!(
+                format!("{stable}{}", update.pending),
+                renderer.render(&doc[..pushed]),
+                "after {pushed} bytes"
+            );
+        }
+        stable.push_str(&stream.finish());
+        assert_eq!(stable, renderer.render(doc));
+    }
+
+    #[test]
+    fn test_stream_matches_full_render() {
+        for style in [Style::Ascii, Style::Dark] {
+            let renderer = Renderer::new().with_style(style).with_word_wrap(40);
+            for size in [1, 3, 17, DOC.len()] {
+                check_stream(&renderer, DOC, size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_without_margin() {
+        let mut config = Style::Ascii.config();
+        config.document.margin = None;
+        let renderer = Renderer::new().with_style_config(config);
+        check_stream(&renderer, DOC, 5);
+    }
+
+    #[test]
+    fn test_stream_settles_only_finished_blocks() {
+        let renderer = Renderer::new().with_style(Style::Ascii);
+        let mut stream = renderer.stream();
+
+        let update = stream.push("Intro.\n\n```\ncode\n\nmore");
+        assert!(update.stable.contains("Intro."));
+        assert!(!update.stable.contains("code"));
+        assert!(update.pending.contains("code"));
+        assert_eq!(stream.tail(), "```\ncode\n\nmore");
+
+        // The open fence keeps the code block unsettled across blank lines
+        let update = stream.push(" code\n\n");
+        assert!(update.stable.is_empty());
+        assert!(update.pending.contains("more code"));
+
+        let update = stream.push("```\n\nAfter.\n");
+        assert!(update.stable.contains("more code"));
+        assert!(update.pending.contains("After."));
+        assert_eq!(stream.tail(), "After.\n");
+    }
+
+    #[test]
+    fn test_stream_waits_for_complete_lines() {
+        let renderer = Renderer::new().with_style(Style::Ascii);
+        let mut stream = renderer.stream();
+        // `Title` may still turn into a setext heading
+        assert!(stream.push("Title\n").stable.is_empty());
+        assert!(stream.push("===").stable.is_empty());
+        assert!(stream.push("\n\nText").stable.is_empty());
+        let update = stream.push("\n");
+        assert!(update.stable.contains("Title"));
+        assert!(update.pending.contains("Text"));
+    }
+}
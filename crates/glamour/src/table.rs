@@ -762,6 +762,24 @@ pub fn align_row(cells: &[TableCell], col_widths: &[usize], cell_margin: usize)
 /// ```
 #[must_use]
 pub fn truncate_content(content: &str, max_width: usize) -> String {
+    truncate_content_with(content, max_width, "…")
+}
+
+/// Truncate content like [`truncate_content`], ending it with `ellipsis`
+/// instead of `…`.
+///
+/// The ellipsis is left out when it is wider than `max_width`.
+///
+/// # Example
+///
+/// ```rust
+/// use glamour::table::truncate_content_with;
+///
+/// assert_eq!(truncate_content_with("Hello, World!", 8, "..."), "Hello...");
+/// assert_eq!(truncate_content_with("Hello, World!", 5, ""), "Hello");
+/// ```
+#[must_use]
+pub fn truncate_content_with(content: &str, max_width: usize, ellipsis: &str) -> String {
     if max_width == 0 {
         return String::new();
     }
@@ -771,8 +789,13 @@ pub fn truncate_content(content: &str, max_width: usize) -> String {
         return content.to_string();
     }
 
-    // Need to truncate - ellipsis takes 1 unit
-    let target_width = max_width.saturating_sub(1);
+    let ellipsis_width = measure_width(ellipsis);
+    let ellipsis = if ellipsis_width > max_width {
+        ""
+    } else {
+        ellipsis
+    };
+    let target_width = max_width - measure_width(ellipsis);
     let mut result = String::new();
     let mut current_width = 0;
     let mut in_escape = false;
@@ -804,10 +827,69 @@ pub fn truncate_content(content: &str, max_width: usize) -> String {
     if has_style {
         result.push_str("\x1b[0m");
     }
-    result.push('…');
+    result.push_str(ellipsis);
     result
 }
 
+/// How cell content wider than its column is fitted, once the table has
+/// been narrowed to the available width.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum TableOverflow {
+    /// Content is cut at the column width and ends with an ellipsis.
+    #[default]
+    Truncate,
+    /// Content wraps onto more lines, breaking at spaces where possible.
+    Wrap,
+}
+
+/// Fit content to a column, returning its lines.
+///
+/// With [`TableOverflow::Truncate`] this is a single line as from
+/// [`fit_content`], ending with `ellipsis` when cut. With
+/// [`TableOverflow::Wrap`] the content is wrapped to `width` and each line
+/// padded to it.
+///
+/// # Example
+///
+/// ```rust
+/// use glamour::table::{TableOverflow, fit_cell_lines};
+/// use pulldown_cmark::Alignment;
+///
+/// let lines = fit_cell_lines("the quick brown fox", 10, Alignment::Left, TableOverflow::Wrap, "…");
+/// assert_eq!(lines, ["the quick ", "brown fox "]);
+///
+/// let lines = fit_cell_lines("the quick brown fox", 10, Alignment::Left, TableOverflow::Truncate, "…");
+/// assert_eq!(lines, ["the quick…"]);
+/// ```
+#[must_use]
+pub fn fit_cell_lines(
+    content: &str,
+    width: usize,
+    alignment: Alignment,
+    overflow: TableOverflow,
+    ellipsis: &str,
+) -> Vec<String> {
+    if measure_width(content) <= width {
+        return vec![pad_content(content, width, alignment)];
+    }
+    match overflow {
+        // A width of zero would disable wrapping
+        TableOverflow::Wrap if width > 0 => lipgloss::wrap(content, width)
+            .lines()
+            .map(|line| pad_content(line, width, alignment))
+            .collect(),
+        _ => {
+            let truncated = truncate_content_with(content, width, ellipsis);
+            vec![pad_content(&truncated, width, alignment)]
+        }
+    }
+}
+
 // ============================================================================
 // Border Rendering
 // ============================================================================
@@ -1177,6 +1259,74 @@ pub fn render_minimal_row(
     parts.join(border.vertical)
 }
 
+/// Render a data row without outer borders like [`render_minimal_row`],
+/// fitting cell content with `overflow`.
+///
+/// Returns one line per line of the tallest cell, so a row of wrapped cells
+/// spans several lines, with shorter cells padded below their content.
+///
+/// # Example
+///
+/// ```rust
+/// use glamour::table::{MINIMAL_BORDER, TableCell, TableOverflow, render_minimal_row_lines};
+/// use pulldown_cmark::Alignment;
+///
+/// let cells = vec![
+///     TableCell::new("Alice", Alignment::Left),
+///     TableCell::new("Likes long walks", Alignment::Left),
+/// ];
+/// let lines =
+///     render_minimal_row_lines(&cells, &[5, 10], &MINIMAL_BORDER, 1, TableOverflow::Wrap, "…");
+/// assert_eq!(lines, [" Alice │ Likes long ", "       │ walks      "]);
+/// ```
+#[must_use]
+pub fn render_minimal_row_lines(
+    cells: &[TableCell],
+    widths: &[usize],
+    border: &TableBorder,
+    cell_padding: usize,
+    overflow: TableOverflow,
+    ellipsis: &str,
+) -> Vec<String> {
+    if cells.is_empty() {
+        return Vec::new();
+    }
+
+    let mut columns: Vec<Vec<String>> = cells
+        .iter()
+        .enumerate()
+        .map(|(i, cell)| {
+            let width = widths.get(i).copied().unwrap_or(0);
+            fit_cell_lines(&cell.content, width, cell.alignment, overflow, ellipsis)
+        })
+        .collect();
+
+    // Handle missing cells (if row has fewer cells than widths)
+    for width in widths.iter().skip(cells.len()) {
+        columns.push(vec![" ".repeat(*width)]);
+    }
+
+    let padding = " ".repeat(cell_padding);
+    let height = columns.iter().map(Vec::len).max().unwrap_or(1);
+    (0..height)
+        .map(|line| {
+            columns
+                .iter()
+                .enumerate()
+                .map(|(i, lines)| {
+                    let width = widths.get(i).copied().unwrap_or(0);
+                    let content = lines
+                        .get(line)
+                        .cloned()
+                        .unwrap_or_else(|| " ".repeat(width));
+                    format!("{padding}{content}{padding}")
+                })
+                .collect::<Vec<_>>()
+                .join(border.vertical)
+        })
+        .collect()
+}
+
 /// Render a horizontal separator without outer edges (minimal style).
 ///
 /// This renders only the internal separator line without left/right corners.
@@ -3048,4 +3198,52 @@ Some text between tables.
         assert_eq!(widths.widths[0], 1);
         assert_eq!(widths.widths[1], 0);
     }
+
+    #[test]
+    fn truncate_content_with_custom_ellipsis() {
+        assert_eq!(truncate_content_with("Hello, World!", 8, "..."), "Hello...");
+        assert_eq!(truncate_content_with("Hello", 2, "..."), "He");
+        assert_eq!(truncate_content_with("日本語", 5, "~"), "日本~");
+        assert_eq!(truncate_content_with("Hi", 2, "..."), "Hi");
+    }
+
+    #[test]
+    fn render_minimal_row_lines_wraps_cells() {
+        let cells = vec![
+            TableCell::new("a long description here", Alignment::Left),
+            TableCell::new("42", Alignment::Right),
+        ];
+        let lines = render_minimal_row_lines(
+            &cells,
+            &[10, 4, 3],
+            &MINIMAL_BORDER,
+            1,
+            TableOverflow::Wrap,
+            "…",
+        );
+        assert_eq!(
+            lines,
+            [
+                " a long     │   42 │     ",
+                " descriptio │      │     ",
+                " n here     │      │     ",
+            ]
+        );
+        assert!(lines.iter().all(|line| measure_width(line) == 25));
+    }
+
+    #[test]
+    fn render_minimal_row_lines_truncates_to_one_line() {
+        let cells = vec![TableCell::new("日本語テキスト", Alignment::Left)];
+        let lines = render_minimal_row_lines(
+            &cells,
+            &[6],
+            &MINIMAL_BORDER,
+            1,
+            TableOverflow::Truncate,
+            "…",
+        );
+        // The ellipsis leaves an odd width, which is padded
+        assert_eq!(lines, [" 日本…  "]);
+    }
 }
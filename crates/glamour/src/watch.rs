@@ -0,0 +1,306 @@
+//! Live re-rendering of a markdown file as it changes on disk.
+//!
+//! [`watch`] renders the file once immediately, then again every time its
+//! contents change. Each render is delivered to the callback as a
+//! [`RenderUpdate`] carrying the full output together with the indices of
+//! the lines that differ from the previous render, so a live preview can
+//! repaint only what changed instead of clearing the screen.
+//!
+//! The parent directory is watched rather than the file itself, which keeps
+//! the watcher working across editors that save by writing a temporary file
+//! and renaming it over the original.
+//!
+//! ```rust,no_run
+//! use glamour::watch::watch;
+//!
+//! let _watcher = watch("README.md", 80, |update| match update {
+//!     Ok(update) => print!("{}", update.output),
+//!     Err(err) => eprintln!("read failed: {err}"),
+//! })
+//! .expect("failed to start watcher");
+//!
+//! // Keep the watcher alive for as long as updates are wanted.
+//! std::thread::park();
+//! ```
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tracing::debug;
+
+use crate::Renderer;
+
+/// The result of re-rendering a watched file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderUpdate {
+    /// The complete rendered output.
+    pub output: String,
+    /// Zero-based indices of output lines that differ from the previous
+    /// render. On the first render every line is listed.
+    pub changed_lines: Vec<usize>,
+    /// Number of lines in the previous render (0 for the first render).
+    ///
+    /// When this is larger than the current line count, the trailing lines
+    /// left over from the previous render need to be cleared.
+    pub previous_line_count: usize,
+    /// Whether this is the first render of the file. Kept apart from
+    /// `previous_line_count`, which is also 0 after an empty file.
+    initial: bool,
+}
+
+impl RenderUpdate {
+    /// Returns true if this is the first render of the file.
+    #[must_use]
+    pub fn is_initial(&self) -> bool {
+        self.initial
+    }
+
+    /// Returns the number of lines in the rendered output.
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.output.lines().count()
+    }
+}
+
+/// A running file watcher.
+///
+/// Watching stops when this handle is dropped.
+pub struct MarkdownWatcher {
+    path: PathBuf,
+    _watcher: RecommendedWatcher,
+}
+
+impl MarkdownWatcher {
+    /// Returns the path of the watched file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl std::fmt::Debug for MarkdownWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownWatcher")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Watches a markdown file and re-renders it with the default renderer
+/// wrapped to `width` columns whenever it changes.
+///
+/// See [`watch_with_renderer`] for details.
+///
+/// # Errors
+///
+/// Returns an error if the file system watcher cannot be started.
+pub fn watch<P, F>(path: P, width: usize, callback: F) -> notify::Result<MarkdownWatcher>
+where
+    P: AsRef<Path>,
+    F: FnMut(io::Result<RenderUpdate>) + Send + 'static,
+{
+    watch_with_renderer(path, Renderer::new().with_word_wrap(width), callback)
+}
+
+/// Watches a markdown file and re-renders it with `renderer` whenever it
+/// changes.
+///
+/// The callback is invoked once synchronously with the initial render before
+/// this function returns, then from the watcher thread for every subsequent
+/// change. Saves that leave the file contents unchanged do not trigger a
+/// callback. Read failures (for example while the file is briefly missing
+/// during an atomic save) are passed to the callback as errors.
+///
+/// # Errors
+///
+/// Returns an error if the file system watcher cannot be started.
+pub fn watch_with_renderer<P, F>(
+    path: P,
+    renderer: Renderer,
+    mut callback: F,
+) -> notify::Result<MarkdownWatcher>
+where
+    P: AsRef<Path>,
+    F: FnMut(io::Result<RenderUpdate>) + Send + 'static,
+{
+    let path = path.as_ref().to_path_buf();
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let file_name = path.file_name().map(ToOwned::to_owned);
+
+    let mut state = WatchState::new(renderer);
+    if let Some(update) = state.refresh(&path) {
+        callback(update);
+    }
+
+    let target = path.clone();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let event = match res {
+            Ok(event) => event,
+            Err(err) => {
+                debug!(error = %err, "glamour.watch.error");
+                return;
+            }
+        };
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+        let touches_target = event
+            .paths
+            .iter()
+            .any(|p| p.file_name() == file_name.as_deref());
+        if touches_target && let Some(update) = state.refresh(&target) {
+            callback(update);
+        }
+    })?;
+    watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+    Ok(MarkdownWatcher {
+        path,
+        _watcher: watcher,
+    })
+}
+
+/// Render state carried between change notifications.
+struct WatchState {
+    renderer: Renderer,
+    source: Option<String>,
+    output: String,
+}
+
+impl WatchState {
+    fn new(renderer: Renderer) -> Self {
+        Self {
+            renderer,
+            source: None,
+            output: String::new(),
+        }
+    }
+
+    /// Re-reads and re-renders the file, returning `None` if its contents
+    /// are unchanged since the last render.
+    fn refresh(&mut self, path: &Path) -> Option<io::Result<RenderUpdate>> {
+        let source = match std::fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(err) => return Some(Err(err)),
+        };
+        if self.source.as_deref() == Some(source.as_str()) {
+            return None;
+        }
+
+        let output = self.renderer.render(&source);
+        let previous_line_count = self.output.lines().count();
+        let changed_lines = changed_lines(&self.output, &output);
+        let initial = self.source.is_none();
+        self.source = Some(source);
+        self.output.clone_from(&output);
+
+        Some(Ok(RenderUpdate {
+            output,
+            changed_lines,
+            previous_line_count,
+            initial,
+        }))
+    }
+}
+
+/// Returns the indices of lines in `new` that differ from the same line in
+/// `old`, including lines past the end of `old`.
+fn changed_lines(old: &str, new: &str) -> Vec<usize> {
+    let mut old_lines = old.lines();
+    new.lines()
+        .enumerate()
+        .filter(|(_, line)| old_lines.next() != Some(*line))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Style;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[test]
+    fn changed_lines_reports_differences() {
+        assert_eq!(changed_lines("a\nb\nc", "a\nx\nc"), vec![1]);
+        assert_eq!(changed_lines("a", "a\nb\nc"), vec![1, 2]);
+        assert_eq!(changed_lines("a\nb\nc", "a"), Vec::<usize>::new());
+        assert_eq!(changed_lines("", "a\nb"), vec![0, 1]);
+    }
+
+    #[test]
+    fn refresh_skips_unchanged_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.md");
+        std::fs::write(&path, "# Title\n\nBody").unwrap();
+
+        let mut state = WatchState::new(Renderer::new().with_style(Style::Ascii));
+        let first = state.refresh(&path).unwrap().unwrap();
+        assert!(first.is_initial());
+        assert_eq!(first.changed_lines.len(), first.line_count());
+
+        assert!(state.refresh(&path).is_none());
+
+        std::fs::write(&path, "# Title\n\nChanged").unwrap();
+        let second = state.refresh(&path).unwrap().unwrap();
+        assert!(!second.is_initial());
+        assert!(second.output.contains("Changed"));
+        assert!(!second.changed_lines.is_empty());
+        assert!(second.changed_lines.len() < second.line_count());
+    }
+
+    #[test]
+    fn refresh_after_empty_file_is_not_initial() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.md");
+        std::fs::write(&path, "").unwrap();
+
+        let mut state = WatchState::new(Renderer::new().with_style(Style::Ascii));
+        let first = state.refresh(&path).unwrap().unwrap();
+        assert!(first.is_initial());
+
+        std::fs::write(&path, "# Title\n\nBody").unwrap();
+        let second = state.refresh(&path).unwrap().unwrap();
+        assert!(!second.is_initial());
+        assert!(second.output.contains("Body"));
+    }
+
+    #[test]
+    fn refresh_reports_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = WatchState::new(Renderer::new());
+        let result = state.refresh(&dir.path().join("missing.md")).unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn watch_delivers_initial_and_changed_renders() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("live.md");
+        std::fs::write(&path, "first").unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        let watcher = watch(&path, 40, move |update| {
+            let _ = tx.send(update.map(|u| u.output));
+        })
+        .unwrap();
+        assert_eq!(watcher.path(), path.as_path());
+
+        let initial = rx.recv_timeout(Duration::from_secs(1)).unwrap().unwrap();
+        assert!(initial.contains("first"));
+
+        std::fs::write(&path, "second").unwrap();
+        // A write may surface as several events (truncate, then data), so
+        // wait for the render that includes the new contents.
+        let saw_change = (0..10).any(|_| {
+            rx.recv_timeout(Duration::from_secs(5))
+                .is_ok_and(|update| update.is_ok_and(|output| output.contains("second")))
+        });
+        assert!(saw_change);
+    }
+}
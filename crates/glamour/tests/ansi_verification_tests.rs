@@ -668,14 +668,18 @@ fn multiple_headings_all_styled() {
 }
 
 // ===========================================================================
-// 16. Auto Style (Alias for Dark)
+// 16. Auto Style (Detected Style)
 // ===========================================================================
 
 #[test]
-fn auto_style_same_as_dark() {
+fn auto_style_same_as_detected() {
     let auto_output = render_with("# Hello\n\nWorld", Style::Auto);
-    let dark_output = render_with("# Hello\n\nWorld", Style::Dark);
-    assert_eq!(auto_output, dark_output, "Auto should equal Dark");
+    let detected_output = render_with("# Hello\n\nWorld", Style::detect());
+    assert_eq!(
+        auto_output, detected_output,
+        "Auto should equal the detected style"
+    );
+    assert_ne!(Style::detect(), Style::Auto);
 }
 
 // ===========================================================================
@@ -0,0 +1,86 @@
+//! Golden tests for block quote layout against Go glamour.
+//!
+//! The `tests/golden/go_quote_<case>.golden` files hold the output of Go
+//! glamour 0.8.0 for the same input with the dark style, as captured by
+//! `tests/conformance/go_reference/cmd/glamour`. Go pads every line to the
+//! wrap width and styles each cell, so the comparison is on the text layout:
+//! ANSI stripped, trailing spaces trimmed and the document's outer blank
+//! lines dropped. Indentation and line breaks must match exactly.
+
+#![allow(clippy::pedantic)]
+
+use std::path::PathBuf;
+
+use glamour::{Renderer, Style};
+
+const CASES: &[(&str, &str)] = &[
+    ("double_marker", "> > Quoted twice."),
+    ("in_list_item", "- item\n\n  > quoted in item"),
+    ("in_nested_list_item", "- outer\n  1. inner\n\n     > quote"),
+];
+
+// ===========================================================================
+// Helpers
+// ===========================================================================
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("go_quote_{name}.golden"))
+}
+
+fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c2 in chars.by_ref() {
+                if ('@'..='~').contains(&c2) && c2 != '[' {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Reduces output to its text layout, without the surrounding blank lines.
+fn layout(output: &str) -> Vec<String> {
+    let plain = strip_ansi(output);
+    let lines: Vec<&str> = plain.lines().map(str::trim_end).collect();
+    let first = lines.iter().position(|l| !l.is_empty()).unwrap_or(0);
+    let last = lines
+        .iter()
+        .rposition(|l| !l.is_empty())
+        .map_or(0, |i| i + 1);
+    lines[first..last.max(first)]
+        .iter()
+        .map(|l| (*l).to_string())
+        .collect()
+}
+
+// ===========================================================================
+// Golden Output
+// ===========================================================================
+
+#[test]
+fn quote_layout_matches_go() {
+    for (name, input) in CASES {
+        let path = golden_path(name);
+        let expected = std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("missing golden file {}: {err}", path.display()));
+        let output = Renderer::new()
+            .with_style(Style::Dark)
+            .with_word_wrap(80)
+            .render(input);
+
+        assert_eq!(
+            layout(&output),
+            layout(&expected),
+            "layout differs from Go glamour for {input:?}"
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! Golden tests for block spacing across all built-in styles.
+//!
+//! Renders `tests/fixtures/spacing.md` under every built-in style and compares
+//! the output byte-for-byte against `tests/golden/spacing_<style>.golden`.
+//! Set `GLAMOUR_UPDATE_GOLDEN=1` to regenerate the golden files after an
+//! intentional rendering change.
+
+#![allow(clippy::pedantic)]
+
+use std::path::PathBuf;
+
+use glamour::{Renderer, Style};
+
+const FIXTURE: &str = include_str!("fixtures/spacing.md");
+
+const STYLES: &[(&str, Style)] = &[
+    ("ascii", Style::Ascii),
+    ("dark", Style::Dark),
+    ("dracula", Style::Dracula),
+    ("light", Style::Light),
+    ("pink", Style::Pink),
+    ("tokyo_night", Style::TokyoNight),
+    ("notty", Style::NoTty),
+];
+
+// ===========================================================================
+// Helpers
+// ===========================================================================
+
+fn render(style: Style) -> String {
+    Renderer::new()
+        .with_style(style)
+        .with_word_wrap(80)
+        .render(FIXTURE)
+}
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("spacing_{name}.golden"))
+}
+
+fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c2 in chars.by_ref() {
+                if ('@'..='~').contains(&c2) && c2 != '[' {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Reduces output to its blank-line layout: `true` for lines with content.
+fn layout(output: &str) -> Vec<bool> {
+    strip_ansi(output)
+        .lines()
+        .map(|line| !line.trim().is_empty())
+        .collect()
+}
+
+// ===========================================================================
+// Golden Output
+// ===========================================================================
+
+#[test]
+fn spacing_matches_golden_for_all_styles() {
+    let update = std::env::var_os("GLAMOUR_UPDATE_GOLDEN").is_some();
+    let mut mismatches = Vec::new();
+
+    for (name, style) in STYLES {
+        let output = render(*style);
+        let path = golden_path(name);
+
+        if update {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, &output).unwrap();
+            continue;
+        }
+
+        let expected = std::fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("missing golden file {}: {err}", path.display()));
+        if output != expected {
+            mismatches.push(*name);
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "rendered output differs from golden files for styles {mismatches:?}; \
+         rerun with GLAMOUR_UPDATE_GOLDEN=1 if the change is intended"
+    );
+}
+
+// ===========================================================================
+// Spacing Invariants
+// ===========================================================================
+
+#[test]
+fn no_consecutive_blank_lines_between_blocks() {
+    for (name, style) in STYLES {
+        let output = render(*style);
+        let lines = layout(&output);
+        let first = lines.iter().position(|&l| l).unwrap();
+        let last = lines.iter().rposition(|&l| l).unwrap();
+
+        for window in lines[first..=last].windows(2) {
+            assert!(
+                window[0] || window[1],
+                "style {name} has consecutive blank lines:\n{}",
+                strip_ansi(&output)
+            );
+        }
+    }
+}
+
+#[test]
+fn document_margins_are_stable() {
+    let margins = |style: Style| {
+        let lines = layout(&render(style));
+        let leading = lines.iter().take_while(|&&l| !l).count();
+        let trailing = lines.iter().rev().take_while(|&&l| !l).count();
+        (leading, trailing)
+    };
+    let reference = margins(Style::Ascii);
+    assert_eq!(reference.0, 1, "expected a single leading blank line");
+
+    for (name, style) in STYLES {
+        assert_eq!(
+            margins(*style),
+            reference,
+            "style {name} has different margins"
+        );
+    }
+}
+
+#[test]
+fn blank_line_layout_is_identical_across_styles() {
+    let (_, reference_style) = STYLES[0];
+    let reference = layout(&render(reference_style));
+    let gaps = |lines: &[bool]| lines.iter().filter(|&&l| !l).count();
+
+    for (name, style) in &STYLES[1..] {
+        let lines = layout(&render(*style));
+        assert_eq!(
+            gaps(&lines),
+            gaps(&reference),
+            "style {name} has a different number of blank lines than ascii"
+        );
+    }
+}
+
+#[test]
+fn block_followed_by_paragraph_has_single_blank_line() {
+    let cases = [
+        "- one\n- two\n\nafter",
+        "> quoted\n\nafter",
+        "> outer\n>> inner\n\nafter",
+        "```\ncode\n```\n\nafter",
+        "| a | b |\n|---|---|\n| 1 | 2 |\n\nafter",
+        "---\n\nafter",
+        "# Heading\n\nafter",
+    ];
+
+    for input in cases {
+        let output = strip_ansi(&Renderer::new().with_style(Style::Ascii).render(input));
+        let lines: Vec<&str> = output.lines().collect();
+        let after = lines
+            .iter()
+            .position(|l| l.trim() == "after")
+            .unwrap_or_else(|| panic!("missing paragraph for {input:?}"));
+        assert!(lines[after - 1].trim().is_empty(), "no gap for {input:?}");
+        assert!(
+            !lines[after - 2].trim().is_empty(),
+            "double gap for {input:?}:\n{output}"
+        );
+    }
+}
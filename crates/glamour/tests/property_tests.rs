@@ -523,9 +523,10 @@ proptest! {
         );
     }
 
-    /// A single very long word is not broken by wrapping.
+    /// A single word longer than the wrap width is broken across lines
+    /// that fit, without losing characters.
     #[test]
-    fn long_word_not_broken(
+    fn long_word_broken_to_fit(
         word_len in 30usize..100,
         wrap_width in 10usize..25,
     ) {
@@ -538,13 +539,20 @@ proptest! {
         let output = renderer.render(&word);
         let plain = strip_ansi(&output);
 
-        // The word should appear intact on some line
-        let found = plain.lines().any(|line| line.contains(&word));
-        prop_assert!(
-            found,
-            "Long word (len={}) should appear intact in output.\nWord: {}\nOutput: {}",
-            word_len, word, plain
-        );
+        // Every piece fits and the pieces rejoin into the word
+        let pieces: Vec<&str> = plain
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+        for piece in &pieces {
+            prop_assert!(
+                piece.chars().count() <= wrap_width,
+                "Piece {:?} exceeds wrap width {}.\nOutput: {}",
+                piece, wrap_width, plain
+            );
+        }
+        prop_assert_eq!(pieces.concat(), word);
     }
 
     /// Only whitespace input renders without panic and produces no content words.
@@ -55,7 +55,7 @@ pub mod github;
 use std::io;
 use std::path::Path;
 
-use glamour::{Style as GlamourStyle, TermRenderer};
+use glamour::{CodeFold, Style as GlamourStyle, TermRenderer};
 
 /// Configuration for the markdown reader.
 ///
@@ -81,6 +81,7 @@ pub struct Config {
     style: String,
     line_numbers: bool,
     preserve_newlines: bool,
+    fold_code: Option<usize>,
 }
 
 impl Config {
@@ -92,6 +93,7 @@ impl Config {
             style: "dark".to_string(),
             line_numbers: false,
             preserve_newlines: false,
+            fold_code: None,
         }
     }
 
@@ -125,6 +127,12 @@ impl Config {
         self
     }
 
+    /// Folds code blocks longer than `lines` lines.
+    pub fn fold_code(mut self, lines: usize) -> Self {
+        self.fold_code = Some(lines);
+        self
+    }
+
     fn glamour_style(&self) -> io::Result<GlamourStyle> {
         parse_style(&self.style).ok_or_else(|| {
             io::Error::new(
@@ -142,6 +150,9 @@ impl Config {
         if let Some(width) = self.width {
             renderer = renderer.with_word_wrap(width);
         }
+        if let Some(lines) = self.fold_code {
+            renderer = renderer.with_code_folding(lines);
+        }
         // line_numbers is only available with syntax-highlighting feature
         #[cfg(feature = "syntax-highlighting")]
         if self.line_numbers {
@@ -198,6 +209,19 @@ impl Reader {
         let renderer = self.config.renderer()?;
         Ok(renderer.render(markdown))
     }
+
+    /// Renders markdown with the code blocks at `expanded` unfolded,
+    /// returning the output and its code folds.
+    pub fn render_markdown_with_folds(
+        &self,
+        markdown: &str,
+        expanded: &[usize],
+    ) -> io::Result<(String, Vec<CodeFold>)> {
+        let renderer = self.config.renderer()?;
+        Ok(renderer
+            .with_expanded_folds(expanded.iter().copied())
+            .render_with_folds(markdown))
+    }
 }
 
 /// Stash for saving and organizing documents.
@@ -426,6 +450,20 @@ fn main() {}
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn reader_render_markdown_with_folds() {
+        let reader = Reader::new(Config::new().style("ascii").fold_code(2));
+        let markdown = "```\na\nb\nc\nd\n```";
+
+        let (output, folds) = reader.render_markdown_with_folds(markdown, &[]).unwrap();
+        assert!(output.contains("… 2 more lines"));
+        assert!(!folds[0].expanded);
+
+        let (output, folds) = reader.render_markdown_with_folds(markdown, &[0]).unwrap();
+        assert!(output.contains('d'));
+        assert!(folds[0].expanded);
+    }
+
     #[test]
     fn reader_render_fails_on_invalid_style() {
         let config = Config::new().style("invalid");
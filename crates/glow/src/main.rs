@@ -19,6 +19,7 @@ use std::process::Command as ProcessCommand;
 use bubbles::viewport::Viewport;
 use bubbletea::{Cmd, KeyMsg, KeyType, Message, Model, Program, WindowSizeMsg, quit};
 use clap::{ArgAction, CommandFactory, Parser};
+use glamour::CodeFold;
 #[cfg(feature = "github")]
 use glow::github::{FetcherConfig, GitHubFetcher, RepoRef};
 use glow::{Config, Reader};
@@ -59,6 +60,10 @@ struct Cli {
     /// Preserve newlines in markdown output
     #[arg(long = "preserve-new-lines")]
     preserve_new_lines: bool,
+
+    /// Fold code blocks longer than this many lines (press z in the pager to unfold)
+    #[arg(long = "fold-code", value_name = "LINES")]
+    fold_code: Option<usize>,
 }
 
 /// Input mode for the pager.
@@ -103,6 +108,10 @@ struct Pager {
     match_style: Style,
     /// Whether mouse support is enabled.
     mouse_enabled: bool,
+    /// Renderer for re-rendering when a code fold is toggled.
+    reader: Option<Reader>,
+    /// Code folds in the rendered content.
+    folds: Vec<CodeFold>,
 }
 
 impl Pager {
@@ -128,6 +137,8 @@ impl Pager {
             search_style: Style::new().foreground("#FFCC00").bold(),
             match_style: Style::new().foreground("#00FF00"),
             mouse_enabled: false,
+            reader: None,
+            folds: Vec::new(),
         }
     }
 
@@ -136,6 +147,52 @@ impl Pager {
         self
     }
 
+    fn with_folds(mut self, reader: Reader, folds: Vec<CodeFold>) -> Self {
+        self.reader = Some(reader);
+        self.folds = folds;
+        self
+    }
+
+    /// Unfolds the first folded code block on screen, or folds the first
+    /// expanded one if none on screen is folded.
+    fn toggle_fold(&mut self) {
+        let Some(reader) = &self.reader else {
+            return;
+        };
+        let top = self.viewport.y_offset();
+        let on_screen: Vec<&CodeFold> = self
+            .folds
+            .iter()
+            .filter(|fold| (top..top + self.viewport.height).contains(&fold.line))
+            .collect();
+        let Some(target) = on_screen
+            .iter()
+            .find(|fold| !fold.expanded)
+            .or_else(|| on_screen.first())
+            .map(|fold| fold.index)
+        else {
+            return;
+        };
+
+        let expanded: Vec<usize> = self
+            .folds
+            .iter()
+            .filter(|fold| fold.expanded != (fold.index == target))
+            .map(|fold| fold.index)
+            .collect();
+        let Ok((content, folds)) =
+            reader.render_markdown_with_folds(&self.source_markdown, &expanded)
+        else {
+            return;
+        };
+        self.lines = content.lines().map(String::from).collect();
+        self.content = content;
+        self.folds = folds;
+        self.viewport.set_content(&self.content);
+        self.viewport.set_y_offset(top);
+        self.perform_search();
+    }
+
     /// Copies content to clipboard using system commands.
     fn copy_to_clipboard(&self) -> bool {
         // Try different clipboard commands based on platform
@@ -266,6 +323,7 @@ impl Pager {
             "  ───────",
             "  c          Copy source to clipboard",
             "  e          Open in $EDITOR",
+            "  z          Fold/unfold code block",
             "  h          Show this help",
             "  q/Esc      Quit",
             "",
@@ -519,6 +577,10 @@ impl Model for Pager {
                                 self.open_in_editor();
                                 return None;
                             }
+                            ['z'] => {
+                                self.toggle_fold();
+                                return None;
+                            }
                             _ => {}
                         },
                         _ => {}
@@ -604,6 +666,9 @@ fn main() {
     if let Some(width) = cli.width {
         config = config.width(width);
     }
+    if let Some(lines) = cli.fold_code {
+        config = config.fold_code(lines);
+    }
 
     let reader = Reader::new(config);
 
@@ -670,7 +735,7 @@ fn main() {
         };
 
         // Render markdown
-        let rendered = match reader.render_markdown(&content) {
+        let (rendered, folds) = match reader.render_markdown_with_folds(&content, &[]) {
             Ok(output) => output,
             Err(err) => {
                 eprintln!("Error rendering markdown: {err}");
@@ -685,7 +750,9 @@ fn main() {
         }
 
         // Run TUI pager
-        let pager = Pager::new(rendered, content, title, source_path).with_mouse(cli.mouse);
+        let pager = Pager::new(rendered, content, title, source_path)
+            .with_mouse(cli.mouse)
+            .with_folds(reader, folds);
         let mut program = Program::new(pager).with_alt_screen();
 
         if cli.mouse {
@@ -8,7 +8,7 @@ use std::fs::{self, File};
 use std::io::Write as _;
 use std::path::Path;
 
-use bubbletea::{KeyMsg, KeyType, Message, Model};
+use bubbletea::{KeyMod, KeyMsg, KeyType, Message, Model};
 use glow::browser::{BrowserConfig, Entry, FileBrowser};
 use glow::{Config, Reader, Stash};
 use tempfile::TempDir;
@@ -36,6 +36,7 @@ fn key_msg(key_type: KeyType) -> Message {
         runes: Vec::new(),
         alt: false,
         paste: false,
+        modifiers: KeyMod::NONE,
     })
 }
 
@@ -45,6 +46,7 @@ fn rune_msg(c: char) -> Message {
         runes: vec![c],
         alt: false,
         paste: false,
+        modifiers: KeyMod::NONE,
     })
 }
 
@@ -0,0 +1,168 @@
+#![forbid(unsafe_code)]
+#![allow(clippy::nursery)]
+#![allow(clippy::pedantic)]
+
+//! # huh-macros
+//!
+//! Procedural macros for the huh forms library.
+//!
+//! This crate provides `#[derive(FormBind)]`, which fills in a struct from
+//! the values of a finished form so answers don't need to be looked up and
+//! downcast one key at a time.
+//!
+//! ```rust,ignore
+//! use huh::{Form, FormBind};
+//!
+//! #[derive(Default, FormBind)]
+//! struct Signup {
+//!     name: String,
+//!     #[form(key = "newsletter")]
+//!     subscribe: bool,
+//!     #[form(nested)]
+//!     address: Address,
+//!     #[form(skip)]
+//!     attempts: u32,
+//! }
+//!
+//! #[derive(Default, FormBind)]
+//! struct Address {
+//!     city: String,
+//! }
+//!
+//! let form = form.run()?;
+//! let mut signup = Signup::default();
+//! form.bind(&mut signup);
+//! ```
+//!
+//! ## Field Attributes
+//!
+//! | Attribute | Effect |
+//! |-----------|--------|
+//! | `#[form(key = "k")]` | Reads the value with key `k` instead of the field name |
+//! | `#[form(nested)]` | Binds a struct that also derives `FormBind`, scoping its keys under the field's key (`address.city`) |
+//! | `#[form(skip)]` | Leaves the field untouched |
+//!
+//! Plain fields must implement `huh::FromFormValue`. Fields whose key has
+//! no value, or whose value has the wrong type, keep their current value.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitStr, parse_macro_input, spanned::Spanned};
+
+/// Derives `huh::FormBind` for a struct with named fields.
+///
+/// See the [crate documentation](crate) for the supported attributes.
+#[proc_macro_derive(FormBind, attributes(form))]
+pub fn derive_form_bind(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// How a single struct field is bound.
+enum Binding {
+    Value(String),
+    Nested(String),
+    Skip,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.ident.span(),
+                    "FormBind can only be derived for structs with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "FormBind can only be derived for structs",
+            ));
+        }
+    };
+
+    let mut statements = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let statement = match parse_binding(field)? {
+            Binding::Value(key) => quote! {
+                if let ::core::option::Option::Some(value) =
+                    <#ty as ::huh::FromFormValue>::from_form_value(
+                        values,
+                        &::huh::scoped_key(scope, #key),
+                    )
+                {
+                    self.#ident = value;
+                }
+            },
+            Binding::Nested(key) => quote! {
+                ::huh::FormBind::bind_scoped(
+                    &mut self.#ident,
+                    values,
+                    &::huh::scoped_key(scope, #key),
+                );
+            },
+            Binding::Skip => continue,
+        };
+        statements.push(statement);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::huh::FormBind for #name #ty_generics #where_clause {
+            fn bind_scoped(&mut self, values: &::huh::FormValues, scope: &str) {
+                #(#statements)*
+            }
+        }
+    })
+}
+
+/// Reads the `#[form(...)]` attributes of a field.
+fn parse_binding(field: &syn::Field) -> syn::Result<Binding> {
+    let mut key = field
+        .ident
+        .as_ref()
+        .map(|ident| ident.to_string().trim_start_matches("r#").to_string())
+        .unwrap_or_default();
+    let mut nested = false;
+    let mut skip = false;
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("form")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("key") {
+                key = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            } else if meta.path.is_ident("nested") {
+                nested = true;
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `key = \"...\"`, `nested` or `skip`"))
+            }
+        })?;
+    }
+
+    if skip && nested {
+        return Err(syn::Error::new(
+            field.span(),
+            "`skip` and `nested` cannot be combined",
+        ));
+    }
+    Ok(if skip {
+        Binding::Skip
+    } else if nested {
+        Binding::Nested(key)
+    } else {
+        Binding::Value(key)
+    })
+}
@@ -0,0 +1,85 @@
+#![allow(clippy::pedantic)]
+#![allow(clippy::nursery)]
+
+//! Tests for `#[derive(FormBind)]`.
+
+use huh::{Confirm, Form, FormBind, Group, Input, MultiSelect, SelectOption};
+
+#[derive(Debug, Default, FormBind)]
+struct Signup {
+    name: String,
+    age: Option<u8>,
+    #[form(key = "newsletter")]
+    subscribe: bool,
+    langs: Vec<String>,
+    #[form(nested)]
+    address: Address,
+    #[form(skip)]
+    attempts: u32,
+}
+
+#[derive(Debug, Default, FormBind)]
+struct Address {
+    city: String,
+    #[form(key = "zip")]
+    postcode: String,
+}
+
+fn signup_form() -> Form {
+    Form::new(vec![
+        Group::new(vec![
+            Box::new(Input::new().key("name").value("Ada")),
+            Box::new(Input::new().key("age").value("36")),
+            Box::new(Confirm::new().key("newsletter").value(true)),
+            Box::new(MultiSelect::new().key("langs").options(vec![
+                SelectOption::new("Rust", "Rust".to_string()).selected(true),
+                SelectOption::new("Go", "Go".to_string()),
+            ])),
+        ]),
+        Group::new(vec![
+            Box::new(Input::new().key("city").value("London")),
+            Box::new(Input::new().key("zip").value("NW1")),
+        ])
+        .scope("address"),
+    ])
+}
+
+#[test]
+fn derive_binds_fields_by_key() {
+    let mut signup = Signup {
+        attempts: 3,
+        ..Signup::default()
+    };
+    signup_form().bind(&mut signup);
+
+    assert_eq!(signup.name, "Ada");
+    assert_eq!(signup.age, Some(36));
+    assert!(signup.subscribe);
+    assert_eq!(signup.langs, vec!["Rust".to_string()]);
+    assert_eq!(signup.attempts, 3);
+}
+
+#[test]
+fn derive_binds_nested_struct_under_scope() {
+    let mut signup = Signup::default();
+    signup_form().bind(&mut signup);
+
+    assert_eq!(signup.address.city, "London");
+    assert_eq!(signup.address.postcode, "NW1");
+}
+
+#[test]
+fn derive_keeps_fields_without_values() {
+    let form = Form::new(vec![Group::new(vec![Box::new(
+        Input::new().key("age").value("not a number"),
+    )])]);
+    let mut signup = Signup {
+        name: "unchanged".to_string(),
+        age: Some(1),
+        ..Signup::default()
+    };
+    form.bind(&mut signup);
+
+    assert_eq!(signup.name, "unchanged");
+    assert_eq!(signup.age, Some(1));
+}
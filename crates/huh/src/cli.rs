@@ -0,0 +1,410 @@
+//! Interactive fallback for command-line arguments parsed with clap.
+
+use std::ffi::OsString;
+
+use clap::builder::PossibleValue;
+use clap::parser::ValueSource;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use crate::{
+    Confirm, Field, Form, FormError, Group, Input, MultiSelect, Result, SelectOption, Theme,
+};
+
+/// Prompts for the arguments of a [`clap::Command`] that were not given on
+/// the command line.
+///
+/// Arguments are parsed as usual; any that are missing are asked for in a
+/// form, and the answers are added to the command line before it is parsed
+/// again, so the returned [`ArgMatches`] look as if everything had been
+/// typed. Flags become [`Confirm`] fields, arguments with possible values a
+/// [`Select`](crate::Select) (or [`MultiSelect`] when they take several
+/// values), and everything else an [`Input`].
+///
+/// Only required arguments are asked for unless
+/// [`prompt_optional`](Self::prompt_optional) is set. Values from the
+/// environment count as given, and defaults are offered as the initial
+/// answer. Subcommands are not prompted for.
+///
+/// Requires the `clap` feature.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use clap::{Arg, Command};
+/// use huh::ClapPrompt;
+///
+/// let command = Command::new("deploy")
+///     .arg(Arg::new("target").long("target").required(true))
+///     .arg(Arg::new("env").long("env").value_parser(["staging", "prod"]));
+///
+/// // `deploy` alone asks for the target; `deploy --target web` asks nothing.
+/// let matches = ClapPrompt::new(command).get_matches();
+/// ```
+pub struct ClapPrompt {
+    command: Command,
+    prompt_optional: bool,
+    theme: Option<Theme>,
+}
+
+impl ClapPrompt {
+    /// Creates a prompt for the arguments of `command`.
+    pub fn new(command: Command) -> Self {
+        Self {
+            command,
+            prompt_optional: false,
+            theme: None,
+        }
+    }
+
+    /// Sets whether optional arguments that were not given are asked for
+    /// too.
+    pub fn prompt_optional(mut self, prompt_optional: bool) -> Self {
+        self.prompt_optional = prompt_optional;
+        self
+    }
+
+    /// Sets the theme of the form.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Parses the process's arguments, prompting for missing ones.
+    ///
+    /// Like [`Command::get_matches`], this prints help, version and usage
+    /// errors and exits, and it also exits if the form is aborted.
+    pub fn get_matches(self) -> ArgMatches {
+        self.try_get_matches_from(std::env::args_os())
+            .unwrap_or_else(|err| {
+                eprintln!("{err}");
+                std::process::exit(if err.is_user_abort() { 130 } else { 1 });
+            })
+    }
+
+    /// Parses `args`, prompting for missing ones.
+    ///
+    /// Help and version requests print and exit as they do in clap.
+    ///
+    /// # Errors
+    ///
+    /// Returns the form's error if it is aborted, or
+    /// [`FormError::Validation`] with clap's message if the completed
+    /// command line is still invalid.
+    pub fn try_get_matches_from<I, T>(self, args: I) -> Result<ArgMatches>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        self.get_matches_with(args, Form::run)
+    }
+
+    /// Parses `args`, running the form for missing arguments with `run`.
+    fn get_matches_with<I, T>(
+        self,
+        args: I,
+        run: impl FnOnce(Form) -> Result<Form>,
+    ) -> Result<ArgMatches>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let mut args: Vec<OsString> = args.into_iter().map(Into::into).collect();
+        let matches = match self.relaxed_command().try_get_matches_from(&args) {
+            Ok(matches) => matches,
+            Err(err) if err.use_stderr() => return Err(FormError::validation(err.to_string())),
+            Err(err) => err.exit(),
+        };
+
+        let missing: Vec<&Arg> = self
+            .command
+            .get_arguments()
+            .filter(|arg| self.should_prompt(arg, &matches))
+            .collect();
+        if !missing.is_empty() {
+            let fields = missing.iter().map(|arg| field_for(arg)).collect();
+            let mut form = Form::new(vec![Group::new(fields)]);
+            if let Some(theme) = &self.theme {
+                form = form.theme(theme.clone());
+            }
+            let form = run(form)?;
+            append_answers(&mut args, &missing, &form);
+        }
+
+        self.command
+            .try_get_matches_from(args)
+            .map_err(|err| FormError::validation(err.to_string()))
+    }
+
+    /// Returns the command with nothing required, so a command line with
+    /// missing arguments still parses.
+    fn relaxed_command(&self) -> Command {
+        let mut command = self
+            .command
+            .clone()
+            .mut_args(|arg| arg.required(false))
+            .subcommand_required(false)
+            .arg_required_else_help(false);
+        let groups: Vec<_> = command
+            .get_groups()
+            .map(|group| group.get_id().clone())
+            .collect();
+        for id in groups {
+            command = command.mut_group(id, |group| group.required(false));
+        }
+        command
+    }
+
+    /// Returns whether `arg` was not given and should be asked for.
+    fn should_prompt(&self, arg: &Arg, matches: &ArgMatches) -> bool {
+        if arg.is_hide_set()
+            || !matches!(
+                arg.get_action(),
+                ArgAction::Set | ArgAction::Append | ArgAction::SetTrue | ArgAction::SetFalse
+            )
+        {
+            return false;
+        }
+        let given = matches!(
+            matches.value_source(arg.get_id().as_str()),
+            Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+        );
+        !given && (arg.is_required_set() || self.prompt_optional)
+    }
+}
+
+impl std::fmt::Debug for ClapPrompt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClapPrompt")
+            .field("command", &self.command.get_name())
+            .field("prompt_optional", &self.prompt_optional)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Builds the field asking for `arg`, keyed by the argument's id.
+fn field_for(arg: &Arg) -> Box<dyn Field> {
+    let key = arg.get_id().to_string();
+    let title = arg
+        .get_help()
+        .map_or_else(|| key.clone(), ToString::to_string);
+    let defaults: Vec<String> = arg
+        .get_default_values()
+        .iter()
+        .map(|value| value.to_string_lossy().into_owned())
+        .collect();
+
+    if matches!(arg.get_action(), ArgAction::SetTrue | ArgAction::SetFalse) {
+        let value = defaults.first().is_some_and(|value| value == "true");
+        return Box::new(Confirm::new().key(key).title(title).value(value));
+    }
+
+    let possible: Vec<PossibleValue> = arg
+        .get_possible_values()
+        .into_iter()
+        .filter(|value| !value.is_hide_set())
+        .collect();
+    let options = |possible: Vec<PossibleValue>| -> Vec<SelectOption<String>> {
+        possible
+            .into_iter()
+            .map(|value| {
+                let name = value.get_name().to_string();
+                let label = match value.get_help() {
+                    Some(help) => format!("{name} - {help}"),
+                    None => name.clone(),
+                };
+                let selected = defaults.contains(&name);
+                SelectOption::new(label, name).selected(selected)
+            })
+            .collect()
+    };
+
+    if possible.is_empty() {
+        let mut input = Input::new().key(key).title(title);
+        if let Some(value_names) = arg.get_value_names() {
+            let names: Vec<&str> = value_names.iter().map(|name| name.as_str()).collect();
+            input = input.placeholder(names.join(" "));
+        }
+        if !defaults.is_empty() {
+            input = input.default_value(defaults.join(","));
+        }
+        if takes_many(arg) {
+            input = input.description("Separate values with commas");
+        }
+        if arg.is_required_set() {
+            input = input.validate(require_value);
+        }
+        Box::new(input)
+    } else if takes_many(arg) {
+        Box::new(
+            MultiSelect::new()
+                .key(key)
+                .title(title)
+                .options(options(possible)),
+        )
+    } else {
+        Box::new(
+            crate::Select::new()
+                .key(key)
+                .title(title)
+                .options(options(possible)),
+        )
+    }
+}
+
+/// Rejects empty answers to required arguments.
+fn require_value(value: &str) -> Option<String> {
+    value
+        .trim()
+        .is_empty()
+        .then(|| "a value is required".to_string())
+}
+
+/// Returns whether `arg` accepts more than one value.
+fn takes_many(arg: &Arg) -> bool {
+    matches!(arg.get_action(), ArgAction::Append)
+        || arg
+            .get_num_args()
+            .is_some_and(|range| range.max_values() > 1)
+}
+
+/// Adds the form's answers for `missing` to the command line.
+///
+/// Options are passed by name, so their values may start with `-`, and
+/// positionals follow everything else in index order.
+fn append_answers(args: &mut Vec<OsString>, missing: &[&Arg], form: &Form) {
+    let mut positionals = Vec::new();
+    for arg in missing {
+        let key = arg.get_id().as_str();
+        let values: Vec<String> = if let Some(answer) = form.get_bool(key) {
+            let set = answer == matches!(arg.get_action(), ArgAction::SetTrue);
+            if set {
+                args.push(flag_name(arg).into());
+            }
+            continue;
+        } else if let Some(answers) = form.get_strings(key) {
+            answers
+        } else if let Some(answer) = form.get_string(key) {
+            if takes_many(arg) {
+                answer
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+                    .map(ToString::to_string)
+                    .collect()
+            } else {
+                vec![answer]
+            }
+        } else {
+            continue;
+        };
+
+        let values = values.into_iter().filter(|value| !value.is_empty());
+        if arg.is_positional() {
+            positionals.extend(values);
+        } else {
+            for value in values {
+                args.push(format!("{}={value}", flag_name(arg)).into());
+            }
+        }
+    }
+
+    if !positionals.is_empty() {
+        if positionals.iter().any(|value| value.starts_with('-'))
+            && !args.iter().any(|arg| arg == "--")
+        {
+            args.push("--".into());
+        }
+        args.extend(positionals.into_iter().map(OsString::from));
+    }
+}
+
+/// Returns `--long`, or `-s` for arguments with only a short name.
+fn flag_name(arg: &Arg) -> String {
+    match (arg.get_long(), arg.get_short()) {
+        (Some(long), _) => format!("--{long}"),
+        (None, Some(short)) => format!("-{short}"),
+        (None, None) => format!("--{}", arg.get_id()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command() -> Command {
+        Command::new("deploy")
+            .arg(Arg::new("target").help("Target host").required(true))
+            .arg(
+                Arg::new("env")
+                    .long("env")
+                    .required(true)
+                    .value_parser(["staging", "prod"]),
+            )
+            .arg(Arg::new("force").long("force").action(ArgAction::SetTrue))
+            .arg(Arg::new("tag").short('t').action(ArgAction::Append))
+    }
+
+    fn run(prompt: ClapPrompt, args: &[&str], answers: &str) -> (Result<ArgMatches>, String) {
+        let mut output = Vec::new();
+        let matches = prompt.get_matches_with(args.iter().copied(), |form| {
+            form.run_accessible(answers.as_bytes(), &mut output)
+        });
+        (matches, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_prompts_only_for_missing_required_args() {
+        let (matches, output) = run(
+            ClapPrompt::new(command()),
+            &["deploy", "--force"],
+            "-web\n2\n",
+        );
+        let matches = matches.unwrap();
+        assert_eq!(matches.get_one::<String>("target").unwrap(), "-web");
+        assert_eq!(matches.get_one::<String>("env").unwrap(), "prod");
+        assert!(matches.get_flag("force"));
+        assert!(output.contains("Target host"));
+        assert!(!output.contains("force"));
+    }
+
+    #[test]
+    fn test_complete_command_line_asks_nothing() {
+        let (matches, output) = run(
+            ClapPrompt::new(command()),
+            &["deploy", "web", "--env", "staging"],
+            "",
+        );
+        assert_eq!(
+            matches.unwrap().get_one::<String>("env").unwrap(),
+            "staging"
+        );
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_prompt_optional_asks_for_flags_and_lists() {
+        let prompt = ClapPrompt::new(command()).prompt_optional(true);
+        let (matches, _) = run(prompt, &["deploy", "web", "--env", "prod"], "y\na, b\n");
+        let matches = matches.unwrap();
+        assert!(matches.get_flag("force"));
+        let tags: Vec<&String> = matches.get_many("tag").unwrap().collect();
+        assert_eq!(tags, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_required_input_rejects_empty_answer() {
+        let (matches, output) = run(
+            ClapPrompt::new(command()),
+            &["deploy", "--env", "prod"],
+            "\nweb\n",
+        );
+        assert_eq!(matches.unwrap().get_one::<String>("target").unwrap(), "web");
+        assert!(output.contains("a value is required"));
+    }
+
+    #[test]
+    fn test_aborted_form_is_an_error() {
+        let (matches, _) = run(ClapPrompt::new(command()), &["deploy"], "");
+        assert_eq!(matches.unwrap_err(), FormError::UserAborted);
+    }
+}
@@ -352,16 +352,7 @@ impl HtmlBackend {
             text = transform(&text);
         }
 
-        let tab_width = if style.has_custom_tab_width() {
-            style.get_tab_width()
-        } else {
-            4
-        };
-        text = match tab_width {
-            -1 => text,
-            0 => text.replace('\t', ""),
-            n => text.replace('\t', &" ".repeat(n as usize)),
-        };
+        text = style.convert_tabs(text);
 
         text = text.replace("\r\n", "\n");
 
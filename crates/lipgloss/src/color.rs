@@ -65,6 +65,14 @@ pub trait TerminalColor: fmt::Debug + Send + Sync {
 
     /// Clone this color into a boxed trait object.
     fn clone_box(&self) -> Box<dyn TerminalColor>;
+
+    /// Returns the RGB value this color shows as, if it has one.
+    ///
+    /// ANSI colors map to the xterm palette. Colors that leave the choice to
+    /// the terminal, such as [`NoColor`], return `None`.
+    fn to_rgb(&self, _dark_bg: bool) -> Option<(u8, u8, u8)> {
+        None
+    }
 }
 
 impl Clone for Box<dyn TerminalColor> {
@@ -405,6 +413,10 @@ impl TerminalColor for Color {
     fn clone_box(&self) -> Box<dyn TerminalColor> {
         Box::new(self.clone())
     }
+
+    fn to_rgb(&self, _dark_bg: bool) -> Option<(u8, u8, u8)> {
+        self.as_rgb().or_else(|| self.as_ansi().map(ansi256_to_rgb))
+    }
 }
 
 /// An ANSI color by number (0-255).
@@ -429,6 +441,10 @@ impl TerminalColor for AnsiColor {
     fn clone_box(&self) -> Box<dyn TerminalColor> {
         Box::new(*self)
     }
+
+    fn to_rgb(&self, _dark_bg: bool) -> Option<(u8, u8, u8)> {
+        Some(ansi256_to_rgb(self.0))
+    }
 }
 
 /// An RGB color.
@@ -497,6 +513,10 @@ impl TerminalColor for RgbColor {
     fn clone_box(&self) -> Box<dyn TerminalColor> {
         Box::new(*self)
     }
+
+    fn to_rgb(&self, _dark_bg: bool) -> Option<(u8, u8, u8)> {
+        Some((self.r, self.g, self.b))
+    }
 }
 
 /// A color that adapts based on terminal background.
@@ -528,6 +548,14 @@ impl TerminalColor for AdaptiveColor {
     fn clone_box(&self) -> Box<dyn TerminalColor> {
         Box::new(self.clone())
     }
+
+    fn to_rgb(&self, dark_bg: bool) -> Option<(u8, u8, u8)> {
+        if dark_bg {
+            self.dark.to_rgb(dark_bg)
+        } else {
+            self.light.to_rgb(dark_bg)
+        }
+    }
 }
 
 /// A color with explicit values for each color profile.
@@ -587,6 +615,13 @@ impl TerminalColor for CompleteColor {
     fn clone_box(&self) -> Box<dyn TerminalColor> {
         Box::new(self.clone())
     }
+
+    fn to_rgb(&self, dark_bg: bool) -> Option<(u8, u8, u8)> {
+        [&self.truecolor, &self.ansi256, &self.ansi]
+            .into_iter()
+            .flatten()
+            .find_map(|c| c.to_rgb(dark_bg))
+    }
 }
 
 /// A complete color with adaptive light/dark variants.
@@ -618,6 +653,14 @@ impl TerminalColor for CompleteAdaptiveColor {
     fn clone_box(&self) -> Box<dyn TerminalColor> {
         Box::new(self.clone())
     }
+
+    fn to_rgb(&self, dark_bg: bool) -> Option<(u8, u8, u8)> {
+        if dark_bg {
+            self.dark.to_rgb(dark_bg)
+        } else {
+            self.light.to_rgb(dark_bg)
+        }
+    }
 }
 
 // Color conversion helpers
@@ -753,6 +796,20 @@ mod tests {
         assert_eq!(ansi256_to_rgb(15), (255, 255, 255));
     }
 
+    #[test]
+    fn test_to_rgb() {
+        assert_eq!(Color::from("#ff8000").to_rgb(true), Some((255, 128, 0)));
+        assert_eq!(Color::from("0").to_rgb(true), Some(ansi256_to_rgb(0)));
+        assert_eq!(NoColor.to_rgb(true), None);
+
+        let adaptive = AdaptiveColor {
+            light: Color::from("#000000"),
+            dark: Color::from("#ffffff"),
+        };
+        assert_eq!(adaptive.to_rgb(true), Some((255, 255, 255)));
+        assert_eq!(adaptive.to_rgb(false), Some((0, 0, 0)));
+    }
+
     #[test]
     fn test_color_profile_supports() {
         assert!(ColorProfile::TrueColor.supports(ColorProfile::Ansi256));
@@ -487,10 +487,471 @@ pub fn height(s: &str) -> usize {
     s.lines().count().max(1)
 }
 
+/// Wrap text so that no line is wider than `width` terminal cells.
+///
+/// Lines are broken at spaces where possible, and words wider than `width`
+/// are broken mid-word. Existing newlines are kept. ANSI styling survives the
+/// inserted breaks: any SGR sequences still active are reset at the end of a
+/// wrapped line and re-applied at the start of the next, so each line renders
+/// correctly on its own.
+///
+/// A `width` of 0 disables wrapping. See [`word_wrap`] for a variant that
+/// never splits words.
+///
+/// # Example
+///
+/// ```rust
+/// use lipgloss::wrap;
+///
+/// assert_eq!(wrap("the quick brown fox", 10), "the quick\nbrown fox");
+/// assert_eq!(wrap("abcdefgh", 3), "abc\ndef\ngh");
+/// assert_eq!(
+///     wrap("\x1b[1mbold text\x1b[0m", 4),
+///     "\x1b[1mbold\x1b[0m\n\x1b[1mtext\x1b[0m",
+/// );
+/// ```
+pub fn wrap(s: &str, width: usize) -> String {
+    wrap_lines(s, width, true, "")
+}
+
+/// Wrap text like [`wrap`], but break words wider than `width` after one of
+/// the `breakpoints` characters where possible.
+///
+/// Long URLs and paths stay readable when broken at `/` instead of at an
+/// arbitrary cell. Pieces between breakpoints that still don't fit on a line
+/// are broken wherever the line fills up.
+///
+/// # Example
+///
+/// ```rust
+/// use lipgloss::wrap_at;
+///
+/// assert_eq!(
+///     wrap_at("see https://example.com/docs/page", 14, "/"),
+///     "see\nhttps://\nexample.com/\ndocs/page",
+/// );
+/// ```
+pub fn wrap_at(s: &str, width: usize, breakpoints: &str) -> String {
+    wrap_lines(s, width, true, breakpoints)
+}
+
+/// Wrap text at spaces so that lines fit in `width` terminal cells where
+/// possible.
+///
+/// Unlike [`wrap`], words wider than `width` are kept whole and overflow the
+/// line, which suits content such as URLs that must not be split. ANSI
+/// styling is carried across breaks the same way.
+///
+/// # Example
+///
+/// ```rust
+/// use lipgloss::word_wrap;
+///
+/// assert_eq!(word_wrap("see https://example.com", 8), "see\nhttps://example.com");
+/// ```
+pub fn word_wrap(s: &str, width: usize) -> String {
+    wrap_lines(s, width, false, "")
+}
+
+/// Expand tabs to spaces, advancing each tab to the next tab stop.
+///
+/// Tab stops fall every `tab_width` columns, so a tab after `a` or after
+/// `abc` both move the following text to column 4. Columns are counted in
+/// terminal cells and restart on every line; ANSI escape sequences take no
+/// space. A `tab_width` of 0 removes tabs.
+///
+/// # Example
+///
+/// ```rust
+/// use lipgloss::expand_tabs;
+///
+/// assert_eq!(expand_tabs("a\tb", 4), "a   b");
+/// assert_eq!(expand_tabs("abcd\tb", 4), "abcd    b");
+/// assert_eq!(expand_tabs("\x1b[1mab\x1b[0m\tc", 4), "\x1b[1mab\x1b[0m  c");
+/// ```
+pub fn expand_tabs(s: &str, tab_width: usize) -> String {
+    if !s.contains('\t') {
+        return s.to_string();
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut column = 0;
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\t' if tab_width > 0 => {
+                let spaces = tab_width - column % tab_width;
+                out.extend(std::iter::repeat_n(' ', spaces));
+                column += spaces;
+            }
+            '\t' => {}
+            '\n' => {
+                out.push(c);
+                column = 0;
+            }
+            '\x1b' => {
+                out.push(c);
+                copy_escape_sequence(&mut chars, &mut out);
+            }
+            _ => {
+                out.push(c);
+                column += unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
+            }
+        }
+    }
+    out
+}
+
+/// Copy the rest of an escape sequence whose ESC has already been consumed.
+fn copy_escape_sequence(chars: &mut std::str::Chars<'_>, out: &mut String) {
+    match chars.next() {
+        Some('[') => {
+            out.push('[');
+            // CSI sequence ends with final byte 0x40-0x7E (@ to ~)
+            for c in chars.by_ref() {
+                out.push(c);
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        }
+        Some(']') => {
+            out.push(']');
+            // OSC ends with BEL (\x07) or ST (ESC \)
+            while let Some(c) = chars.next() {
+                out.push(c);
+                if c == '\x07' {
+                    break;
+                }
+                if c == '\x1b' {
+                    out.extend(chars.next());
+                    break;
+                }
+            }
+        }
+        Some(c) => out.push(c),
+        None => {}
+    }
+}
+
+fn wrap_lines(s: &str, width: usize, break_words: bool, breakpoints: &str) -> String {
+    if width == 0 {
+        return s.to_string();
+    }
+
+    let mut wrapper = Wrapper {
+        out: String::with_capacity(s.len()),
+        width,
+        col: 0,
+        sgr: String::new(),
+        link: String::new(),
+    };
+
+    for (i, line) in s.lines().enumerate() {
+        if i > 0 {
+            wrapper.out.push('\n');
+            wrapper.col = 0;
+        }
+
+        if visible_width(line) <= width {
+            if line.contains('\x1b') {
+                for token in AnsiTokens(line) {
+                    wrapper.track(token);
+                }
+            }
+            wrapper.out.push_str(line);
+            continue;
+        }
+
+        for (j, word) in line.split(' ').enumerate() {
+            let word_width = visible_width(word);
+            if j > 0 {
+                if wrapper.col > 0 && wrapper.col + 1 + word_width > width {
+                    wrapper.break_line();
+                } else {
+                    wrapper.out.push(' ');
+                    wrapper.col += 1;
+                }
+            }
+            if !break_words || wrapper.col + word_width <= width {
+                for token in AnsiTokens(word) {
+                    wrapper.track(token);
+                }
+                wrapper.out.push_str(word);
+                wrapper.col += word_width;
+            } else {
+                wrapper.push_hard(word, breakpoints);
+            }
+        }
+    }
+
+    wrapper.out
+}
+
+/// Output state for [`wrap`].
+struct Wrapper {
+    out: String,
+    width: usize,
+    col: usize,
+    /// SGR sequences in effect since the last reset.
+    sgr: String,
+    /// The OSC 8 sequence opening the hyperlink in effect, if any.
+    link: String,
+}
+
+impl Wrapper {
+    /// Records an escape sequence's effect on the active SGR and hyperlink
+    /// state.
+    fn track(&mut self, token: &str) {
+        if token.starts_with("\x1b[") && token.ends_with('m') {
+            if token == "\x1b[0m" || token == "\x1b[m" {
+                self.sgr.clear();
+            } else {
+                self.sgr.push_str(token);
+            }
+        } else if let Some(rest) = token.strip_prefix("\x1b]8;") {
+            // OSC 8 ; params ; URI, closed by an empty URI
+            let uri = rest.split_once(';').map_or("", |(_, uri)| uri);
+            if uri.trim_end_matches(['\x07', '\x1b', '\\']).is_empty() {
+                self.link.clear();
+            } else {
+                self.link = token.to_string();
+            }
+        }
+    }
+
+    /// Inserts a line break, carrying the active styling and hyperlink over
+    /// to the new line.
+    fn break_line(&mut self) {
+        if !self.sgr.is_empty() {
+            self.out.push_str("\x1b[0m");
+        }
+        if !self.link.is_empty() {
+            let st = if self.link.ends_with('\x07') {
+                "\x07"
+            } else {
+                "\x1b\\"
+            };
+            self.out.push_str("\x1b]8;;");
+            self.out.push_str(st);
+        }
+        self.out.push('\n');
+        self.out.push_str(&self.link);
+        self.out.push_str(&self.sgr);
+        self.col = 0;
+    }
+
+    /// Writes a word that is too wide for the line, breaking after one of
+    /// `breakpoints` where possible.
+    fn push_hard(&mut self, word: &str, breakpoints: &str) {
+        for piece in split_after(word, breakpoints) {
+            let cells = visible_width(piece);
+            if self.col > 0 && self.col + cells > self.width && cells <= self.width {
+                self.break_line();
+            }
+            if self.col + cells <= self.width {
+                self.push_word(piece, cells);
+            } else {
+                self.push_cells(piece);
+            }
+        }
+    }
+
+    /// Writes a word that fits on the current line.
+    fn push_word(&mut self, word: &str, cells: usize) {
+        for token in AnsiTokens(word) {
+            self.track(token);
+        }
+        self.out.push_str(word);
+        self.col += cells;
+    }
+
+    /// Writes a word cell by cell, breaking wherever the line fills up.
+    fn push_cells(&mut self, word: &str) {
+        for token in AnsiTokens(word) {
+            if token.starts_with('\x1b') {
+                self.track(token);
+                self.out.push_str(token);
+                continue;
+            }
+            let cells = visible_width(token);
+            if self.col > 0 && self.col + cells > self.width {
+                self.break_line();
+            }
+            self.out.push_str(token);
+            self.col += cells;
+        }
+    }
+}
+
+/// Splits a word into pieces that each end after one of `breakpoints`.
+///
+/// Characters inside escape sequences are never breakpoints.
+fn split_after<'a>(word: &'a str, breakpoints: &str) -> Vec<&'a str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut end = 0;
+    for token in AnsiTokens(word) {
+        end += token.len();
+        if !token.starts_with('\x1b') && breakpoints.contains(token) {
+            pieces.push(&word[start..end]);
+            start = end;
+        }
+    }
+    if start < word.len() {
+        pieces.push(&word[start..]);
+    }
+    pieces
+}
+
+/// Splits a string into escape sequences and grapheme clusters, so that
+/// wrapping never separates a character from its combining marks or the
+/// parts of an emoji sequence.
+struct AnsiTokens<'a>(&'a str);
+
+impl<'a> Iterator for AnsiTokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let s = self.0;
+        let mut chars = s.char_indices();
+        let (_, first) = chars.next()?;
+        let mut end = first.len_utf8();
+
+        if first != '\x1b' {
+            // ESC is a control character, so no cluster extends over it
+            end = unicode_segmentation::UnicodeSegmentation::graphemes(s, true)
+                .next()
+                .map_or(end, str::len);
+        } else {
+            match chars.next() {
+                Some((_, '[')) => {
+                    // CSI: ends with a final byte in 0x40-0x7E
+                    end = s.len();
+                    for (idx, c) in chars {
+                        if ('@'..='~').contains(&c) {
+                            end = idx + 1;
+                            break;
+                        }
+                    }
+                }
+                Some((_, ']')) => {
+                    // OSC: ends with BEL or ST (ESC \)
+                    end = s.len();
+                    let mut prev_esc = false;
+                    for (idx, c) in chars {
+                        if c == '\x07' || (prev_esc && c == '\\') {
+                            end = idx + 1;
+                            break;
+                        }
+                        prev_esc = c == '\x1b';
+                    }
+                }
+                Some((idx, c)) => end = idx + c.len_utf8(),
+                None => {}
+            }
+        }
+
+        let (token, rest) = s.split_at(end);
+        self.0 = rest;
+        Some(token)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_wrap_breaks_at_spaces() {
+        assert_eq!(wrap("one two three", 7), "one two\nthree");
+        assert_eq!(wrap("short", 10), "short");
+        assert_eq!(wrap("keep\nlines", 10), "keep\nlines");
+        assert_eq!(wrap("no limit here", 0), "no limit here");
+    }
+
+    #[test]
+    fn test_wrap_breaks_long_words() {
+        assert_eq!(wrap("abcdefghij", 4), "abcd\nefgh\nij");
+        assert_eq!(wrap("hi abcdefgh", 4), "hi\nabcd\nefgh");
+        // Wide characters are never split across lines
+        assert_eq!(wrap("日本語", 4), "日本\n語");
+    }
+
+    #[test]
+    fn test_wrap_keeps_grapheme_clusters() {
+        // "e" with a combining acute accent stays one cell
+        assert_eq!(
+            wrap("e\u{301}e\u{301}e\u{301}", 2),
+            "e\u{301}e\u{301}\ne\u{301}"
+        );
+        // A flag is two regional indicators that must not be split
+        assert_eq!(wrap("ab\u{1F1EF}\u{1F1F5}", 3), "ab\n\u{1F1EF}\u{1F1F5}");
+    }
+
+    #[test]
+    fn test_wrap_at_prefers_breakpoints() {
+        assert_eq!(wrap_at("a/bb/ccc", 5, "/"), "a/bb/\nccc");
+        // Pieces wider than a line still break at the cell limit
+        assert_eq!(wrap_at("x/abcdefgh", 4, "/"), "x/ab\ncdef\ngh");
+        assert_eq!(wrap_at("abcdefgh", 4, ""), wrap("abcdefgh", 4));
+        // Styling and hyperlinks are carried across breaks, and escapes
+        // never break
+        assert_eq!(
+            wrap_at("\x1b]8;;a/b\x07\x1b[4mab/cd\x1b[0m", 3, "/"),
+            "\x1b]8;;a/b\x07\x1b[4mab/\x1b[0m\x1b]8;;\x07\n\x1b]8;;a/b\x07\x1b[4mcd\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_word_wrap_keeps_long_words() {
+        assert_eq!(word_wrap("abcdefghij", 4), "abcdefghij");
+        assert_eq!(word_wrap("hi abcdefgh ok", 4), "hi\nabcdefgh\nok");
+        assert_eq!(
+            word_wrap("\x1b[4mtwo words\x1b[0m", 3),
+            "\x1b[4mtwo\x1b[0m\n\x1b[4mwords\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_expand_tabs_to_tab_stops() {
+        assert_eq!(expand_tabs("\tx", 4), "    x");
+        assert_eq!(expand_tabs("ab\tc\nabc\td", 4), "ab  c\nabc d");
+        assert_eq!(expand_tabs("日本\tx", 8), "日本    x");
+        assert_eq!(expand_tabs("\x1b]0;t\x07a\tb", 2), "\x1b]0;t\x07a b");
+        assert_eq!(expand_tabs("a\tb", 0), "ab");
+        assert_eq!(expand_tabs("no tabs", 4), "no tabs");
+    }
+
+    #[test]
+    fn test_wrap_carries_ansi_state() {
+        let wrapped = wrap("\x1b[31mred words\x1b[0m plain text", 5);
+        assert_eq!(
+            wrapped,
+            "\x1b[31mred\x1b[0m\n\x1b[31mwords\x1b[0m\nplain\ntext"
+        );
+        for line in wrapped.lines() {
+            assert!(visible_width(line) <= 5);
+        }
+    }
+
+    #[test]
+    fn test_wrap_carries_hyperlinks() {
+        let wrapped = wrap("\x1b]8;;https://x.y\x07link text\x1b]8;;\x07 end", 4);
+        assert_eq!(
+            wrapped,
+            "\x1b]8;;https://x.y\x07link\x1b]8;;\x07\n\x1b]8;;https://x.y\x07text\x1b]8;;\x07\nend"
+        );
+        // ST-terminated links are closed the same way
+        let wrapped = wrap("\x1b]8;id=1;https://x.y\x1b\\abcdef\x1b]8;;\x1b\\", 3);
+        assert_eq!(
+            wrapped,
+            "\x1b]8;id=1;https://x.y\x1b\\abc\x1b]8;;\x1b\\\n\x1b]8;id=1;https://x.y\x1b\\def\x1b]8;;\x1b\\"
+        );
+        // Other escapes are passed through
+        assert_eq!(wrap("\x1b]0;title\x07ab cd", 2), "\x1b]0;title\x07ab\ncd");
+    }
+
     #[test]
     fn test_join_vertical_left_alignment() {
         let result = join_vertical(Position::Left, &["Short", "LongerText"]);
@@ -941,24 +1402,52 @@ mod escape_sequence_tests {
     #[test]
     fn test_width_with_terminal_escape_sequences() {
         // CSI DEC private modes (like hide cursor)
-        assert_eq!(visible_width("\x1b[?25l"), 0, "Hide cursor CSI should have 0 width");
-        assert_eq!(visible_width("\x1b[?1000h"), 0, "Mouse mode CSI should have 0 width");
+        assert_eq!(
+            visible_width("\x1b[?25l"),
+            0,
+            "Hide cursor CSI should have 0 width"
+        );
+        assert_eq!(
+            visible_width("\x1b[?1000h"),
+            0,
+            "Mouse mode CSI should have 0 width"
+        );
 
         // MoveTo and Clear
-        assert_eq!(visible_width("\x1b[1;1H"), 0, "MoveTo CSI should have 0 width");
+        assert_eq!(
+            visible_width("\x1b[1;1H"),
+            0,
+            "MoveTo CSI should have 0 width"
+        );
         assert_eq!(visible_width("\x1b[2J"), 0, "Clear CSI should have 0 width");
 
         // OSC title
-        assert_eq!(visible_width("\x1b]0;Title\x07"), 0, "OSC title should have 0 width");
-        assert_eq!(visible_width("\x1b]0;Title\x1b\\"), 0, "OSC title with ST should have 0 width");
+        assert_eq!(
+            visible_width("\x1b]0;Title\x07"),
+            0,
+            "OSC title should have 0 width"
+        );
+        assert_eq!(
+            visible_width("\x1b]0;Title\x1b\\"),
+            0,
+            "OSC title with ST should have 0 width"
+        );
 
         // Combined sequence like in PTY output
         let setup = "\x1b[?25l\x1b[?1000h\x1b[1;1H\x1b[2JLoading...";
-        assert_eq!(visible_width(setup), 10, "Setup + Loading... should be 10 chars");
+        assert_eq!(
+            visible_width(setup),
+            10,
+            "Setup + Loading... should be 10 chars"
+        );
 
         // With OSC title
         let with_title = "\x1b[?25l\x1b[1;1H\x1b[2JLoading...\x1b]0;Charmed\x07More";
-        assert_eq!(visible_width(with_title), 14, "With OSC should count Loading + More = 14");
+        assert_eq!(
+            visible_width(with_title),
+            14,
+            "With OSC should count Loading + More = 14"
+        );
 
         println!("All escape sequence width tests passed!");
     }
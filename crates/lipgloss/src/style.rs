@@ -86,6 +86,9 @@ bitflags! {
         const MAX_HEIGHT = 1 << 40;
         const TAB_WIDTH = 1 << 41;
         const TRANSFORM = 1 << 42;
+        const NO_WRAP = 1 << 43;
+        const TAB_STOPS = 1 << 44;
+        const FILL = 1 << 45;
     }
 }
 
@@ -104,6 +107,9 @@ bitflags! {
         const STRIKETHROUGH_SPACES = 1 << 8;
         const COLOR_WHITESPACE = 1 << 9;
         const INLINE = 1 << 10;
+        const NO_WRAP = 1 << 11;
+        const TAB_STOPS = 1 << 12;
+        const FILL = 1 << 13;
     }
 }
 
@@ -808,13 +814,65 @@ impl Style {
         self
     }
 
+    /// Disable wrapping of content wider than the style's width.
+    ///
+    /// Lines that overflow are left intact and the width only pads shorter
+    /// lines, which is useful for content that has already been laid out.
+    pub fn no_wrap(mut self) -> Self {
+        self.props |= Props::NO_WRAP;
+        self.attrs |= Attrs::NO_WRAP;
+        self
+    }
+
+    /// Set whether the background fills the whole block.
+    ///
+    /// Lines shorter than the widest line, or than the width if one is set,
+    /// are padded out with the background, and so are the blank lines added
+    /// to reach the height. Otherwise only the text, the padding and the
+    /// space added for the width are colored.
+    pub fn fill(mut self, v: bool) -> Self {
+        self.props |= Props::FILL;
+        if v {
+            self.attrs |= Attrs::FILL;
+        } else {
+            self.attrs.remove(Attrs::FILL);
+        }
+        self
+    }
+
     /// Set tab width (-1 = no conversion, 0 = remove tabs).
+    ///
+    /// Tabs are converted before any width calculations, so padding,
+    /// alignment and wrapping see the expanded text. The default is 4.
     pub fn tab_width(mut self, n: i8) -> Self {
         self.props |= Props::TAB_WIDTH;
         self.tab_width = n.max(-1);
         self
     }
 
+    /// Set whether tabs advance to the next tab stop.
+    ///
+    /// By default every tab becomes `tab_width` spaces. With tab stops
+    /// enabled a tab only pads to the next multiple of `tab_width` columns,
+    /// which keeps tab-aligned columns lined up as a terminal would.
+    ///
+    /// # Example
+    /// ```rust
+    /// use lipgloss::Style;
+    ///
+    /// let style = Style::new().tab_width(4).tab_stops(true);
+    /// assert_eq!(style.render("ab\tc"), "ab  c");
+    /// ```
+    pub fn tab_stops(mut self, v: bool) -> Self {
+        self.props |= Props::TAB_STOPS;
+        if v {
+            self.attrs |= Attrs::TAB_STOPS;
+        } else {
+            self.attrs.remove(Attrs::TAB_STOPS);
+        }
+        self
+    }
+
     /// Set text transform function.
     pub fn transform<F>(mut self, f: F) -> Self
     where
@@ -958,16 +1016,20 @@ impl Style {
         }
     }
 
-    pub(crate) fn get_align_horizontal(&self) -> Position {
-        self.align_horizontal
+    /// Returns the foreground color, if set.
+    #[must_use]
+    pub fn get_foreground(&self) -> Option<&dyn TerminalColor> {
+        self.fg_color.as_deref()
     }
 
-    pub(crate) fn get_tab_width(&self) -> i8 {
-        self.tab_width
+    /// Returns the background color, if set.
+    #[must_use]
+    pub fn get_background(&self) -> Option<&dyn TerminalColor> {
+        self.bg_color.as_deref()
     }
 
-    pub(crate) fn has_custom_tab_width(&self) -> bool {
-        self.props.contains(Props::TAB_WIDTH)
+    pub(crate) fn get_align_horizontal(&self) -> Position {
+        self.align_horizontal
     }
 
     pub(crate) fn transform_ref(&self) -> Option<&TransformFn> {
@@ -1005,11 +1067,11 @@ impl Style {
 
         // Early return if no props set
         if self.props.is_empty() {
-            return self.maybe_convert_tabs(str);
+            return self.convert_tabs(str);
         }
 
         // Convert tabs
-        str = self.maybe_convert_tabs(str);
+        str = self.convert_tabs(str);
 
         // Strip carriage returns (only if present - avoid allocation when not needed)
         if str.contains('\r') {
@@ -1023,12 +1085,16 @@ impl Style {
         }
 
         // Word wrap if width is set
-        if !is_inline && self.props.contains(Props::WIDTH) && self.width > 0 {
+        if !is_inline
+            && !self.attrs.contains(Attrs::NO_WRAP)
+            && self.props.contains(Props::WIDTH)
+            && self.width > 0
+        {
             let wrap_at = (self.width as usize)
                 .saturating_sub(self.padding.left as usize)
                 .saturating_sub(self.padding.right as usize);
             if wrap_at > 0 {
-                str = wrap_text(&str, wrap_at);
+                str = crate::wrap(&str, wrap_at);
             }
         }
 
@@ -1092,9 +1158,15 @@ impl Style {
             str = self.apply_padding(&str, profile, dark_bg);
         }
 
+        // Square off the block so the background covers it
+        let fill = !is_inline && self.attrs.contains(Attrs::FILL);
+        if fill {
+            str = self.apply_fill(&str, profile, dark_bg);
+        }
+
         // Apply height
         if self.props.contains(Props::HEIGHT) && self.height > 0 {
-            str = self.apply_height(&str);
+            str = self.apply_height(&str, fill, profile, dark_bg);
         }
 
         // Apply width/alignment
@@ -1123,9 +1195,10 @@ impl Style {
         str
     }
 
-    /// Convert tabs in the string according to tab_width setting.
-    /// Takes ownership to avoid allocation when no conversion is needed.
-    fn maybe_convert_tabs(&self, s: String) -> String {
+    /// Convert tabs in the string according to the tab width and tab stop
+    /// settings. Takes ownership to avoid allocation when no conversion is
+    /// needed.
+    pub(crate) fn convert_tabs(&self, s: String) -> String {
         let tw = if self.props.contains(Props::TAB_WIDTH) {
             self.tab_width
         } else {
@@ -1139,6 +1212,7 @@ impl Style {
 
         match tw {
             0 => s.replace('\t', ""),
+            n if self.attrs.contains(Attrs::TAB_STOPS) => crate::expand_tabs(&s, n as usize),
             n => s.replace('\t', &" ".repeat(n as usize)),
         }
     }
@@ -1240,7 +1314,36 @@ impl Style {
         result
     }
 
-    fn apply_height(&self, s: &str) -> String {
+    /// Pads every line to the width of the widest one, in the background
+    /// color, following the horizontal alignment.
+    fn apply_fill(&self, s: &str, profile: ColorProfile, dark_bg: bool) -> String {
+        let widths: Vec<usize> = s.lines().map(visible_width).collect();
+        let block_width = widths.iter().copied().max().unwrap_or(0);
+        let ws_style = self
+            .bg_color
+            .as_ref()
+            .map(|bg| bg.to_ansi_bg(profile, dark_bg));
+        let pad = |n: usize| match &ws_style {
+            Some(style) if n > 0 => format!("{}{}\x1b[0m", style, " ".repeat(n)),
+            _ => " ".repeat(n),
+        };
+
+        let factor = self.align_horizontal.factor();
+        let mut result = String::with_capacity(s.len() + widths.len() * 20);
+        for (i, (line, width)) in s.lines().zip(widths).enumerate() {
+            if i > 0 {
+                result.push('\n');
+            }
+            let extra = block_width - width;
+            let left = (extra as f64 * factor).round() as usize;
+            result.push_str(&pad(left));
+            result.push_str(line);
+            result.push_str(&pad(extra - left));
+        }
+        result
+    }
+
+    fn apply_height(&self, s: &str, fill: bool, profile: ColorProfile, dark_bg: bool) -> String {
         let lines: Vec<&str> = s.lines().collect();
         let current_height = lines.len();
         let target_height = self.height as usize;
@@ -1251,7 +1354,14 @@ impl Style {
 
         // Calculate content width for blank lines
         let content_width = lines.iter().map(|l| visible_width(l)).max().unwrap_or(0);
-        let blank_line = " ".repeat(content_width);
+        let blank_line = match &self.bg_color {
+            Some(bg) if fill && content_width > 0 => format!(
+                "{}{}\x1b[0m",
+                bg.to_ansi_bg(profile, dark_bg),
+                " ".repeat(content_width)
+            ),
+            _ => " ".repeat(content_width),
+        };
 
         let extra = target_height - current_height;
         let factor = self.align_vertical.factor();
@@ -1920,6 +2030,20 @@ impl Style {
         self
     }
 
+    /// Removes the no-wrap style rule.
+    pub fn unset_no_wrap(mut self) -> Self {
+        self.props.remove(Props::NO_WRAP);
+        self.attrs.remove(Attrs::NO_WRAP);
+        self
+    }
+
+    /// Removes the fill style rule.
+    pub fn unset_fill(mut self) -> Self {
+        self.props.remove(Props::FILL);
+        self.attrs.remove(Attrs::FILL);
+        self
+    }
+
     /// Removes the tab width style rule.
     pub fn unset_tab_width(mut self) -> Self {
         self.props.remove(Props::TAB_WIDTH);
@@ -1927,6 +2051,13 @@ impl Style {
         self
     }
 
+    /// Removes the tab stops style rule.
+    pub fn unset_tab_stops(mut self) -> Self {
+        self.props.remove(Props::TAB_STOPS);
+        self.attrs.remove(Attrs::TAB_STOPS);
+        self
+    }
+
     /// Removes the underline spaces value.
     pub fn unset_underline_spaces(mut self) -> Self {
         self.props.remove(Props::UNDERLINE_SPACES);
@@ -1965,49 +2096,6 @@ impl std::fmt::Display for Style {
 // Helper functions
 // Note: visible_width is imported from crate root (canonical implementation)
 
-/// Simple text wrapping.
-fn wrap_text(s: &str, width: usize) -> String {
-    if width == 0 {
-        return s.to_string();
-    }
-
-    let mut result = Vec::new();
-    for line in s.lines() {
-        if visible_width(line) <= width {
-            result.push(line.to_string());
-        } else {
-            // Word wrap preserving whitespace
-            let words: Vec<&str> = line.split(' ').collect();
-            let mut current_line = String::new();
-            let mut current_width = 0;
-
-            for (i, word) in words.iter().enumerate() {
-                let word_width = visible_width(word);
-                // Account for the space separator if this isn't the first word
-                let space_width = if i > 0 { 1 } else { 0 };
-
-                if current_width + space_width + word_width > width && current_width > 0 {
-                    result.push(current_line);
-                    current_line = word.to_string();
-                    current_width = word_width;
-                } else {
-                    if i > 0 {
-                        current_line.push(' ');
-                        current_width += 1;
-                    }
-                    current_line.push_str(word);
-                    current_width += word_width;
-                }
-            }
-            if !current_line.is_empty() {
-                result.push(current_line);
-            }
-        }
-    }
-
-    result.join("\n")
-}
-
 /// Truncate a single line to max_width, preserving ANSI escape sequences.
 ///
 /// This function handles:
@@ -2671,6 +2759,39 @@ mod tests {
         // Content should be in the middle
     }
 
+    #[test]
+    fn test_fill_pads_lines_to_widest() {
+        let bg = "\x1b[48;5;21m";
+        let rendered = Style::new().background("21").fill(true).render("a\nabc");
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert!(lines.iter().all(|line| visible_width(line) == 3));
+        assert!(
+            lines[0].ends_with(&format!("{bg}  \x1b[0m")),
+            "{rendered:?}"
+        );
+
+        let unfilled = Style::new().background("21").render("a\nabc");
+        assert_eq!(visible_width(unfilled.lines().next().unwrap()), 1);
+    }
+
+    #[test]
+    fn test_fill_colors_blank_lines_to_height() {
+        let bg = "\x1b[48;5;21m";
+        let style = Style::new().background("21").width(6).height(3);
+
+        let rendered = style.clone().fill(true).render("hi");
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for line in &lines {
+            assert_eq!(visible_width(line), 6);
+            assert!(line.starts_with(bg), "{rendered:?}");
+            assert!(!line.contains("\x1b[0m "), "{rendered:?}");
+        }
+
+        let unfilled = style.render("hi");
+        assert!(unfilled.lines().nth(1).unwrap().contains("\x1b[0m  "));
+    }
+
     #[test]
     fn test_max_width_truncates() {
         let style = Style::new().max_width(5);
@@ -3199,6 +3320,29 @@ mod tests {
         assert!(rendered.contains('\t'));
     }
 
+    #[test]
+    fn test_render_tab_stops_align_columns() {
+        let style = Style::new().tab_stops(true);
+        assert_eq!(style.render("a\tb\nabc\tb"), "a   b\nabc b");
+
+        let style = style.unset_tab_stops();
+        assert_eq!(style.render("abc\tb"), "abc    b");
+    }
+
+    #[test]
+    fn test_tabs_expand_before_width_calculations() {
+        let style = Style::new().tab_width(4).width(10).padding_left(1);
+        let rendered = style.render("a\tb");
+        assert_eq!(rendered, " a    b   ");
+        assert_eq!(crate::width(&rendered), 10);
+
+        let aligned = Style::new()
+            .tab_stops(true)
+            .width(12)
+            .render("ab\tc\nabcd\te");
+        assert_eq!(aligned, "ab  c       \nabcd    e   ");
+    }
+
     #[test]
     fn test_inline_mode_removes_newlines() {
         let style = Style::new().inline();
@@ -3296,6 +3440,29 @@ mod tests {
         assert!(rendered.lines().count() >= 4);
     }
 
+    #[test]
+    fn test_width_wrap_preserves_inner_styles() {
+        let inner = Style::new().bold().render("bold words here");
+        let rendered = Style::new().width(6).render(&inner);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for line in &lines {
+            assert!(line.contains("\x1b[1m"), "line lost bold: {line:?}");
+            assert_eq!(visible_width(line), 6);
+        }
+    }
+
+    #[test]
+    fn test_no_wrap_pads_without_wrapping() {
+        let style = Style::new().width(4).no_wrap();
+        assert_eq!(style.render("overflowing"), "overflowing");
+        assert_eq!(style.render("ab"), "ab  ");
+        assert_eq!(
+            style.unset_no_wrap().render("overflowing").lines().count(),
+            3
+        );
+    }
+
     #[test]
     fn test_width_with_wrapping() {
         let style = Style::new().width(10);
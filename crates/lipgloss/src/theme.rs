@@ -945,6 +945,12 @@ impl ThemedStyle {
         self
     }
 
+    /// Expand tabs to the next tab stop.
+    pub fn tab_stops(mut self, v: bool) -> Self {
+        self.base_style = self.base_style.tab_stops(v);
+        self
+    }
+
     /// Apply a transform function to the rendered string.
     pub fn transform<F>(mut self, f: F) -> Self
     where
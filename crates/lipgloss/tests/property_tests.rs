@@ -126,21 +126,34 @@ proptest! {
     }
 
     #[test]
-    fn width_never_less_than_content_single_word(
+    fn width_breaks_single_word_to_target(
         content in "[a-zA-Z]{1,15}",
         target_width in 1u16..200,
     ) {
         let style = Style::new().width(target_width);
         let rendered = style.render(&content);
         let rendered_w = output_width(&rendered);
-        let content_w = visible_width(&content);
 
-        // Output width should be at least content width (single word can't wrap)
-        prop_assert!(
-            rendered_w >= content_w,
-            "Output width {} < content width {} for '{}'",
-            rendered_w, content_w, content
+        // Words longer than the target are broken, so the output is exactly
+        // the target width either way
+        prop_assert_eq!(
+            rendered_w, target_width as usize,
+            "Output width {} != target {} for '{}'",
+            rendered_w, target_width, content
         );
+        prop_assert_eq!(rendered.replace(['\n', ' '], ""), content);
+    }
+
+    #[test]
+    fn no_wrap_keeps_single_word_intact(
+        content in "[a-zA-Z]{1,15}",
+        target_width in 1u16..200,
+    ) {
+        let style = Style::new().width(target_width).no_wrap();
+        let rendered = style.render(&content);
+
+        prop_assert_eq!(rendered.lines().count(), 1);
+        prop_assert!(output_width(&rendered) >= visible_width(&content));
     }
 }
 
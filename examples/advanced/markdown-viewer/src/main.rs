@@ -11,7 +11,7 @@
 #![forbid(unsafe_code)]
 
 use bubbles::viewport::Viewport;
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Program, quit};
+use bubbletea::{Cmd, KeyMod, KeyMsg, KeyType, Message, Program, quit};
 use glamour::{Renderer, Style as GlamourStyle};
 use lipgloss::Style;
 
@@ -257,6 +257,7 @@ mod tests {
             runes: vec![ch],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -267,6 +268,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -23,7 +23,7 @@
 #![forbid(unsafe_code)]
 
 use bubbles::viewport::Viewport;
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Program, quit};
+use bubbletea::{Cmd, KeyMod, KeyMsg, KeyType, Message, Program, quit};
 use lipgloss::Style;
 
 /// Menu items for the sidebar.
@@ -357,6 +357,7 @@ mod tests {
             runes: vec![ch],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -367,6 +368,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
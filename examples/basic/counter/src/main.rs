@@ -7,7 +7,7 @@
 
 #![forbid(unsafe_code)]
 
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Program, quit};
+use bubbletea::{Cmd, KeyMod, KeyMsg, KeyType, Message, Program, quit};
 
 /// The application model using the derive macro.
 ///
@@ -81,6 +81,7 @@ mod tests {
             runes: vec![ch],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -91,6 +92,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -10,7 +10,7 @@
 #![forbid(unsafe_code)]
 
 use bubbles::spinner::{SpinnerModel, spinners};
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Model, Program, quit};
+use bubbletea::{Cmd, KeyMod, KeyMsg, KeyType, Message, Model, Program, quit};
 use lipgloss::Style;
 
 /// Application model that wraps the spinner component.
@@ -93,6 +93,7 @@ mod tests {
             runes: vec![ch],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -103,6 +104,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -10,7 +10,7 @@
 #![forbid(unsafe_code)]
 
 use bubbles::textinput::TextInput;
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Model, Program, quit};
+use bubbletea::{Cmd, KeyMod, KeyMsg, KeyType, Message, Model, Program, quit};
 use lipgloss::Style;
 
 /// Application model that wraps the text input component.
@@ -105,6 +105,7 @@ mod tests {
             runes: vec![ch],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -115,6 +116,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
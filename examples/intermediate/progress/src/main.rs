@@ -11,7 +11,7 @@
 #![forbid(unsafe_code)]
 
 use bubbles::progress::Progress;
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Program, quit, tick};
+use bubbletea::{Cmd, KeyMod, KeyMsg, KeyType, Message, Program, quit, tick};
 use lipgloss::Style;
 use std::time::{Duration, Instant};
 
@@ -190,6 +190,7 @@ mod tests {
             runes: vec![ch],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -200,6 +201,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
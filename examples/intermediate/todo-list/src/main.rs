@@ -10,7 +10,7 @@
 
 #![forbid(unsafe_code)]
 
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Program, quit};
+use bubbletea::{Cmd, KeyMod, KeyMsg, KeyType, Message, Program, quit};
 use lipgloss::Style;
 
 /// A single todo item.
@@ -241,6 +241,7 @@ mod tests {
             runes: vec![ch],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -251,6 +252,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
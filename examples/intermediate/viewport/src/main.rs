@@ -11,7 +11,7 @@
 #![forbid(unsafe_code)]
 
 use bubbles::viewport::Viewport;
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Program, quit};
+use bubbletea::{Cmd, KeyMod, KeyMsg, KeyType, Message, Program, quit};
 use lipgloss::Style;
 
 /// Sample content to display in the viewport.
@@ -218,6 +218,7 @@ mod tests {
             runes: vec![ch],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -228,6 +229,7 @@ mod tests {
             runes: vec![],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
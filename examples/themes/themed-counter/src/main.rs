@@ -11,7 +11,7 @@
 
 use std::sync::Arc;
 
-use bubbletea::{Cmd, KeyMsg, KeyType, Message, Program, quit};
+use bubbletea::{Cmd, KeyMod, KeyMsg, KeyType, Message, Program, quit};
 use lipgloss::{Border, ColorSlot, ThemeContext, ThemePreset, ThemedStyle};
 
 /// Application styles organized in one place.
@@ -164,6 +164,7 @@ mod tests {
             runes: vec![ch],
             alt: false,
             paste: false,
+            modifiers: KeyMod::NONE,
         })
     }
 
@@ -17,7 +17,7 @@
 use crate::harness::{FixtureLoader, TestFixture};
 use bubbletea::message::{BatchMsg, SequenceMsg};
 use bubbletea::{
-    Cmd, KeyMsg, KeyType, Message, MouseAction, MouseButton, MouseMsg, batch,
+    Cmd, KeyMod, KeyMsg, KeyType, Message, MouseAction, MouseButton, MouseMsg, batch,
     parse_mouse_event_sequence, parse_sequence, sequence,
 };
 use serde::Deserialize;
@@ -551,6 +551,7 @@ fn run_key_string_test(fixture: &TestFixture) -> Result<(), String> {
         runes,
         alt: input.alt,
         paste: input.paste,
+        modifiers: KeyMod::NONE,
     };
 
     let actual_string = key.to_string();
@@ -251,6 +251,9 @@ func captureBlockquoteTests(fs *capture.FixtureSet) {
 		{"multi_paragraph", "> Paragraph 1\n>\n> Paragraph 2"},
 		{"nested", "> Outer\n>> Inner\n> Back to outer"},
 		{"with_formatting", "> **Bold** in quote\n> *Italic* in quote"},
+		{"double_marker", "> > Quoted twice."},
+		{"in_list_item", "- item\n\n  > quoted in item"},
+		{"in_nested_list_item", "- outer\n  1. inner\n\n     > quote"},
 	}
 
 	for _, q := range quotes {